@@ -40,7 +40,7 @@ const httpSpecsGroup = {
   'spector_jmergepatch': {input: 'payload/json-merge-patch'},
   'spector_corepageable': {input: 'payload/pageable'},
   'spector_mediatype': {input: 'payload/media-type'},
-  //'spector_multipart': {input: 'payload/multipart'},
+  'spector_multipart': {input: 'payload/multipart'},
   'spector_xml': {input: 'payload/xml'},
   'spector_routes': {input: 'routes'},
   'spector_jsonencodedname': {input: 'serialization/encoded-name/json'},
@@ -49,8 +49,8 @@ const httpSpecsGroup = {
   'spector_single': {input: 'server/path/single'},
   'spector_unversioned': {input: 'server/versions/not-versioned'},
   'spector_versioned': {input: 'server/versions/versioned'},
-  //'spector_condreq': {input: 'special-headers/conditional-request'},
-  //'spector_repeatability': {input: 'special-headers/repeatability'},
+  'spector_condreq': {input: 'special-headers/conditional-request'},
+  'spector_repeatability': {input: 'special-headers/repeatability'},
   'spector_specialwords': {input: 'special-words'},
   'spector_array': {input: 'type/array'},           // needs additional codegen work before we can add tests
   'spector_dictionary': {input: 'type/dictionary'}, // needs additional codegen work before we can add tests
@@ -59,24 +59,24 @@ const httpSpecsGroup = {
   'spector_empty': {input: 'type/model/empty'},
   'spector_enumdisc': {input: 'type/model/inheritance/enum-discriminator'},
   'spector_nodisc': {input: 'type/model/inheritance/not-discriminated'},
-  //'spector_nesteddisc': {input: 'type/model/inheritance/nested-discriminator'},
+  'spector_nesteddisc': {input: 'type/model/inheritance/nested-discriminator'},
   'spector_recursive': {input: 'type/model/inheritance/recursive'},
   'spector_singledisc': {input: 'type/model/inheritance/single-discriminator'},
   'spector_usage': {input: 'type/model/usage'},
   'spector_visibility': {input: 'type/model/visibility'},
-  //'spector_addlprops': {input: 'type/property/additional-properties'},
+  'spector_addlprops': {input: 'type/property/additional-properties'},
   'spector_nullable': {input: 'type/property/nullable'},
   'spector_optionality': {input: 'type/property/optionality'},
   'spector_valuetypes': {input: 'type/property/value-types'},
   'spector_scalar': {input: 'type/scalar'},
   'spector_union_nondiscriminated': {input: 'type/union', output: 'type/union/non-discriminated'},
   'spector_union_discriminated': {input: 'type/union/discriminated'},
-  //'spector_veradded': {input: 'versioning/added'},
+  'spector_veradded': {input: 'versioning/added'},
   'spector_madeoptional': {input: 'versioning/madeOptional'},
-  //'spector_verremoved': {input: 'versioning/removed'},
-  //'spector_renamedfrom': {input: 'versioning/renamedFrom'},
-  //'spector_returntypechanged': {input: 'versioning/returnTypeChangedFrom'},
-  //'spector_typechanged': {input: 'versioning/typeChangedFrom'},
+  'spector_verremoved': {input: 'versioning/removed'},
+  'spector_renamedfrom': {input: 'versioning/renamedFrom'},
+  'spector_returntypechanged': {input: 'versioning/returnTypeChangedFrom'},
+  'spector_typechanged': {input: 'versioning/typeChangedFrom'},
 };
 
 const azureHttpSpecsGroup = {
@@ -130,6 +130,7 @@ const azureHttpSpecsGroup = {
   'spector_srvdrivenold': {input: 'resiliency/srv-driven/old.tsp', output: 'resiliency/srv-driven/old'},
   'spector_srvdrivennew': {input: 'resiliency/srv-driven', output: 'resiliency/srv-driven/new'},
   'spector_multi_service': {input: 'service/multi-service/client.tsp'},
+  'spector_multi_service_features': {input: 'service/multi-service/client.tsp', output: 'service/multi-service-features', args: ['sub-module-features=true']},
 };
 
 const args = process.argv.slice(2);
@@ -192,6 +193,9 @@ generate('colliding_locals', colliding_locals, 'test/other/colliding_locals');
 const lro = pkgRoot + 'test/tsp/lro';
 generate('lro', lro, 'test/other/lro');
 
+const method_errors = pkgRoot + 'test/tsp/MethodErrors';
+generate('method_errors', method_errors, 'test/other/method_errors', ['emit-error-traits=true']);
+
 const misc_tests = pkgRoot + 'test/tsp/MiscTests';
 generate('misc_tests', misc_tests, 'test/other/misc_tests');
 
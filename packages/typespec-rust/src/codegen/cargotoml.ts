@@ -3,30 +3,569 @@
 *  Licensed under the MIT License. See License.txt in the project root for license information.
 *--------------------------------------------------------------------------------------------*/
 
+import { CodegenError } from './errors.js';
 import * as rust from '../codemodel/index.js';
+import { parse as parseToml } from 'smol-toml';
+
+/** the version requirement used for dependencies with no known version */
+const placeholderVersion = '*';
+
+/**
+ * called for each dependency with no known version. the dependency's version is set to *
+ * 
+ * @param name the name of the dependency
+ */
+export type UnknownVersionCallback = (name: string) => void;
+
+/**
+ * the dependency versions used when the crate isn't a member of a workspace.
+ * the version of azure_core is specified by the crate.
+ * third-party versions should be kept in sync with the workspace dependencies in test/Cargo.toml.
+ */
+const standaloneDependencies: Record<string, { version: string, features?: Array<string> }> = {
+  'async-trait': { version: '0.1.89' },
+  'bytes': { version: '1.11.1' },
+  'futures': { version: '0.3.31' },
+  'geojson': { version: '0.24.2' },
+  'regex': { version: '1.11.1' },
+  'rust_decimal': { version: '1.37.2' },
+  'serde': { version: '1.0.228', features: ['derive'] },
+  'serde_json': { version: '1.0.149' },
+  'time': { version: '0.3.47', features: ['serde-well-known'] },
+};
 
 /**
  * emits the Cargo.toml file for the provided crate
  * 
  * @param crate the crate for which to emit a Cargo.toml file
+ * @param onUnknownVersion called for each dependency with no known version
  * @returns the contents of the Cargo.toml file
  */
-export function emitCargoToml(crate: rust.Crate): string {
+export function emitCargoToml(crate: rust.Crate, onUnknownVersion?: UnknownVersionCallback): string {
   let content = `[package]\nname = "${crate.name}"\nversion = "${crate.version}"\n`;
-  content += 'authors.workspace = true\n';
-  content += 'edition.workspace = true\n';
-  content += 'license.workspace = true\n';
-  content += 'repository.workspace = true\n';
-  content += 'rust-version.workspace = true\n\n';
+  if (crate.workspace) {
+    content += 'authors.workspace = true\n';
+    content += 'edition.workspace = true\n';
+    content += 'license.workspace = true\n';
+    content += 'repository.workspace = true\n';
+    content += 'rust-version.workspace = true\n\n';
+  } else {
+    content += `edition = "${crate.standalone.edition}"\n`;
+    if (crate.standalone.license) {
+      content += `license = "${crate.standalone.license}"\n`;
+    }
+    content += `rust-version = "${crate.standalone.rustVersion}"\n\n`;
+  }
   content += '[features]\n';
   content += 'default = ["azure_core/default"]\n';
+  for (const feature of crate.features.slice().sort((a, b) => a.name.localeCompare(b.name))) {
+    content += `${feature.name} = [${feature.enables.map((each) => `"${each.name}"`).sort().join(', ')}]\n`;
+  }
   if (crate.dependencies.length > 0) {
     content += '\n[dependencies]\n';
     for (const dependency of crate.dependencies) {
-      // dependency versions are managed by the workspace's Cargo.toml file
-      const features = dependency.features.length > 0 ? `, features = [${dependency.features.sort().map(f => `"${f}"`).join(', ')}]` : '';
-      content += `${dependency.name} = { workspace = true${features} }\n`;
+      content += `${formatDependency(crate, dependency, crate.workspace, onUnknownVersion)}\n`;
     }
   }
   return content;
 }
+
+/**
+ * merges the dependencies and features for the provided crate into the contents of an existing Cargo.toml file.
+ * missing dependencies, dependency features, and features are added and outdated dependency versions are updated.
+ * dependencies can be in [dependencies] or any [target.*.dependencies] table. whether dependencies are inherited
+ * from a Cargo workspace is determined by the existing file. key/value pairs that don't change are preserved as is,
+ * including comments. note that nothing is ever removed.
+ * 
+ * @param crate the crate for which to merge the Cargo.toml file
+ * @param existing the contents of the existing Cargo.toml file
+ * @param onUnknownVersion called for each added dependency with no known version
+ * @returns the contents of the merged Cargo.toml file
+ */
+export function mergeCargoToml(crate: rust.Crate, existing: string, onUnknownVersion?: UnknownVersionCallback): string {
+  const manifest = tryParseToml(existing);
+  if (!manifest) {
+    throw new CodegenError('InvalidArgument', 'the existing Cargo.toml file isn\'t valid TOML');
+  }
+
+  const workspace = usesWorkspace(manifest) ?? crate.workspace;
+  const eol = existing.includes('\r\n') ? '\r\n' : '\n';
+  const lines = existing.split(/\r?\n/);
+
+  for (const dependency of crate.dependencies) {
+    const features = getDependencyFeatures(crate, dependency, workspace);
+    // dependency versions for workspace members are managed by the workspace's Cargo.toml file
+    const version = workspace ? undefined : getDependencyVersion(crate, dependency);
+
+    // the dependency can be in [dependencies] or any target-specific dependencies table
+    let found = false;
+    for (const depsTable of getDependenciesTables(manifest)) {
+      const value = getTable(manifest, depsTable)?.[dependency.name];
+      if (value !== undefined) {
+        mergeDependency(lines, [...depsTable, dependency.name], value, features, version);
+        found = true;
+      }
+    }
+    if (!found) {
+      setValue(lines, ['dependencies', dependency.name], getDependencyValue(crate, dependency, workspace, onUnknownVersion));
+    }
+  }
+
+  const existingFeatures = getTable(manifest, ['features']);
+  for (const feature of crate.features.slice().sort((a, b) => a.name.localeCompare(b.name))) {
+    const enables = feature.enables.map((each) => each.name).sort();
+    const existingEnables = existingFeatures?.[feature.name];
+    if (existingEnables === undefined) {
+      setValue(lines, ['features', feature.name], enables);
+    } else if (Array.isArray(existingEnables)) {
+      const missing = enables.filter((each) => !existingEnables.includes(each));
+      if (missing.length > 0) {
+        setValue(lines, ['features', feature.name], [...existingEnables, ...missing]);
+      }
+    }
+  }
+
+  const merged = lines.join(eol);
+  if (!tryParseToml(merged)) {
+    throw new CodegenError('InternalError', 'merging the existing Cargo.toml file produced invalid TOML');
+  }
+  return merged;
+}
+
+/** a TOML table as returned by the parser */
+type TomlTable = ReturnType<typeof parseToml>;
+
+/** any TOML value as returned by the parser */
+type TomlValue = TomlTable[string];
+
+/** a table header or key/value pair in a TOML file */
+interface TomlStatement {
+  kind: 'header' | 'keyValue';
+
+  /** the index of the statement's first line */
+  start: number;
+
+  /** the index after the statement's last line */
+  end: number;
+
+  /** the full path of the table or key, e.g. ['dependencies', 'serde', 'features'] */
+  path: Array<string>;
+
+  /** the path of the table that contains the statement. for headers this is the same as path */
+  table: Array<string>;
+}
+
+/**
+ * merges the features and version into the existing definition of a dependency
+ * 
+ * @param lines the lines of the Cargo.toml file
+ * @param path the path of the dependency, e.g. ['dependencies', 'serde']
+ * @param value the dependency's existing value
+ * @param features the features the dependency requires. can be empty
+ * @param version the version the dependency requires or undefined if the version isn't managed
+ */
+function mergeDependency(lines: Array<string>, path: Array<string>, value: TomlValue, features: Array<string>, version?: string): void {
+  const existingVersion = typeof value === 'string' ? value : isTable(value) && typeof value.version === 'string' ? value.version : undefined;
+  const newVersion = version !== undefined && existingVersion !== undefined && isOutdated(existingVersion, version) ? version : undefined;
+  const existingFeatures = isTable(value) && Array.isArray(value.features) ? value.features : [];
+  const missing = features.filter((feature) => !existingFeatures.includes(feature));
+  if (newVersion === undefined && missing.length === 0) {
+    return;
+  }
+
+  if (parseStatements(lines).find((statement) => statement.kind === 'keyValue' && equalPaths(statement.path, path))) {
+    // a single key/value pair, e.g. serde = "1.0" or serde = { workspace = true }
+    if (typeof value === 'string') {
+      setValue(lines, path, missing.length > 0 ? { version: newVersion ?? value, features: missing } : newVersion!);
+    } else {
+      const merged: TomlTable = { ...(<TomlTable>value) };
+      if (newVersion) {
+        merged.version = newVersion;
+      }
+      if (missing.length > 0) {
+        merged.features = [...existingFeatures, ...missing];
+      }
+      setValue(lines, path, merged);
+    }
+    return;
+  }
+
+  // dotted keys or a dependency table, e.g. serde.workspace = true or [dependencies.serde]
+  if (newVersion) {
+    setValue(lines, [...path, 'version'], newVersion);
+  }
+  if (missing.length > 0) {
+    setValue(lines, [...path, 'features'], [...existingFeatures, ...missing]);
+  }
+}
+
+/**
+ * sets the value for the specified key. an existing key/value pair is replaced and keeps
+ * its trailing comment. a new key/value pair is inserted after the last key/value pair
+ * in the key's parent table, or the table is appended to the end of the file.
+ * 
+ * @param lines the lines of the Cargo.toml file
+ * @param path the full path of the key, e.g. ['dependencies', 'serde']
+ * @param value the value to set
+ */
+function setValue(lines: Array<string>, path: Array<string>, value: TomlValue): void {
+  const statements = parseStatements(lines);
+  const formatEntry = (table: Array<string>): string => `${formatKeyPath(path.slice(table.length))} = ${formatValue(value)}`;
+
+  const existing = statements.find((statement) => statement.kind === 'keyValue' && equalPaths(statement.path, path));
+  if (existing) {
+    const indent = lines[existing.start].match(/^\s*/)![0];
+    lines.splice(existing.start, existing.end - existing.start, `${indent}${formatEntry(existing.table)}${getTrailingComment(lines, existing)}`);
+    return;
+  }
+
+  // siblings can be in the parent table or any table that contains it via dotted keys.
+  // any comments after the last sibling are assumed to belong to the next table.
+  const parent = path.slice(0, -1);
+  const siblings = statements.filter((statement) => statement.kind === 'keyValue' && startsWithPath(parent, statement.table) && startsWithPath(statement.path, parent));
+  const anchor = siblings.at(-1) ?? statements.find((statement) => statement.kind === 'header' && equalPaths(statement.path, parent));
+  if (anchor) {
+    lines.splice(anchor.end, 0, formatEntry(anchor.table));
+    return;
+  }
+
+  // keep any trailing newline at the end of the file
+  let index = lines.length;
+  while (index > 0 && lines[index - 1].trim() === '') {
+    --index;
+  }
+  const newLines = [`[${formatKeyPath(parent)}]`, formatEntry(parent)];
+  if (index > 0) {
+    newLines.unshift('');
+  }
+  lines.splice(index, 0, ...newLines);
+}
+
+/**
+ * splits the lines of a TOML file into its table headers and key/value pairs.
+ * a statement ends at the first line at which it's valid TOML on its own,
+ * so values that span multiple lines (e.g. arrays) are a single statement.
+ * 
+ * @param lines the lines of the TOML file
+ * @returns the statements in the file
+ */
+function parseStatements(lines: Array<string>): Array<TomlStatement> {
+  const statements = new Array<TomlStatement>();
+  let table = new Array<string>();
+  for (let start = 0; start < lines.length;) {
+    const trimmed = lines[start].trim();
+    if (trimmed === '' || trimmed.startsWith('#')) {
+      ++start;
+      continue;
+    }
+
+    let end = start + 1;
+    let parsed = tryParseToml(lines.slice(start, end).join('\n'));
+    while (!parsed && end < lines.length) {
+      parsed = tryParseToml(lines.slice(start, ++end).join('\n'));
+    }
+    if (!parsed) {
+      throw new CodegenError('InternalError', `failed to parse the Cargo.toml statement on line ${start + 1}`);
+    }
+
+    const text = lines.slice(start, end).join('\n');
+    if (trimmed.startsWith('[')) {
+      // headers are parsed as empty tables, e.g. [dependencies.serde] is { dependencies: { serde: {} } }
+      // and array of tables headers contain an array, e.g. [[bin]] is { bin: [{}] }
+      const path = new Array<string>();
+      let current: TomlValue = parsed;
+      while (isTable(current) && Object.keys(current).length === 1) {
+        const key = Object.keys(current)[0];
+        path.push(key);
+        current = current[key];
+      }
+      table = path;
+      statements.push({ kind: 'header', start, end, path, table });
+    } else {
+      // a key/value pair with a table value is either a dotted key or an inline table,
+      // e.g. serde.workspace = true or serde = { workspace = true }. only dotted keys
+      // define tables that can be extended with additional keys.
+      const keyPath = new Array<string>();
+      let current: TomlValue = parsed;
+      do {
+        const key = Object.keys(<TomlTable>current)[0];
+        keyPath.push(key);
+        current = (<TomlTable>current)[key];
+      } while (isTable(current) && Object.keys(current).length === 1 && tryParseToml(`${text}\n${formatKeyPath([...keyPath, '__probe'])} = 0`));
+      statements.push({ kind: 'keyValue', start, end, path: [...table, ...keyPath], table });
+    }
+    start = end;
+  }
+  return statements;
+}
+
+/**
+ * returns the comment at the end of the statement's last line, including its leading whitespace
+ * 
+ * @param lines the lines of the TOML file
+ * @param statement the statement that might have a trailing comment
+ * @returns the trailing comment or the empty string
+ */
+function getTrailingComment(lines: Array<string>, statement: TomlStatement): string {
+  const head = lines.slice(statement.start, statement.end - 1);
+  const last = lines[statement.end - 1];
+  // a # within a string isn't a comment and removing it makes the statement invalid
+  for (let index = last.indexOf('#'); index >= 0; index = last.indexOf('#', index + 1)) {
+    const beforeComment = last.substring(0, index);
+    if (tryParseToml([...head, beforeComment].join('\n'))) {
+      return last.substring(beforeComment.trimEnd().length);
+    }
+  }
+  return '';
+}
+
+/**
+ * returns true if the manifest's package fields or dependencies are inherited from a Cargo workspace.
+ * 
+ * @param manifest the parsed Cargo.toml file
+ * @returns true or false if the manifest does or doesn't use a workspace, or undefined if it can't be determined
+ */
+function usesWorkspace(manifest: TomlTable): boolean | undefined {
+  const inherits = (value: TomlValue | undefined): boolean => isTable(value) && value.workspace === true;
+  const pkg = getTable(manifest, ['package']);
+  if (pkg && Object.values(pkg).some(inherits)) {
+    return true;
+  }
+
+  let hasDependencies = false;
+  for (const depsTable of getDependenciesTables(manifest, true)) {
+    const dependencies = Object.values(getTable(manifest, depsTable) ?? {});
+    if (dependencies.some(inherits)) {
+      return true;
+    }
+    hasDependencies ||= dependencies.length > 0;
+  }
+  return hasDependencies ? false : undefined;
+}
+
+/**
+ * returns the paths of the dependencies tables in the manifest,
+ * i.e. [dependencies] and any [target.*.dependencies] tables.
+ * 
+ * @param manifest the parsed Cargo.toml file
+ * @param includeDevAndBuild includes [dev-dependencies] and [build-dependencies] tables when true
+ * @returns the paths of the dependencies tables. can be empty
+ */
+function getDependenciesTables(manifest: TomlTable, includeDevAndBuild = false): Array<Array<string>> {
+  const names = includeDevAndBuild ? ['dependencies', 'dev-dependencies', 'build-dependencies'] : ['dependencies'];
+  const tables = new Array<Array<string>>();
+  for (const name of names) {
+    if (getTable(manifest, [name])) {
+      tables.push([name]);
+    }
+    for (const target of Object.keys(getTable(manifest, ['target']) ?? {})) {
+      if (getTable(manifest, ['target', target, name])) {
+        tables.push(['target', target, name]);
+      }
+    }
+  }
+  return tables;
+}
+
+/**
+ * returns the table at the specified path
+ * 
+ * @param table the table to search
+ * @param path the path of the nested table
+ * @returns the nested table or undefined if it doesn't exist
+ */
+function getTable(table: TomlTable, path: Array<string>): TomlTable | undefined {
+  let current: TomlValue = table;
+  for (const key of path) {
+    if (!isTable(current)) {
+      return undefined;
+    }
+    current = current[key];
+  }
+  return isTable(current) ? current : undefined;
+}
+
+/**
+ * returns true if the value is a table (i.e. not a primitive, date, or array)
+ * 
+ * @param value the value to check
+ * @returns true for a table
+ */
+function isTable(value: TomlValue | undefined): value is TomlTable {
+  return typeof value === 'object' && value !== null && !Array.isArray(value) && !(value instanceof Date);
+}
+
+/**
+ * parses the provided TOML
+ * 
+ * @param toml the TOML to parse
+ * @returns the parsed table or undefined if the TOML isn't valid
+ */
+function tryParseToml(toml: string): TomlTable | undefined {
+  try {
+    return parseToml(toml);
+  } catch {
+    return undefined;
+  }
+}
+
+/**
+ * formats the provided value as an inline TOML value
+ * 
+ * @param value the value to format
+ * @returns the TOML value
+ */
+function formatValue(value: TomlValue): string {
+  if (typeof value === 'string') {
+    // JSON strings are valid TOML basic strings
+    return JSON.stringify(value);
+  } else if (Array.isArray(value)) {
+    return `[${value.map(formatValue).join(', ')}]`;
+  } else if (value instanceof Date) {
+    return value.toISOString();
+  } else if (isTable(value)) {
+    const entries = Object.entries(value).map(([key, each]) => `${formatKey(key)} = ${formatValue(each)}`);
+    return entries.length > 0 ? `{ ${entries.join(', ')} }` : '{}';
+  }
+  return String(value);
+}
+
+/**
+ * formats the provided key path as a dotted TOML key
+ * 
+ * @param path the key path to format
+ * @returns the dotted key
+ */
+function formatKeyPath(path: Array<string>): string {
+  return path.map(formatKey).join('.');
+}
+
+/**
+ * formats the provided key as a bare key or, if that's not possible, a quoted key
+ * 
+ * @param key the key to format
+ * @returns the TOML key
+ */
+function formatKey(key: string): string {
+  return key.match(/^[A-Za-z0-9_-]+$/) ? key : JSON.stringify(key);
+}
+
+/**
+ * returns true if both paths contain the same keys
+ * 
+ * @param lhs the first path
+ * @param rhs the second path
+ * @returns true if the paths are equal
+ */
+function equalPaths(lhs: Array<string>, rhs: Array<string>): boolean {
+  return lhs.length === rhs.length && startsWithPath(lhs, rhs);
+}
+
+/**
+ * returns true if the path starts with the keys in prefix
+ * 
+ * @param path the path to check
+ * @param prefix the keys the path must start with
+ * @returns true if path starts with prefix
+ */
+function startsWithPath(path: Array<string>, prefix: Array<string>): boolean {
+  return prefix.length <= path.length && prefix.every((key, index) => path[index] === key);
+}
+
+/**
+ * returns true if the existing version requirement is older than the required version.
+ * requirements that contain operators (e.g. >=, ~, or *) aren't considered outdated.
+ * 
+ * @param existing the existing version requirement, e.g. 0.30 or ^0.30.0
+ * @param required the required version, e.g. 0.33.0
+ * @returns true if the existing version requirement is outdated
+ */
+function isOutdated(existing: string, required: string): boolean {
+  const match = existing.trim().match(/^\^?(\d+(?:\.\d+){0,2})$/);
+  if (!match) {
+    return false;
+  }
+  const existingParts = match[1].split('.').map(Number);
+  const requiredParts = required.split('.').map(Number);
+  for (let i = 0; i < requiredParts.length; ++i) {
+    const part = existingParts[i] ?? 0;
+    if (part !== requiredParts[i]) {
+      return part < requiredParts[i];
+    }
+  }
+  return false;
+}
+
+/**
+ * returns the Cargo.toml entry for the provided dependency
+ * 
+ * @param crate the crate that contains the dependency
+ * @param dependency the dependency for which to create the entry
+ * @param workspace indicates if the dependency is inherited from a Cargo workspace
+ * @param onUnknownVersion called if the dependency has no known version
+ * @returns the dependency's entry
+ */
+function formatDependency(crate: rust.Crate, dependency: rust.CrateDependency, workspace: boolean, onUnknownVersion?: UnknownVersionCallback): string {
+  return `${formatKey(dependency.name)} = ${formatValue(getDependencyValue(crate, dependency, workspace, onUnknownVersion))}`;
+}
+
+/**
+ * returns the value of the Cargo.toml entry for the provided dependency
+ * 
+ * @param crate the crate that contains the dependency
+ * @param dependency the dependency for which to create the value
+ * @param workspace indicates if the dependency is inherited from a Cargo workspace
+ * @param onUnknownVersion called if the dependency has no known version
+ * @returns the version string or the dependency's inline table
+ */
+function getDependencyValue(crate: rust.Crate, dependency: rust.CrateDependency, workspace: boolean, onUnknownVersion?: UnknownVersionCallback): TomlValue {
+  const features = getDependencyFeatures(crate, dependency, workspace);
+  if (workspace) {
+    // dependency versions are managed by the workspace's Cargo.toml file
+    return features.length > 0 ? { workspace: true, features: features } : { workspace: true };
+  }
+
+  let version = getDependencyVersion(crate, dependency);
+  if (!version) {
+    version = placeholderVersion;
+    onUnknownVersion?.(dependency.name);
+  }
+  return features.length > 0 ? { version: version, features: features } : version;
+}
+
+/**
+ * returns the version of the provided dependency when the crate isn't a member of a workspace
+ * 
+ * @param crate the crate that contains the dependency
+ * @param dependency the dependency for which to return the version
+ * @returns the dependency's version or undefined if no version is known
+ */
+function getDependencyVersion(crate: rust.Crate, dependency: rust.CrateDependency): string | undefined {
+  if (dependency.name === 'azure_core') {
+    return crate.standalone.azureCoreVersion;
+  }
+  // crates of external types can provide their version
+  return standaloneDependencies[dependency.name]?.version ?? dependency.version;
+}
+
+/**
+ * returns the sorted features to enable for the provided dependency.
+ * when the crate isn't a member of a workspace, this includes the
+ * features that would otherwise be enabled by the workspace.
+ * 
+ * @param crate the crate that contains the dependency
+ * @param dependency the dependency for which to return the features
+ * @param workspace indicates if the dependency is inherited from a Cargo workspace
+ * @returns the dependency's features. can be empty
+ */
+function getDependencyFeatures(crate: rust.Crate, dependency: rust.CrateDependency, workspace: boolean): Array<string> {
+  const features = new Set(dependency.features);
+  if (!workspace) {
+    standaloneDependencies[dependency.name]?.features?.forEach((feature) => features.add(feature));
+    if (dependency.name === 'azure_core' && crate.dependencies.find((each) => each.name === 'rust_decimal')) {
+      // azure_core's support for rust_decimal::Decimal is behind its decimal feature
+      features.add('decimal');
+    }
+  }
+  return Array.from(features).sort();
+}
@@ -5,7 +5,10 @@
 
 // cspell: ignore conv
 
+import { getCookieEncoder } from './cookies.js';
+import { getPlainDateTimeMethod } from './dates.js';
 import { emitHeaderTraitDocExample } from './docTests.js';
+import { getDurationEncodingMethod, isDurationToFallible } from './durations.js';
 import { CodegenError } from './errors.js';
 import * as helpers from './helpers.js';
 import queryString from 'query-string';
@@ -136,9 +139,10 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
 
         // propagate the required client params to the initializer
         // NOTE: we do this on a sorted copy of the client params as we must preserve their order.
-        // exclude endpoint params as they aren't propagated to clients (they're consumed when creating the complete endpoint)
+        // exclude endpoint params as they aren't propagated to clients (they're consumed when creating the complete endpoint).
+        // the exception is the endpoint's API version param when methods need the API version.
         const sortedParams = [...constructor.params]
-          .filter((each) => each.kind !== 'clientSupplementalEndpoint' && each.kind !== 'clientCredential')
+          .filter((each) => (each.kind !== 'clientSupplementalEndpoint' || each.name === client.endpointApiVersion?.name) && each.kind !== 'clientCredential')
           .sort((a: rust.ClientParameter, b: rust.ClientParameter) => { return helpers.sortAscending(a.name, b.name); });
 
         for (const param of sortedParams) {
@@ -200,7 +204,7 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
 
     for (let i = 0; i < client.methods.length; ++i) {
       const method = client.methods[i];
-      const returnType = helpers.getTypeDeclaration(method.returns);
+      let returnType = helpers.getTypeDeclaration(method.returns);
       let async = '';
       // NOTE: when methodBody is called, the starting indentation
       // will be correct for the current scope, so there's no need
@@ -225,6 +229,10 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
           break;
         case 'lro':
           isPublicApi = true;
+          if (method.pageStrategy) {
+            // paged LROs return a Pager over the final result
+            returnType = `Result<Pager<${getLroPageTypeDeclaration(method)}>>`;
+          }
           methodBody = (indentation: helpers.indentation): string => {
             return getLroMethodBody(crate, indentation, use, client, method);
           };
@@ -242,11 +250,16 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
         body += paramsDocs;
       }
 
-      // client accessors will never have response headers
-      if (method.kind !== 'clientaccessor' && method.responseHeaders) {
+      // client accessors will never have response headers, and
+      // the status monitor of a paged LRO isn't returned to callers
+      if (method.kind !== 'clientaccessor' && method.responseHeaders && (method.kind !== 'lro' || !method.pageStrategy)) {
         body += getHeaderTraitDocComment(indent, crate, method);
       }
 
+      if (method.kind !== 'clientaccessor' && method.errors) {
+        body += getMethodErrorsDocComment(indent, module, method.errors);
+      }
+
       const paramsInfo = getMethodParamsCountAndSig(method, use);
       if (paramsInfo.count > 7) {
         // clippy will by default warn on 7+ args in a method.
@@ -255,6 +268,14 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
         body += `${indent.get()}#[allow(clippy::too_many_arguments)]\n`;
       }
 
+      if (method.kind === 'clientaccessor') {
+        // accessors for sub-clients in gated modules share the module's gate
+        const feature = utils.getFeatureGate(module, method.returns.module);
+        if (feature) {
+          body += helpers.emitFeatureGate(feature, indent);
+        }
+      }
+
       if (isPublicApi) {
         body += `${indent.get()}#[tracing::function("${method.languageIndependentName}")]\n`;
       } else if (isSubclientNew) {
@@ -302,7 +323,12 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
             // Intra-doc link — the options type exists and the link resolves.
             body += `/// Default value for [\`${client.constructable.options.type.name}::${field.name}\`].\n`;
           }
-          body += `pub(crate) const ${field.defaultValueConstant.name}: &str = "${field.defaultValueConstant.value}";\n\n`;
+          if (field.type.kind === 'enum') {
+            use.addForType(field.type);
+            body += `pub(crate) const ${field.defaultValueConstant.name}: ${field.type.name} = ${getEnumVariant(field.type, field.defaultValueConstant.value)};\n\n`;
+          } else {
+            body += `pub(crate) const ${field.defaultValueConstant.name}: &str = "${field.defaultValueConstant.value}";\n\n`;
+          }
         }
       }
     }
@@ -360,6 +386,11 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
     });
   }
 
+  const credentials = emitCredentials(module);
+  if (credentials) {
+    clientModules.push(credentials);
+  }
+
   return {
     modules: clientModules,
     options: getMethodOptions(module),
@@ -646,6 +677,7 @@ function getHeaderTraitDocComment(indent: helpers.indentation, module: rust.Modu
   let returnType: string;
   switch (method.returns.type.kind) {
     case 'asyncResponse':
+    case 'itemStream':
     case 'response':
       returnType = method.returns.type.name;
       break;
@@ -672,6 +704,29 @@ function getHeaderTraitDocComment(indent: helpers.indentation, module: rust.Modu
   return headerDocs;
 }
 
+/**
+ * returns documentation for the typed error responses of a method.
+ * 
+ * @param indent the current indentation level
+ * @param module the module to which method belongs
+ * @param errors the method's typed error responses
+ * @returns the typed error responses documentation
+ */
+function getMethodErrorsDocComment(indent: helpers.indentation, module: rust.ModuleContainer, errors: rust.MethodErrors): string {
+  const errorsPath = `${utils.buildImportPath(module, module)}::models::${errors.name}`;
+  let errorsDocs = `${indent.get()}///\n`;
+  errorsDocs += `${indent.get()}/// ## Errors\n`;
+  errorsDocs += `${indent.get()}///\n`;
+  errorsDocs += `${indent.get()}/// The returned error can be converted into a [${helpers.wrapInBackTicks(errors.name)}], which provides\n`;
+  errorsDocs += `${indent.get()}/// access to the following typed error responses:\n`;
+  for (const variant of errors.variants) {
+    errorsDocs += `${indent.get()}/// * [${helpers.wrapInBackTicks(variant.name)}](${errorsPath}::${variant.name}) - ${variant.statusCode}\n`;
+  }
+  errorsDocs += `${indent.get()}///\n`;
+  errorsDocs += `${indent.get()}/// [${helpers.wrapInBackTicks(errors.name)}]: ${errorsPath}\n`;
+  return errorsDocs;
+}
+
 /**
  * returns the auth policy instantiation code if the ctor contains a credential param.
  * the policy will be a local var named auth_policy.
@@ -691,10 +746,191 @@ function getAuthPolicy(ctor: rust.Constructor, use: Use): string | undefined {
       }
       return `let auth_policy: Arc<dyn Policy> = Arc::new(BearerTokenAuthorizationPolicy::new(credential, vec![${scopes.join(', ')}]));`;
     }
+
+    switch (param.type.kind) {
+      case 'basicCredential':
+        use.add('azure_core::http::policies', 'Policy');
+        use.add('std::sync', 'Arc');
+        use.add(`${utils.buildImportPath(param.type.module, param.type.module)}::clients`, 'BasicCredentialPolicy');
+        return 'let auth_policy: Arc<dyn Policy> = Arc::new(BasicCredentialPolicy::new(credential));';
+      case 'keyCredential':
+        use.add('azure_core::http::policies', 'Policy');
+        use.add('std::sync', 'Arc');
+        use.add(`${utils.buildImportPath(param.type.module, param.type.module)}::clients`, 'KeyCredentialLocation', 'KeyCredentialPolicy');
+        return `let auth_policy: Arc<dyn Policy> = Arc::new(KeyCredentialPolicy::new(credential, ${getKeyCredentialLocation(param.type)}));`;
+    }
   }
   return undefined;
 }
 
+/**
+ * returns the KeyCredentialLocation variant for the provided key credential
+ * 
+ * @param keyCredential the key credential
+ * @returns the KeyCredentialLocation variant
+ */
+function getKeyCredentialLocation(keyCredential: rust.KeyCredential): string {
+  switch (keyCredential.location) {
+    case 'authorization':
+      if (!keyCredential.scheme) {
+        throw new CodegenError('InternalError', 'missing scheme for key credential in the authorization header');
+      }
+      return `KeyCredentialLocation::Authorization("${keyCredential.scheme}")`;
+    case 'header':
+      return `KeyCredentialLocation::Header("${keyCredential.paramName}")`;
+    case 'query':
+      return `KeyCredentialLocation::Query("${keyCredential.paramName}")`;
+  }
+}
+
+/**
+ * emits the credential types and their pipeline policies
+ * if any client within the module requires them.
+ * 
+ * @param module the module for which to emit the credentials
+ * @returns the credentials module or undefined if no client uses a generated credential
+ */
+function emitCredentials(module: rust.ModuleContainer): helpers.Module | undefined {
+  const credentials = helpers.getCredentials(module);
+  if (credentials.length === 0) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'clients');
+  use.add('async_trait', 'async_trait');
+  use.add('azure_core::credentials', 'Secret');
+  use.add('azure_core::fmt', 'SafeDebug');
+  use.add('azure_core::http', 'Context', 'Request');
+  use.add('azure_core::http::policies', 'Policy', 'PolicyResult');
+  use.add('std::sync', 'Arc');
+
+  const indent = new helpers.indentation();
+  let body = '';
+
+  const keyCredentials = credentials.filter((credential) => credential.kind === 'keyCredential');
+  if (credentials.some((credential) => credential.kind === 'basicCredential')) {
+    use.add('azure_core', 'base64');
+
+    body += '/// Contains the user name and password used to authenticate requests with HTTP Basic authentication.\n';
+    body += '#[derive(Clone, SafeDebug)]\n';
+    body += 'pub struct BasicCredential {\n';
+    body += `${indent.get()}username: String,\n`;
+    body += `${indent.get()}password: Secret,\n`;
+    body += '}\n\n';
+
+    body += 'impl BasicCredential {\n';
+    body += `${indent.get()}/// Creates a new \`BasicCredential\` from the provided user name and password.\n`;
+    body += `${indent.get()}pub fn new(username: String, password: String) -> Self {\n`;
+    body += `${indent.push().get()}Self { username, password: Secret::new(password) }\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += '}\n\n';
+
+    body += '/// Adds the HTTP Basic Authorization header from a [`BasicCredential`] to each request.\n';
+    body += '#[derive(Debug)]\n';
+    body += 'pub(crate) struct BasicCredentialPolicy {\n';
+    body += `${indent.get()}credential: BasicCredential,\n`;
+    body += '}\n\n';
+
+    body += 'impl BasicCredentialPolicy {\n';
+    body += `${indent.get()}pub(crate) fn new(credential: BasicCredential) -> Self {\n`;
+    body += `${indent.push().get()}Self { credential }\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += '}\n\n';
+
+    body += '#[async_trait]\n';
+    body += 'impl Policy for BasicCredentialPolicy {\n';
+    body += `${indent.get()}async fn send(&self, ctx: &Context, request: &mut Request, next: &[Arc<dyn Policy>]) -> PolicyResult {\n`;
+    body += `${indent.push().get()}let value = base64::encode(format!("{}:{}", self.credential.username, self.credential.password.secret()));\n`;
+    body += `${indent.get()}request.insert_header("authorization", format!("Basic {value}"));\n`;
+    body += `${indent.get()}next[0].send(ctx, request, &next[1..]).await\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += '}\n';
+
+    if (keyCredentials.length > 0) {
+      body += '\n';
+    }
+  }
+
+  if (keyCredentials.length > 0) {
+    body += '/// Contains a secret key used to authenticate requests.\n';
+    body += '#[derive(Clone, SafeDebug)]\n';
+    body += 'pub struct KeyCredential {\n';
+    body += `${indent.get()}key: Secret,\n`;
+    body += '}\n\n';
+
+    body += 'impl KeyCredential {\n';
+    body += `${indent.get()}/// Creates a new \`KeyCredential\` from the provided key.\n`;
+    body += `${indent.get()}pub fn new(key: String) -> Self {\n`;
+    body += `${indent.push().get()}Self { key: Secret::new(key) }\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += '}\n\n';
+
+    // only emit the locations that are in use to avoid dead code warnings
+    const locations = new Set(keyCredentials.map((keyCredential) => keyCredential.location));
+    const arms = new Array<helpers.matchArm>();
+
+    body += '/// Indicates where in the request a [`KeyCredential`] is placed.\n';
+    body += '#[derive(Debug)]\n';
+    body += 'pub(crate) enum KeyCredentialLocation {\n';
+    if (locations.has('authorization')) {
+      body += `${indent.get()}/// The key is sent in the Authorization header, prefixed with the specified scheme.\n`;
+      body += `${indent.get()}Authorization(&'static str),\n`;
+      arms.push({
+        pattern: 'KeyCredentialLocation::Authorization(scheme)',
+        body: (indent) => `${indent.get()}request.insert_header("authorization", format!("{scheme} {}", self.credential.key.secret()));\n`,
+      });
+    }
+    if (locations.has('header')) {
+      body += `${indent.get()}/// The key is sent in the specified header.\n`;
+      body += `${indent.get()}Header(&'static str),\n`;
+      arms.push({
+        pattern: 'KeyCredentialLocation::Header(name)',
+        body: (indent) => `${indent.get()}request.insert_header(name, self.credential.key.secret().to_string());\n`,
+      });
+    }
+    if (locations.has('query')) {
+      body += `${indent.get()}/// The key is sent in the specified query parameter.\n`;
+      body += `${indent.get()}Query(&'static str),\n`;
+      arms.push({
+        pattern: 'KeyCredentialLocation::Query(name)',
+        body: (indent) => `${indent.get()}request.url_mut().query_pairs_mut().append_pair(name, self.credential.key.secret());\n`,
+      });
+    }
+    body += '}\n\n';
+
+    body += '/// Adds the key from a [`KeyCredential`] to each request.\n';
+    body += '#[derive(Debug)]\n';
+    body += 'pub(crate) struct KeyCredentialPolicy {\n';
+    body += `${indent.get()}credential: KeyCredential,\n`;
+    body += `${indent.get()}location: KeyCredentialLocation,\n`;
+    body += '}\n\n';
+
+    body += 'impl KeyCredentialPolicy {\n';
+    body += `${indent.get()}pub(crate) fn new(credential: KeyCredential, location: KeyCredentialLocation) -> Self {\n`;
+    body += `${indent.push().get()}Self { credential, location }\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += '}\n\n';
+
+    body += '#[async_trait]\n';
+    body += 'impl Policy for KeyCredentialPolicy {\n';
+    body += `${indent.get()}async fn send(&self, ctx: &Context, request: &mut Request, next: &[Arc<dyn Policy>]) -> PolicyResult {\n`;
+    body += `${indent.push().get()}${helpers.buildMatch(indent, 'self.location', arms)}\n`;
+    body += `${indent.get()}next[0].send(ctx, request, &next[1..]).await\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += '}\n';
+  }
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'credentials',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
+
 /**
  * returns the complete text for the provided parameter's type
  * e.g. self, &String, mut SomeStruct
@@ -781,9 +1017,15 @@ interface MethodParamGroups {
   /** the api version parameter if applicable */
   apiVersion?: ApiVersionParamType;
 
+  /** the client field for the API version selected via the endpoint if applicable */
+  endpointApiVersion?: rust.StructField;
+
   /** the body parameter if applicable */
   body?: rust.BodyParameter;
 
+  /** cookie parameters. can be empty */
+  cookie: Array<rust.CookieScalarParameter>;
+
   /** header parameters. can be empty */
   header: Array<HeaderParamType>;
 
@@ -800,18 +1042,24 @@ interface MethodParamGroups {
 /**
  * enumerates method parameters and returns them based on groups
  * 
+ * @param client the client to which the method belongs
  * @param method the method containing the parameters to group
+ * @param params the parameters to group. defaults to the method's parameters
  * @returns the groups parameters
  */
-function getMethodParamGroup(method: ClientMethod): MethodParamGroups {
+function getMethodParamGroup(client: rust.Client, method: ClientMethod, params: Array<rust.MethodParameter> = method.params): MethodParamGroups {
   // collect and sort all the header/path/query params
   let apiVersionParam: ApiVersionParamType | undefined;
+  const cookieParams = new Array<rust.CookieScalarParameter>();
   const headerParams = new Array<HeaderParamType>();
   const pathParams = new Array<PathParamType>();
   const queryParams = new Array<QueryParamType>();
   const partialBodyParams = new Array<rust.PartialBodyParameter>();
-  for (const param of method.params) {
+  for (const param of params) {
     switch (param.kind) {
+      case 'cookieScalar':
+        cookieParams.push(param);
+        break;
       case 'headerScalar':
       case 'headerCollection':
       case 'headerHashMap':
@@ -836,12 +1084,13 @@ function getMethodParamGroup(method: ClientMethod): MethodParamGroups {
     }
   }
 
+  cookieParams.sort((a: rust.CookieScalarParameter, b: rust.CookieScalarParameter) => { return helpers.sortAscending(a.cookie, b.cookie); });
   headerParams.sort((a: HeaderParamType, b: HeaderParamType) => { return helpers.sortAscending(a.header, b.header); });
   pathParams.sort((a: PathParamType, b: PathParamType) => { return helpers.sortAscending(a.segment, b.segment); });
   queryParams.sort((a: QueryParamType, b: QueryParamType) => { return helpers.sortAscending(a.key, b.key); });
 
   let bodyParam: rust.BodyParameter | undefined;
-  for (const param of method.params) {
+  for (const param of params) {
     if (param.kind === 'body') {
       if (bodyParam) {
         throw new CodegenError('InternalError', `method ${method.name} has multiple body parameters`);
@@ -852,7 +1101,9 @@ function getMethodParamGroup(method: ClientMethod): MethodParamGroups {
 
   return {
     apiVersion: apiVersionParam,
+    endpointApiVersion: apiVersionParam ? undefined : client.endpointApiVersion,
     body: bodyParam,
+    cookie: cookieParams,
     header: headerParams,
     partialBody: partialBodyParams,
     path: pathParams,
@@ -871,9 +1122,10 @@ function getMethodParamGroup(method: ClientMethod): MethodParamGroups {
  * @param param the parameter to which the contents of setter apply
  * @param setter the callback that emits the code to read from a param var
  * @param optionsPrefix Syntax to access the options structure, including the dot
+ * @param clientPrefix Syntax to access optional client params, including the dot (empty when they're locals)
  * @returns 
  */
-function getParamValueHelper(indent: helpers.indentation, param: rust.MethodParameter, setter: () => string, optionsPrefix: string = 'options.'): string {
+function getParamValueHelper(indent: helpers.indentation, param: rust.MethodParameter, setter: () => string, optionsPrefix: string = 'options.', clientPrefix: string = 'self.'): string {
   if (param.optional && param.type.kind !== 'literal') {
     let asRefOrClone = ''; // Empty value is ok as well, depending on what is needed.
     if (param.type.kind === 'requestContent') {
@@ -883,7 +1135,7 @@ function getParamValueHelper(indent: helpers.indentation, param: rust.MethodPara
     }
     // optional params are in the unwrapped options local var
     const op = indent.get() + helpers.buildIfBlock(indent, {
-      condition: `let Some(${param.name}) = ${param.location === 'client' ? 'self.' : optionsPrefix}${param.name}${asRefOrClone}`,
+      condition: `let Some(${param.name}) = ${param.location === 'client' ? clientPrefix : optionsPrefix}${optionsFieldPath(param)}${asRefOrClone}`,
       body: setter,
     });
     return op + '\n';
@@ -1074,7 +1326,7 @@ function constructUrl(indent: helpers.indentation, use: Use, method: ClientMetho
       });
     } else {
       body += getParamValueHelper(indent, queryParam, () => {
-        return `${indent.get()}query_builder.set_pair("${queryParam.key}", ${getHeaderPathQueryParamValue(use, queryParam, !queryParam.optional, false)});\n`;
+        return `${indent.get()}query_builder.set_pair(${getParamWireName(queryParam, queryParam.key, paramGroups, false)}, ${getHeaderPathQueryParamValue(use, queryParam, !queryParam.optional, false)});\n`;
       });
     }
   }
@@ -1130,6 +1382,21 @@ function applyHeaderParams(indent: helpers.indentation, use: Use, method: Client
       continue;
     }
 
+    if (isMultipartBody(paramGroups) && headerParam.header.toLowerCase() === 'content-type') {
+      // the Content-Type header for multipart bodies includes
+      // the boundary so it's set when the body is constructed.
+      continue;
+    }
+
+    if (headerParam.kind === 'headerScalar' && headerParam.repeatability) {
+      // repeatability headers are always sent. when the caller doesn't provide
+      // a value, one is generated. the value is set on the request so it doesn't
+      // change when the pipeline retries the request.
+      body += `${indent.get()}let ${headerParam.name} = ${optionsPrefix}${headerParam.name}${getRepeatabilityDefault(use, headerParam.repeatability)};\n`;
+      body += `${indent.get()}${requestVarName}.insert_header("${headerParam.header.toLowerCase()}", ${getHeaderPathQueryParamValue(use, headerParam, false, false)});\n`;
+      continue;
+    }
+
     body += getParamValueHelper(indent, headerParam, () => {
       if (headerParam.kind === 'headerHashMap') {
         const headerParamRef = qualifiedParamName(headerParam);
@@ -1138,18 +1405,191 @@ function applyHeaderParams(indent: helpers.indentation, use: Use, method: Client
         setter += `${indent.pop().get()}}\n`;
         return setter;
       }
-      return `${indent.get()}${requestVarName}.insert_header("${headerParam.header.toLowerCase()}", ${getHeaderPathQueryParamValue(use, headerParam, !inClosure && !headerParam.optional, false)});\n`;
+      const headerName = headerParam.kind === 'headerScalar' ? getParamWireName(headerParam, headerParam.header.toLowerCase(), paramGroups, inClosure) : `"${headerParam.header.toLowerCase()}"`;
+      return `${indent.get()}${requestVarName}.insert_header(${headerName}, ${getHeaderPathQueryParamValue(use, headerParam, !inClosure && !headerParam.optional, false)});\n`;
+    }, optionsPrefix);
+  }
+
+  body += applyCookieParams(indent, use, method, paramGroups, inClosure, requestVarName, optionsPrefix);
+
+  return body;
+}
+
+/**
+ * returns the code that unwraps an optional repeatability header's
+ * value, generating a value when one wasn't provided.
+ *
+ * @param use the use statement builder currently in scope
+ * @param repeatability the kind of repeatability header
+ * @returns the code to append to the optional value
+ */
+function getRepeatabilityDefault(use: Use, repeatability: rust.RepeatabilityHeader): string {
+  switch (repeatability) {
+    case 'firstSent':
+      use.add('azure_core::time', 'OffsetDateTime');
+      return '.unwrap_or_else(OffsetDateTime::now_utc)';
+    case 'requestId':
+      use.add('azure_core', 'Uuid');
+      return '.clone().unwrap_or_else(|| Uuid::new_v4().to_string())';
+  }
+}
+
+/**
+ * emits the code for merging all cookie params into a single Cookie header.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param method the method for which we're building the body
+ * @param paramGroups the param groups for the provided method
+ * @param inClosure indicates if the request is being constructed within a closure (e.g. pageable methods)
+ * @param requestVarName name for the request variable
+ * @param optionsPrefix Syntax to access the options structure, including the dot
+ * @returns the code which sets the Cookie header for the request
+ */
+function applyCookieParams(indent: helpers.indentation, use: Use, method: ClientMethod, paramGroups: MethodParamGroups, inClosure: boolean, requestVarName: string, optionsPrefix: string): string {
+  if (paramGroups.cookie.length === 0) {
+    return '';
+  }
+
+  // returns the format string and any trailing format args for the cookie
+  const getCookiePair = (cookieParam: rust.CookieScalarParameter): { pair: string, args: Array<string> } => {
+    const value = getCookieParamValue(use, cookieParam, inClosure);
+    if (value === cookieParam.name) {
+      // plain locals are inlined into the format string
+      return { pair: `${cookieParam.cookie}={${value}}`, args: [] };
+    }
+    return { pair: `${cookieParam.cookie}={}`, args: [value] };
+  };
+
+  const formatPairs = (cookieParams: Array<rust.CookieScalarParameter>): string => {
+    const pairs = cookieParams.map((cookieParam) => getCookiePair(cookieParam));
+    const args = pairs.flatMap((pair) => pair.args);
+    return `format!("${pairs.map((pair) => pair.pair).join('; ')}"${args.length > 0 ? `, ${args.join(', ')}` : ''})`;
+  };
+
+  const requiredCookies = paramGroups.cookie.filter((cookieParam) => !cookieParam.optional);
+  const optionalCookies = paramGroups.cookie.filter((cookieParam) => cookieParam.optional);
+  if (optionalCookies.length === 0) {
+    // all cookies are always sent so we can build the header value in one shot
+    return `${indent.get()}${requestVarName}.insert_header("cookie", ${formatPairs(requiredCookies)});\n`;
+  }
+
+  const cookiesVar = helpers.getUniqueVarName(method.params, ['cookies', 'cookies_var']);
+  const initCookies = requiredCookies.length > 0 ? `vec![${requiredCookies.map((cookieParam) => formatPairs([cookieParam])).join(', ')}]` : 'Vec::new()';
+  let body = `${indent.get()}let mut ${cookiesVar} = ${initCookies};\n`;
+  for (const cookieParam of optionalCookies) {
+    body += getParamValueHelper(indent, cookieParam, () => {
+      return `${indent.get()}${cookiesVar}.push(${formatPairs([cookieParam])});\n`;
     }, optionsPrefix);
   }
 
+  const setCookies = (indent: helpers.indentation) => `${indent.get()}${requestVarName}.insert_header("cookie", ${cookiesVar}.join("; "));\n`;
+  if (requiredCookies.length > 0) {
+    body += setCookies(indent);
+  } else {
+    // omit the Cookie header when no optional cookies were specified
+    body += indent.get() + helpers.buildIfBlock(indent, {
+      condition: `!${cookiesVar}.is_empty()`,
+      body: setCookies,
+    }) + '\n';
+  }
   return body;
 }
 
+/**
+ * returns true if the cookie param's value can contain characters
+ * that must be percent-encoded (i.e. it's not a number or boolean)
+ *
+ * @param cookieParam the cookie param to inspect
+ * @returns true if the value is passed to encode_cookie_value
+ */
+function isEncodedCookie(cookieParam: rust.CookieScalarParameter): boolean {
+  const paramType = helpers.unwrapType(cookieParam.type);
+  switch (paramType.kind) {
+    case 'enum':
+      return isEnumString(paramType);
+    case 'literal':
+    case 'scalar':
+      return false;
+    default:
+      return true;
+  }
+}
+
+/**
+ * returns the expression for a cookie param's value. the expression
+ * implements Display so it can be passed directly to format!.
+ *
+ * @param use the use statement builder currently in scope
+ * @param cookieParam the cookie param for which to get the value
+ * @param inClosure indicates if the value is read within a closure (e.g. pageable methods)
+ * @returns the value expression
+ */
+function getCookieParamValue(use: Use, cookieParam: rust.CookieScalarParameter, inClosure: boolean): string {
+  const paramType = helpers.unwrapType(cookieParam.type);
+  if (paramType.kind === 'literal') {
+    return `"${encodeURIComponent(String(paramType.value))}"`;
+  } else if (paramType.kind === 'enumValue') {
+    return `${paramType.type.name}::${paramType.name}`;
+  }
+
+  let paramName = cookieParam.name;
+  if (cookieParam.location === 'client' && !inClosure && !cookieParam.optional) {
+    paramName = `self.${paramName}`;
+  } else if (cookieParam.group) {
+    paramName = qualifiedParamName(cookieParam);
+  }
+
+  // optional params are bound to locals by reference. required params are owned
+  // unless they're borrowed method args that haven't been captured by a closure.
+  const owned = !cookieParam.optional && (inClosure || paramName !== cookieParam.name || cookieParam.type.kind !== 'ref');
+
+  if (!isEncodedCookie(cookieParam)) {
+    // numbers, booleans, and numeric enums are formatted as-is
+    return paramName;
+  }
+
+  const encodeCookieValue = getCookieEncoder(use);
+  switch (paramType.kind) {
+    case 'String':
+    case 'str':
+      return `${encodeCookieValue}(${owned ? '&' : ''}${paramName})`;
+    case 'enum':
+      return `${encodeCookieValue}(${paramName}.as_ref())`;
+    default:
+      // the remaining types are converted to a String first
+      return `${encodeCookieValue}(&${getHeaderPathQueryParamValue(use, cookieParam, false, true, paramName)})`;
+  }
+}
+
 /** type guard to determine if headerParam is an optional Content-Type header */
 function isOptionalContentTypeHeader(headerParam: HeaderParamType): headerParam is rust.HeaderScalarParameter {
   return headerParam.kind === 'headerScalar' && headerParam.optional && headerParam.header.toLowerCase() === 'content-type';
 }
 
+/** returns true if the method's body is sent as multipart/form-data */
+function isMultipartBody(paramGroups: MethodParamGroups): boolean {
+  return paramGroups.body?.type.format === 'MultipartFormat' || paramGroups.partialBody.at(0)?.type.format === 'MultipartFormat';
+}
+
+/**
+ * emits the code that encodes a multipart model and sets it as the request body.
+ * 
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param module the module that contains the multipart model
+ * @param requestVarName the name of the request var
+ * @param bodyExpr the expression that evaluates to the multipart model
+ * @returns the code to set the multipart body
+ */
+function setMultipartBody(indent: helpers.indentation, use: Use, module: rust.ModuleContainer, requestVarName: string, bodyExpr: string): string {
+  use.add(`${utils.buildImportPath(module, module)}::models`, 'MultipartForm');
+  let body = `${indent.get()}let form: MultipartForm = ${bodyExpr}.try_into()?;\n`;
+  body += `${indent.get()}${requestVarName}.insert_header("content-type", form.content_type());\n`;
+  body += `${indent.get()}${requestVarName}.set_body(form.into_body());\n`;
+  return body;
+}
+
 /**
  * emits the code for building the HTTP request.
  * assumes that there's a local var 'url' which is the Url.
@@ -1169,7 +1609,7 @@ function constructRequest(indent: helpers.indentation, use: Use, method: ClientM
   // when constructing the request var name we need to ensure
   // that it doesn't collide with any parameter name.
   const requestVarName = helpers.getUniqueVarName(method.params, ['request', 'core_req']);
-  let body = `${indent.get()}let ${(forceMut || paramGroups.header.length > 0) ? 'mut ' : ''}${requestVarName} = Request::new(${urlVarName}${cloneUrl ? '.clone()' : ''}, Method::${utils.capitalize(method.httpMethod)});\n`;
+  let body = `${indent.get()}let ${(forceMut || paramGroups.header.length > 0 || paramGroups.cookie.length > 0) ? 'mut ' : ''}${requestVarName} = Request::new(${urlVarName}${cloneUrl ? '.clone()' : ''}, Method::${utils.capitalize(method.httpMethod)});\n`;
 
   body += applyHeaderParams(indent, use, method, paramGroups, inClosure, requestVarName);
 
@@ -1185,6 +1625,12 @@ function constructRequest(indent: helpers.indentation, use: Use, method: ClientM
   if (bodyParam) {
     body += getParamValueHelper(indent, bodyParam, () => {
       let bodyParamContent = '';
+      if (bodyParam.type.format === 'MultipartFormat') {
+        if (inClosure || bodyParam.type.content.kind !== 'model') {
+          throw new CodegenError('InternalError', `unsupported multipart body ${bodyParam.name} for method ${method.name}`);
+        }
+        return setMultipartBody(indent, use, bodyParam.type.content.module, requestVarName, bodyParam.name);
+      }
       if (optionalContentTypeParam) {
         bodyParamContent = `${indent.get()}${requestVarName}.insert_header("${optionalContentTypeParam.header.toLowerCase()}", ${getHeaderPathQueryParamValue(use, optionalContentTypeParam, !inClosure, false)});\n`;
       }
@@ -1194,8 +1640,14 @@ function constructRequest(indent: helpers.indentation, use: Use, method: ClientM
   } else if (paramGroups.partialBody.length > 0) {
     // all partial body params should point to the same underlying model type.
     const requestContentType = paramGroups.partialBody[0].type;
+    const isMultipart = requestContentType.format === 'MultipartFormat';
+    if (isMultipart && inClosure) {
+      throw new CodegenError('InternalError', `multipart body for method ${method.name} within a closure is not supported`);
+    }
     use.addForType(requestContentType);
-    if (inClosure) {
+    if (isMultipart) {
+      body += `${indent.get()}let body = ${requestContentType.content.name} {\n`;
+    } else if (inClosure) {
       body += `${indent.get()}let body: Result<${helpers.getTypeDeclaration(requestContentType)}> = ${requestContentType.content.name} {\n`;
     } else {
       body += `${indent.get()}let body: ${helpers.getTypeDeclaration(requestContentType)} = ${requestContentType.content.name} {\n`;
@@ -1206,8 +1658,15 @@ function constructRequest(indent: helpers.indentation, use: Use, method: ClientM
         throw new CodegenError('InternalError', `spread param ${partialBodyParam.name} has conflicting model type ${partialBodyParam.type.content.name}, expected model type ${requestContentType.content.name}`);
       }
 
+      // nullable fields are a Nullable<T> instead of an Option<T>
+      const field = requestContentType.content.fields.find((each) => each.kind === 'modelField' && each.serde === partialBodyParam.serde);
+      const isNullable = field?.type.kind === 'nullable';
+      if (field?.type.kind === 'nullable') {
+        use.add(`${utils.buildImportPath(use.module, field.type.module)}::models`, field.type.name);
+      }
+
       if (partialBodyParam.optional) {
-        body += `${indent.get()}${partialBodyParam.name}: options.${partialBodyParam.name}${inClosure ? '.clone()' : ''},\n`;
+        body += `${indent.get()}${partialBodyParam.name}: options.${partialBodyParam.name}${inClosure ? '.clone()' : ''}${isNullable ? '.map_or(Nullable::Absent, Nullable::Value)' : ''},\n`;
         continue;
       }
 
@@ -1216,8 +1675,8 @@ function constructRequest(indent: helpers.indentation, use: Use, method: ClientM
         initializer = initializer + '.clone()';
       }
       if (requestContentType.content.visibility === 'pub') {
-        // spread param maps to a non-internal model, so it must be wrapped in Some()
-        initializer = `Some(${initializer})`;
+        // spread param maps to a non-internal model, so it must be wrapped in Some() or Nullable::Value()
+        initializer = `${isNullable ? 'Nullable::Value' : 'Some'}(${initializer})`;
       }
 
       // can't use shorthand init if it's more than just the param name
@@ -1227,7 +1686,10 @@ function constructRequest(indent: helpers.indentation, use: Use, method: ClientM
 
       body += `${indent.get()}${initializer},\n`;
     }
-    if (inClosure) {
+    if (isMultipart) {
+      body += `${indent.pop().get()}};\n`;
+      body += setMultipartBody(indent, use, requestContentType.content.module, requestVarName, 'body');
+    } else if (inClosure) {
       body += `${indent.pop().get()}}.try_into();\n`;
       body += `${indent.get()}if let Ok(body) = body { ${requestVarName}.set_body(body); }\n`;
     } else {
@@ -1268,6 +1730,9 @@ function checkEmptyRequiredPathParams(indent: helpers.indentation, params: Array
   for (const param of params) {
     if (param.optional || param.location === 'client' || param.minLength === 0) {
       continue;
+    } else if (param.minLength !== undefined && param.minLength > 1 && getConstrainedStrConversion(param) !== undefined) {
+      // checkParamConstraints emits a minimum length check that also rejects the empty string
+      continue;
     }
     checks += emitEmptyPathParamCheck(indent, param);
   }
@@ -1311,6 +1776,337 @@ function emitEmptyPathParamCheck(indent: helpers.indentation, param: PathParamTy
   });
 }
 
+/**
+ * returns the conversion to a string slice for a param that
+ * can have length and pattern constraints (i.e. it's a string
+ * or string enum), or undefined if constraints don't apply.
+ *
+ * @param param the param to inspect
+ * @returns the conversion (can be the empty string) or undefined
+ */
+function getConstrainedStrConversion(param: rust.MethodParameter): string | undefined {
+  const paramType = param.type.kind === 'ref' ? param.type.type : param.type;
+  switch (paramType.kind) {
+    case 'String':
+    case 'str':
+      return '';
+    case 'enum':
+      return isEnumString(paramType) ? '.as_ref()' : undefined;
+    default:
+      return undefined;
+  }
+}
+
+/**
+ * emits the checks for the length and pattern constraints on method path, query,
+ * and header params. the checks return an error before the request is sent.
+ * 
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param paramGroups the param groups for the method
+ * @returns the constraint checks or the empty string if there are no checks
+ */
+function checkParamConstraints(indent: helpers.indentation, use: Use, paramGroups: MethodParamGroups): string {
+  let checks = '';
+  for (const param of [...paramGroups.path, ...paramGroups.query, ...paramGroups.header]) {
+    if (param.kind !== 'headerScalar' && param.kind !== 'pathScalar' && param.kind !== 'queryScalar') {
+      continue;
+    } else if (param.location === 'client' || (param.maxLength === undefined && !param.pattern && !param.minLength)) {
+      continue;
+    }
+
+    const asStr = getConstrainedStrConversion(param);
+    if (asStr === undefined) {
+      // constraints only apply to strings
+      continue;
+    }
+    const paramType = param.type.kind === 'ref' ? param.type.type : param.type;
+
+    checks += getParamValueHelper(indent, param, () => {
+      // optional params are bound by reference
+      const paramRef = param.optional ? param.name : qualifiedParamName(param);
+      const strRef = asStr !== '' ? `${paramRef}${asStr}` : param.optional || paramType.kind === 'str' ? paramRef : `&${paramRef}`;
+      const emitCheck = (condition: string, message: string): string => {
+        return `${indent.get()}${helpers.buildIfBlock(indent, {
+          condition: condition,
+          body: (indent) => `${indent.get()}return Err(azure_core::Error::with_message(azure_core::error::ErrorKind::Other, "parameter ${param.name} ${message}"));\n`,
+        })}\n`;
+      };
+
+      let checks = '';
+      // a minLength of one is the same as the empty check for required path params
+      if (param.minLength === 1 && !(param.kind === 'pathScalar' && !param.optional)) {
+        checks += emitCheck(`${paramRef}${asStr}.is_empty()`, 'cannot be empty');
+      } else if (param.minLength !== undefined && param.minLength > 1) {
+        checks += emitCheck(`${paramRef}${asStr}.chars().count() < ${param.minLength}`, `must be at least ${param.minLength} characters`);
+      }
+      if (param.maxLength !== undefined) {
+        checks += emitCheck(`${paramRef}${asStr}.chars().count() > ${param.maxLength}`, `must be at most ${param.maxLength} characters`);
+      }
+      if (param.pattern) {
+        use.add('regex', 'Regex');
+        use.add('std::sync', 'LazyLock');
+        // pick a raw string delimiter that doesn't occur in the pattern
+        let hashes = '';
+        while (param.pattern.includes(`"${hashes}`)) {
+          hashes += '#';
+        }
+        const escapedPattern = param.pattern.replace(/\\/g, '\\\\').replace(/"/g, '\\"');
+        // the pattern is a valid ECMAScript regex but the regex crate might still
+        // reject it (e.g. unsupported escapes) so the error is returned instead of
+        // panicking. the match binding is suffixed so it can't shadow the param.
+        const patternVar = `${param.name.toUpperCase()}_PATTERN`;
+        const patternBinding = `${param.name}_pattern`;
+        checks += `${indent.get()}static ${patternVar}: LazyLock<Result<Regex, regex::Error>> = LazyLock::new(|| Regex::new(r${hashes}"${param.pattern}"${hashes}));\n`;
+        checks += `${indent.get()}${helpers.buildMatch(indent, `&*${patternVar}`, [{
+          pattern: `Ok(${patternBinding})`,
+          body: () => emitCheck(`!${patternBinding}.is_match(${strRef})`, `must match the pattern ${escapedPattern}`),
+        }, {
+          pattern: 'Err(err)',
+          body: (indent) => `${indent.get()}return Err(azure_core::Error::with_message(azure_core::error::ErrorKind::Other, format!("parameter ${param.name} has an invalid pattern: {err}")));\n`,
+        }])}\n`;
+      }
+      return checks;
+    });
+  }
+  return checks;
+}
+
+/**
+ * emits the checks for method path, query, and header params whose Duration
+ * values might not fit in the wire type of their encoding (e.g. milliseconds).
+ * the checks return an error before the request is sent.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param paramGroups the param groups for the method
+ * @returns the duration checks or the empty string if there are no checks
+ */
+function checkDurationParams(indent: helpers.indentation, use: Use, paramGroups: MethodParamGroups): string {
+  let checks = '';
+  for (const param of [...paramGroups.path, ...paramGroups.query, ...paramGroups.header]) {
+    const isCollection = param.kind === 'headerCollection' || param.kind === 'queryCollection';
+    if (!isCollection && param.kind !== 'headerScalar' && param.kind !== 'pathScalar' && param.kind !== 'queryScalar') {
+      continue;
+    }
+    const paramType = helpers.unwrapType(param.type);
+    if (paramType.kind !== 'duration' || !isDurationToFallible(paramType.encoding)) {
+      continue;
+    }
+
+    const durationTo = getDurationEncodingMethod(paramType.encoding, 'to', use);
+    checks += getParamValueHelper(indent, param, () => {
+      // optional params are bound by reference
+      let paramRef = param.optional ? param.name : qualifiedParamName(param);
+      if (param.location === 'client' && !param.optional) {
+        paramRef = `self.${paramRef}`;
+      }
+      if (isCollection) {
+        let check = `${indent.get()}for item in ${paramRef}.iter() {\n`;
+        check += `${indent.push().get()}${durationTo}(item)?;\n`;
+        check += `${indent.pop().get()}}\n`;
+        return check;
+      }
+      return `${indent.get()}${durationTo}(${param.type.kind !== 'ref' ? '&' : ''}${paramRef})?;\n`;
+    });
+  }
+  return checks;
+}
+
+/**
+ * emits the checks that reject request conditions the method doesn't support.
+ * the conditions types contain all of their conditions, but a method might only
+ * support some of them (e.g. If-Match but not If-None-Match).
+ *
+ * @param indent the indentation helper currently in scope
+ * @param method the method for which to emit the checks
+ * @param paramGroups the param groups for the method
+ * @returns the request conditions checks or the empty string if there are no checks
+ */
+function checkRequestConditions(indent: helpers.indentation, method: ClientMethod, paramGroups: MethodParamGroups): string {
+  let conditions: rust.RequestConditions | undefined;
+  const conditionalParams = new Array<string>();
+  for (const param of paramGroups.header) {
+    if (param.kind === 'headerScalar' && param.conditions) {
+      conditions = param.conditions;
+      conditionalParams.push(param.name);
+    }
+  }
+
+  if (!conditions) {
+    return '';
+  }
+
+  const conditionNames = ['if_match', 'if_none_match'];
+  if (conditions.name === 'RequestConditions') {
+    conditionNames.push('if_modified_since', 'if_unmodified_since');
+  }
+
+  let checks = '';
+  for (const conditionName of conditionNames) {
+    if (conditionalParams.includes(conditionName)) {
+      continue;
+    }
+    checks += `${indent.get()}${helpers.buildIfBlock(indent, {
+      condition: `options.${utils.snakeCaseName(conditions.name)}.${conditionName}.is_some()`,
+      body: (indent) => `${indent.get()}return Err(azure_core::Error::with_message(azure_core::error::ErrorKind::Other, "request condition ${conditionName} isn't supported by method ${method.name}"));\n`,
+    })}\n`;
+  }
+  return checks;
+}
+
+/**
+ * emits the checks that the method and its optional params are available
+ * in the client's selected API version. the checks only reject API versions
+ * known to the client so that callers can still target newer API versions.
+ * 
+ * @param indent the indentation helper currently in scope
+ * @param client the client to which the method belongs
+ * @param method the method for which to emit the checks
+ * @param paramGroups the param groups for the method
+ * @returns the API version checks or the empty string if there are no checks
+ */
+function checkApiVersions(indent: helpers.indentation, client: rust.Client, method: ClientMethod, paramGroups: MethodParamGroups): string {
+  const apiVersion = paramGroups.apiVersion;
+  if (!apiVersion || apiVersion.location !== 'client') {
+    // the API version is only known up front when it's set on the client
+    return '';
+  }
+
+  const emitCheck = (condition: string, name: string, enclosing: Array<string>, apiVersions: Array<string>): string => {
+    const unsupported = enclosing.filter((version) => !apiVersions.includes(version));
+    let versionsCheck: string;
+    if (isEnumString(apiVersion.type)) {
+      const apiVersionType = apiVersion.type;
+      versionsCheck = `[${unsupported.map((version) => getEnumVariant(apiVersionType, version)).join(', ')}].contains(&self.${apiVersion.name})`;
+    } else {
+      versionsCheck = `[${unsupported.map((version) => `"${version}"`).join(', ')}].contains(&self.${apiVersion.name}.as_str())`;
+    }
+    const message = apiVersions.length > 0
+      ? `${name} is only available in api-version${apiVersions.length > 1 ? 's' : ''} ${apiVersions.join(', ')}`
+      : `${name} isn't available in api-version${unsupported.length > 1 ? 's' : ''} ${unsupported.join(', ')}`;
+    return `${indent.get()}${helpers.buildIfBlock(indent, {
+      condition: condition !== '' ? `${condition} && ${versionsCheck}` : versionsCheck,
+      body: (indent) => `${indent.get()}return Err(azure_core::Error::with_message(azure_core::error::ErrorKind::Other, "${message}"));\n`,
+    })}\n`;
+  };
+
+  let checks = '';
+  if (method.apiVersions) {
+    checks += emitCheck('', `method ${method.name}`, client.apiVersions, method.apiVersions);
+  }
+
+  // required params are always sent so only the optional params are checked
+  const methodApiVersions = method.apiVersions ?? client.apiVersions;
+  for (const param of method.params) {
+    if (!param.optional || param.location === 'client' || !param.apiVersions) {
+      continue;
+    }
+    checks += emitCheck(`options.${optionsFieldPath(param)}.is_some()`, `parameter ${param.name}`, methodApiVersions, param.apiVersions);
+  }
+  return checks;
+}
+
+/**
+ * returns the code for the name of a header or query param. when the param was
+ * renamed, the name is selected from the client's API version. API versions that
+ * aren't known to the client use the latest name.
+ *
+ * @param param the header or query param
+ * @param name the param's name in the latest API version
+ * @param paramGroups the param groups for the method
+ * @param inClosure indicates if the name is used within a closure (e.g. pageable methods)
+ * @returns the code for the param's name
+ */
+function getParamWireName(param: rust.HeaderScalarParameter | rust.QueryScalarParameter, name: string, paramGroups: MethodParamGroups, inClosure: boolean): string {
+  const apiVersion = paramGroups.apiVersion ?? paramGroups.endpointApiVersion;
+  if (param.previousNames.length === 0 || !apiVersion) {
+    return `"${name}"`;
+  }
+
+  // within closures the API version is a local clone
+  const apiVersionVar = inClosure ? apiVersion.name : `self.${apiVersion.name}`;
+  let wireName = '';
+  for (const previousName of param.previousNames) {
+    wireName += `if ${getApiVersionsCheck(apiVersion, apiVersionVar, previousName.apiVersions)} { "${previousName.name}" } else `;
+  }
+  return `${wireName}{ "${name}" }`;
+}
+
+/**
+ * returns the code that checks if the API version is one of the specified API versions
+ *
+ * @param apiVersion the API version param or client field
+ * @param apiVersionVar the code for the API version's value
+ * @param apiVersions the API versions to check for
+ * @returns the code for the check
+ */
+function getApiVersionsCheck(apiVersion: ApiVersionParamType | rust.StructField, apiVersionVar: string, apiVersions: Array<string>): string {
+  if (isEnumString(apiVersion.type)) {
+    const apiVersionType = apiVersion.type;
+    return `[${apiVersions.map((version) => getEnumVariant(apiVersionType, version)).join(', ')}].contains(&${apiVersionVar})`;
+  }
+  return `[${apiVersions.map((version) => `"${version}"`).join(', ')}].contains(&${apiVersionVar}.as_str())`;
+}
+
+/**
+ * returns the code that selects the changes made to the wire format of a JSON
+ * body for the client's API version. the latest wire format is used for API
+ * versions that aren't known to the client.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param client the client to which the method belongs
+ * @param paramGroups the param groups for the method
+ * @param varName the name of the local that contains the selected changes
+ * @param changes the changes made to the body's wire format
+ * @returns the code that selects the changes
+ */
+function getWireChanges(indent: helpers.indentation, use: Use, client: rust.Client, paramGroups: MethodParamGroups, varName: string, changes: Array<rust.WireChange>): string {
+  const apiVersion = paramGroups.apiVersion ?? paramGroups.endpointApiVersion;
+  if (!apiVersion) {
+    throw new CodegenError('InternalError', `method in client ${client.name} has wire changes but no API version`);
+  }
+  use.add(`${utils.buildImportPath(client.module, client.module)}::models`, 'WireChange');
+
+  const emitChange = (change: rust.WireChange): string => {
+    const path = `&[${change.path.map((name) => `"${name}"`).join(', ')}]`;
+    switch (change.kind) {
+      case 'name':
+        return `WireChange::Name { path: ${path}, name: "${change.name}", previous: "${change.previousName}" }`;
+      case 'type':
+        use.add(`${utils.buildImportPath(client.module, client.module)}::models`, 'JsonKind');
+        return `WireChange::Type { path: ${path}, kind: JsonKind::${utils.capitalize(change.type)}, previous: JsonKind::${utils.capitalize(change.previousType)} }`;
+      case 'value':
+        return `WireChange::Value { path: ${path}, value: "${change.value}", previous: "${change.previousValue}" }`;
+    }
+  };
+
+  // group the API versions that use the same changes
+  const groups = new Array<{ apiVersions: Array<string>, changes: Array<rust.WireChange> }>();
+  for (const version of client.apiVersions) {
+    const versionChanges = changes.filter((change) => change.apiVersions.includes(version));
+    if (versionChanges.length === 0) {
+      continue;
+    }
+    const group = groups.find((group) => group.changes.length === versionChanges.length && group.changes.every((change, i) => change === versionChanges[i]));
+    if (group) {
+      group.apiVersions.push(version);
+    } else {
+      groups.push({ apiVersions: [version], changes: versionChanges });
+    }
+  }
+
+  let selected = `${indent.get()}let ${varName}: &[WireChange] = `;
+  for (const group of groups) {
+    selected += `if ${getApiVersionsCheck(apiVersion, `self.${apiVersion.name}`, group.apiVersions)} {\n`;
+    selected += `${indent.push().get()}&[${group.changes.map(emitChange).join(', ')}]\n`;
+    selected += `${indent.pop().get()}} else `;
+  }
+  selected += `{\n${indent.push().get()}&[]\n${indent.pop().get()}};\n`;
+  return selected;
+}
+
 /**
  * constructs the body for an async client method
  * 
@@ -1324,9 +2120,13 @@ function getAsyncMethodBody(indent: helpers.indentation, use: Use, client: rust.
   use.add('azure_core::http', 'Method', 'Request');
 
   const urlVarName = helpers.getUniqueVarName(method.params, ['url', 'url_var']);
-  const paramGroups = getMethodParamGroup(method);
+  const paramGroups = getMethodParamGroup(client, method);
   let body = checkEmptyRequiredPathParams(indent, paramGroups.path);
   body += 'let options = options.unwrap_or_default();\n';
+  body += checkApiVersions(indent, client, method, paramGroups);
+  body += checkRequestConditions(indent, method, paramGroups);
+  body += checkParamConstraints(indent, use, paramGroups);
+  body += checkDurationParams(indent, use, paramGroups);
   body += `${indent.get()}let ctx = options.method_options.context.to_borrowed();\n`;
   body += `${indent.get()}let ${urlVarNeedsMut(paramGroups, method)}${urlVarName} = self.${client.endpoint.name}.clone();\n`;
 
@@ -1334,9 +2134,18 @@ function getAsyncMethodBody(indent: helpers.indentation, use: Use, client: rust.
   const requestResult = constructRequest(indent, use, method, paramGroups, false, urlVarName);
   body += requestResult.content;
 
+  const modelsPath = `${utils.buildImportPath(client.module, client.module)}::models`;
+  if (method.requestWireChanges.length > 0) {
+    // the body is converted to the wire format of the client's API version after it's set
+    use.add(modelsPath, 'to_previous_wire_format');
+    body += getWireChanges(indent, use, client, paramGroups, 'request_wire_changes', method.requestWireChanges);
+    body += `${indent.get()}to_previous_wire_format(&mut ${requestResult.requestVarName}, request_wire_changes)?;\n`;
+  }
+
   let pipelineMethod: string;
   switch (method.returns.type.kind) {
     case 'asyncResponse':
+    case 'itemStream':
       pipelineMethod = 'stream';
       break;
     case 'response':
@@ -1344,7 +2153,18 @@ function getAsyncMethodBody(indent: helpers.indentation, use: Use, client: rust.
       break;
   }
   body += `${indent.get()}let rsp = self.pipeline.${pipelineMethod}(&ctx, &mut ${requestResult.requestVarName}, ${getPipelineOptions(indent, use, method)}).await?;\n`;
-  body += `${indent.get()}Ok(rsp.into())\n`;
+  if (method.returns.type.kind === 'itemStream') {
+    // the items are parsed from the body as it's streamed
+    const streamFn = `${method.returns.type.format}_stream`;
+    use.add(`${utils.buildImportPath(client.module, method.returns.type.module)}::models`, streamFn);
+    body += `${indent.get()}Ok(${streamFn}(rsp.into_body()))\n`;
+  } else if (method.responseWireChanges.length > 0) {
+    use.add(modelsPath, 'from_previous_wire_format');
+    body += getWireChanges(indent, use, client, paramGroups, 'response_wire_changes', method.responseWireChanges);
+    body += `${indent.get()}Ok(from_previous_wire_format(rsp, response_wire_changes)?.into())\n`;
+  } else {
+    body += `${indent.get()}Ok(rsp.into())\n`;
+  }
   return body;
 }
 
@@ -1364,11 +2184,15 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
   use.addForType(method.returns.type);
   use.addForType(helpers.unwrapType(method.returns.type));
 
-  const paramGroups = getMethodParamGroup(method);
+  const paramGroups = getMethodParamGroup(client, method);
   const urlVar = method.strategy ? 'first_url' : helpers.getUniqueVarName(method.params, ['url', 'url_var']);
 
   let body = checkEmptyRequiredPathParams(indent, paramGroups.path);
   body += 'let options = options.unwrap_or_default().into_owned();\n';
+  body += checkApiVersions(indent, client, method, paramGroups);
+  body += checkRequestConditions(indent, method, paramGroups);
+  body += checkParamConstraints(indent, use, paramGroups);
+  body += checkDurationParams(indent, use, paramGroups);
   body += `${indent.get()}let pipeline = self.pipeline.clone();\n`;
   body += `${indent.get()}let ${urlVarNeedsMut(paramGroups, method)}${urlVar} = self.${client.endpoint.name}.clone();\n`;
   body += constructUrl(indent, use, method, paramGroups, urlVar);
@@ -1382,10 +2206,22 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
   // no paging strategy
   let srcUrlVar: string;
 
+  // for the nextLinkOperation strategy, the name of the
+  // local that indicates if we're fetching a subsequent page
+  let nextPageVar: string | undefined;
+
   if (method.strategy) {
     if (paramGroups.apiVersion) {
-      body += `${indent.get()}let ${paramGroups.apiVersion.name} = ${getHeaderPathQueryParamValue(use, paramGroups.apiVersion, true, true)}.clone();\n`;
+      body += `${indent.get()}let ${paramGroups.apiVersion.name} = ${cloneApiVersionParam(use, paramGroups.apiVersion)};\n`;
+    }
+    const closureParams: Array<rust.MethodParameter | rust.NextLinkOperationParameter> = [...paramGroups.header];
+    if (method.strategy.kind === 'nextLink' || method.strategy.kind === 'nextLinkOperation') {
+      closureParams.push(...method.strategy.reinjectedParams);
     }
+    if (method.strategy.kind === 'nextLinkOperation') {
+      closureParams.push(...method.strategy.params);
+    }
+    body += cloneEndpointApiVersion(indent, paramGroups, closureParams);
 
     switch (method.strategy.kind) {
       case 'continuationToken': {
@@ -1410,11 +2246,18 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
         srcUrlVar = 'url';
         break;
       }
-      case 'nextLink': {
+      case 'nextLink':
+      case 'nextLinkOperation': {
         const nextLinkName = method.strategy.nextLinkPath[method.strategy.nextLinkPath.length - 1].name;
         const reinjectedParams = method.strategy.reinjectedParams;
         body += `${indent.get()}Ok(${method.returns.type.name}::new(move |${nextLinkName}: PagerState, pager_options| {\n`;
-        body += `${indent.push().get()}let url = ` + helpers.buildMatch(indent, nextLinkName, [{
+        indent.push();
+        if (method.strategy.kind === 'nextLinkOperation') {
+          // subsequent pages are fetched with a different request so track which one to build
+          nextPageVar = helpers.getUniqueVarName(method.params, ['next_page', 'is_next_page']);
+          body += `${indent.get()}let ${nextPageVar} = matches!(${nextLinkName}, PagerState::More(_));\n`;
+        }
+        body += `${indent.get()}let url = ` + helpers.buildMatch(indent, nextLinkName, [{
           pattern: `PagerState::More(${nextLinkName})`,
           body: (indent) => {
             const cloneNextLink = `${indent.get()}let mut ${nextLinkName}: Url = ${nextLinkName}.try_into().expect("expected Url");\n`;
@@ -1427,7 +2270,7 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
               needsTryInto = false;
               use.add('azure_core::http', 'UrlExt');
               content += `${indent.get()}let mut query_builder = ${nextLinkName}.query_builder();\n`;
-              content += `${indent.get()}query_builder.set_pair("${paramGroups.apiVersion.key}", &${paramGroups.apiVersion.name});\n`;
+              content += `${indent.get()}query_builder.set_pair("${paramGroups.apiVersion.key}", ${getApiVersionQueryValue(paramGroups.apiVersion)});\n`;
             } else if (reinjectedParams.length > 0) {
               // if we didn't try_into above, we'll need to do it for this case
               needsTryInto = false;
@@ -1442,7 +2285,7 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
             }
             for (const reinjectedParam of reinjectedParams) {
               content += getParamValueHelper(indent, reinjectedParam, () => {
-                return `${indent.get()}query_builder.set_pair("${reinjectedParam.key}", ${getHeaderPathQueryParamValue(use, reinjectedParam, false, false)});\n`;
+                return `${indent.get()}query_builder.set_pair(${reinjectedParam.kind === 'queryScalar' ? getParamWireName(reinjectedParam, reinjectedParam.key, paramGroups, true) : `"${reinjectedParam.key}"`}, ${getHeaderPathQueryParamValue(use, reinjectedParam, false, false)});\n`;
               });
             }
             if (hasQueryBuilder) {
@@ -1461,6 +2304,12 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
       }
     }
   } else {
+    if (paramGroups.apiVersion && paramGroups.header.some((param) => param.kind === 'headerScalar' && param.previousNames.length > 0)) {
+      // the API version selects the names of any renamed headers
+      body += `${indent.get()}let ${paramGroups.apiVersion.name} = ${cloneApiVersionParam(use, paramGroups.apiVersion)};\n`;
+    }
+    body += cloneEndpointApiVersion(indent, paramGroups, paramGroups.header);
+
     // no next link when there's no strategy
     body += `${indent.get()}Ok(${method.returns.type.name}::new(move |_: PagerState, pager_options| {\n`;
     indent.push();
@@ -1480,18 +2329,35 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
     rspInto = '.into()';
   }
 
-  const requestResult = constructRequest(indent, use, method, paramGroups, true, srcUrlVar, cloneUrl);
+  let requestResult: { requestVarName: string, content: string };
+  if (method.strategy?.kind === 'nextLinkOperation' && nextPageVar) {
+    const strategy = method.strategy;
+    const requestVarName = helpers.getUniqueVarName(method.params, ['request', 'core_req']);
+    let content = `${indent.get()}let mut ${requestVarName} = ` + helpers.buildIfBlock(indent, {
+      condition: nextPageVar,
+      body: (indent) => getNextLinkOperationRequest(indent, use, client, method, strategy, requestVarName, srcUrlVar),
+    }, {
+      body: (indent) => {
+        // the request is returned from the block so it only needs to be mutable if it's modified
+        const initialRequest = constructRequest(indent, use, method, paramGroups, true, srcUrlVar, false, !!paramGroups.body || paramGroups.partialBody.length > 0);
+        return initialRequest.content + `${indent.get()}${initialRequest.requestVarName}\n`;
+      },
+    }) + ';\n';
+    requestResult = { requestVarName: requestVarName, content: content };
+  } else {
+    requestResult = constructRequest(indent, use, method, paramGroups, true, srcUrlVar, cloneUrl);
+  }
   body += requestResult.content;
   body += `${indent.get()}let pipeline = pipeline.clone();\n`;
   body += `${indent.get()}Box::pin(`;
-  if (method.strategy?.kind === 'nextLink') {
+  if (method.strategy?.kind === 'nextLink' || method.strategy?.kind === 'nextLinkOperation') {
     body += `{\n${indent.push().get()}let first_url = first_url.clone();\n${indent.get()}`;
   }
   body += `async move {\n`;
   body += `${indent.push().get()}let rsp${rspType} = pipeline.send(&pager_options.context, &mut ${requestResult.requestVarName}, ${getPipelineOptions(indent, use, method)}).await?${rspInto};\n`;
 
   // check if we need to extract the next link field from the response model
-  if (method.strategy && (method.strategy.kind === 'nextLink' || method.strategy.responseToken.kind === 'nextLink')) {
+  if (method.strategy && (method.strategy.kind === 'nextLink' || method.strategy.kind === 'nextLinkOperation' || method.strategy.responseToken.kind === 'nextLink')) {
     const bodyFormat = helpers.convertResponseFormat(method.returns.type.type.format);
     use.add('azure_core', bodyFormat, 'http::RawResponse');
     body += `${indent.get()}let (status, headers, body) = rsp.deconstruct();\n`;
@@ -1537,7 +2403,8 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
         }
         continuation = `PagerContinuation::Token(${nextPageValue})`;
         break;
-      case 'nextLink': {
+      case 'nextLink':
+      case 'nextLinkOperation': {
         const lastFieldName = method.strategy.nextLinkPath[method.strategy.nextLinkPath.length - 1].name;
         nextPageValue = lastFieldName;
         srcNextPage = `res.${buildNextLinkPath(method.strategy.nextLinkPath)}`;
@@ -1568,7 +2435,7 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
     body += `${indent.get()}Ok(PagerResult::Done { response: rsp.into() })\n`;
   }
 
-  if (method.strategy?.kind === 'nextLink') {
+  if (method.strategy?.kind === 'nextLink' || method.strategy?.kind === 'nextLinkOperation') {
     body += `${indent.pop().get()}}\n`;
   }
   body += `${indent.pop().get()}})\n`; // end Box::pin(async move {
@@ -1578,6 +2445,37 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
   return body;
 }
 
+/**
+ * emits the code for building the request that fetches a subsequent
+ * page for a pageable method using the nextLinkOperation strategy.
+ * the emitted code evaluates to the request.
+ * 
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param client the client to which the method belongs
+ * @param method the pageable method
+ * @param strategy the method's paging strategy
+ * @param requestVarName the name of the request var
+ * @param urlVarName the name of the var that contains the next link URL
+ * @returns the code for building the next page request
+ */
+function getNextLinkOperationRequest(indent: helpers.indentation, use: Use, client: rust.Client, method: rust.PageableMethod, strategy: rust.PageableStrategyNextLinkOperation, requestVarName: string, urlVarName: string): string {
+  const paramGroups = getMethodParamGroup(client, method, strategy.params);
+  const headers = paramGroups.header.filter((headerParam) => !isOptionalContentTypeHeader(headerParam));
+  const mutRequest = (paramGroups.body || paramGroups.cookie.length > 0 || headers.length > 0) ? 'mut ' : '';
+  let body = `${indent.get()}let ${mutRequest}${requestVarName} = Request::new(${urlVarName}, Method::${utils.capitalize(strategy.httpMethod)});\n`;
+  body += applyHeaderParams(indent, use, method, paramGroups, true, requestVarName);
+  const bodyParam = paramGroups.body;
+  if (bodyParam) {
+    if (bodyParam.type.format === 'MultipartFormat') {
+      throw new CodegenError('InternalError', `multipart body for next page operation of method ${method.name} is not supported`);
+    }
+    body += getParamValueHelper(indent, bodyParam, () => `${indent.get()}${requestVarName}.set_body(${bodyParam.name}.clone());\n`);
+  }
+  body += `${indent.get()}${requestVarName}\n`;
+  return body;
+}
+
 /**
  * constructs the body for an LRO client method
  *
@@ -1589,45 +2487,62 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
  * @returns the contents of the method body
  */
 function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: Use, client: rust.Client, method: rust.LroMethod): string {
-  let pollingStepHeaderName = undefined;
-  for (const header of ['operation-location', 'azure-asyncoperation', 'location']) {
-    if (method.responseHeaders?.headers.some(h => h.header.toLowerCase() === header)) {
-      pollingStepHeaderName = header;
-      break;
+  let pollingStepHeaderName: string | undefined;
+  if (method.statusMonitor?.kind === 'header') {
+    pollingStepHeaderName = method.statusMonitor.headerName;
+  } else if (!method.statusMonitor) {
+    for (const header of ['operation-location', 'azure-asyncoperation', 'location']) {
+      if (method.responseHeaders?.headers.some(h => h.header.toLowerCase() === header)) {
+        pollingStepHeaderName = header;
+        break;
+      }
     }
   }
 
+  // when the final result is read from the status monitor, it's
+  // captured from the response that indicates the LRO has completed.
+  const finalResultStrategy = method.finalResultStrategy;
+  const finalResultFromStatusMonitor = finalResultStrategy.kind === 'statusMonitor'
+    || (finalResultStrategy.kind === 'header' && finalResultStrategy.headerName === pollingStepHeaderName);
+
   const bodyFormat = helpers.convertResponseFormat(method.returns.type.type.format);
 
   use.add('azure_core::http', 'Method', 'RawResponse', 'Request', 'Url');
   use.add('azure_core::http::headers', 'RETRY_AFTER', 'X_MS_RETRY_AFTER_MS', 'RETRY_AFTER_MS');
   use.add('azure_core::http::poller', 'get_retry_after', 'PollerContinuation', 'PollerResult', 'PollerState', 'PollerStatus', 'StatusMonitor');
-  if (pollingStepHeaderName !== undefined || method.finalResultStrategy.kind !== 'originalUri') {
+  if (pollingStepHeaderName !== undefined || method.finalResultStrategy.kind === 'header') {
     use.add('azure_core::http::headers', 'HeaderName');
   }
 
   use.addForType(method.returns.type);
   use.addForType(helpers.unwrapType(method.returns.type));
 
-  const paramGroups = getMethodParamGroup(method);
+  const paramGroups = getMethodParamGroup(client, method);
   const urlVar = helpers.getUniqueVarName(method.params, ['url', 'url_var']);
 
   let body = 'let options = options.unwrap_or_default().into_owned();\n';
+  body += checkApiVersions(indent, client, method, paramGroups);
+  body += checkRequestConditions(indent, method, paramGroups);
+  body += checkParamConstraints(indent, use, paramGroups);
+  body += checkDurationParams(indent, use, paramGroups);
   body += `${indent.get()}let pipeline = self.pipeline.clone();\n`;
   body += `${indent.get()}let ${urlVarNeedsMut(paramGroups, method)}${urlVar} = self.${client.endpoint.name}.clone();\n`;
   body += constructUrl(indent, use, method, paramGroups, urlVar);
   if (paramGroups.apiVersion) {
-    body += `${indent.get()}let ${paramGroups.apiVersion.name} = ${getHeaderPathQueryParamValue(use, paramGroups.apiVersion, true, true)}.clone();\n`;
+    body += `${indent.get()}let ${paramGroups.apiVersion.name} = ${cloneApiVersionParam(use, paramGroups.apiVersion)};\n`;
   }
+  body += cloneEndpointApiVersion(indent, paramGroups, paramGroups.header);
 
   // we call this eagerly so that we have access to the request var name
   const initialRequestResult = constructRequest(indent, use, method, paramGroups, true, urlVar, true, false);
 
   const declareRequest = function (indent: helpers.indentation, use: Use, method: rust.LroMethod, paramGroups: MethodParamGroups, requestVarName: string, linkExpr: string, forceMut?: boolean, optionsPrefix?: string): string {
+    // repeatability headers only apply to the initial request
+    paramGroups = { ...paramGroups, header: paramGroups.header.filter((headerParam) => headerParam.kind !== 'headerScalar' || !headerParam.repeatability) };
     let mutRequest = '';
     // if the only header is optional Content-Type it will not be used
     // by applyHeaderParams() in this case so don't make request mutable
-    if (forceMut || paramGroups.header.length > 1 || (paramGroups.header.length === 1 && !isOptionalContentTypeHeader(paramGroups.header[0]))) {
+    if (forceMut || paramGroups.cookie.length > 0 || paramGroups.header.length > 1 || (paramGroups.header.length === 1 && !isOptionalContentTypeHeader(paramGroups.header[0]))) {
       mutRequest = 'mut ';
     }
 
@@ -1635,7 +2550,8 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
       + applyHeaderParams(indent, use, method, paramGroups, true, requestVarName, optionsPrefix);
   };
 
-  body += `${indent.get()}Ok(${method.returns.type.name}::new(\n`
+  // for paged LROs, the poller is driven by the pager that's returned
+  body += `${indent.get()}${method.pageStrategy ? 'let poller = ' : 'Ok('}${method.returns.type.name}::new(\n`
   body += `${indent.push().get()}move |poller_state: PollerState, poller_options| {\n`;
   body += `${indent.push().get()}let (mut ${initialRequestResult.requestVarName}, continuation) = ${helpers.buildMatch(indent, 'poller_state', [{
     pattern: `PollerState::More(continuation)`,
@@ -1653,7 +2569,7 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
       if (paramGroups.apiVersion?.kind === 'queryScalar') {
         use.add('azure_core::http', 'UrlExt');
         body += `${indent.get()}let mut query_builder = next_link.query_builder();\n`;
-        body += `${indent.get()}query_builder.set_pair("${paramGroups.apiVersion.key}", &${paramGroups.apiVersion.name});\n`;
+        body += `${indent.get()}query_builder.set_pair("${paramGroups.apiVersion.key}", ${getApiVersionQueryValue(paramGroups.apiVersion)});\n`;
         body += `${indent.get()}query_builder.build();\n`;
       }
 
@@ -1681,10 +2597,10 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
   if (method.finalResultStrategy.kind === 'originalUri') {
     body += `${indent.get()}let final_link = url.clone();\n`
 
-    for (const headerParam of paramGroups.header.filter(h => h.type.kind !== 'literal' && !isOptionalContentTypeHeader(h))) {
+    for (const headerParam of paramGroups.header.filter(h => h.type.kind !== 'literal' && !isOptionalContentTypeHeader(h) && (h.kind !== 'headerScalar' || !h.repeatability))) {
       if (headerParam.type.kind !== 'enum') {
         const optionsPrefix = headerParam.optional ? 'options.' : '';
-        body += `${indent.get()}let ${headerParam.name} = ${optionsPrefix}${headerParam.name}.clone();\n`
+        body += `${indent.get()}let ${headerParam.name} = ${optionsPrefix}${headerParam.optional ? optionsFieldPath(headerParam) : headerParam.name}.clone();\n`
       }
     }
   }
@@ -1719,6 +2635,17 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
     && method.statusCodes.some(sc => sc == 200)
     && method.statusCodes.some(sc => sc == 202);
 
+  // Match an ARM POST operation polled via the Location header (e.g. a paged action), which
+  // may have an empty initial response and whose 200 OK response contains the final result
+  const isArmPostLocationLro = bodyFormat === 'json'
+    && method.httpMethod === 'post'
+    && method.returns.type.resultType !== undefined
+    && method.finalResultStrategy.kind === 'header'
+    && method.finalResultStrategy.headerName === 'location'
+    && pollingStepHeaderName === 'location'
+    && method.statusCodes.some(sc => sc == 200)
+    && method.statusCodes.some(sc => sc == 202);
+
   // Match an ARM DELETE operation, which may have empty responses and only communicate via status codes
   const isArmDeleteLro = bodyFormat === 'json'
     && method.httpMethod === 'delete'
@@ -1736,10 +2663,10 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
   body += `${indent.get()}Box::pin(async move {\n`
   body += `${indent.push().get()}let rsp = pipeline.send(&ctx, &mut ${initialRequestResult.requestVarName}, ${getPipelineOptions(indent, use, method)}).await?;\n`
 
-  const needsMutBody = isArmPutLro || isArmPatchLro || isArmPostLro || isArmDeleteLro;
+  const needsMutBody = isArmPutLro || isArmPatchLro || isArmPostLro || isArmPostLocationLro || isArmDeleteLro;
   body += `${indent.get()}let (status, headers, ${needsMutBody ? 'mut' : ''} body) = rsp.deconstruct();\n`
 
-  if (isArmPostLro || isArmDeleteLro) {
+  if (isArmPostLro || isArmPostLocationLro || isArmDeleteLro) {
     body += `${indent.get()}if body.is_empty() {\n`
     let emptyBodyExpr = '"{}"'
     if (isArmDeleteLro) {
@@ -1750,9 +2677,10 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
     body += `${indent.pop().get()}}\n`;
   }
 
-  if (pollingStepHeaderName !== undefined) {
-    body += `${indent.get()}let continuation = ${helpers.buildIfBlock(indent, {
-      condition: `let Some(operation_location) = headers.get_optional_string(&HeaderName::from_static("${pollingStepHeaderName}"))`,
+  /** updates the continuation's next link when the condition binds operation_location */
+  const updateNextLink = (condition: string): string => {
+    return `${indent.get()}let continuation = ${helpers.buildIfBlock(indent, {
+      condition: condition,
       body: (indent) => {
         let body = `${indent.get()}let next_link = Url::parse(&operation_location)?;\n`;
         body += `${indent.get()}${helpers.buildMatch(indent, 'continuation', [{
@@ -1767,6 +2695,16 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
     }, {
       body: (indent) => `${indent.get()}continuation\n`
     })};\n`;
+  };
+
+  if (pollingStepHeaderName !== undefined) {
+    body += updateNextLink(`let Some(operation_location) = headers.get_optional_string(&HeaderName::from_static("${pollingStepHeaderName}"))`);
+  } else if (method.statusMonitor?.kind === 'body') {
+    // responses without the link (e.g. from the status monitor) might not have a JSON body
+    use.add('azure_core', 'json');
+    body += `${indent.get()}let operation_location = json::from_json::<_, azure_core::Value>(&body).ok()`
+      + `.and_then(|value| value.get("${method.statusMonitor.fieldName}")?.as_str().map(String::from));\n`;
+    body += updateNextLink('let Some(operation_location) = operation_location');
   }
 
   if (isArmPutLro || isArmPatchLro) {
@@ -1783,6 +2721,13 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
     body += `${indent.push().get()}final_body = Some(body);\n`;
     body += `${indent.get()}body = azure_core::http::response::ResponseBody::from_bytes("{\\"status\\":\\"Succeeded\\"}");\n`;
     body += `${indent.pop().get()}}\n`;
+  } else if (isArmPostLocationLro) {
+    use.add('azure_core::http', 'StatusCode');
+    body += `${indent.get()}let mut final_body = None;\n`;
+    body += `${indent.get()}if status == StatusCode::Ok {\n`;
+    body += `${indent.push().get()}final_body = Some(body);\n`;
+    body += `${indent.get()}body = azure_core::http::response::ResponseBody::from_bytes("{\\"status\\":\\"Succeeded\\"}");\n`;
+    body += `${indent.pop().get()}}\n`;
   }
 
   if (method.finalResultStrategy.kind === 'header' && method.finalResultStrategy.headerName !== pollingStepHeaderName) { // separate link for picking up the result
@@ -1818,18 +2763,18 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
 
   body += `${indent.push().get()}let res: ${helpers.getTypeDeclaration(helpers.unwrapType(method.returns.type))} = ${deserialize}(&body)?;\n`;
 
-  if (method.finalResultStrategy.kind === 'header' && method.finalResultStrategy.headerName === pollingStepHeaderName) {
+  if (finalResultFromStatusMonitor) {
     body += `${indent.get()}let mut final_rsp: Option<RawResponse> = None;\n`
     body += `if res.status() == PollerStatus::Succeeded {\n`
     let responseBodyExpr = 'body.clone()';
-    if (isArmPutLro || isArmPatchLro) {
+    if (isArmPutLro || isArmPatchLro || isArmPostLocationLro) {
       responseBodyExpr = 'if let Some(final_body) = final_body { final_body } else { body.clone() }';
-    } else if (method.finalResultStrategy.propertyName !== undefined) {
+    } else if (finalResultStrategy.kind !== 'originalUri' && finalResultStrategy.propertyName !== undefined) {
       responseBodyExpr = 'body';
       if (bodyFormat === 'json') {
         crate.addDependency(new rust.CrateDependency('serde_json'));
         body += `${indent.get()}let body = azure_core::http::response::ResponseBody::from_bytes(`
-          + `serde_json::from_str::<azure_core::Value>(body.clone().into_string()?.as_str())?["${method.finalResultStrategy.propertyName}"].to_string());\n`;
+          + `serde_json::from_str::<azure_core::Value>(body.clone().into_string()?.as_str())?["${finalResultStrategy.propertyName}"].to_string());\n`;
       }
     }
     body += `${indent.get()}final_rsp = Some(RawResponse::from_bytes(status, headers.clone(), ${responseBodyExpr}));\n`;
@@ -1855,7 +2800,7 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
         + `${indent.get()}target: Box::new(move || {\n`
         + `${indent.push().get()}Box::pin(async move {\n`;
 
-      if (method.finalResultStrategy.kind === 'header' && method.finalResultStrategy.headerName === pollingStepHeaderName) {
+      if (finalResultFromStatusMonitor) {
         use.add('azure_core::error', 'Error', 'ErrorKind');
         body += `Ok(final_rsp.ok_or_else(|| { Error::new(ErrorKind::Other, "missing final response")})?.into())\n`
       } else {
@@ -1882,8 +2827,153 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
   body += `${indent.get()}Ok(${helpers.buildMatch(indent, 'res.status()', arms)})\n`;
   body += `${indent.pop().get()}})\n`; // end async move
   body += `${indent.pop().get()}},\n`; // end move
-  body += `${indent.pop().get()} Some(options.method_options),))`; // end Ok/Poller::new
+  if (method.pageStrategy) {
+    body += `${indent.pop().get()} Some(options.method_options),);\n`; // end Poller::new
+    body += getLroPagerBody(indent, use, client, method, method.pageStrategy);
+  } else {
+    body += `${indent.pop().get()} Some(options.method_options),))`; // end Ok/Poller::new
+  }
+
+  return body;
+}
+
+/**
+ * returns the type declaration of the pages of a paged LRO,
+ * including the format when it's not the default.
+ *
+ * @param method the paged LRO method
+ * @returns the page type declaration, e.g. ListResult
+ */
+function getLroPageTypeDeclaration(method: rust.LroMethod): string {
+  const pageType = method.returns.type.resultType;
+  if (!pageType || pageType.content.kind !== 'model') {
+    throw new CodegenError('InternalError', `missing page type for paged LRO method ${method.name}`);
+  }
+  const formatParam = pageType.format !== 'JsonFormat' ? `, ${pageType.format}` : '';
+  return `${helpers.getTypeDeclaration(pageType.content)}${formatParam}`;
+}
+
+/**
+ * constructs the Pager returned from a paged LRO. the first page is the
+ * final result of the LRO's poller, which is polled to completion when
+ * the first page is requested.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param client the client to which the method belongs
+ * @param method the paged LRO method
+ * @param strategy the strategy used to fetch subsequent pages
+ * @returns the code that returns the Pager from the LRO method
+ */
+function getLroPagerBody(indent: helpers.indentation, use: Use, client: rust.Client, method: rust.LroMethod, strategy: rust.PageableStrategyNextLink): string {
+  const pageType = method.returns.type.resultType;
+  if (!pageType || pageType.content.kind !== 'model') {
+    throw new CodegenError('InternalError', `missing page type for paged LRO method ${method.name}`);
+  }
 
+  use.add('azure_core', 'Result');
+  use.add('azure_core::error', 'Error', 'ErrorKind');
+  use.add('azure_core::http', 'Method', 'RawResponse', 'Request', 'Url');
+  use.add('azure_core::http::pager', 'Pager', 'PagerContinuation', 'PagerResult', 'PagerState');
+  use.add('std::sync', 'Arc', 'Mutex', 'PoisonError');
+  use.addForType(pageType);
+
+  const bodyFormat = helpers.convertResponseFormat(pageType.format);
+  use.add('azure_core', bodyFormat);
+  const pageTypeDecl = helpers.getTypeDeclaration(pageType.content);
+
+  // only client api-version params are available outside of the poller
+  const paramGroups = getMethodParamGroup(client, method);
+  const apiVersion = paramGroups.apiVersion?.kind === 'queryScalar' && paramGroups.apiVersion.location === 'client' ? paramGroups.apiVersion : undefined;
+
+  const nextLinkName = strategy.nextLinkPath[strategy.nextLinkPath.length - 1].name;
+  let nextLinkPath = strategy.nextLinkPath[0].name;
+  for (let i = 1; i < strategy.nextLinkPath.length; ++i) {
+    nextLinkPath += `.and_then(|${strategy.nextLinkPath[i - 1].name}| ${strategy.nextLinkPath[i - 1].name}.${strategy.nextLinkPath[i].name})`;
+  }
+
+  // the poller's closure has taken ownership of the locals it uses,
+  // so the pager's closure gets its own. optional reinjected params
+  // are read from the method's options.
+  const reinjectedParams = strategy.reinjectedParams;
+  let body = `${indent.get()}let poller = Arc::new(Mutex::new(Some(poller)));\n`;
+  body += `${indent.get()}let pipeline = self.pipeline.clone();\n`;
+  body += `${indent.get()}let endpoint = self.${client.endpoint.name}.clone();\n`;
+  if (apiVersion) {
+    body += `${indent.get()}let ${apiVersion.name} = ${cloneApiVersionParam(use, apiVersion)};\n`;
+  }
+  for (const param of reinjectedParams) {
+    if (param.location === 'client') {
+      body += `${indent.get()}let ${param.name} = self.${param.name}.clone();\n`;
+    } else if (!param.optional && param.type.kind === 'ref') {
+      body += `${indent.get()}let ${param.name} = ${param.name}.to_owned();\n`;
+    }
+  }
+  body += `${indent.get()}Ok(Pager::new(move |${nextLinkName}: PagerState, pager_options| {\n`;
+  body += `${indent.push().get()}let request = ${helpers.buildMatch(indent, nextLinkName, [{
+    pattern: `PagerState::More(${nextLinkName})`,
+    body: (indent) => {
+      const hasQueryBuilder = !!apiVersion || reinjectedParams.length > 0;
+      let content = `${indent.get()}let ${hasQueryBuilder ? 'mut ' : ''}${nextLinkName}: Url = ${nextLinkName}.try_into().expect("expected Url");\n`;
+      if (hasQueryBuilder) {
+        use.add('azure_core::http', 'UrlExt');
+        content += `${indent.get()}let mut query_builder = ${nextLinkName}.query_builder();\n`;
+      }
+      if (apiVersion) {
+        content += `${indent.get()}query_builder.set_pair("${apiVersion.key}", ${getApiVersionQueryValue(apiVersion)});\n`;
+      }
+      for (const param of reinjectedParams) {
+        content += getParamValueHelper(indent, param, () => {
+          // required and client params are owned locals
+          const paramValue = getHeaderPathQueryParamValue(use, param, false, false, !param.optional && param.location === 'method' && param.type.kind === 'ref' ? `&${param.name}` : undefined);
+          const wireName = param.kind === 'queryScalar' ? getParamWireName(param, param.key, { ...paramGroups, apiVersion, endpointApiVersion: undefined }, true) : `"${param.key}"`;
+          return `${indent.get()}query_builder.set_pair(${wireName}, ${paramValue});\n`;
+        }, `${method.options.name}.`, '');
+      }
+      if (hasQueryBuilder) {
+        content += `${indent.get()}query_builder.build();\n`;
+      }
+      content += `${indent.get()}let mut request = Request::new(${nextLinkName}, Method::Get);\n`;
+      content += `${indent.get()}request.insert_header("accept", "application/${bodyFormat}");\n`;
+      content += `${indent.get()}Some(request)\n`;
+      return content;
+    },
+  }, {
+    pattern: 'PagerState::Initial',
+    body: (indent) => `${indent.get()}None\n`,
+  }])};\n`;
+  body += `${indent.get()}let pipeline = pipeline.clone();\n`;
+  body += `${indent.get()}let endpoint = endpoint.clone();\n`;
+  body += `${indent.get()}let poller = poller.clone();\n`;
+  body += `${indent.get()}Box::pin(async move {\n`;
+  body += `${indent.push().get()}let rsp = ${helpers.buildMatch(indent, 'request', [{
+    // subsequent pages have the same success codes as the LRO's requests
+    pattern: 'Some(mut request)',
+    body: (indent) => `${indent.get()}pipeline.send(&pager_options.context, &mut request, ${getPipelineOptions(indent, use, method)}).await?\n`,
+  }, {
+    pattern: 'None',
+    body: (indent) => {
+      let content = `${indent.get()}let poller = poller.lock().unwrap_or_else(PoisonError::into_inner).take().ok_or_else(|| Error::new(ErrorKind::Other, "the long-running operation has already been polled"))?;\n`;
+      content += `${indent.get()}let (status, headers, body) = poller.await?.deconstruct();\n`;
+      content += `${indent.get()}RawResponse::from_bytes(status, headers, body)\n`;
+      return content;
+    },
+  }])};\n`;
+  body += `${indent.get()}let (status, headers, body) = rsp.deconstruct();\n`;
+  body += `${indent.get()}let res: ${pageTypeDecl} = ${bodyFormat}::from_${bodyFormat}(&body)?;\n`;
+  body += `${indent.get()}let rsp = RawResponse::from_bytes(status, headers, body).into();\n`;
+  body += `${indent.get()}Ok(${helpers.buildMatch(indent, `res.${nextLinkPath}`, [{
+    pattern: `Some(${nextLinkName}) if !${nextLinkName}.is_empty()`,
+    body: (indent) => `${indent.get()}response: rsp, continuation: PagerContinuation::Link(endpoint.join(${nextLinkName}.as_ref())?)\n`,
+    returns: 'PagerResult::More',
+  }, {
+    pattern: '_',
+    body: (indent) => `${indent.get()}response: rsp\n`,
+    returns: 'PagerResult::Done',
+  }])})\n`;
+  body += `${indent.pop().get()}})\n`; // end Box::pin(async move {
+  body += `${indent.pop().get()}},\n${indent.get()}None,\n`; // end move {
+  body += `${indent.get()}))`; // end Ok(Pager::new(
   return body;
 }
 
@@ -1911,6 +3001,8 @@ function getClientSupplementalEndpointParamValue(param: rust.ClientSupplementalE
       return `&${paramName}.to_string()`;
     case 'str':
       return paramName;
+    case 'Url':
+      return `${paramName}.as_str()`;
     default:
       throw new CodegenError('InternalError', `unhandled ${param.kind} param type kind ${param.type.kind}`);
   }
@@ -1933,7 +3025,7 @@ function getClientSupplementalEndpointParamValue(param: rust.ClientSupplementalE
  * @param overrideParamName optional value to use as the parameter name instead of param.name
  * @returns the code to use for the param's value
  */
-function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathParamType | QueryParamType, fromSelf: boolean, neverBorrow: boolean, overrideParamName?: string): string {
+function getHeaderPathQueryParamValue(use: Use, param: rust.CookieScalarParameter | HeaderParamType | PathParamType | QueryParamType, fromSelf: boolean, neverBorrow: boolean, overrideParamName?: string): string {
   let paramName = param.name;
   // when fromSelf is false we assume that there's a local with the same name.
   // e.g. in pageable methods where we need to clone the params so they can be
@@ -1969,6 +3061,17 @@ function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathPar
     }
   };
 
+  const encodeDuration = function (type: rust.Duration, paramName: string, borrow: boolean): string {
+    if (isDurationToFallible(type.encoding)) {
+      // the value was checked by checkDurationParams before the request was built.
+      // this can be in a closure (e.g. pageable methods) so the helper's Result can't be propagated.
+      return `${paramName}.whole_milliseconds().to_string()`;
+    }
+    const encoding = getDurationEncodingMethod(type.encoding, 'to', use);
+    const encoded = `${encoding}(${borrow ? '&' : ''}${paramName})`;
+    return type.encoding === 'iso8601' ? encoded : `${encoded}.to_string()`;
+  };
+
   // this will contain the final text for
   // retrieving the param value. this will include
   // any required conversions and/or borrowing
@@ -1992,9 +3095,17 @@ function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathPar
         case 'encodedBytes':
           strConv = encodeBytes(paramType);
           break;
+        case 'duration':
+          // the iso8601 helper already returns a String
+          strConv = paramType.encoding === 'iso8601' ? getDurationEncodingMethod(paramType.encoding, 'to', use) : `|i| ${encodeDuration(paramType, 'i', false)}`;
+          break;
         case 'offsetDateTime':
           strConv = `|i| ${encodeDateTime(paramType, 'i')}`;
           break;
+        case 'plainDate':
+        case 'plainTime':
+          strConv = getPlainDateTimeMethod(paramType, 'to', use);
+          break;
         default:
           strConv = '|i| i.to_string()';
       }
@@ -2016,6 +3127,7 @@ function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathPar
       case 'decimal':
       case 'Etag':
       case 'external':
+      case 'Url':
         // NOTE: for external we're assuming the type provides a to_string() implementation
         paramValue = `${paramName}.to_string()`;
         break;
@@ -2026,7 +3138,7 @@ function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathPar
       case 'scalar':
         if (isEnumString(paramType) && (helpers.isPathParameter(param) || helpers.isQueryParameter(param))) {
           // append_pair and path.replace() want a reference to the string
-          paramValue = (param.location === 'client' && fromSelf) ? `${paramName}.as_str()` : `${paramName}.as_ref()`;
+          paramValue = `${paramName}.as_ref()`;
           // as_ref() elides the need to borrow
           mustBorrow = false;
         } else {
@@ -2039,9 +3151,16 @@ function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathPar
       case 'literal':
         paramValue = `"${paramType.value}"`;
         break;
+      case 'duration':
+        paramValue = encodeDuration(paramType, paramName, param.type.kind !== 'ref');
+        break;
       case 'offsetDateTime':
         paramValue = encodeDateTime(paramType, paramName);
         break;
+      case 'plainDate':
+      case 'plainTime':
+        paramValue = `${getPlainDateTimeMethod(paramType, 'to', use)}(${param.type.kind !== 'ref' ? '&' : ''}${paramName})`;
+        break;
       default:
         throw new CodegenError('InternalError', `unhandled ${param.kind} param type kind ${paramType.kind}`);
     }
@@ -2083,6 +3202,7 @@ function getCollectionDelimiter(format: rust.CollectionFormat): string {
 function nonCopyableType(type: rust.Type): boolean {
   const unwrappedType = utils.unwrapOption(type);
   switch (unwrappedType.kind) {
+    case 'Etag':
     case 'String':
     case 'Url':
     case 'external':
@@ -2109,6 +3229,7 @@ function getPipelineOptions(indent: helpers.indentation, use: Use, method: Clien
     let pipelineOptions: string;
     switch (method.returns.type.kind) {
       case 'asyncResponse':
+      case 'itemStream':
         pipelineOptions = 'PipelineStreamOptions';
         break;
       default:
@@ -2134,6 +3255,80 @@ function isEnumString(type: rust.Type): type is rust.Enum {
   return unwrapped.kind === 'enum' && unwrapped.type === 'String';
 }
 
+/**
+ * returns the fully qualified enum variant for the provided value
+ * 
+ * @param rustEnum the enum that contains the value
+ * @param value the value for which to find the variant
+ * @returns the enum variant, e.g. ApiVersion::V2024_01_01
+ */
+function getEnumVariant(rustEnum: rust.Enum, value: string): string {
+  const enumValue = rustEnum.values.find((each) => each.value === value);
+  if (!enumValue) {
+    throw new CodegenError('InternalError', `didn't find value ${value} in enum ${rustEnum.name}`);
+  }
+  return `${rustEnum.name}::${enumValue.name}`;
+}
+
+/**
+ * returns the code to clone the api-version param into a local
+ * so that it can be moved into a pager or poller closure.
+ * 
+ * @param use the use statement builder currently in scope
+ * @param param the api-version param to clone
+ * @returns the code to clone the param
+ */
+function cloneApiVersionParam(use: Use, param: ApiVersionParamType): string {
+  if (isEnumString(param.type)) {
+    // the enum's string conversions borrow so clone the enum itself
+    return `${param.location === 'client' ? 'self.' : ''}${param.name}.clone()`;
+  }
+  return `${getHeaderPathQueryParamValue(use, param, true, true)}.clone()`;
+}
+
+/**
+ * returns the code that clones the client's endpoint API version into a local
+ * so it can be moved into a closure. the local is only required when a renamed
+ * param is sent from within the closure.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param paramGroups the param groups for the method
+ * @param closureParams the params that are sent from within the closure
+ * @returns the code to clone the API version or the empty string
+ */
+function cloneEndpointApiVersion(indent: helpers.indentation, paramGroups: MethodParamGroups, closureParams: Array<rust.MethodParameter | rust.NextLinkOperationParameter>): string {
+  const apiVersion = paramGroups.endpointApiVersion;
+  if (!apiVersion || !closureParams.some((param) => (param.kind === 'headerScalar' || param.kind === 'queryScalar') && param.previousNames.length > 0)) {
+    return '';
+  }
+  return `${indent.get()}let ${apiVersion.name} = self.${apiVersion.name}.clone();\n`;
+}
+
+/**
+ * returns the code to set the api-version query param from
+ * the local created by cloneApiVersionParam.
+ * 
+ * @param param the api-version param
+ * @returns the code for the query param's value
+ */
+function getApiVersionQueryValue(param: ApiVersionParamType): string {
+  return isEnumString(param.type) ? `${param.name}.as_ref()` : `&${param.name}`;
+}
+
+/**
+ * returns the path to an optional param's value within the method options.
+ * conditional request headers are fields within the options' conditions type.
+ *
+ * @param param the optional param
+ * @returns the path to the param's value, e.g. match_conditions.if_match
+ */
+function optionsFieldPath(param: rust.MethodParameter): string {
+  if (param.kind === 'headerScalar' && param.conditions) {
+    return `${utils.snakeCaseName(param.conditions.name)}.${param.name}`;
+  }
+  return param.name;
+}
+
 /** returns the qualified name for a param, prefixing with the group name when the param belongs to a parameter group */
 function qualifiedParamName(param: rust.MethodParameter): string {
   return param.group ? `${param.group.name}.${param.name}` : param.name;
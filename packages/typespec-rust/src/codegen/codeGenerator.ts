@@ -3,16 +3,23 @@
  *  Licensed under the MIT License. See License.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
 // cspell: ignore cargotoml
-import { emitCargoToml } from './cargotoml.js';
+import { emitCargoToml, mergeCargoToml, UnknownVersionCallback } from './cargotoml.js';
 import { emitClients } from './clients.js';
 import { Context } from './context.js';
 import { emitEnums } from './enums.js';
 import { emitUnions } from './unions.js';
 import { Module } from './helpers.js';
 import { emitLibRs } from './lib.js';
+import { emitMethodErrors } from './methodErrors.js';
 import { emitHeaderTraits } from './headerTraits.js';
 import { emitClientsModRs, emitGeneratedModRs, emitModelsModRs, emitSubModRs } from './mod.js';
 import { emitModels } from './models.js';
+import { emitRequestConditions } from './requestConditions.js';
+import { emitItemStreams } from './streams.js';
+import { emitVersioning } from './versioning.js';
+import { emitCookies } from './cookies.js';
+import { emitDates } from './dates.js';
+import { emitDurations } from './durations.js';
 
 import * as rust from '../codemodel/index.js';
 
@@ -43,10 +50,22 @@ export class CodeGenerator {
   /**
    * generates a Cargo.toml file
    * 
+   * @param onUnknownVersion called for each dependency with no known version
    * @returns the contents for the Cargo.toml file
    */
-  emitCargoToml(): string {
-    return emitCargoToml(this.crate);
+  emitCargoToml(onUnknownVersion?: UnknownVersionCallback): string {
+    return emitCargoToml(this.crate, onUnknownVersion);
+  }
+
+  /**
+   * merges any missing dependencies and features into an existing Cargo.toml file
+   * 
+   * @param existing the contents of the existing Cargo.toml file
+   * @param onUnknownVersion called for each added dependency with no known version
+   * @returns the contents for the merged Cargo.toml file
+   */
+  mergeCargoToml(existing: string, onUnknownVersion?: UnknownVersionCallback): string {
+    return mergeCargoToml(this.crate, existing, onUnknownVersion);
   }
 
   /**
@@ -105,8 +124,19 @@ export class CodeGenerator {
       addModelsFile(dir, files, modelsModRS, models.serde);
       addModelsFile(dir, files, modelsModRS, models.impls);
       addModelsFile(dir, files, modelsModRS, models.xmlHelpers);
+      addModelsFile(dir, files, modelsModRS, models.multipart);
+      addModelsFile(dir, files, modelsModRS, models.nullable);
 
       addModelsFile(dir, files, modelsModRS, emitHeaderTraits(module));
+      addModelsFile(dir, files, modelsModRS, emitMethodErrors(module, this.context));
+      addModelsFile(dir, files, modelsModRS, emitRequestConditions(module));
+      addModelsFile(dir, files, modelsModRS, emitItemStreams(module));
+      addModelsFile(dir, files, modelsModRS, emitVersioning(module));
+
+      // must come last as they emit the helpers referenced by the preceding content
+      addModelsFile(dir, files, modelsModRS, emitCookies(module));
+      addModelsFile(dir, files, modelsModRS, emitDates(module));
+      addModelsFile(dir, files, modelsModRS, emitDurations(module));
 
       if (modelsModRS.length > 0) {
         files.push({ name: `${dir}${modelsSubDir}/mod.rs`, content: emitModelsModRs(modelsModRS) })
@@ -189,7 +219,7 @@ function sortContent(content: rust.ModuleContainer): void {
     for (const method of client.methods) {
       if (method.kind === 'clientaccessor') {
         continue;
-      } else if (method.kind === 'pageable' && method.strategy?.kind === 'nextLink') {
+      } else if (method.kind === 'pageable' && (method.strategy?.kind === 'nextLink' || method.strategy?.kind === 'nextLinkOperation')) {
         method.strategy.reinjectedParams.sort((a: rust.MethodParameter, b: rust.MethodParameter) => sortAscending(a.name, b.name));
       }
       method.options.type.type.fields.sort((a: rust.StructField, b: rust.StructField) => { return sortAscending(a.name, b.name); });
@@ -65,6 +65,11 @@ export class Context {
 
           if (method.returns.type.resultType !== undefined) {
             this.lroResultTypes.set(method.returns.type.type.content, method.returns.type.resultType.content);
+
+            // the final result of a paged LRO is the first page
+            if (method.pageStrategy && method.returns.type.resultType.content.kind === 'model') {
+              this.pagedResponseTypes.add(method.returns.type.resultType.content);
+            }
           }
         }
 
@@ -77,6 +82,15 @@ export class Context {
             if (param.type.format === 'NoFormat' || param.type.format === 'BinaryFormat') {
               // no body format to propagate
               continue;
+            } else if (param.type.format === 'MultipartFormat') {
+              // the multipart model itself isn't serialized with serde.
+              // any structured parts within it are sent as JSON.
+              if (param.type.content.kind === 'model') {
+                for (const field of param.type.content.fields) {
+                  recursiveAddBodyFormat(field.type, 'json');
+                }
+              }
+              continue;
             }
             if (param.type.content.kind === 'enum' || param.type.content.kind === 'model' || param.type.content.kind === 'discriminatedUnion') {
               this.tryFromForRequestTypes.set(helpers.getTypeDeclaration(param.type.content), param.type.format);
@@ -86,6 +100,10 @@ export class Context {
         }
 
         switch (method.returns.type.kind) {
+          case 'itemStream':
+            // each item in the stream is a JSON value
+            recursiveAddBodyFormat(method.returns.type.type, 'json');
+            break;
           case 'pager': {
             recursiveAddBodyFormat(method.returns.type.type.content, helpers.convertResponseFormat(method.returns.type.type.format));
             break;
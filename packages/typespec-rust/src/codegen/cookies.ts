@@ -0,0 +1,73 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+
+// the cookie helpers referenced by each module.
+// used by getCookieEncoder and emitCookies.
+const cookieHelpers = new helpers.HelperFnTracker();
+
+/**
+ * returns the helper that percent-encodes cookie values and brings it into scope.
+ * the helper is emitted in generated/models/cookies.rs for the module in scope.
+ *
+ * the helper takes a &str and returns a String.
+ *
+ * @param use the use statement builder currently in scope
+ * @returns the helper function
+ */
+export function getCookieEncoder(use: Use): string {
+  return cookieHelpers.reference('encode_cookie_value', use);
+}
+
+/**
+ * returns the emitted cookie helpers, or undefined if
+ * no cookie helpers were referenced by the module.
+ * this MUST be called after all other content for the
+ * module has been emitted.
+ *
+ * @param module the module for which to emit the helpers
+ * @returns the cookie helpers content or undefined
+ */
+export function emitCookies(module: rust.ModuleContainer): helpers.Module | undefined {
+  const used = cookieHelpers.take(module);
+  if (!used) {
+    return undefined;
+  }
+
+  const indent = new helpers.indentation();
+
+  let body = '';
+  for (const name of used) {
+    switch (name) {
+      case 'encode_cookie_value': {
+        // RFC 6265 cookie-octet is %x21 / %x23-2B / %x2D-3A / %x3C-5B / %x5D-7E.
+        // % is also encoded so that encoded values can be decoded unambiguously.
+        let fnBody = `${indent.get()}let mut encoded = String::with_capacity(value.len());\n`;
+        fnBody += `${indent.get()}for b in value.bytes() {\n`;
+        fnBody += `${indent.push().get()}match b {\n`;
+        fnBody += `${indent.push().get()}b'"' | b'%' | b',' | b';' | b'\\\\' => encoded.push_str(&format!("%{b:02X}")),\n`;
+        fnBody += `${indent.get()}0x21..=0x7E => encoded.push(char::from(b)),\n`;
+        fnBody += `${indent.get()}_ => encoded.push_str(&format!("%{b:02X}")),\n`;
+        fnBody += `${indent.pop().get()}}\n`;
+        fnBody += `${indent.pop().get()}}\n`;
+        fnBody += `${indent.get()}encoded\n`;
+        body += helpers.emitHelperFn(name, 'Percent-encodes the UTF-8 bytes of a cookie value that aren\'t RFC 6265 cookie octets.', '&str', 'String', fnBody);
+        break;
+      }
+    }
+  }
+
+  let content = helpers.contentPreamble();
+  content += body.trimEnd() + '\n';
+
+  return {
+    name: 'cookies',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
@@ -0,0 +1,95 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+import * as utils from '../utils/utils.js';
+
+// the Date and Time helpers referenced by each module.
+// used by getPlainDateTimeMethod and emitDates.
+const dateHelpers = new helpers.HelperFnTracker();
+
+/**
+ * returns the applicable Date/Time parse/to helper and brings it into scope.
+ * the helper is emitted in generated/models/dates.rs for the module in scope.
+ *
+ * parse helpers take a &str and return a Result<Date> or Result<Time>.
+ * to helpers take a &Date or &Time and return a String.
+ *
+ * @param type the plainDate or plainTime type
+ * @param direction if the value is being parsed or converted to the wire format
+ * @param use the use statement builder currently in scope
+ * @returns the helper function
+ */
+export function getPlainDateTimeMethod(type: rust.PlainDate | rust.PlainTime, direction: 'parse' | 'to', use: Use): string {
+  return dateHelpers.reference(`${direction}_${utils.deconstruct(type.kind).join('_')}`, use);
+}
+
+/**
+ * returns the emitted Date and Time helpers, or undefined if
+ * no Date or Time helpers were referenced by the module.
+ * this MUST be called after all other content for the
+ * module has been emitted.
+ *
+ * @param module the module for which to emit the helpers
+ * @returns the Date and Time helpers content or undefined
+ */
+export function emitDates(module: rust.ModuleContainer): helpers.Module | undefined {
+  const used = dateHelpers.take(module);
+  if (!used) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  const indent = new helpers.indentation();
+
+  const parseIso8601 = function (typeName: 'Date' | 'Time', format: 'DATE' | 'TIME'): string {
+    use.add('azure_core', 'Result');
+    use.add('azure_core::error', 'Error', 'ErrorKind');
+    use.add('time::format_description::well_known', 'Iso8601');
+    return `${indent.get()}${typeName}::parse(value, &Iso8601::${format}).map_err(|err| Error::new(ErrorKind::DataConversion, err))\n`;
+  };
+
+  let body = '';
+  for (const name of used) {
+    switch (name) {
+      case 'parse_plain_date':
+        use.add('time', 'Date');
+        body += helpers.emitHelperFn(name, 'Parses an ISO 8601 calendar date (e.g. 2022-08-26) into a Date.', '&str', 'Result<Date>', parseIso8601('Date', 'DATE'));
+        break;
+      case 'to_plain_date':
+        use.add('time', 'Date');
+        body += helpers.emitHelperFn(name, 'Formats a Date as an ISO 8601 calendar date (e.g. 2022-08-26).', '&Date', 'String',
+          `${indent.get()}format!("{:04}-{:02}-{:02}", value.year(), u8::from(value.month()), value.day())\n`);
+        break;
+      case 'parse_plain_time':
+        use.add('time', 'Time');
+        body += helpers.emitHelperFn(name, 'Parses an ISO 8601 time of day (e.g. 13:06:12) into a Time.', '&str', 'Result<Time>', parseIso8601('Time', 'TIME'));
+        break;
+      case 'to_plain_time': {
+        use.add('time', 'Time');
+        let fnBody = `${indent.get()}let mut result = format!("{:02}:{:02}:{:02}", value.hour(), value.minute(), value.second());\n`;
+        fnBody += `${indent.get()}if value.nanosecond() > 0 {\n`;
+        fnBody += `${indent.push().get()}let fraction = format!("{:09}", value.nanosecond());\n`;
+        fnBody += `${indent.get()}result.push_str(&format!(".{}", fraction.trim_end_matches('0')));\n`;
+        fnBody += `${indent.pop().get()}}\n`;
+        fnBody += `${indent.get()}result\n`;
+        body += helpers.emitHelperFn(name, 'Formats a Time as an ISO 8601 time of day (e.g. 13:06:12).', '&Time', 'String', fnBody);
+        break;
+      }
+    }
+  }
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body.trimEnd() + '\n';
+
+  return {
+    name: 'dates',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
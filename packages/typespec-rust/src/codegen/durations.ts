@@ -0,0 +1,241 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+import * as utils from '../utils/utils.js';
+
+// the duration helpers referenced by each module.
+// used by getDurationEncodingMethod and emitDurations.
+const durationHelpers = new helpers.HelperFnTracker();
+
+/**
+ * returns the applicable Duration parse/to helper and brings it into scope.
+ * the helper is emitted in generated/models/durations.rs for the module in scope.
+ *
+ * parse helpers take the wire value and return a Result<Duration>.
+ * to helpers take a &Duration and return the wire value.
+ *
+ * @param encoding the type of encoding
+ * @param direction if the value is being parsed or converted to the wire format
+ * @param use the use statement builder currently in scope
+ * @returns the helper function
+ */
+export function getDurationEncodingMethod(encoding: rust.DurationEncoding, direction: 'parse' | 'to', use: Use): string {
+  return durationHelpers.reference(`${direction}_${utils.deconstruct(encoding).join('_')}_duration`, use);
+}
+
+/**
+ * returns true if the to helper for the specified duration encoding
+ * returns a Result<T> as the Duration might not fit in its wire type.
+ *
+ * @param encoding the type of encoding
+ * @returns true if the to helper is fallible
+ */
+export function isDurationToFallible(encoding: rust.DurationEncoding): boolean {
+  return encoding === 'milliseconds';
+}
+
+/**
+ * returns the wire type for the specified duration encoding
+ *
+ * @param encoding the type of encoding
+ * @returns the Rust type that's sent over the wire
+ */
+export function getDurationWireType(encoding: rust.DurationEncoding): 'f64' | 'i64' | 'String' {
+  switch (encoding) {
+    case 'iso8601':
+      return 'String';
+    case 'milliseconds':
+    case 'seconds':
+      return 'i64';
+    case 'milliseconds-float':
+    case 'seconds-float':
+      return 'f64';
+  }
+}
+
+/**
+ * returns the emitted Duration helpers, or undefined if
+ * no Duration helpers were referenced by the module.
+ * this MUST be called after all other content for the
+ * module has been emitted.
+ *
+ * @param module the module for which to emit the helpers
+ * @returns the Duration helpers content or undefined
+ */
+export function emitDurations(module: rust.ModuleContainer): helpers.Module | undefined {
+  const used = durationHelpers.take(module);
+  if (!used) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core', 'Result', 'time::Duration');
+
+  const indent = new helpers.indentation();
+
+  const invalidDuration = `${indent.get()}${indent.get()}Error::with_message(ErrorKind::DataConversion, format!("invalid duration {value}"))\n`;
+
+  let body = '';
+  for (const name of used) {
+    switch (name) {
+      case 'parse_iso8601_duration': {
+        use.add('azure_core::error', 'Error', 'ErrorKind');
+        let fnBody = `${indent.get()}let invalid = || {\n`;
+        fnBody += invalidDuration;
+        fnBody += `${indent.get()}};\n`;
+        fnBody += `${indent.get()}let designators = value.strip_prefix('-').unwrap_or(value);\n`;
+        fnBody += `${indent.get()}let designators = designators.strip_prefix('P').ok_or_else(invalid)?;\n`;
+        // the components are summed as whole nanoseconds so large values don't lose precision
+        fnBody += `${indent.get()}let mut nanoseconds: i128 = 0;\n`;
+        fnBody += `${indent.get()}let mut number = String::new();\n`;
+        fnBody += `${indent.get()}let mut in_time = false;\n`;
+        fnBody += `${indent.get()}let mut has_components = false;\n`;
+        fnBody += `${indent.get()}for c in designators.chars() {\n`;
+        fnBody += `${indent.push().get()}` + helpers.buildMatch(indent, 'c', [{
+          pattern: `'0'..='9' | '.'`,
+          body: (indent) => `${indent.get()}number.push(c)\n`,
+        }, {
+          pattern: `','`,
+          body: (indent) => `${indent.get()}number.push('.')\n`,
+        }, {
+          pattern: `'T' if !in_time && number.is_empty()`,
+          body: (indent) => `${indent.get()}in_time = true\n`,
+        }, {
+          pattern: '_',
+          body: (indent) => {
+            let arm = `${indent.get()}let unit_seconds: i128 = ` + helpers.buildMatch(indent, '(c, in_time)', [{
+              pattern: `('W', false)`,
+              body: (indent) => `${indent.get()}604_800\n`,
+            }, {
+              pattern: `('D', false)`,
+              body: (indent) => `${indent.get()}86_400\n`,
+            }, {
+              pattern: `('H', true)`,
+              body: (indent) => `${indent.get()}3_600\n`,
+            }, {
+              pattern: `('M', true)`,
+              body: (indent) => `${indent.get()}60\n`,
+            }, {
+              pattern: `('S', true)`,
+              body: (indent) => `${indent.get()}1\n`,
+            }, {
+              // years and months don't have a fixed length
+              pattern: '_',
+              body: (indent) => `${indent.get()}return Err(invalid())\n`,
+            }]) + ';\n';
+            arm += `${indent.get()}let unit = unit_seconds * 1_000_000_000;\n`;
+            arm += `${indent.get()}let (whole, fraction) = number.split_once('.').unwrap_or((number.as_str(), ""));\n`;
+            arm += `${indent.get()}if (whole.is_empty() && fraction.is_empty()) || fraction.contains('.') {\n`;
+            arm += `${indent.push().get()}return Err(invalid());\n`;
+            arm += `${indent.pop().get()}}\n`;
+            arm += `${indent.get()}let whole: i128 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| invalid())? };\n`;
+            arm += `${indent.get()}// fractional digits after the 18th are less than a nanosecond for all units\n`;
+            arm += `${indent.get()}let fraction = &fraction[..fraction.len().min(18)];\n`;
+            arm += `${indent.get()}let scale = 10_i128.pow(fraction.len() as u32);\n`;
+            arm += `${indent.get()}let fraction: i128 = if fraction.is_empty() { 0 } else { fraction.parse().map_err(|_| invalid())? };\n`;
+            arm += `${indent.get()}nanoseconds = whole\n`;
+            arm += `${indent.push().get()}.checked_mul(unit)\n`;
+            arm += `${indent.get()}.and_then(|whole| whole.checked_add(fraction * unit / scale))\n`;
+            arm += `${indent.get()}.and_then(|component| nanoseconds.checked_add(component))\n`;
+            arm += `${indent.get()}.ok_or_else(invalid)?;\n`;
+            indent.pop();
+            arm += `${indent.get()}number.clear();\n`;
+            arm += `${indent.get()}has_components = true;\n`;
+            return arm;
+          },
+        }]) + '\n';
+        fnBody += `${indent.pop().get()}}\n`;
+        // a time designator must be followed by at least one time component
+        fnBody += `${indent.get()}if !has_components || !number.is_empty() || designators.ends_with('T') {\n`;
+        fnBody += `${indent.push().get()}return Err(invalid());\n`;
+        fnBody += `${indent.pop().get()}}\n`;
+        fnBody += `${indent.get()}let seconds = i64::try_from(nanoseconds / 1_000_000_000).map_err(|_| invalid())?;\n`;
+        fnBody += `${indent.get()}let duration = Duration::new(seconds, (nanoseconds % 1_000_000_000) as i32);\n`;
+        fnBody += `${indent.get()}Ok(if value.starts_with('-') { -duration } else { duration })\n`;
+        body += helpers.emitHelperFn(name, 'Parses an ISO 8601 duration (e.g. P1DT2H30M) into a Duration.', '&str', 'Result<Duration>', fnBody);
+        break;
+      }
+      case 'to_iso8601_duration': {
+        let fnBody = `${indent.get()}let mut result = String::from(if value.is_negative() { "-P" } else { "P" });\n`;
+        fnBody += `${indent.get()}let value = value.abs();\n`;
+        fnBody += `${indent.get()}let (days, hours, minutes, seconds, nanoseconds) = (\n`;
+        fnBody += `${indent.push().get()}value.whole_days(),\n`;
+        fnBody += `${indent.get()}value.whole_hours() % 24,\n`;
+        fnBody += `${indent.get()}value.whole_minutes() % 60,\n`;
+        fnBody += `${indent.get()}value.whole_seconds() % 60,\n`;
+        fnBody += `${indent.get()}value.subsec_nanoseconds(),\n`;
+        fnBody += `${indent.pop().get()});\n`;
+        fnBody += `${indent.get()}if days > 0 {\n`;
+        fnBody += `${indent.push().get()}result.push_str(&format!("{days}D"));\n`;
+        fnBody += `${indent.pop().get()}}\n`;
+        fnBody += `${indent.get()}if days > 0 && hours == 0 && minutes == 0 && seconds == 0 && nanoseconds == 0 {\n`;
+        fnBody += `${indent.push().get()}return result;\n`;
+        fnBody += `${indent.pop().get()}}\n`;
+        fnBody += `${indent.get()}result.push('T');\n`;
+        fnBody += `${indent.get()}if hours > 0 {\n`;
+        fnBody += `${indent.push().get()}result.push_str(&format!("{hours}H"));\n`;
+        fnBody += `${indent.pop().get()}}\n`;
+        fnBody += `${indent.get()}if minutes > 0 {\n`;
+        fnBody += `${indent.push().get()}result.push_str(&format!("{minutes}M"));\n`;
+        fnBody += `${indent.pop().get()}}\n`;
+        fnBody += `${indent.get()}if nanoseconds > 0 {\n`;
+        fnBody += `${indent.push().get()}let fraction = format!("{nanoseconds:09}");\n`;
+        fnBody += `${indent.get()}result.push_str(&format!("{seconds}.{}S", fraction.trim_end_matches('0')));\n`;
+        fnBody += `${indent.pop().get()}} else if seconds > 0 || (hours == 0 && minutes == 0) {\n`;
+        fnBody += `${indent.push().get()}result.push_str(&format!("{seconds}S"));\n`;
+        fnBody += `${indent.pop().get()}}\n`;
+        fnBody += `${indent.get()}result\n`;
+        body += helpers.emitHelperFn(name, 'Formats a Duration as an ISO 8601 duration (e.g. P1DT2H30M).', '&Duration', 'String', fnBody);
+        break;
+      }
+      case 'parse_milliseconds_duration':
+        body += helpers.emitHelperFn(name, 'Converts a whole number of milliseconds into a Duration.', 'i64', 'Result<Duration>', `${indent.get()}Ok(Duration::milliseconds(value))\n`);
+        break;
+      case 'to_milliseconds_duration':
+        // whole_milliseconds() returns an i128 as the milliseconds of a Duration can exceed an i64
+        use.add('azure_core::error', 'Error', 'ErrorKind');
+        body += helpers.emitHelperFn(name, 'Converts a Duration into a whole number of milliseconds.', '&Duration', 'Result<i64>',
+          `${indent.get()}i64::try_from(value.whole_milliseconds()).map_err(|_| {\n`
+          + `${indent.get()}${indent.get()}Error::with_message(ErrorKind::DataConversion, format!("duration {value} is too large to send as milliseconds"))\n`
+          + `${indent.get()}})\n`);
+        break;
+      case 'parse_milliseconds_float_duration':
+        use.add('azure_core::error', 'Error', 'ErrorKind');
+        body += helpers.emitHelperFn(name, 'Converts a number of milliseconds into a Duration.', 'f64', 'Result<Duration>',
+          `${indent.get()}Duration::checked_seconds_f64(value / 1000.0).ok_or_else(|| {\n${invalidDuration}${indent.get()}})\n`);
+        break;
+      case 'to_milliseconds_float_duration':
+        body += helpers.emitHelperFn(name, 'Converts a Duration into a number of milliseconds.', '&Duration', 'f64', `${indent.get()}value.as_seconds_f64() * 1000.0\n`);
+        break;
+      case 'parse_seconds_duration':
+        body += helpers.emitHelperFn(name, 'Converts a whole number of seconds into a Duration.', 'i64', 'Result<Duration>', `${indent.get()}Ok(Duration::seconds(value))\n`);
+        break;
+      case 'to_seconds_duration':
+        body += helpers.emitHelperFn(name, 'Converts a Duration into a whole number of seconds.', '&Duration', 'i64', `${indent.get()}value.whole_seconds()\n`);
+        break;
+      case 'parse_seconds_float_duration':
+        use.add('azure_core::error', 'Error', 'ErrorKind');
+        body += helpers.emitHelperFn(name, 'Converts a number of seconds into a Duration.', 'f64', 'Result<Duration>',
+          `${indent.get()}Duration::checked_seconds_f64(value).ok_or_else(|| {\n${invalidDuration}${indent.get()}})\n`);
+        break;
+      case 'to_seconds_float_duration':
+        body += helpers.emitHelperFn(name, 'Converts a Duration into a number of seconds.', '&Duration', 'f64', `${indent.get()}value.as_seconds_f64()\n`);
+        break;
+    }
+  }
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body.trimEnd() + '\n';
+
+  return {
+    name: 'durations',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
@@ -6,7 +6,10 @@
 /** ErrorCode defines the types of errors */
 export type ErrorCode =
   /** the emitter encountered an internal error. this is always a bug in the emitter */
-  'InternalError';
+  'InternalError' |
+
+  /** invalid arguments were passed to the emitter */
+  'InvalidArgument';
 
 /**
  * CodegenError is thrown when the emitter fails some condition
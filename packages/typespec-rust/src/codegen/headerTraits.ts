@@ -3,7 +3,9 @@
  *  Licensed under the MIT License. See License.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
 
+import { getPlainDateTimeMethod } from './dates.js';
 import { emitHeaderTraitDocExample } from './docTests.js';
+import { getDurationEncodingMethod, getDurationWireType } from './durations.js';
 import { CodegenError } from './errors.js';
 import * as helpers from './helpers.js';
 import { Use } from './use.js';
@@ -233,12 +235,27 @@ function getHeaderDeserialization(indent: helpers.indentation, use: Use, header:
     case 'enum':
     case 'scalar':
     case 'String':
+    case 'Url':
       return `${indent.get()}Headers::get_optional_as(self.headers(), &${headerConstName})\n`
+    case 'duration': {
+      const durationParse = getDurationEncodingMethod(header.type.encoding, 'parse', use);
+      if (header.type.encoding === 'iso8601') {
+        return `${indent.get()}Headers::get_optional_with(self.headers(), &${headerConstName}, |h| ${durationParse}(h.as_str()))\n`;
+      }
+      // numeric encodings are parsed to their wire type first
+      const wireType = getDurationWireType(header.type.encoding);
+      return `${indent.get()}Headers::get_optional_as::<${wireType}, _>(self.headers(), &${headerConstName})?.map(${durationParse}).transpose()\n`;
+    }
     case 'offsetDateTime': {
       const timeParse = `parse_${header.type.encoding}`;
       use.add('azure_core', `time::${timeParse}`);
       return `${indent.get()}Headers::get_optional_with(self.headers(), &${headerConstName}, |h| ${timeParse}(h.as_str()))\n`;
     }
+    case 'plainDate':
+    case 'plainTime': {
+      const dateParse = getPlainDateTimeMethod(header.type, 'parse', use);
+      return `${indent.get()}Headers::get_optional_with(self.headers(), &${headerConstName}, |h| ${dateParse}(h.as_str()))\n`;
+    }
     default:
       return `${indent.get()}todo!();\n`;
   }
@@ -67,6 +67,89 @@ export class VisibilityTracker {
   }
 }
 
+/**
+ * used to track the helper functions referenced by each module.
+ * the referenced helpers are emitted in a file under generated/models
+ * for the module (e.g. the Duration helpers in durations.rs).
+ * the state is scoped to the module's crate so nothing carries
+ * over from one crate to the next within an emitter run.
+ */
+export class HelperFnTracker {
+  private readonly referenced = new WeakMap<rust.Crate, Map<rust.ModuleContainer, Set<string>>>();
+
+  /**
+   * returns the referenced helpers for the modules in the module's crate.
+   * 
+   * @param module the module in scope
+   * @returns the referenced helpers for the crate
+   */
+  private forCrate(module: rust.ModuleContainer): Map<rust.ModuleContainer, Set<string>> {
+    const crate = getCrate(module);
+    let modules = this.referenced.get(crate);
+    if (!modules) {
+      modules = new Map<rust.ModuleContainer, Set<string>>();
+      this.referenced.set(crate, modules);
+    }
+    return modules;
+  }
+
+  /**
+   * records that the module in scope references the
+   * helper function and brings the helper into scope.
+   * 
+   * @param name the name of the helper function
+   * @param use the use statement builder currently in scope
+   * @returns the name of the helper function
+   */
+  reference(name: string, use: Use): string {
+    const modules = this.forCrate(use.module);
+    let names = modules.get(use.module);
+    if (!names) {
+      names = new Set<string>();
+      modules.set(use.module, names);
+    }
+    names.add(name);
+    use.add(`${utils.buildImportPath(use.module, use.module)}::models`, name);
+    return name;
+  }
+
+  /**
+   * returns the sorted names of the helper functions referenced
+   * by the module and stops tracking them, or undefined if the
+   * module doesn't reference any helper functions.
+   * 
+   * @param module the module that references the helper functions
+   * @returns the helper function names or undefined
+   */
+  take(module: rust.ModuleContainer): Array<string> | undefined {
+    const modules = this.forCrate(module);
+    const names = modules.get(module);
+    if (!names) {
+      return undefined;
+    }
+    modules.delete(module);
+    return Array.from(names).sort();
+  }
+}
+
+/**
+ * emits a pub(crate) helper function that takes a single value.
+ * 
+ * @param name the name of the helper function
+ * @param docs the doc comment for the helper function
+ * @param param the type of the value param
+ * @param returns the return type
+ * @param body the body of the helper function
+ * @returns the helper function
+ */
+export function emitHelperFn(name: string, docs: string, param: string, returns: string, body: string): string {
+  let content = `/// ${docs}\n`;
+  content += `pub(crate) fn ${name}(value: ${param}) -> ${returns} {\n`;
+  content += body;
+  content += '}\n\n';
+  return content;
+}
+
 /**
  * returns the content preamble common to all emitted files
  * 
@@ -162,6 +245,18 @@ export function formatDocComment(docs: rust.Docs, forDocAttr = false, prefix?: s
   return docStr;
 }
 
+/**
+ * returns the attributes that gate an item behind the specified Cargo feature
+ * 
+ * @param feature the feature that gates the item
+ * @param indent optional indentation helper currently in scope, else defaults to no indentation
+ * @returns the cfg attributes
+ */
+export function emitFeatureGate(feature: rust.CrateFeature, indent?: indentation): string {
+  const prefix = indent ? indent.get() : '';
+  return `${prefix}#[cfg(feature = "${feature.name}")]\n${prefix}#[cfg_attr(docsrs, doc(cfg(feature = "${feature.name}")))]\n`;
+}
+
 /**
  * returns the specified visibility prefix
  * 
@@ -229,6 +324,9 @@ export function getTypeDeclaration(type: rust.Client | rust.ResponseHeadersTrait
       // we explicitly omit the Response<T> from the type decl
       return `Pager<${getTypeDeclaration(type.type.content, withLifetime)}${formatParam}>`;
     }
+    case 'itemStream':
+    case 'nullable':
+      return `${type.name}<${getTypeDeclaration(type.type, withLifetime)}>`;
     case 'pagerOptions':
       return `${type.name}<${type.lifetime.name}>`;
     case 'poller':
@@ -240,6 +338,10 @@ export function getTypeDeclaration(type: rust.Client | rust.ResponseHeadersTrait
       }
       return `&${type.lifetime ? `${type.lifetime.name} ` : ''}${getTypeDeclaration(type.type)}`;
     case 'requestContent': {
+      if (type.format === 'MultipartFormat') {
+        // multipart bodies are passed as the model and encoded by the client method
+        return getTypeDeclaration(type.content, withLifetime);
+      }
       const formatType = `${type.format !== 'JsonFormat' ? `, ${type.format}` : ''}`;
       return `${type.name}<${getTypeDeclaration(type.content, withLifetime)}${formatType}>`;
     }
@@ -256,11 +358,18 @@ export function getTypeDeclaration(type: rust.Client | rust.ResponseHeadersTrait
       return type.type;
     case 'slice':
       return `[${getTypeDeclaration(type.type)}]`;
+    case 'basicCredential':
     case 'discriminatedUnion':
+    case 'duration':
     case 'enum':
     case 'jsonValue':
+    case 'keyCredential':
+    case 'multipartFile':
     case 'offsetDateTime':
+    case 'plainDate':
+    case 'plainTime':
     case 'rawResponse':
+    case 'requestConditions':
     case 'responseHeadersTrait':
     case 'safeint':
     case 'tokenCredential':
@@ -503,6 +612,8 @@ export function unwrapType(type: rust.Type): rust.Type {
   switch (type.kind) {
     case 'arc':
     case 'hashmap':
+    case 'itemStream':
+    case 'nullable':
     case 'option':
     case 'ref':
     case 'slice':
@@ -677,3 +788,26 @@ export function getCrate(module: rust.ModuleContainer): rust.Crate {
   }
   return cur;
 }
+
+/**
+ * returns the generated credentials used by the client constructors within a module.
+ * 
+ * @param module the module containing the clients
+ * @returns the generated credentials. can be empty
+ */
+export function getCredentials(module: rust.ModuleContainer): Array<rust.BasicCredential | rust.KeyCredential> {
+  const credentials = new Array<rust.BasicCredential | rust.KeyCredential>();
+  for (const client of module.clients) {
+    if (!client.constructable || client.constructable.suppressed === 'yes') {
+      continue;
+    }
+    for (const constructor of client.constructable.constructors) {
+      for (const param of constructor.params) {
+        if (param.kind === 'clientCredential' && (param.type.kind === 'basicCredential' || param.type.kind === 'keyCredential')) {
+          credentials.push(param.type);
+        }
+      }
+    }
+  }
+  return credentials;
+}
@@ -19,6 +19,9 @@ export function emitLibRs(crate: rust.Crate): string {
   content += 'mod generated;\n';
   content += 'pub use generated::*;\n';
   for (const subModule of crate.subModules) {
+    if (subModule.feature) {
+      content += helpers.emitFeatureGate(subModule.feature);
+    }
     content += `pub mod ${subModule.name};\n`;
   }
   return content;
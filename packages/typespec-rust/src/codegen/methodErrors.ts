@@ -0,0 +1,105 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import { Context } from './context.js';
+import { CodegenError } from './errors.js';
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+
+/**
+ * returns the emitted method error enums, or undefined if there
+ * are no method error enums.
+ * the method error enums provide access to typed error responses
+ *
+ * @param module the module for which to emit method error enums
+ * @param context the context for the provided module
+ * @returns the method error enums content or undefined
+ */
+export function emitMethodErrors(module: rust.ModuleContainer, context: Context): helpers.Module | undefined {
+  const methodErrors = new Array<rust.MethodErrors>();
+  for (const client of module.clients) {
+    for (const method of client.methods) {
+      if (method.kind !== 'clientaccessor' && method.errors) {
+        methodErrors.push(method.errors);
+      }
+    }
+  }
+
+  if (methodErrors.length === 0) {
+    return undefined;
+  }
+
+  methodErrors.sort((a: rust.MethodErrors, b: rust.MethodErrors) => helpers.sortAscending(a.name, b.name));
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core::error', 'ErrorKind');
+  use.add('azure_core::fmt', 'SafeDebug');
+  use.add('azure_core::http', 'StatusCode');
+
+  const indent = new helpers.indentation();
+  const visTracker = new helpers.VisibilityTracker();
+
+  let body = '';
+  for (const errors of methodErrors) {
+    body += `/// Provides access to typed error responses for ${errors.docs}\n`;
+    body += '///\n';
+    body += '/// Convert the error returned by the method into this type to match on its error responses.\n';
+    body += '/// Errors that aren\'t one of the typed error responses are returned in the `Other` variant.\n';
+    body += '#[derive(SafeDebug)]\n';
+    body += `${helpers.emitVisibility(errors.visibility)}enum ${errors.name} {\n`;
+    visTracker.update(errors.visibility);
+    for (const variant of errors.variants) {
+      use.addForType(variant.type);
+      body += `${indent.get()}/// The service returned a ${variant.statusCode} status code.\n`;
+      body += `${indent.get()}${variant.name}(${helpers.getTypeDeclaration(variant.type)}),\n\n`;
+    }
+    body += `${indent.get()}/// Any other error.\n`;
+    body += `${indent.get()}Other(azure_core::Error),\n`;
+    body += '}\n\n';
+
+    body += `impl From<azure_core::Error> for ${errors.name} {\n`;
+    body += `${indent.get()}fn from(error: azure_core::Error) -> Self {\n`;
+    body += `${indent.push().get()}let typed = match error.kind() {\n`;
+    indent.push();
+    for (const variant of errors.variants) {
+      body += `${indent.get()}ErrorKind::HttpResponse { status: StatusCode::${variant.name}, raw_response: Some(raw_response), .. } => `;
+      body += `raw_response.body().${getDeserializeMethod(context, variant.type)}().map(Self::${variant.name}).ok(),\n`;
+    }
+    body += `${indent.get()}_ => None,\n`;
+    body += `${indent.pop().get()}};\n`;
+    body += `${indent.get()}typed.unwrap_or(Self::Other(error))\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += '}\n\n';
+  }
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'method_errors',
+    content: content,
+    visibility: visTracker.get(),
+  };
+}
+
+/**
+ * returns the name of the method used to deserialize the error model from the response body
+ *
+ * @param context the context for the module that contains the model
+ * @param model the error model to deserialize
+ * @returns the name of the deserialization method
+ */
+function getDeserializeMethod(context: Context, model: rust.Model): string {
+  const bodyFormat = context.getModelBodyFormat(model) as string;
+  switch (bodyFormat) {
+    case 'json':
+    case 'xml':
+      return bodyFormat;
+    default:
+      throw new CodegenError('InternalError', `found unknown model body format '${bodyFormat}' for model ${model.name}.`);
+  }
+}
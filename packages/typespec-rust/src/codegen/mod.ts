@@ -60,6 +60,11 @@ export function emitGeneratedModRs(module: rust.ModuleContainer): string {
       }
     }
 
+    // the credential types are required to construct clients that use them
+    for (const credentialName of new Set(helpers.getCredentials(module).map((credential) => credential.name))) {
+      clientsAndClientOptions.push(credentialName);
+    }
+
     if (clientsAndClientOptions.length > 0) {
       content += `pub use clients::{${clientsAndClientOptions.join(', ')}};\n`;
     }
@@ -92,6 +97,9 @@ export function emitSubModRs(module: rust.SubModule): string {
     content += 'mod generated;\npub use generated::*;\n';
   }
   for (const subModule of module.subModules) {
+    if (subModule.feature) {
+      content += helpers.emitFeatureGate(subModule.feature);
+    }
     content += `pub mod ${subModule.name};\n`;
   }
   return content;
@@ -6,6 +6,8 @@
 //cspell: ignore addl
 
 import { Context } from './context.js';
+import { getPlainDateTimeMethod } from './dates.js';
+import { getDurationEncodingMethod, getDurationWireType, isDurationToFallible } from './durations.js';
 import { CodegenError } from './errors.js';
 import * as helpers from './helpers.js';
 import { Use } from './use.js';
@@ -25,6 +27,12 @@ export interface Models {
 
   /** XML-specific helpers for internal use only */
   xmlHelpers?: helpers.Module;
+
+  /** multipart/form-data types and helpers */
+  multipart?: helpers.Module;
+
+  /** tri-state type for JSON merge-patch models */
+  nullable?: helpers.Module;
 }
 
 /**
@@ -49,6 +57,8 @@ export function emitModels(module: rust.ModuleContainer, context: Context): Mode
     serde: emitModelsSerde(module),
     impls: emitModelImpls(module, context),
     xmlHelpers: emitXMLListWrappers(module),
+    multipart: emitMultipart(module),
+    nullable: emitNullable(module),
   };
 }
 
@@ -91,10 +101,14 @@ function emitModelDefinitions(module: rust.ModuleContainer, context: Context): h
     // need to omit any serde derive annotations.
     const hasXmlAddlProps = bodyFormat === 'xml' ? model.fields.some((each) => each.kind === 'additionalProperties') : false;
 
+    // multipart models are encoded part by part so they don't participate in serde
+    const isMultipart = <rust.ModelFlags>(model.flags & rust.ModelFlags.Multipart) === rust.ModelFlags.Multipart;
+    const useSerde = !hasXmlAddlProps && !isMultipart;
+
     body += helpers.formatDocComment(model.docs);
 
     if (model.flags !== rust.ModelFlags.PolymorphicBase) {
-      if (!hasXmlAddlProps) {
+      if (useSerde) {
         use.add('serde', 'Deserialize');
         use.add('serde', 'Serialize');
       }
@@ -103,7 +117,7 @@ function emitModelDefinitions(module: rust.ModuleContainer, context: Context): h
       // it's not necessary and will cause compilation failures
       // when the type contains something that doesn't have a
       // default impl (e.g. enum types).
-      body += helpers.annotationDerive(!hasXmlAddlProps, model.flags !== rust.ModelFlags.Unspecified ? 'Default' : '');
+      body += helpers.annotationDerive(useSerde, model.flags !== rust.ModelFlags.Unspecified ? 'Default' : '');
     } else {
       // rust.ModelFlags.PolymorphicBase only needs this
       use.add('serde', 'Serialize');
@@ -166,6 +180,11 @@ function emitModelDefinitions(module: rust.ModuleContainer, context: Context): h
       use.addForType(field.type);
       body += helpers.formatDocComment(field.docs);
 
+      if (isMultipart) {
+        body += `${indent.get()}${helpers.emitVisibility(field.visibility)}${field.name}: ${helpers.getTypeDeclaration(field.type)},\n\n`;
+        continue;
+      }
+
       if (field.kind === 'additionalProperties') {
         if (bodyFormat === 'json') {
           body += `#[serde(flatten)]\n`;
@@ -187,7 +206,7 @@ function emitModelDefinitions(module: rust.ModuleContainer, context: Context): h
       // check for custom deserialize_with.  if present, it will override what we'd normally emit
       const deserializeWith = field.customizations.find((each) => each.kind === 'deserializeWith');
 
-      if (unwrappedType.kind === 'encodedBytes' || unwrappedType.kind === 'enumValue' || unwrappedType.kind === 'literal' || unwrappedType.kind === 'offsetDateTime' || encodeAsString(unwrappedType)) {
+      if (unwrappedType.kind === 'duration' || unwrappedType.kind === 'encodedBytes' || unwrappedType.kind === 'enumValue' || unwrappedType.kind === 'literal' || unwrappedType.kind === 'offsetDateTime' || unwrappedType.kind === 'plainDate' || unwrappedType.kind === 'plainTime' || unwrappedType.kind === 'Url' || encodeAsString(unwrappedType)) {
         addSerDeHelper(module, field, serdeParams, bodyFormat, use, deserializeWith);
       } else if (bodyFormat === 'xml' && utils.unwrapOption(field.type).kind === 'Vec' && field.xmlKind !== 'unwrappedList') {
         // this is a wrapped list so we need a helper type for serde
@@ -204,10 +223,12 @@ function emitModelDefinitions(module: rust.ModuleContainer, context: Context): h
         serdeParams.add(`deserialize_with = "empty_as_null::deserialize"`);
       }
 
-      // TODO: omit skip_serializing_if if we need to send explicit JSON null
-      // https://github.com/Azure/typespec-rust/issues/78
       if (field.flags & rust.ModelFieldFlags.ReadOnly) {
         serdeParams.add('skip_serializing');
+      } else if (field.type.kind === 'nullable') {
+        // absent values are omitted while Null is sent as an explicit JSON null
+        serdeParams.add('default');
+        serdeParams.add('skip_serializing_if = "Nullable::is_absent"');
       } else if (field.type.kind === 'option') {
         // optional literals need to skip serializing when it's None
         if ((field.type.type.kind !== 'enumValue' && field.type.type.kind !== 'literal') || field.optional) {
@@ -304,6 +325,33 @@ function emitModelImpls(module: rust.ModuleContainer, context: Context): helpers
       from += '}\n\n'; // end impl
       entries.push(from);
     }
+
+    // sub-types of nested tagged enums (e.g. Fish -> Shark -> SawShark)
+    // can be converted into the outer tagged enum via the nested one.
+    const converted = new Set<rust.DiscriminatedUnion | rust.Model>(union.members.map((member) => member.type));
+    const emitNestedFrom = (nestedUnion: rust.DiscriminatedUnion, variant: rust.DiscriminatedUnion): void => {
+      for (const member of nestedUnion.members) {
+        if (converted.has(member.type)) {
+          continue;
+        }
+        converted.add(member.type);
+        use.addForType(member.type);
+        let from = `impl From<${member.type.name}> for ${union.name} {\n`;
+        from += `${indent.get()}fn from(value: ${member.type.name}) -> Self {\n`;
+        from += `${indent.push().get()}Self::${variant.name}(value.into())\n`;
+        from += `${indent.pop().get()}}\n`; // end fn
+        from += '}\n\n'; // end impl
+        entries.push(from);
+        if (member.type.kind === 'discriminatedUnion') {
+          emitNestedFrom(member.type, variant);
+        }
+      }
+    };
+    for (const member of union.members) {
+      if (member.type.kind === 'discriminatedUnion') {
+        emitNestedFrom(member.type, member.type);
+      }
+    }
   }
 
   // emit TryFrom as required
@@ -565,6 +613,332 @@ function emitXMLListWrappers(module: rust.ModuleContainer): helpers.Module | und
   };
 }
 
+/** the kinds of parts in a multipart/form-data request body */
+type MultipartPartKind = 'file' | 'json' | 'text';
+
+/**
+ * returns the kind of part used to send the provided field
+ * 
+ * @param field the field within a multipart model
+ * @returns the kind of part for the field
+ */
+function getMultipartPartKind(field: rust.ModelFieldType): MultipartPartKind {
+  const fieldType = utils.unwrapOption(field.type);
+  if (fieldType.kind === 'multipartFile' || (fieldType.kind === 'Vec' && fieldType.type.kind === 'multipartFile')) {
+    return 'file';
+  }
+  switch (fieldType.kind) {
+    case 'decimal':
+    case 'enum':
+    case 'enumValue':
+    case 'literal':
+    case 'safeint':
+    case 'scalar':
+    case 'String':
+    case 'Url':
+      return 'text';
+    default:
+      return 'json';
+  }
+}
+
+/**
+ * emits the MultipartFile type, the internal MultipartForm builder, and
+ * the conversions from multipart models into a MultipartForm.
+ * returns undefined if the module contains no multipart models.
+ * 
+ * @param module the module being processed
+ * @returns the multipart types and helpers or undefined
+ */
+function emitMultipart(module: rust.ModuleContainer): helpers.Module | undefined {
+  const multipartModels = module.models.filter((model) => model.kind === 'model' && <rust.ModelFlags>(model.flags & rust.ModelFlags.Multipart) === rust.ModelFlags.Multipart) as Array<rust.Model>;
+  if (multipartModels.length === 0) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core', 'Bytes', 'Result');
+  use.add('azure_core::fmt', 'SafeDebug');
+  use.add('std::sync::atomic', 'AtomicU64', 'Ordering');
+  use.add('std::time', 'SystemTime', 'UNIX_EPOCH');
+
+  // only emit the builder methods that are in use to avoid dead code warnings
+  const partKinds = new Set<MultipartPartKind>();
+  for (const model of multipartModels) {
+    for (const field of model.fields) {
+      partKinds.add(getMultipartPartKind(field));
+    }
+  }
+
+  const indent = new helpers.indentation();
+  let body = '/// A file sent as a part in a multipart/form-data request body.\n';
+  body += '#[derive(Clone, Default, SafeDebug)]\n';
+  body += 'pub struct MultipartFile {\n';
+  body += `${indent.get()}/// The contents of the file.\n`;
+  body += `${indent.get()}pub content: Bytes,\n\n`;
+  body += `${indent.get()}/// The content type of the file. When \`None\`, the service's default content type is sent.\n`;
+  body += `${indent.get()}pub content_type: Option<String>,\n\n`;
+  body += `${indent.get()}/// The name of the file. When \`None\`, the name of the part is sent.\n`;
+  body += `${indent.get()}pub filename: Option<String>,\n`;
+  body += '}\n\n';
+
+  body += 'impl MultipartFile {\n';
+  body += `${indent.get()}/// Creates a new \`MultipartFile\` from the provided content.\n`;
+  body += `${indent.get()}pub fn new(content: impl Into<Bytes>) -> Self {\n`;
+  body += `${indent.push().get()}Self { content: content.into(), content_type: None, filename: None }\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += '/// Builds a multipart/form-data request body.\n';
+  body += 'pub(crate) struct MultipartForm {\n';
+  body += `${indent.get()}boundary: String,\n`;
+  body += `${indent.get()}body: Vec<u8>,\n`;
+  body += '}\n\n';
+
+  body += 'impl MultipartForm {\n';
+  body += `${indent.get()}pub(crate) fn new() -> Self {\n`;
+  body += `${indent.push().get()}// the boundary only needs to be unique within a request\n`;
+  body += `${indent.get()}static COUNTER: AtomicU64 = AtomicU64::new(0);\n`;
+  body += `${indent.get()}let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or_default();\n`;
+  body += `${indent.get()}let count = COUNTER.fetch_add(1, Ordering::Relaxed);\n`;
+  body += `${indent.get()}Self { boundary: format!("----AzureSdkFormBoundary{nanos:x}{count:x}"), body: Vec::new() }\n`;
+  body += `${indent.pop().get()}}\n\n`;
+
+  body += `${indent.get()}/// Returns the value of the Content-Type header for this form.\n`;
+  body += `${indent.get()}pub(crate) fn content_type(&self) -> String {\n`;
+  body += `${indent.push().get()}format!("multipart/form-data; boundary={}", self.boundary)\n`;
+  body += `${indent.pop().get()}}\n\n`;
+
+  if (partKinds.has('file')) {
+    use.add('azure_core::error', 'Error', 'ErrorKind');
+    body += `${indent.get()}pub(crate) fn add_file(&mut self, name: &str, file: &MultipartFile, default_content_type: &str) -> Result<()> {\n`;
+    body += `${indent.push().get()}let filename = file.filename.as_deref().unwrap_or(name);\n`;
+    body += `${indent.get()}let content_type = file.content_type.as_deref().unwrap_or(default_content_type);\n`;
+    body += `${indent.get()}// the content type is sent as-is so it can't contain line breaks\n`;
+    body += `${indent.get()}if content_type.chars().any(|c| c.is_ascii_control()) {\n`;
+    body += `${indent.push().get()}return Err(Error::with_message(ErrorKind::DataConversion, format!("content type for part {name} contains control characters")));\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.get()}self.add_part(name, Some(filename), content_type, &file.content);\n`;
+    body += `${indent.get()}Ok(())\n`;
+    body += `${indent.pop().get()}}\n\n`;
+  }
+
+  if (partKinds.has('json')) {
+    use.add('azure_core', 'json::to_json');
+    use.add('serde', 'Serialize');
+    body += `${indent.get()}pub(crate) fn add_json<T: Serialize + ?Sized>(&mut self, name: &str, value: &T) -> Result<()> {\n`;
+    body += `${indent.push().get()}self.add_part(name, None, "application/json", &to_json(value)?);\n`;
+    body += `${indent.get()}Ok(())\n`;
+    body += `${indent.pop().get()}}\n\n`;
+  }
+
+  if (partKinds.has('text')) {
+    body += `${indent.get()}pub(crate) fn add_text(&mut self, name: &str, value: impl ToString) {\n`;
+    body += `${indent.push().get()}self.add_part(name, None, "text/plain", value.to_string().as_bytes());\n`;
+    body += `${indent.pop().get()}}\n\n`;
+  }
+
+  body += `${indent.get()}fn add_part(&mut self, name: &str, filename: Option<&str>, content_type: &str, content: &[u8]) {\n`;
+  body += `${indent.push().get()}let mut disposition = format!("form-data; name=\\"{}\\"", quote(name));\n`;
+  body += `${indent.get()}if let Some(filename) = filename {\n`;
+  body += `${indent.push().get()}disposition.push_str(&format!("; filename=\\"{}\\"", quote(filename)));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}self.body.extend_from_slice(format!("--{}\\r\\nContent-Disposition: {disposition}\\r\\nContent-Type: {content_type}\\r\\n\\r\\n", self.boundary).as_bytes());\n`;
+  body += `${indent.get()}self.body.extend_from_slice(content);\n`;
+  body += `${indent.get()}self.body.extend_from_slice(b"\\r\\n");\n`;
+  body += `${indent.pop().get()}}\n\n`;
+
+  body += `${indent.get()}/// Consumes the form, returning the complete request body.\n`;
+  body += `${indent.get()}pub(crate) fn into_body(mut self) -> Bytes {\n`;
+  body += `${indent.push().get()}self.body.extend_from_slice(format!("--{}--\\r\\n", self.boundary).as_bytes());\n`;
+  body += `${indent.get()}self.body.into()\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  // per RFC 7578 section 4.2, the characters that would end the quoted
+  // string or the header are percent-encoded as browsers do.
+  body += '/// Percent-encodes double quotes, backslashes, and control characters in a Content-Disposition parameter value.\n';
+  body += 'fn quote(value: &str) -> String {\n';
+  body += `${indent.get()}let mut quoted = String::with_capacity(value.len());\n`;
+  body += `${indent.get()}for c in value.chars() {\n`;
+  body += `${indent.push().get()}if c == '"' || c == '\\\\' || c.is_ascii_control() {\n`;
+  body += `${indent.push().get()}quoted.push_str(&format!("%{:02X}", c as u32));\n`;
+  body += `${indent.pop().get()}} else {\n`;
+  body += `${indent.push().get()}quoted.push(c);\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}quoted\n`;
+  body += '}\n\n';
+
+  for (const model of multipartModels) {
+    use.addForType(model);
+    body += `impl TryFrom<${model.name}> for MultipartForm {\n`;
+    body += `${indent.get()}type Error = azure_core::Error;\n`;
+    body += `${indent.get()}fn try_from(value: ${model.name}) -> Result<Self> {\n`;
+    body += `${indent.push().get()}let mut form = MultipartForm::new();\n`;
+    for (const field of model.fields) {
+      if (field.kind === 'additionalProperties') {
+        throw new CodegenError('InternalError', `unexpected additional properties in multipart model ${model.name}`);
+      }
+      const fieldType = utils.unwrapOption(field.type);
+      const addPart = (indent: helpers.indentation, fieldVar: string): string => {
+        switch (getMultipartPartKind(field)) {
+          case 'file': {
+            const fileType = fieldType.kind === 'Vec' ? fieldType.type : fieldType;
+            if (fileType.kind !== 'multipartFile') {
+              throw new CodegenError('InternalError', `unexpected kind ${fileType.kind} for multipart file field ${model.name}.${field.name}`);
+            }
+            if (fieldType.kind === 'Vec') {
+              let loop = `${indent.get()}for file in ${fieldVar} {\n`;
+              loop += `${indent.push().get()}form.add_file("${field.serde}", file, "${fileType.defaultContentType}")?;\n`;
+              loop += `${indent.pop().get()}}\n`;
+              return loop;
+            }
+            return `${indent.get()}form.add_file("${field.serde}", ${fieldVar}, "${fileType.defaultContentType}")?;\n`;
+          }
+          case 'json':
+            return `${indent.get()}form.add_json("${field.serde}", ${fieldVar})?;\n`;
+          case 'text':
+            if (fieldType.kind === 'literal') {
+              // literals always send their constant value
+              return `${indent.get()}form.add_text("${field.serde}", "${fieldType.value}");\n`;
+            } else if (fieldType.kind === 'enumValue') {
+              return `${indent.get()}form.add_text("${field.serde}", ${fieldType.type.name}::${fieldType.name});\n`;
+            }
+            return `${indent.get()}form.add_text("${field.serde}", ${fieldVar});\n`;
+        }
+      };
+
+      if (field.type.kind === 'option') {
+        body += indent.get() + helpers.buildIfBlock(indent, {
+          condition: `let Some(${field.name}) = &value.${field.name}`,
+          body: (indent) => addPart(indent, field.name),
+        }) + '\n';
+      } else {
+        body += addPart(indent, `&value.${field.name}`);
+      }
+    }
+    body += `${indent.get()}Ok(form)\n`;
+    body += `${indent.pop().get()}}\n`; // end fn
+    body += '}\n\n'; // end impl
+  }
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'multipart',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
+
+/**
+ * emits the Nullable<T> tri-state type used by nullable fields and JSON merge-patch models.
+ * returns undefined if no models in the module use it.
+ * 
+ * @param module the module being processed
+ * @returns the Nullable<T> type or undefined
+ */
+function emitNullable(module: rust.ModuleContainer): helpers.Module | undefined {
+  // Nullable<T> can also be the element type of a collection (e.g. Vec<Nullable<String>>)
+  const isNullable = (type: rust.Type): boolean => {
+    switch (type.kind) {
+      case 'nullable':
+        return true;
+      case 'box':
+      case 'hashmap':
+      case 'option':
+      case 'Vec':
+        return isNullable(type.type);
+      default:
+        return false;
+    }
+  };
+  const usesNullable = module.models.some((model) => model.kind === 'model' && model.fields.some((field) => isNullable(field.type)));
+  if (!usesNullable) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('serde', 'Deserialize', 'Deserializer', 'Serialize', 'Serializer');
+
+  const indent = new helpers.indentation();
+  let body = '/// A value that can be absent, an explicit null, or a value.\n';
+  body += '///\n';
+  body += '/// Used by nullable fields to distinguish an omitted field from an explicit `null`.\n';
+  body += '/// For JSON merge-patch models, sending `null` removes the value\n';
+  body += '/// and omitting the field leaves the value unchanged.\n';
+  body += '#[derive(Clone, Debug, Default, PartialEq)]\n';
+  body += 'pub enum Nullable<T> {\n';
+  body += `${indent.get()}/// The value is omitted.\n`;
+  body += `${indent.get()}#[default]\n`;
+  body += `${indent.get()}Absent,\n\n`;
+  body += `${indent.get()}/// The value is an explicit null.\n`;
+  body += `${indent.get()}Null,\n\n`;
+  body += `${indent.get()}/// The value is present.\n`;
+  body += `${indent.get()}Value(T),\n`;
+  body += '}\n\n';
+
+  body += 'impl<T> Nullable<T> {\n';
+  body += `${indent.get()}/// Returns \`true\` if the value is omitted.\n`;
+  body += `${indent.get()}pub fn is_absent(&self) -> bool {\n`;
+  body += `${indent.push().get()}matches!(self, Nullable::Absent)\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns \`true\` if the value is an explicit null.\n`;
+  body += `${indent.get()}pub fn is_null(&self) -> bool {\n`;
+  body += `${indent.push().get()}matches!(self, Nullable::Null)\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the value if present.\n`;
+  body += `${indent.get()}pub fn as_value(&self) -> Option<&T> {\n`;
+  body += indent.push().get() + helpers.buildMatch(indent, 'self', [{
+    pattern: 'Nullable::Value(value)',
+    body: (indent) => `${indent.get()}Some(value)\n`,
+  }, {
+    pattern: '_',
+    body: (indent) => `${indent.get()}None\n`,
+  }]) + '\n';
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += 'impl<T> From<T> for Nullable<T> {\n';
+  body += `${indent.get()}fn from(value: T) -> Self {\n`;
+  body += `${indent.push().get()}Nullable::Value(value)\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += 'impl<T: Serialize> Serialize for Nullable<T> {\n';
+  body += `${indent.get()}fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {\n`;
+  body += indent.push().get() + helpers.buildMatch(indent, 'self', [{
+    pattern: 'Nullable::Value(value)',
+    body: (indent) => `${indent.get()}serializer.serialize_some(value)\n`,
+  }, {
+    // absent fields are skipped by the containing model so this only applies to map values
+    pattern: 'Nullable::Absent | Nullable::Null',
+    body: (indent) => `${indent.get()}serializer.serialize_none()\n`,
+  }]) + '\n';
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += `impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {\n`;
+  body += `${indent.get()}fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {\n`;
+  body += `${indent.push().get()}// missing fields are handled by serde(default) so only null and values are seen here\n`;
+  body += `${indent.get()}Ok(Option::<T>::deserialize(deserializer)?.map_or(Nullable::Null, Nullable::Value))\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n';
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'nullable',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // serde builder infrastructure
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -574,7 +948,8 @@ const serdeHelpers = new Map<string, (indent: helpers.indentation, use: Use) =>
 const serdeHelpersForXmlAddlProps = new Map<rust.Model, rust.ModelAdditionalProperties>();
 
 /**
- * defines serde helpers for encodedBytes and offsetDateTime types.
+ * defines serde helpers for duration, encodedBytes, offsetDateTime, plainDate, plainTime, and Url types.
+ * this includes any of these types wrapped in a Nullable<T>.
  * any other type will cause this function to throw.
  * 
  * @param module the module being processed
@@ -587,12 +962,16 @@ const serdeHelpersForXmlAddlProps = new Map<rust.Model, rust.ModelAdditionalProp
 function addSerDeHelper(module: rust.ModuleContainer, field: rust.ModelField, serdeParams: Set<string>, format: helpers.ModelFormat, use: Use, deserializeWith?: rust.DeserializeWith): void {
   const unwrapped = helpers.unwrapType(field.type);
   switch (unwrapped.kind) {
+    case 'duration':
     case 'encodedBytes':
     case 'enumValue':
     case 'literal':
     case 'offsetDateTime':
+    case 'plainDate':
+    case 'plainTime':
     case 'safeint':
     case 'scalar':
+    case 'Url':
       break;
     default:
       throw new CodegenError('InternalError', `getSerDeHelper unexpected kind ${unwrapped.kind}`);
@@ -627,16 +1006,22 @@ function addSerDeHelper(module: rust.ModuleContainer, field: rust.ModelField, se
   const buildSerDeModName = function (type: rust.Type): string {
     let name = utils.deconstruct(type.kind).join('_');
     let unwrapped = type;
-    while (unwrapped.kind === 'hashmap' || unwrapped.kind === 'option' || unwrapped.kind === 'ref' || unwrapped.kind === 'Vec') {
+    while (unwrapped.kind === 'hashmap' || unwrapped.kind === 'nullable' || unwrapped.kind === 'option' || unwrapped.kind === 'ref' || unwrapped.kind === 'Vec') {
       unwrapped = unwrapped.type;
       name += '_' + utils.deconstruct(unwrapped.kind).join('_');
     }
 
     switch (unwrapped.kind) {
+      case 'duration':
       case 'encodedBytes':
       case 'offsetDateTime':
         name += `_${utils.deconstruct(unwrapped.encoding).join('_')}`;
         break;
+      case 'plainDate':
+      case 'plainTime':
+      case 'Url':
+        // these types have a single wire format
+        break;
       default:
         throw new CodegenError('InternalError', `unexpected kind ${unwrapped.kind}`);
     }
@@ -762,6 +1147,7 @@ function addSerDeHelper(module: rust.ModuleContainer, field: rust.ModelField, se
       // if we get here, it means we have one of the following cases
       //  - HashMap/Vec of encoded thing (spread params)
       //  - Option of HashMap/Vec of encoded thing
+      //  - Nullable of encoded thing
       addSerDeHelper();
       break;
     }
@@ -1010,11 +1396,20 @@ function buildLiteralSerialize(indent: helpers.indentation, name: string, field:
 function buildDeserialize(indent: helpers.indentation, type: rust.Type, use: Use): string {
   use.add('serde', 'Deserialize', 'Deserializer');
   use.add('std', 'result::Result');
-  use.addForType(type);
+  addForSerDeHelperType(use, type);
   let content = `${indent.get()}pub fn deserialize<'de, D>(deserializer: D) -> Result<${helpers.getTypeDeclaration(type)}, D::Error>\n`;
   content += `${indent.get()}where D: Deserializer<'de>\n${indent.get()}{\n`;
-  content += `${indent.push().get()}let to_deserialize = <Option<${getSerDeTypeDeclaration(type.kind === 'option' ? type.type : type, 'deserialize')}>>::deserialize(deserializer)?;\n`;
+  content += `${indent.push().get()}let to_deserialize = <Option<${getSerDeTypeDeclaration(type.kind === 'option' || type.kind === 'nullable' ? type.type : type, 'deserialize')}>>::deserialize(deserializer)?;\n`;
+  const matchArms: Array<helpers.matchArm> = [];
+  if (type.kind === 'option' && type.type.kind === 'Url') {
+    // an empty value (e.g. <Link />) isn't a valid Url so treat it as absent
+    matchArms.push({
+      pattern: 'Some(to_deserialize) if to_deserialize.is_empty()',
+      body: (indent) => `${indent.get()}Ok(None)\n`,
+    });
+  }
   content += `${indent.get()}${helpers.buildMatch(indent, 'to_deserialize', [
+    ...matchArms,
     {
       pattern: 'Some(to_deserialize)',
       body: (indent) => recursiveBuildDeserializeBody(indent, use, {
@@ -1025,8 +1420,9 @@ function buildDeserialize(indent: helpers.indentation, type: rust.Type, use: Use
       }),
     },
     {
+      // for Nullable<T>, absent values are handled by serde(default) so None is an explicit null
       pattern: 'None',
-      body: (indent) => `${indent.get()}Ok(${type.kind === 'option' ? 'None' : `<${getSerDeTypeDeclaration(type, 'result')}>::default()`})\n`,
+      body: (indent) => `${indent.get()}Ok(${type.kind === 'option' ? 'None' : type.kind === 'nullable' ? 'Nullable::Null' : `<${getSerDeTypeDeclaration(type, 'result')}>::default()`})\n`,
     }
   ])}\n`;
   content += `${indent.pop().get()}}\n`;
@@ -1044,7 +1440,7 @@ function buildDeserialize(indent: helpers.indentation, type: rust.Type, use: Use
 function buildSerialize(indent: helpers.indentation, type: rust.Type, use: Use): string {
   use.add('serde', 'Serialize', 'Serializer');
   use.add('std', 'result::Result');
-  use.addForType(type);
+  addForSerDeHelperType(use, type);
 
   // clippy wants the outer-most Vec<T> to be a [] instead
   const getTypeDeclaration = function (type: rust.Type): string {
@@ -1074,6 +1470,22 @@ function buildSerialize(indent: helpers.indentation, type: rust.Type, use: Use):
   return content;
 }
 
+/**
+ * adds the use statements for the type of a serde helper.
+ * the helpers are in a nested module so Nullable<T> must
+ * be imported with its full path.
+ * 
+ * @param use the use statement builder currently in scope
+ * @param type the type for which to build the helper
+ */
+function addForSerDeHelperType(use: Use, type: rust.Type): void {
+  if (type.kind === 'nullable') {
+    use.add(`${utils.buildImportPath(use.module, type.module)}::models`, type.name);
+    type = type.type;
+  }
+  use.addForType(type);
+}
+
 /** a stack for variable names */
 class VarStack {
   private readonly prefix: string;
@@ -1178,11 +1590,26 @@ function recursiveBuildDeserializeBody(indent: helpers.indentation, use: Use, ct
 
   let content = '';
   switch (ctx.type.kind) {
+    case 'duration': {
+      // terminal case
+      const durationParse = getDurationEncodingMethod(ctx.type.encoding, 'parse', use);
+      content = `${durationParse}(${ctx.type.encoding === 'iso8601' ? '&' : ''}${ctx.srcVar}).map_err(serde::de::Error::custom)?`;
+      if (ctx.caller === 'option' || ctx.caller === 'start') {
+        content = `${indent.get()}let ${ctx.destVar.get()} = ${content};\n`;
+      } else {
+        content = insertOrPush(content, true);
+      }
+      break;
+    }
     case 'encodedBytes': {
       // terminal case (NEVER the start case)
       const base64Decode = helpers.getBytesEncodingMethod(ctx.type.encoding, 'decode', use);
       content = `${base64Decode}(${ctx.srcVar}).map_err(serde::de::Error::custom)?`;
-      content = insertOrPush(content, true);
+      if (ctx.caller === 'option') {
+        content = `${indent.get()}let ${ctx.destVar.get()} = ${content};\n`;
+      } else {
+        content = insertOrPush(content, true);
+      }
       break;
     }
     case 'hashmap': {
@@ -1213,6 +1640,29 @@ function recursiveBuildDeserializeBody(indent: helpers.indentation, use: Use, ct
       }
       break;
     }
+    case 'plainDate':
+    case 'plainTime': {
+      // terminal case
+      const dateParse = getPlainDateTimeMethod(ctx.type, 'parse', use);
+      content = `${dateParse}(&${ctx.srcVar}).map_err(serde::de::Error::custom)?`;
+      if (ctx.caller === 'option' || ctx.caller === 'start') {
+        content = `${indent.get()}let ${ctx.destVar.get()} = ${content};\n`;
+      } else {
+        content = insertOrPush(content, true);
+      }
+      break;
+    }
+    case 'Url': {
+      // terminal case
+      content = `Url::parse(&${ctx.srcVar}).map_err(serde::de::Error::custom)?`;
+      if (ctx.caller === 'option' || ctx.caller === 'start') {
+        content = `${indent.get()}let ${ctx.destVar.get()} = ${content};\n`;
+      } else {
+        content = insertOrPush(content, true);
+      }
+      break;
+    }
+    case 'nullable':
     case 'option':
       content += recursiveBuildDeserializeBody(indent, use, {
         caller: 'option',
@@ -1242,12 +1692,30 @@ function recursiveBuildDeserializeBody(indent: helpers.indentation, use: Use, ct
 
   if (ctx.caller === 'start') {
     const destVar = ctx.destVar.get();
-    content += `${indent.get()}Ok(${ctx.type.kind === 'option' ? `Some(${destVar})` : destVar})\n`;
+    content += `${indent.get()}Ok(${ctx.type.kind === 'option' ? `Some(${destVar})` : ctx.type.kind === 'nullable' ? `Nullable::Value(${destVar})` : destVar})\n`;
   }
 
   return content;
 }
 
+/**
+ * returns true if converting a Vec item of the specified type to its
+ * wire type returns a Result (i.e. it calls a fallible to helper).
+ *
+ * @param type the Vec item type
+ * @returns true if the conversion is fallible
+ */
+function isSerializeFallible(type: rust.Type): boolean {
+  switch (type.kind) {
+    case 'duration':
+      return isDurationToFallible(type.encoding);
+    case 'Vec':
+      return isSerializeFallible(type.type);
+    default:
+      return false;
+  }
+}
+
 /**
  * recursive state machine to construct the body of the serialize function.
  * 
@@ -1264,6 +1732,25 @@ function recursiveBuildSerializeBody(indent: helpers.indentation, use: Use, ctx:
 
   let content = '';
   switch (ctx.type.kind) {
+    case 'duration': {
+      // terminal case
+      const durationTo = getDurationEncodingMethod(ctx.type.encoding, 'to', use);
+      const mapErr = isDurationToFallible(ctx.type.encoding) ? '.map_err(serde::ser::Error::custom)?' : '';
+      switch (ctx.caller) {
+        case 'hashmap':
+          content = hashMapInsert(`${durationTo}(${ctx.srcVar})${mapErr}`);
+          break;
+        case 'option':
+        case 'start':
+          content = `${indent.get()}let ${ctx.destVar.get()} = ${durationTo}(${ctx.srcVar})${mapErr};\n`;
+          break;
+        case 'vec':
+          // fallible items are collected into a Result (see the Vec case)
+          content = durationTo;
+          break;
+      }
+      break;
+    }
     case 'encodedBytes': {
       // terminal case (NEVER the start case)
       const base64Encode = helpers.getBytesEncodingMethod(ctx.type.encoding, 'encode', use);
@@ -1271,6 +1758,9 @@ function recursiveBuildSerializeBody(indent: helpers.indentation, use: Use, ctx:
         case 'hashmap':
           content = `${hashMapInsert(`${base64Encode}(${ctx.srcVar})`)}`;
           break;
+        case 'option':
+          content = `${indent.get()}let ${ctx.destVar.get()} = ${base64Encode}(${ctx.srcVar});\n`;
+          break;
         default:
           content = base64Encode;
       }
@@ -1327,13 +1817,48 @@ function recursiveBuildSerializeBody(indent: helpers.indentation, use: Use, ctx:
       }
       break;
     }
+    case 'plainDate':
+    case 'plainTime': {
+      // terminal case
+      const dateTo = getPlainDateTimeMethod(ctx.type, 'to', use);
+      switch (ctx.caller) {
+        case 'hashmap':
+          content = hashMapInsert(`${dateTo}(${ctx.srcVar})`);
+          break;
+        case 'option':
+        case 'start':
+          content = `${indent.get()}let ${ctx.destVar.get()} = ${dateTo}(${ctx.srcVar});\n`;
+          break;
+        case 'vec':
+          content = dateTo;
+          break;
+      }
+      break;
+    }
+    case 'Url':
+      // terminal case
+      switch (ctx.caller) {
+        case 'hashmap':
+          content = hashMapInsert(`${ctx.srcVar}.to_string()`);
+          break;
+        case 'option':
+        case 'start':
+          content = `${indent.get()}let ${ctx.destVar.get()} = ${ctx.srcVar}.to_string();\n`;
+          break;
+        case 'vec':
+          content = 'Url::to_string';
+          break;
+      }
+      break;
+    case 'nullable':
     case 'option': {
+      // for Nullable<T>, absent values are skipped by the containing model so only Null is sent as none
       content = indent.get() + helpers.buildIfBlock(indent, {
-        condition: `let Some(${ctx.srcVar}) = ${ctx.srcVar}`,
+        condition: `let ${ctx.type.kind === 'option' ? 'Some' : 'Nullable::Value'}(${ctx.srcVar}) = ${ctx.srcVar}`,
         body: (indent) => {
           let body = recursiveBuildSerializeBody(indent, use, {
             caller: 'option',
-            type: (<rust.Option>ctx.type).type,
+            type: (<rust.Nullable | rust.Option>ctx.type).type,
             srcVar: ctx.srcVar,
             destVar: ctx.destVar,
           });
@@ -1345,22 +1870,26 @@ function recursiveBuildSerializeBody(indent: helpers.indentation, use: Use, ctx:
       break;
     }
     case 'Vec': {
+      // when converting an item can fail, the items are collected into a Result.
+      // nested Vecs return the Result to their enclosing Vec.
+      const fallible = isSerializeFallible(ctx.type.type);
       const convertVec = `.iter().map(${recursiveBuildSerializeBody(indent.push(), use, {
         caller: 'vec',
         type: ctx.type.type,
         srcVar: 'v',
         destVar: ctx.destVar.push(),
-      })}).collect()`;
+      })})${fallible ? '.collect::<Result<Vec<_>, _>>()' : '.collect()'}`;
       ctx.destVar.pop();
       indent.pop();
+      const mapErr = fallible ? '.map_err(serde::ser::Error::custom)?' : '';
 
       switch (ctx.caller) {
         case 'hashmap':
-          content = `${hashMapInsert(`${ctx.srcVar}${convertVec}`)}`;
+          content = `${hashMapInsert(`${ctx.srcVar}${convertVec}${mapErr}`)}`;
           break;
         case 'start':
         case 'option':
-          content = `${indent.get()}let ${ctx.destVar.get()} = ${ctx.srcVar}${convertVec};\n`;
+          content = `${indent.get()}let ${ctx.destVar.get()} = ${ctx.srcVar}${convertVec}${mapErr};\n`;
           break;
         case 'vec':
           content = `|${ctx.srcVar}|${ctx.srcVar}${convertVec}`;
@@ -1372,8 +1901,8 @@ function recursiveBuildSerializeBody(indent: helpers.indentation, use: Use, ctx:
       throw new CodegenError('InternalError', `unexpected kind ${ctx.type.kind}`);
   }
 
-  if (ctx.caller === 'start' && ctx.type.kind !== 'option') {
-    // for the Option<T> case, this was emitted within the "if let Some()" body earlier
+  if (ctx.caller === 'start' && ctx.type.kind !== 'nullable' && ctx.type.kind !== 'option') {
+    // for the Nullable<T> and Option<T> cases, this was emitted within the "if let" body earlier
     content += `${indent.get()}<${getSerDeTypeDeclaration(ctx.type, 'serialize')}>::serialize(&${ctx.destVar.get()}, serializer)\n`;
   }
 
@@ -1395,14 +1924,23 @@ function recursiveBuildSerializeBody(indent: helpers.indentation, use: Use, ctx:
  */
 function getSerDeTypeDeclaration(type: rust.Type, usage: 'serialize' | 'deserialize' | 'result'): string {
   switch (type.kind) {
+    case 'duration':
+      return usage === 'result' ? 'Duration' : getDurationWireType(type.encoding);
     case 'encodedBytes':
       return usage === 'result' ? 'Vec<u8>' : 'String';
     case 'offsetDateTime':
       return usage === 'result' ? 'OffsetDateTime' : type.encoding === 'unix_time' ? 'i64' : 'String';
+    case 'plainDate':
+    case 'plainTime':
+    case 'Url':
+      return usage === 'result' ? type.name : 'String';
     case 'hashmap':
       return `${type.name}<${usage === 'serialize' ? '&' : ''}String, ${getSerDeTypeDeclaration(type.type, usage)}>`;
     case 'Vec':
       return `${type.kind}<${getSerDeTypeDeclaration(type.type, usage)}>`;
+    case 'nullable':
+      // a Nullable<T> is sent over the wire as an Option<T>
+      return `${usage === 'result' ? type.name : 'Option'}<${getSerDeTypeDeclaration(type.type, usage)}>`;
     case 'option':
       return `Option<${getSerDeTypeDeclaration(type.type, usage)}>`;
     default:
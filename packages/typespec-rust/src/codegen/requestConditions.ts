@@ -0,0 +1,108 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+
+/**
+ * returns the emitted request conditions types, or undefined if
+ * no methods in the module have conditional request headers.
+ * the ConditionalRequestError type is emitted alongside them
+ * so callers can handle conditions that weren't met.
+ *
+ * @param module the module for which to emit the request conditions types
+ * @returns the request conditions content or undefined
+ */
+export function emitRequestConditions(module: rust.ModuleContainer): helpers.Module | undefined {
+  const conditionsNames = new Set<string>();
+  for (const client of module.clients) {
+    for (const method of client.methods) {
+      if (method.kind === 'clientaccessor') {
+        continue;
+      }
+      for (const param of method.params) {
+        if (param.kind === 'headerScalar' && param.conditions) {
+          conditionsNames.add(param.conditions.name);
+        }
+      }
+    }
+  }
+
+  if (conditionsNames.size === 0) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core::error', 'ErrorKind');
+  use.add('azure_core::fmt', 'SafeDebug');
+  use.add('azure_core::http', 'Etag', 'StatusCode');
+
+  const indent = new helpers.indentation();
+
+  let body = '/// The reason a request with conditions failed.\n';
+  body += '///\n';
+  body += '/// Convert the error returned by a method with request conditions into this type to determine if the conditions weren\'t met.\n';
+  body += '#[derive(SafeDebug)]\n';
+  body += 'pub enum ConditionalRequestError {\n';
+  body += `${indent.get()}/// The resource wasn't modified. The service returned a 304 status code.\n`;
+  body += `${indent.get()}NotModified,\n\n`;
+  body += `${indent.get()}/// The conditions weren't met. The service returned a 412 status code.\n`;
+  body += `${indent.get()}PreconditionFailed,\n\n`;
+  body += `${indent.get()}/// Any other error.\n`;
+  body += `${indent.get()}Other(azure_core::Error),\n`;
+  body += '}\n\n';
+
+  body += 'impl From<azure_core::Error> for ConditionalRequestError {\n';
+  body += `${indent.get()}fn from(error: azure_core::Error) -> Self {\n`;
+  body += `${indent.push().get()}` + helpers.buildMatch(indent, 'error.kind()', [{
+    pattern: 'ErrorKind::HttpResponse { status: StatusCode::NotModified, .. }',
+    body: (indent) => `${indent.get()}Self::NotModified\n`,
+  }, {
+    pattern: 'ErrorKind::HttpResponse { status: StatusCode::PreconditionFailed, .. }',
+    body: (indent) => `${indent.get()}Self::PreconditionFailed\n`,
+  }, {
+    pattern: '_',
+    body: (indent) => `${indent.get()}Self::Other(error)\n`,
+  }]) + '\n';
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n';
+
+  const emitField = (name: string, type: string, docs: string): string => {
+    return `${indent.get()}/// ${docs}\n${indent.get()}pub ${name}: Option<${type}>,\n`;
+  };
+  const ifMatch = emitField('if_match', 'Etag', 'The request should only proceed if the resource\'s ETag matches this value.');
+  const ifNoneMatch = emitField('if_none_match', 'Etag', 'The request should only proceed if the resource\'s ETag doesn\'t match this value.');
+
+  if (conditionsNames.has('MatchConditions')) {
+    body += '\n/// Conditions on the ETag of a resource that must be met for the request to proceed.\n';
+    body += '#[derive(Clone, Default, SafeDebug)]\n';
+    body += 'pub struct MatchConditions {\n';
+    body += `${ifMatch}\n${ifNoneMatch}`;
+    body += '}\n';
+  }
+
+  if (conditionsNames.has('RequestConditions')) {
+    use.add('azure_core::time', 'OffsetDateTime');
+    body += '\n/// Conditions on the ETag and modification time of a resource that must be met for the request to proceed.\n';
+    body += '#[derive(Clone, Default, SafeDebug)]\n';
+    body += 'pub struct RequestConditions {\n';
+    body += `${ifMatch}\n`;
+    body += `${emitField('if_modified_since', 'OffsetDateTime', 'The request should only proceed if the resource was modified after this time.')}\n`;
+    body += `${ifNoneMatch}\n`;
+    body += emitField('if_unmodified_since', 'OffsetDateTime', 'The request should only proceed if the resource wasn\'t modified after this time.');
+    body += '}\n';
+  }
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'request_conditions',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
@@ -0,0 +1,150 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+
+/**
+ * returns the emitted ItemStream<T> type and the helpers that
+ * parse streaming response bodies into items, or undefined if
+ * no methods in the module return an ItemStream<T>.
+ *
+ * @param module the module for which to emit the stream helpers
+ * @returns the stream helpers content or undefined
+ */
+export function emitItemStreams(module: rust.ModuleContainer): helpers.Module | undefined {
+  const formats = new Set<rust.StreamFormat>();
+  for (const client of module.clients) {
+    for (const method of client.methods) {
+      if (method.kind === 'async' && method.returns.type.kind === 'itemStream') {
+        formats.add(method.returns.type.format);
+      }
+    }
+  }
+
+  if (formats.size === 0) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core', 'Bytes', 'Result');
+  use.add('azure_core::error', 'Error', 'ErrorKind');
+  use.add('azure_core::json', 'from_json');
+  use.add('futures::stream', 'Stream', 'StreamExt', 'unfold');
+  use.add('serde::de', 'DeserializeOwned');
+  use.add('std::pin', 'Pin');
+
+  const indent = new helpers.indentation();
+  const streamBounds = `where\n${indent.get()}T: DeserializeOwned + Send + 'static,\n${indent.get()}S: Stream<Item = Result<Bytes>> + Send + Unpin + 'static,\n`;
+
+  let body = '/// A stream of items parsed from a streaming response body.\n';
+  body += 'pub type ItemStream<T> = Pin<Box<dyn Stream<Item = Result<T>> + Send>>;\n\n';
+
+  if (formats.has('jsonl')) {
+    use.add('futures', 'future::ready', 'stream::TryStreamExt');
+    body += '/// Returns a stream of items parsed from a JSON Lines response body.\n';
+    body += 'pub(crate) fn jsonl_stream<T, S>(body: S) -> ItemStream<T>\n';
+    body += streamBounds;
+    body += '{\n';
+    body += `${indent.get()}Box::pin(\n`;
+    body += `${indent.push().get()}lines(body)\n`;
+    body += `${indent.push().get()}.try_filter(|line| ready(!line.trim().is_empty()))\n`;
+    body += `${indent.get()}.and_then(|line| ready(from_json::<_, T>(line))),\n`;
+    indent.pop();
+    body += `${indent.pop().get()})\n`;
+    body += '}\n\n';
+  }
+
+  if (formats.has('sse')) {
+    body += '/// Returns a stream of items parsed from the data fields of a server-sent events response body.\n';
+    body += 'pub(crate) fn sse_stream<T, S>(body: S) -> ItemStream<T>\n';
+    body += streamBounds;
+    body += '{\n';
+    body += `${indent.get()}Box::pin(unfold(\n`;
+    body += `${indent.push().get()}(Box::pin(lines(body)), String::new()),\n`;
+    body += `${indent.get()}|(mut lines, mut data)| async move {\n`;
+    body += `${indent.push().get()}while let Some(line) = lines.next().await {\n`;
+    body += `${indent.push().get()}let line = ` + helpers.buildMatch(indent, 'line', [{
+      pattern: 'Ok(line)',
+      body: (indent) => `${indent.get()}line\n`,
+    }, {
+      pattern: 'Err(err)',
+      body: (indent) => `${indent.get()}return Some((Err(err), (lines, data)))\n`,
+    }]) + ';\n';
+    body += `${indent.get()}if line.is_empty() {\n`;
+    body += `${indent.push().get()}// a blank line dispatches the event\n`;
+    body += `${indent.get()}if !data.is_empty() {\n`;
+    body += `${indent.push().get()}let event = std::mem::take(&mut data);\n`;
+    body += `${indent.get()}return Some((from_json::<_, T>(event), (lines, data)));\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.pop().get()}} else if let Some(value) = line.strip_prefix("data:") {\n`;
+    body += `${indent.push().get()}if !data.is_empty() {\n`;
+    body += `${indent.push().get()}data.push('\\n');\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.get()}data.push_str(value.strip_prefix(' ').unwrap_or(value));\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.get()}// comments and the event, id, and retry fields are ignored\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.get()}// an event that isn't terminated by a blank line is discarded\n`;
+    body += `${indent.get()}None\n`;
+    body += `${indent.pop().get()}},\n`;
+    body += `${indent.pop().get()}))\n`;
+    body += '}\n\n';
+  }
+
+  body += '/// Splits a response body into lines without their line endings.\n';
+  body += 'fn lines<S>(body: S) -> impl Stream<Item = Result<String>> + Send\n';
+  body += `where\n${indent.get()}S: Stream<Item = Result<Bytes>> + Send + Unpin,\n`;
+  body += '{\n';
+  body += `${indent.get()}unfold(\n`;
+  body += `${indent.push().get()}(body, Vec::new(), false),\n`;
+  body += `${indent.get()}|(mut body, mut buffer, mut done)| async move {\n`;
+  body += `${indent.push().get()}loop {\n`;
+  body += `${indent.push().get()}if let Some(pos) = buffer.iter().position(|b| *b == b'\\n') {\n`;
+  body += `${indent.push().get()}let mut line: Vec<u8> = buffer.drain(..=pos).collect();\n`;
+  body += `${indent.get()}line.pop();\n`;
+  body += `${indent.get()}if line.last() == Some(&b'\\r') {\n`;
+  body += `${indent.push().get()}line.pop();\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}return Some((into_string(line), (body, buffer, done)));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}if done {\n`;
+  body += `${indent.push().get()}if buffer.is_empty() {\n`;
+  body += `${indent.push().get()}return None;\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}// the final line has no line ending\n`;
+  body += `${indent.get()}let line = std::mem::take(&mut buffer);\n`;
+  body += `${indent.get()}return Some((into_string(line), (body, buffer, done)));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}` + helpers.buildMatch(indent, 'body.next().await', [{
+    pattern: 'Some(Ok(bytes))',
+    body: (indent) => `${indent.get()}buffer.extend_from_slice(&bytes)\n`,
+  }, {
+    pattern: 'Some(Err(err))',
+    body: (indent) => `${indent.get()}return Some((Err(err), (body, Vec::new(), true)))\n`,
+  }, {
+    pattern: 'None',
+    body: (indent) => `${indent.get()}done = true\n`,
+  }]) + '\n';
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}},\n`;
+  body += `${indent.pop().get()})\n`;
+  body += '}\n\n';
+
+  body += 'fn into_string(line: Vec<u8>) -> Result<String> {\n';
+  body += `${indent.get()}String::from_utf8(line).map_err(|err| Error::new(ErrorKind::DataConversion, err))\n`;
+  body += '}\n';
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'streams',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
@@ -155,6 +155,10 @@ function getPolymorphicUnknownVariant(indent: helpers.indentation, use: Use, bas
       unknownDef += `${field.name}, `;
     } else {
       use.addForType(field.type);
+      if (field.kind === 'additionalProperties') {
+        // unnamed key/value pairs are collected alongside the named fields
+        unknownDef += `${indent.get()}#[serde(flatten)]\n`;
+      }
       // the base internal type declares its fields with refs and a lifetime.
       // we need to omit those for the inline anonymous unknown kind definition.
       unknownDef += `${indent.get()}${field.name}: ${helpers.getTypeDeclaration(field.type, 'omit')},\n\n`;
@@ -10,8 +10,10 @@ import * as utils from '../utils/utils.js';
 
 /** used to generate use statements */
 export class Use {
-  private readonly module: rust.ModuleContainer;
+  /** the module that contains the files in scope */
+  readonly module: rust.ModuleContainer;
   private readonly trees: Array<useTree>;
+  private readonly gated: Array<{ feature: rust.CrateFeature; path: string }>;
   private readonly scope: 'clients' | 'models' | 'modelsOther' | 'unions';
 
   /**
@@ -28,6 +30,7 @@ export class Use {
   constructor(module: rust.ModuleContainer, scope: 'clients' | 'models' | 'modelsOther' | 'unions') {
     this.module = module;
     this.trees = new Array<useTree>();
+    this.gated = new Array<{ feature: rust.CrateFeature; path: string }>();
     this.scope = scope;
   }
 
@@ -69,7 +72,7 @@ export class Use {
    * @param type the Rust type to add
    */
   addForType(type: rust.Client | rust.ResponseHeadersTrait | rust.Type): void {
-    const addForClientsOrCrossModule = (type: rust.DiscriminatedUnion | rust.Enum | rust.Model | rust.UntaggedUnion): boolean => {
+    const addForClientsOrCrossModule = (type: rust.DiscriminatedUnion | rust.Enum | rust.ItemStream | rust.Model | rust.MultipartFile | rust.Nullable | rust.UntaggedUnion): boolean => {
       if (this.scope === 'clients' || this.module !== type.module) {
         this.add(`${utils.buildImportPath(this.module, type.module)}::models`, type.name);
         return true;
@@ -83,9 +86,21 @@ export class Use {
         return this.addForType(type.type);
       case 'client': {
         // client type are only referenced from other things in generated/clients so we ignore any scope
-        this.add(`${utils.buildImportPath(this.module, type.module)}::clients`, type.name);
+        const path = `${utils.buildImportPath(this.module, type.module)}::clients`;
+        const feature = utils.getFeatureGate(this.module, type.module);
+        if (!feature) {
+          this.add(path, type.name);
+        } else if (!this.gated.find((each) => each.path === `${path}::${type.name}`)) {
+          // clients in gated modules are imported separately so the import can share the gate
+          this.gated.push({ feature: feature, path: `${path}::${type.name}` });
+        }
         break;
       }
+      case 'basicCredential':
+      case 'keyCredential':
+        // credentials are emitted in generated/clients alongside the clients that use them
+        this.add(`${utils.buildImportPath(this.module, type.module)}::clients`, type.name);
+        break;
       case 'discriminatedUnion':
       case 'untaggedUnion':
         if (!addForClientsOrCrossModule(type) && this.scope !== 'unions') {
@@ -120,6 +135,26 @@ export class Use {
           this.add('super', type.name);
         }
         break;
+      case 'multipartFile':
+        // MultipartFile is defined in generated/models/multipart.rs
+        if (!addForClientsOrCrossModule(type)) {
+          this.add('super', type.name);
+        }
+        break;
+      case 'itemStream':
+        // ItemStream is defined in generated/models/streams.rs
+        if (!addForClientsOrCrossModule(type)) {
+          this.add('super', type.name);
+        }
+        this.addForType(type.type);
+        break;
+      case 'nullable':
+        // Nullable is defined in generated/models/nullable.rs
+        if (!addForClientsOrCrossModule(type)) {
+          this.add('super', type.name);
+        }
+        this.addForType(type.type);
+        break;
       case 'asyncResponse':
       case 'option':
       case 'ref':
@@ -136,12 +171,25 @@ export class Use {
         }
         break;
       case 'requestContent':
-        if (type.format !== 'JsonFormat') {
-          // JsonFormat is the default so no need to bring it into scope
+        if (type.format !== 'JsonFormat' && type.format !== 'MultipartFormat') {
+          // JsonFormat is the default so no need to bring it into scope.
+          // multipart bodies are passed as the model so there's no format type.
           this.add('azure_core::http', type.format);
         }
         this.addForType(type.content);
         break;
+      case 'requestConditions':
+        switch (this.scope) {
+          case 'clients':
+            // conditions types are always in the same module as their client method
+            this.add(`${utils.buildImportPath(this.module, this.module)}::models`, type.name);
+            break;
+          case 'models':
+          case 'modelsOther':
+            this.add('super', type.name);
+            break;
+        }
+        break;
       case 'response':
         if (type.format !== 'JsonFormat') {
           // JsonFormat is the default so no need to bring it into scope
@@ -177,7 +225,7 @@ export class Use {
    * @returns returns Rust formatted use statements
    */
   text(indent?: helpers.indentation): string {
-    if (this.trees.length === 0) {
+    if (this.trees.length === 0 && this.gated.length === 0) {
       return '';
     }
 
@@ -215,6 +263,12 @@ export class Use {
       content += ';\n';
     }
 
+    this.gated.sort((a, b) => helpers.sortAscending(a.path, b.path));
+    for (const gated of this.gated) {
+      content += `${indent.get()}#[cfg(feature = "${gated.feature.name}")]\n`;
+      content += `${indent.get()}use ${gated.path};\n`;
+    }
+
     content += '\n';
     return content;
   }
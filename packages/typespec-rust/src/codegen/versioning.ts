@@ -0,0 +1,234 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+import * as utils from '../utils/utils.js';
+
+/**
+ * returns the emitted helpers that convert JSON bodies between the wire
+ * formats of API versions, or undefined if no methods in the module
+ * have request or response bodies whose wire format changed.
+ * the helpers are crate private so only the helpers and kinds
+ * of changes used by the module are emitted.
+ *
+ * @param module the module for which to emit the helpers
+ * @returns the versioning helpers content or undefined
+ */
+export function emitVersioning(module: rust.ModuleContainer): helpers.Module | undefined {
+  const changeKinds = new Set<rust.WireChange['kind']>();
+  const jsonKinds = new Set<rust.JsonScalarKind>();
+  let convertsRequests = false;
+  let convertsResponses = false;
+  for (const client of module.clients) {
+    for (const method of client.methods) {
+      if (method.kind !== 'async') {
+        continue;
+      }
+      convertsRequests ||= method.requestWireChanges.length > 0;
+      convertsResponses ||= method.responseWireChanges.length > 0;
+      for (const change of [...method.requestWireChanges, ...method.responseWireChanges]) {
+        changeKinds.add(change.kind);
+        if (change.kind === 'type') {
+          jsonKinds.add(change.type);
+          jsonKinds.add(change.previousType);
+        }
+      }
+    }
+  }
+
+  if (changeKinds.size === 0) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core', 'Result', 'Value');
+  use.add('azure_core::json', 'from_json', 'to_json');
+
+  const indent = new helpers.indentation();
+  const variants = new Array<{ kind: rust.WireChange['kind'], name: string, doc: string, fields: Array<[string, string]> }>(
+    { kind: 'name', name: 'Name', doc: 'A property was renamed.', fields: [['name', '&\'static str'], ['previous', '&\'static str']] },
+    { kind: 'type', name: 'Type', doc: 'The type of a scalar value was changed.', fields: [['kind', 'JsonKind'], ['previous', 'JsonKind']] },
+    { kind: 'value', name: 'Value', doc: 'An enum value was renamed.', fields: [['value', '&\'static str'], ['previous', '&\'static str']] },
+  ).filter((variant) => changeKinds.has(variant.kind));
+
+  let body = '/// A change to the JSON wire format of a body made in a later API version.\n';
+  body += '///\n';
+  body += '/// The path contains the wire names of the enclosing properties in the latest API version, with `*` for the elements of arrays and dictionaries.\n';
+  body += 'pub(crate) enum WireChange {\n';
+  body += variants.map((variant) => {
+    let text = `${indent.get()}/// ${variant.doc}\n`;
+    text += `${indent.get()}${variant.name} {\n`;
+    text += `${indent.push().get()}path: &'static [&'static str],\n`;
+    for (const [fieldName, fieldType] of variant.fields) {
+      text += `${indent.get()}${fieldName}: ${fieldType},\n`;
+    }
+    text += `${indent.pop().get()}},\n`;
+    return text;
+  }).join('\n');
+  body += '}\n\n';
+
+  if (changeKinds.has('type')) {
+    body += '/// The kinds of JSON values a scalar type is sent as.\n';
+    body += '#[derive(Clone, Copy)]\n';
+    body += 'pub(crate) enum JsonKind {\n';
+    for (const jsonKind of [...jsonKinds].sort()) {
+      body += `${indent.get()}${utils.capitalize(jsonKind)},\n`;
+    }
+    body += '}\n\n';
+  }
+
+  body += 'impl WireChange {\n';
+  body += `${indent.get()}fn path(&self) -> &'static [&'static str] {\n`;
+  body += `${indent.push().get()}match self {\n`;
+  body += `${indent.push().get()}${variants.map((variant) => `Self::${variant.name} { path, .. }`).join(' | ')} => path,\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}fn apply(&self, value: &mut Value, to_previous: bool) {\n`;
+  body += `${indent.push().get()}${helpers.buildMatch(indent, '*self', variants.map((variant): helpers.matchArm => {
+    switch (variant.kind) {
+      case 'name':
+        return {
+          pattern: 'Self::Name { path, name, previous }',
+          body: (indent) => {
+            let arm = `${indent.get()}let (from, to) = if to_previous { (name, previous) } else { (previous, name) };\n`;
+            arm += `${indent.get()}visit(value, path, &mut |object| {\n`;
+            arm += `${indent.push().get()}let Some(object) = object.as_object_mut() else {\n`;
+            arm += `${indent.push().get()}return;\n`;
+            arm += `${indent.pop().get()}};\n`;
+            arm += `${indent.get()}if let Some(item) = object.remove(from) {\n`;
+            arm += `${indent.push().get()}object.insert(to.to_string(), item);\n`;
+            arm += `${indent.pop().get()}}\n`;
+            arm += `${indent.pop().get()}});\n`;
+            return arm;
+          },
+        };
+      case 'type':
+        return {
+          pattern: 'Self::Type { path, kind, previous }',
+          body: (indent) => {
+            let arm = `${indent.get()}let kind = if to_previous { previous } else { kind };\n`;
+            arm += `${indent.get()}visit(value, path, &mut |item| convert(item, kind));\n`;
+            return arm;
+          },
+        };
+      case 'value':
+        return {
+          pattern: 'Self::Value { path, value: latest, previous }',
+          body: (indent) => {
+            let arm = `${indent.get()}let (from, to) = if to_previous { (latest, previous) } else { (previous, latest) };\n`;
+            arm += `${indent.get()}visit(value, path, &mut |item| {\n`;
+            arm += `${indent.push().get()}if item.as_str() == Some(from) {\n`;
+            arm += `${indent.push().get()}*item = Value::from(to);\n`;
+            arm += `${indent.pop().get()}}\n`;
+            arm += `${indent.pop().get()}});\n`;
+            return arm;
+          },
+        };
+    }
+  }))}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  if (convertsRequests) {
+    use.add('azure_core::http', 'Body', 'Request');
+    body += '/// Converts a JSON request body from the latest wire format to the wire format of an earlier API version.\n';
+    body += 'pub(crate) fn to_previous_wire_format(request: &mut Request, changes: &[WireChange]) -> Result<()> {\n';
+    body += `${indent.get()}let Body::Bytes(bytes) = request.body() else {\n`;
+    body += `${indent.push().get()}return Ok(());\n`;
+    body += `${indent.pop().get()}};\n`;
+    body += `${indent.get()}if changes.is_empty() || bytes.is_empty() {\n`;
+    body += `${indent.push().get()}return Ok(());\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.get()}let mut value: Value = from_json(bytes)?;\n`;
+    body += `${indent.get()}// nested values are changed first as their paths contain the latest wire names\n`;
+    body += `${indent.get()}let mut changes: Vec<&WireChange> = changes.iter().collect();\n`;
+    body += `${indent.get()}changes.sort_by_key(|change| std::cmp::Reverse(change.path().len()));\n`;
+    body += `${indent.get()}for change in changes {\n`;
+    body += `${indent.push().get()}change.apply(&mut value, true);\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.get()}request.set_body(to_json(&value)?);\n`;
+    body += `${indent.get()}Ok(())\n`;
+    body += '}\n\n';
+  }
+
+  if (convertsResponses) {
+    use.add('azure_core::http', 'RawResponse');
+    body += '/// Converts a JSON response body from the wire format of an earlier API version to the latest wire format.\n';
+    body += 'pub(crate) fn from_previous_wire_format(rsp: RawResponse, changes: &[WireChange]) -> Result<RawResponse> {\n';
+    body += `${indent.get()}let (status, headers, body) = rsp.deconstruct();\n`;
+    body += `${indent.get()}if changes.is_empty() || body.is_empty() {\n`;
+    body += `${indent.push().get()}return Ok(RawResponse::from_bytes(status, headers, body));\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.get()}let mut value: Value = from_json(&body)?;\n`;
+    body += `${indent.get()}// enclosing values are changed first so nested paths contain the latest wire names\n`;
+    body += `${indent.get()}let mut changes: Vec<&WireChange> = changes.iter().collect();\n`;
+    body += `${indent.get()}changes.sort_by_key(|change| change.path().len());\n`;
+    body += `${indent.get()}for change in changes {\n`;
+    body += `${indent.push().get()}change.apply(&mut value, false);\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.get()}Ok(RawResponse::from_bytes(status, headers, to_json(&value)?))\n`;
+    body += '}\n\n';
+  }
+
+  body += '/// Calls f for each value at the path within value.\n';
+  body += 'fn visit(value: &mut Value, path: &[&str], f: &mut dyn FnMut(&mut Value)) {\n';
+  body += `${indent.get()}let Some((first, rest)) = path.split_first() else {\n`;
+  body += `${indent.push().get()}f(value);\n`;
+  body += `${indent.get()}return;\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}match value {\n`;
+  body += `${indent.push().get()}Value::Array(items) if *first == "*" => {\n`;
+  body += `${indent.push().get()}for item in items {\n`;
+  body += `${indent.push().get()}visit(item, rest, f);\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Value::Object(object) if *first == "*" => {\n`;
+  body += `${indent.push().get()}for item in object.values_mut() {\n`;
+  body += `${indent.push().get()}visit(item, rest, f);\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Value::Object(object) => {\n`;
+  body += `${indent.push().get()}if let Some(item) = object.get_mut(*first) {\n`;
+  body += `${indent.push().get()}visit(item, rest, f);\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}_ => {}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n';
+
+  if (changeKinds.has('type')) {
+    body += '\n/// Converts a JSON scalar to the specified kind. Values that can\'t be converted are left unchanged.\n';
+    body += 'fn convert(value: &mut Value, kind: JsonKind) {\n';
+    body += `${indent.get()}let converted = match (kind, &*value) {\n`;
+    indent.push();
+    if (jsonKinds.has('boolean')) {
+      body += `${indent.get()}(JsonKind::Boolean, Value::String(s)) => s.parse().ok().map(Value::Bool),\n`;
+    }
+    if (jsonKinds.has('number')) {
+      body += `${indent.get()}(JsonKind::Number, Value::String(s)) => from_json::<_, Value>(s).ok().filter(Value::is_number),\n`;
+    }
+    if (jsonKinds.has('string')) {
+      body += `${indent.get()}(JsonKind::String, Value::Bool(_) | Value::Number(_)) => Some(Value::String(value.to_string())),\n`;
+    }
+    body += `${indent.get()}_ => None,\n`;
+    body += `${indent.pop().get()}};\n`;
+    body += `${indent.get()}if let Some(converted) = converted {\n`;
+    body += `${indent.push().get()}*value = converted;\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += '}\n';
+  }
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'versioning',
+    content: content,
+    visibility: 'pubCrate',
+  };
+}
@@ -49,6 +49,20 @@ export interface Client {
 
   /** the parent client in a hierarchical client */
   parent?: Client;
+
+  /**
+   * contains the field for the API version selected via the endpoint.
+   * this is only set when methods need the API version after the client is
+   * created (e.g. to send the previous names of renamed params). the field
+   * is also in the fields array.
+   */
+  endpointApiVersion?: types.StructField;
+
+  /**
+   * the API versions known to the client in ascending order.
+   * empty when the client isn't versioned.
+   */
+  apiVersions: Array<string>;
 }
 
 /** ClientConstruction contains data for instantiable clients. */
@@ -119,6 +133,12 @@ export interface ClientSupplementalEndpointParameter extends ClientParameterBase
 
   /** the segment name to be replaced with the param's value */
   segment: string;
+
+  /**
+   * indicates this is an API version parameter.
+   * the default value is false.
+   */
+  isApiVersion: boolean;
 }
 
 /** ClientCredentialParameter is the client's credential parameter */
@@ -156,7 +176,19 @@ export interface AsyncMethod extends HTTPMethodBase {
   params: Array<MethodParameter>;
 
   /** the type returned by the method */
-  returns: types.Result<types.AsyncResponse | types.Response>;
+  returns: types.Result<types.AsyncResponse | types.ItemStream | types.Response>;
+
+  /**
+   * the changes made to the JSON request body when an earlier API version is selected.
+   * empty when the request body has the same wire format in all API versions.
+   */
+  requestWireChanges: Array<WireChange>;
+
+  /**
+   * the changes made to the JSON response body when an earlier API version is selected.
+   * empty when the response body has the same wire format in all API versions.
+   */
+  responseWireChanges: Array<WireChange>;
 }
 
 /** ClientAccessor is a method that returns a sub-client instance. */
@@ -206,8 +238,37 @@ export interface LroFinalResultStrategyHeader {
   propertyName?: string;
 }
 
+/** LRO final result gets returned in the response from the status monitor once the operation has completed */
+export interface LroFinalResultStrategyStatusMonitor {
+  kind: 'statusMonitor';
+
+  /** name of the field in the status monitor response object to read the final result from.
+   * If undefined, the entire object is the final result.
+   */
+  propertyName?: string;
+}
+
 /** A type that describes how the final result from an LRO is available. */
-export type LroFinalResultStrategyKind = LroFinalResultStrategyOriginalUri | LroFinalResultStrategyHeader;
+export type LroFinalResultStrategyKind = LroFinalResultStrategyOriginalUri | LroFinalResultStrategyHeader | LroFinalResultStrategyStatusMonitor;
+
+/** the URL of the LRO status monitor is returned in a response header */
+export interface LroStatusMonitorHeader {
+  kind: 'header';
+
+  /** name of the header containing the URL of the status monitor */
+  headerName: string;
+}
+
+/** the URL of the LRO status monitor is returned in a field in the response body */
+export interface LroStatusMonitorBody {
+  kind: 'body';
+
+  /** the serialized name of the field containing the URL of the status monitor */
+  fieldName: string;
+}
+
+/** A type that describes where the URL of the LRO status monitor is available. */
+export type LroStatusMonitorKind = LroStatusMonitorHeader | LroStatusMonitorBody;
 
 /** LroMethod is a method that returns a long-running operation. */
 export interface LroMethod extends HTTPMethodBase {
@@ -221,6 +282,52 @@ export interface LroMethod extends HTTPMethodBase {
 
   /** A description of how the final result from the LRO is available. */
   finalResultStrategy: LroFinalResultStrategyKind;
+
+  /**
+   * A description of where the URL of the status monitor is available.
+   * If undefined, the status monitor is read from one of the well-known headers.
+   */
+  statusMonitor?: LroStatusMonitorKind;
+
+  /**
+   * the strategy used to fetch subsequent pages when the final result is paged.
+   * the final result of the LRO contains the first page.
+   */
+  pageStrategy?: PageableStrategyNextLink;
+}
+
+/** MethodErrors is an enum used to access the strongly typed error responses of a method */
+export interface MethodErrors {
+  kind: 'methodErrors';
+
+  /** name of the enum */
+  name: string;
+
+  /** the variants in the enum, one per status code */
+  variants: Array<MethodErrorVariant>;
+
+  /** doc string for the enum */
+  docs: string;
+
+  /** indicates the visibility of the enum */
+  visibility: types.Visibility;
+
+  /** the module to which this enum belongs */
+  module: ModuleContainer;
+}
+
+/** MethodErrorVariant is a typed error response for a specific HTTP status code */
+export interface MethodErrorVariant {
+  kind: 'methodErrorVariant';
+
+  /** the name of the variant */
+  name: string;
+
+  /** the HTTP status code of the error response */
+  statusCode: number;
+
+  /** the error model in the response body */
+  type: types.Model;
 }
 
 /** PageableStrategyContinuationToken indicates a pageable method uses the continuation token strategy */
@@ -252,8 +359,38 @@ export interface PageableStrategyNextLink {
   reinjectedParams: Array<QueryCollectionParameter | QueryHashMapParameter | QueryScalarParameter>;
 }
 
+/**
+ * PageableStrategyNextLinkOperation indicates a pageable method uses the nextLink
+ * strategy where subsequent pages are fetched with a dedicated operation.
+ */
+export interface PageableStrategyNextLinkOperation {
+  kind: 'nextLinkOperation';
+
+  /**
+   * the field path in the response that contains the next link URL.
+   * one entry at minimum. when the next link is nested in the response
+   * type, the array will contain the "path" to the next link.
+   */
+  nextLinkPath: Array<types.ModelField>;
+
+  /** the HTTP method used to fetch subsequent pages */
+  httpMethod: HTTPMethod;
+
+  /**
+   * the params sent when fetching subsequent pages. can be empty.
+   * these are either params of the pageable method or constants.
+   */
+  params: Array<NextLinkOperationParameter>;
+
+  /** the query params to be reinjected when fetching pages. can be empty */
+  reinjectedParams: Array<QueryCollectionParameter | QueryHashMapParameter | QueryScalarParameter>;
+}
+
+/** NextLinkOperationParameter defines the possible params sent by a next page operation */
+export type NextLinkOperationParameter = BodyParameter | CookieScalarParameter | HeaderCollectionParameter | HeaderHashMapParameter | HeaderScalarParameter;
+
 /** PageableStrategyKind contains different strategies for fetching subsequent pages */
-export type PageableStrategyKind = PageableStrategyContinuationToken | PageableStrategyNextLink;
+export type PageableStrategyKind = PageableStrategyContinuationToken | PageableStrategyNextLink | PageableStrategyNextLinkOperation;
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // parameters
@@ -297,7 +434,7 @@ export type ExtendedCollectionFormat = CollectionFormat | 'multi';
 export type ParameterLocation = 'client' | 'method';
 
 /** MethodParameter defines the possible method parameter types */
-export type MethodParameter = BodyParameter | HeaderCollectionParameter | HeaderHashMapParameter | HeaderScalarParameter | PartialBodyParameter | PathCollectionParameter | PathHashMapParameter | PathScalarParameter | QueryCollectionParameter | QueryHashMapParameter | QueryScalarParameter;
+export type MethodParameter = BodyParameter | CookieScalarParameter | HeaderCollectionParameter | HeaderHashMapParameter | HeaderScalarParameter | PartialBodyParameter | PathCollectionParameter | PathHashMapParameter | PathScalarParameter | QueryCollectionParameter | QueryHashMapParameter | QueryScalarParameter;
 
 /** BodyParameter is a param that's passed via the HTTP request body */
 export interface BodyParameter extends HTTPParameterBase {
@@ -307,6 +444,23 @@ export interface BodyParameter extends HTTPParameterBase {
   type: types.RequestContent;
 }
 
+/** CookieScalarParameterType defines the possible types for a CookieScalarParameter */
+export type CookieScalarParameterType = Exclude<types.WireType, types.HashMap | types.JsonValue | types.Model | types.Slice | types.StringSlice | types.Vector>;
+
+/**
+ * CookieScalarParameter is a scalar param that goes in the HTTP Cookie header.
+ * all cookie params for a method are merged into a single Cookie header.
+ */
+export interface CookieScalarParameter extends HTTPParameterBase {
+  kind: 'cookieScalar';
+
+  /** the name of the cookie */
+  cookie: string;
+
+  /** the type of the param */
+  type: CookieScalarParameterType;
+}
+
 /** HeaderCollectionParameterType defines the possible types for a HeaderCollectionParameter */
 export type HeaderCollectionParameterType = types.Ref<types.Slice> | types.Vector;
 
@@ -356,6 +510,92 @@ export interface HeaderScalarParameter extends HTTPParameterBase {
    * the default value is false.
    */
   isApiVersion: boolean;
+
+  /**
+   * set when this is a conditional request header (e.g. If-Match).
+   * the param is a field within the conditions type in the method's options.
+   */
+  conditions?: types.RequestConditions;
+
+  /**
+   * set when this is a repeatability header (e.g. Repeatability-Request-ID).
+   * the param is optional and a value is generated when one isn't provided.
+   */
+  repeatability?: RepeatabilityHeader;
+
+  /**
+   * the header names used in earlier API versions.
+   * empty when the header has the same name in all API versions.
+   */
+  previousNames: Array<PreviousName>;
+}
+
+/**
+ * the kinds of repeatability headers
+ * firstSent - the Repeatability-First-Sent header
+ * requestId - the Repeatability-Request-ID header
+ */
+export type RepeatabilityHeader = 'firstSent' | 'requestId';
+
+/** PreviousName is a wire name used by a parameter in earlier API versions */
+export interface PreviousName {
+  /** the name sent on the wire */
+  name: string;
+
+  /** the API versions in which the name is sent */
+  apiVersions: Array<string>;
+}
+
+/** the kinds of JSON values that a scalar type is sent as */
+export type JsonScalarKind = 'boolean' | 'number' | 'string';
+
+/** WireChange is a change to the wire format of a JSON body made in a later API version */
+export type WireChange = WireNameChange | WireTypeChange | WireValueChange;
+
+/** base type for wire changes */
+interface WireChangeBase {
+  /**
+   * the path to the changed JSON value from the root of the body.
+   * contains the wire names of the enclosing properties in the latest
+   * API version, with * for the elements of arrays and dictionaries.
+   */
+  path: Array<string>;
+
+  /** the API versions in which the previous wire format is used */
+  apiVersions: Array<string>;
+}
+
+/** WireNameChange is a property renamed via the @renamedFrom decorator */
+export interface WireNameChange extends WireChangeBase {
+  kind: 'name';
+
+  /** the property's name in the latest API version */
+  name: string;
+
+  /** the property's name in the earlier API versions */
+  previousName: string;
+}
+
+/** WireTypeChange is a property or return type changed via the @typeChangedFrom or @returnTypeChangedFrom decorators */
+export interface WireTypeChange extends WireChangeBase {
+  kind: 'type';
+
+  /** the kind of JSON value sent in the latest API version */
+  type: JsonScalarKind;
+
+  /** the kind of JSON value sent in the earlier API versions */
+  previousType: JsonScalarKind;
+}
+
+/** WireValueChange is an enum value renamed via the @renamedFrom decorator */
+export interface WireValueChange extends WireChangeBase {
+  kind: 'value';
+
+  /** the enum value in the latest API version */
+  value: string;
+
+  /** the enum value in the earlier API versions */
+  previousValue: string;
 }
 
 /** ParameterGroup is a group of parameters */
@@ -511,6 +751,12 @@ export interface QueryScalarParameter extends HTTPParameterBase {
    * the default value is false.
    */
   isApiVersion: boolean;
+
+  /**
+   * the key names used in earlier API versions.
+   * empty when the key has the same name in all API versions.
+   */
+  previousNames: Array<PreviousName>;
 }
 
 /** ResponseHeader defines the possible typed headers returned in a HTTP response */
@@ -617,11 +863,21 @@ interface HTTPMethodBase extends method.Method<types.Type> {
   /** contains the trait for accessing response headers */
   responseHeaders?: ResponseHeadersTrait;
 
+  /** contains the enum for accessing typed error responses */
+  errors?: MethodErrors;
+
   /** the HTTP verb used for the request */
   httpMethod: HTTPMethod;
 
   /** the HTTP path for the request */
   httpPath: string;
+
+  /**
+   * the API versions in which the method is available.
+   * undefined when it's available in all of the client's API versions and
+   * empty when it isn't available in any of them.
+   */
+  apiVersions?: Array<string>;
 }
 
 /** base type for HTTP-based method parameters */
@@ -634,6 +890,13 @@ interface HTTPParameterBase extends method.Parameter {
 
   /** set when this parameter belongs to a parameter group */
   group?: ParameterGroup<types.Struct>;
+
+  /**
+   * the API versions in which the parameter is available.
+   * undefined when it's available in all of the method's API versions and
+   * empty when it isn't available in any of them.
+   */
+  apiVersions?: Array<string>;
 }
 
 class ClientParameterBase implements ClientParameterBase {
@@ -672,6 +935,8 @@ export class AsyncMethod extends HTTPMethodBase implements AsyncMethod {
     this.kind = 'async';
     this.params = new Array<MethodParameter>();
     this.options = options;
+    this.requestWireChanges = new Array<WireChange>();
+    this.responseWireChanges = new Array<WireChange>();
   }
 }
 
@@ -690,6 +955,7 @@ export class Client implements Client {
     this.methods = new Array<MethodType>();
     this.module = module;
     this.docs = {};
+    this.apiVersions = new Array<string>();
   }
 }
 
@@ -751,6 +1017,15 @@ export class ClientSupplementalEndpointParameter extends ClientParameterBase imp
     super(name, type, optional);
     this.kind = 'clientSupplementalEndpoint';
     this.segment = segment;
+    this.isApiVersion = false;
+  }
+}
+
+export class CookieScalarParameter extends HTTPParameterBase implements CookieScalarParameter {
+  constructor(name: string, cookie: string, location: ParameterLocation, optional: boolean, type: CookieScalarParameterType) {
+    super(name, location, optional, type);
+    this.kind = 'cookieScalar';
+    this.cookie = cookie;
   }
 }
 
@@ -777,6 +1052,7 @@ export class HeaderScalarParameter extends HTTPParameterBase implements HeaderSc
     this.kind = 'headerScalar';
     this.header = header;
     this.isApiVersion = false;
+    this.previousNames = new Array<PreviousName>();
   }
 }
 
@@ -802,6 +1078,12 @@ export class LroFinalResultStrategyHeader implements LroFinalResultStrategyHeade
   }
 }
 
+export class LroFinalResultStrategyStatusMonitor implements LroFinalResultStrategyStatusMonitor {
+  constructor() {
+    this.kind = 'statusMonitor';
+  }
+}
+
 export class LroMethod extends HTTPMethodBase implements LroMethod {
   constructor(name: string, languageIndependentName: string, client: Client, visibility: types.Visibility, options: ParameterGroup<types.Option<types.Struct>>, httpMethod: HTTPMethod, httpPath: string, finalResultStrategy: LroFinalResultStrategyKind) {
     super(name, languageIndependentName, httpMethod, httpPath, visibility, client.name, new method.Self(false, true));
@@ -812,6 +1094,40 @@ export class LroMethod extends HTTPMethodBase implements LroMethod {
   }
 }
 
+export class LroStatusMonitorBody implements LroStatusMonitorBody {
+  constructor(fieldName: string) {
+    this.kind = 'body';
+    this.fieldName = fieldName;
+  }
+}
+
+export class LroStatusMonitorHeader implements LroStatusMonitorHeader {
+  constructor(headerName: string) {
+    this.kind = 'header';
+    this.headerName = headerName;
+  }
+}
+
+export class MethodErrors implements MethodErrors {
+  constructor(name: string, docs: string, visibility: types.Visibility, module: ModuleContainer) {
+    this.kind = 'methodErrors';
+    this.name = name;
+    this.variants = new Array<MethodErrorVariant>();
+    this.docs = docs;
+    this.visibility = visibility;
+    this.module = module;
+  }
+}
+
+export class MethodErrorVariant implements MethodErrorVariant {
+  constructor(name: string, statusCode: number, type: types.Model) {
+    this.kind = 'methodErrorVariant';
+    this.name = name;
+    this.statusCode = statusCode;
+    this.type = type;
+  }
+}
+
 export class PageableStrategyContinuationToken implements PageableStrategyContinuationToken {
   constructor(requestToken: HeaderScalarParameter | QueryScalarParameter, responseToken: ResponseHeaderScalar | PageableStrategyNextLink) {
     this.kind = 'continuationToken';
@@ -828,6 +1144,16 @@ export class PageableStrategyNextLink implements PageableStrategyNextLink {
   }
 }
 
+export class PageableStrategyNextLinkOperation implements PageableStrategyNextLinkOperation {
+  constructor(nextLinkPath: Array<types.ModelField>, httpMethod: HTTPMethod) {
+    this.kind = 'nextLinkOperation';
+    this.nextLinkPath = nextLinkPath;
+    this.httpMethod = httpMethod;
+    this.params = new Array<NextLinkOperationParameter>();
+    this.reinjectedParams = new Array<QueryCollectionParameter | QueryHashMapParameter | QueryScalarParameter>();
+  }
+}
+
 export class ParameterGroup<T extends types.Option<types.Struct> | types.Struct> implements ParameterGroup<T> {
   constructor(name: string, type: T) {
     this.name = name;
@@ -904,6 +1230,7 @@ export class QueryScalarParameter extends HTTPParameterBase implements QueryScal
     this.key = key;
     this.encoded = encoded;
     this.isApiVersion = false;
+    this.previousNames = new Array<PreviousName>();
   }
 }
 
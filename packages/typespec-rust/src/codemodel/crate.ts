@@ -25,17 +25,44 @@ export interface Crate extends ModuleBase {
   /** the Crates on which this Crate depends */
   dependencies: Array<CrateDependency>;
 
+  /** the Cargo features defined by this Crate. can be empty */
+  features: Array<CrateFeature>;
+
+  /**
+   * indicates if the Crate is a member of a Cargo workspace.
+   * when true, package fields and dependency versions are inherited from the workspace.
+   */
+  workspace: boolean;
+
+  /** the package fields and azure_core version used when the Crate isn't a member of a Cargo workspace */
+  standalone: StandalonePackage;
+
   /** any sub-modules. can be empty */
   subModules: Array<SubModule>;
 }
 
+/** StandalonePackage contains the Cargo.toml values that are otherwise inherited from a Cargo workspace */
+export interface StandalonePackage {
+  /** the Rust edition */
+  edition: string;
+
+  /** the SPDX license expression. when undefined, no license is specified */
+  license?: string;
+
+  /** the minimum supported Rust version */
+  rustVersion: string;
+
+  /** the version of the azure_core dependency */
+  azureCoreVersion: string;
+}
+
 /** ServiceType defines the possible service types */
 export type ServiceType = 'azure-arm' | 'data-plane';
 
 /**
  * CrateDependency is an external Crate dependency
  * note that dependency versions are centralized which is
- * why there's no version info specified here.
+ * why there's usually no version info specified here.
  */
 export interface CrateDependency {
   /** the name of the Crate */
@@ -43,6 +70,22 @@ export interface CrateDependency {
 
   /** the features to enable for the Crate */
   features: Array<string>;
+
+  /**
+   * the minimum version of the Crate for Crates that aren't
+   * known to the emitter (e.g. the Crate of an external type).
+   * only used when the dependent Crate isn't a member of a workspace.
+   */
+  version?: string;
+}
+
+/** CrateFeature is a Cargo feature defined by the Crate */
+export interface CrateFeature {
+  /** the name of the feature */
+  name: string;
+
+  /** the other features enabled by this feature. can be empty */
+  enables: Array<CrateFeature>;
 }
 
 /** defines the container for emitted content */
@@ -68,6 +111,9 @@ export interface SubModule extends ModuleBase {
 
   /** this module's parent */
   parent: ModuleContainer;
+
+  /** the Cargo feature that gates this module. when undefined, the module is always compiled */
+  feature?: CrateFeature;
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -108,6 +154,13 @@ export class Crate extends ModuleBase implements Crate {
     this.version = version;
     this.type = type;
     this.dependencies = new Array<CrateDependency>();
+    this.features = new Array<CrateFeature>();
+    this.workspace = true;
+    this.standalone = {
+      edition: '2021',
+      rustVersion: '1.88',
+      azureCoreVersion: '0.33.0',
+    };
     this.subModules = new Array<SubModule>();
   }
 
@@ -120,6 +173,7 @@ export class Crate extends ModuleBase implements Crate {
       if (dep.name === dependency.name) {
         // merge in any features
         dep.features = dep.features.concat(dependency.features.filter(item => !dep.features.includes(item)));
+        dep.version ??= dependency.version;
         return;
       }
     }
@@ -128,9 +182,17 @@ export class Crate extends ModuleBase implements Crate {
 }
 
 export class CrateDependency implements CrateDependency {
-  constructor(name: string, features = new Array<string>()) {
+  constructor(name: string, features = new Array<string>(), version?: string) {
     this.name = name;
     this.features = features;
+    this.version = version;
+  }
+}
+
+export class CrateFeature implements CrateFeature {
+  constructor(name: string) {
+    this.name = name;
+    this.enables = new Array<CrateFeature>();
   }
 }
 
@@ -48,6 +48,12 @@ export interface Parameter {
 
   /** indicates minimum length for the parameter */
   minLength: number | undefined;
+
+  /** indicates maximum length for the parameter */
+  maxLength: number | undefined;
+
+  /** the regular expression the parameter's value must match */
+  pattern: string | undefined;
 }
 
 /** Self is a method's self parameter */
@@ -83,6 +89,8 @@ export class Parameter implements Parameter {
     this.mut = false;
     this.docs = {};
     this.minLength = undefined;
+    this.maxLength = undefined;
+    this.pattern = undefined;
   }
 }
 
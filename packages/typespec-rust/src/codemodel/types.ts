@@ -16,10 +16,10 @@ export interface Docs {
 }
 
 /** SdkType defines types used in generated code but do not directly participate in serde */
-export type SdkType =  Arc | AsyncResponse | Box | ClientMethodOptions | ImplTrait | MarkerType | Option | Pager | PagerOptions | Poller | PollerOptions | RawResponse | RequestContent | Response | Result | Struct | TokenCredential | Unit;
+export type SdkType =  Arc | AsyncResponse | BasicCredential | Box | ClientMethodOptions | ImplTrait | ItemStream | KeyCredential | MarkerType | Option | Pager | PagerOptions | Poller | PollerOptions | RawResponse | RequestConditions | RequestContent | Response | Result | Struct | TokenCredential | Unit;
 
 /** WireType defines types that go across the wire */
-export type WireType = Bytes | Decimal | DiscriminatedUnion | EncodedBytes | Enum | EnumValue | Etag | ExternalType | HashMap | JsonValue | Literal | Model | OffsetDateTime | RefBase | SafeInt | Scalar | Slice | StringSlice | StringType | UntaggedUnion | Url | Vector;
+export type WireType = Bytes | Decimal | DiscriminatedUnion | Duration | EncodedBytes | Enum | EnumValue | Etag | ExternalType | HashMap | JsonValue | Literal | Model | MultipartFile | Nullable | OffsetDateTime | PlainDate | PlainTime | RefBase | SafeInt | Scalar | Slice | StringSlice | StringType | UntaggedUnion | Url | Vector;
 
 /** Type defines a type within the Rust type system */
 export type Type = SdkType | WireType;
@@ -46,6 +46,17 @@ export interface AsyncResponse<T extends MarkerType | Unit = MarkerType | Unit>
   type: T;
 }
 
+/** BasicCredential is a generated credential type that contains a user name and password */
+export interface BasicCredential {
+  kind: 'basicCredential';
+
+  /** the name of the credential type */
+  name: string;
+
+  /** the module to which this credential type belongs */
+  module: ModuleContainer;
+}
+
 /** Box is a Rust Box<T> */
 export interface Box {
   kind: 'box';
@@ -75,6 +86,20 @@ export interface Decimal extends External {
   stringEncoding: boolean;
 }
 
+/** Duration is a Rust time::Duration type */
+export interface Duration extends External {
+  kind: 'duration';
+
+  /** the encoding format */
+  encoding: DurationEncoding;
+}
+
+/**
+ * DurationEncoding is the wire format of the duration.
+ * the -float variants are sent as floating point numbers.
+ */
+export type DurationEncoding = 'iso8601' | 'milliseconds' | 'milliseconds-float' | 'seconds' | 'seconds-float';
+
 /** BytesEncoding defines the possible types of base64-encoding. */
 export type BytesEncoding = 'std' | 'url';
 
@@ -111,6 +136,9 @@ export interface Enum {
   /** indicates if the enum is extensible or not */
   extensible: boolean;
 
+  /** indicates if the enum is annotated with #[non_exhaustive] */
+  nonExhaustive: boolean;
+
   /** the underlying type of the enum */
   type: EnumType;
 
@@ -234,11 +262,57 @@ export interface ImplTrait {
   type: Type;
 }
 
+/** ItemStream is a generated stream of typed items parsed from a streaming response */
+export interface ItemStream {
+  kind: 'itemStream';
+
+  /** the name of the stream type */
+  name: 'ItemStream';
+
+  /** the framing of the items within the response body */
+  format: StreamFormat;
+
+  /** the type of each item in the stream */
+  type: WireType;
+
+  /** the module to which this type belongs */
+  module: ModuleContainer;
+}
+
 /** JsonValue is a raw JSON value */
 export interface JsonValue extends External {
   kind: 'jsonValue';
 }
 
+/** KeyCredential is a generated credential type that contains a secret key */
+export interface KeyCredential {
+  kind: 'keyCredential';
+
+  /** the name of the credential type */
+  name: string;
+
+  /** indicates where in the request the key is placed */
+  location: KeyCredentialLocation;
+
+  /** the name of the header or query parameter that contains the key */
+  paramName: string;
+
+  /**
+   * the auth scheme that prefixes the key (e.g. SharedAccessKey).
+   * only applicable when location is authorization.
+   */
+  scheme?: string;
+
+  /** the module to which this credential type belongs */
+  module: ModuleContainer;
+}
+
+/**
+ * KeyCredentialLocation defines where in the request a key credential is placed.
+ * authorization places the key in the Authorization header, prefixed with the auth scheme.
+ */
+export type KeyCredentialLocation = 'authorization' | 'header' | 'query';
+
 /** Lifetime is a Rust lifetime name. */
 export interface Lifetime {
   name: string;
@@ -361,11 +435,45 @@ export enum ModelFlags {
    * this flag is mutually exclusive with all other flags.
    */
   PolymorphicBase = 16,
+
+  /** model is sent as a multipart/form-data request body */
+  Multipart = 32,
+}
+
+/** MultipartFile is a generated type for a file part in a multipart/form-data request body */
+export interface MultipartFile {
+  kind: 'multipartFile';
+
+  /** the name of the file type */
+  name: string;
+
+  /** the content type to send when the caller doesn't specify one */
+  defaultContentType: string;
+
+  /** the module to which this file type belongs */
+  module: ModuleContainer;
 }
 
 /** DateTimeEncoding is the wire format of the date/time */
 export type DateTimeEncoding = 'rfc3339' | 'rfc3339-fixed-width' | 'rfc7231' | 'unix_time';
 
+/**
+ * Nullable is a generated tri-state type that distinguishes
+ * between an absent value, an explicit null, and a value.
+ */
+export interface Nullable {
+  kind: 'nullable';
+
+  /** the name of the tri-state type */
+  name: 'Nullable';
+
+  /** the generic type param */
+  type: WireType;
+
+  /** the module to which the tri-state type belongs */
+  module: ModuleContainer;
+}
+
 /** OffsetDateTime is a Rust time::OffsetDateTime type */
 export interface OffsetDateTime extends External {
   kind: 'offsetDateTime';
@@ -415,6 +523,16 @@ export interface PagerOptions extends External {
   continuation: PagerContinuationKind;
 }
 
+/** PlainDate is a Rust time::Date type */
+export interface PlainDate extends External {
+  kind: 'plainDate';
+}
+
+/** PlainTime is a Rust time::Time type */
+export interface PlainTime extends External {
+  kind: 'plainTime';
+}
+
 /** Poller is a Poller<T> from azure_core */
 export interface Poller extends External {
   kind: 'poller';
@@ -463,6 +581,21 @@ export interface Ref<T extends RefType = RefType> extends RefBase {
   type: T;
 }
 
+/**
+ * RequestConditions is a generated type that groups the conditional request headers.
+ * MatchConditions contains the If-Match and If-None-Match headers. RequestConditions
+ * also contains the If-Modified-Since and If-Unmodified-Since headers.
+ */
+export interface RequestConditions {
+  kind: 'requestConditions';
+
+  /** the name of the conditions type */
+  name: 'MatchConditions' | 'RequestConditions';
+
+  /** the module to which this conditions type belongs */
+  module: ModuleContainer;
+}
+
 /** RequestContent is a Rust RequestContent<T> from azure_core */
 export interface RequestContent<T extends WireType = WireType, Format extends PayloadFormatType = PayloadFormatType> extends External {
   kind: 'requestContent';
@@ -475,10 +608,10 @@ export interface RequestContent<T extends WireType = WireType, Format extends Pa
 }
 
 /** ResponseFormat is the format of the response body */
-export type PayloadFormatType = 'BinaryFormat' | 'JsonFormat' | 'NoFormat' | 'XmlFormat';
+export type PayloadFormatType = 'BinaryFormat' | 'JsonFormat' | 'MultipartFormat' | 'NoFormat' | 'XmlFormat';
 
-/** ModelPayloadFormatType is a PayloadFormatType for modeled payloads (i.e. excludes binary and no-format) */
-export type ModelPayloadFormatType = Exclude<PayloadFormatType, 'BinaryFormat' | 'NoFormat'>;
+/** ModelPayloadFormatType is a PayloadFormatType for modeled payloads (i.e. excludes binary, multipart, and no-format) */
+export type ModelPayloadFormatType = Exclude<PayloadFormatType, 'BinaryFormat' | 'MultipartFormat' | 'NoFormat'>;
 
 /** ResponseTypes defines the type constraint when creating a Response<T> */
 export type ResponseTypes = MarkerType | Unit | WireType;
@@ -495,7 +628,7 @@ export interface Response<T extends ResponseTypes = ResponseTypes, Format extend
 }
 
 /** ResultTypes defines the type constraint when creating a Result<T> */
-export type ResultTypes = AsyncResponse | Pager | Poller | Response;
+export type ResultTypes = AsyncResponse | ItemStream | Pager | Poller | Response;
 
 /** Result is a Rust Result<T> from azure_core */
 export interface Result<T extends ResultTypes = ResultTypes> extends External {
@@ -535,6 +668,13 @@ export interface Slice {
   type: WireType;
 }
 
+/**
+ * StreamFormat defines how items are framed within a streaming response body
+ *   jsonl - one JSON value per line (application/jsonl)
+ *     sse - server-sent events where each data field is a JSON value (text/event-stream)
+ */
+export type StreamFormat = 'jsonl' | 'sse';
+
 /** StringSlice is a Rust string slice */
 export interface StringSlice {
   kind: 'str';
@@ -663,7 +803,7 @@ export type Visibility = 'pub' | 'pubCrate';
 interface External extends QualifiedType {}
 
 class External extends QualifiedType implements External {
-  constructor(crate: Crate, name: string, path: string, features = new Array<string>) {
+  constructor(crate: Crate, name: string, path: string, features = new Array<string>, version?: string) {
     super(name, path);
     let crateName = this.path;
     const pathSep = crateName.indexOf('::');
@@ -671,7 +811,7 @@ class External extends QualifiedType implements External {
       crateName = crateName.substring(0, pathSep);
     }
     if (crateName !== 'crate') {
-      crate.addDependency(new CrateDependency(crateName, features));
+      crate.addDependency(new CrateDependency(crateName, features, version));
     }
   }
 }
@@ -754,6 +894,14 @@ export class AsyncResponse<T> extends External implements AsyncResponse<T> {
   }
 }
 
+export class BasicCredential implements BasicCredential {
+  constructor(module: ModuleContainer) {
+    this.kind = 'basicCredential';
+    this.name = 'BasicCredential';
+    this.module = module;
+  }
+}
+
 export class Box implements Box {
   constructor(type: WireType) {
     this.kind = 'box';
@@ -784,6 +932,14 @@ export class Decimal extends External implements Decimal {
   }
 }
 
+export class Duration extends External implements Duration {
+  constructor(crate: Crate, encoding: DurationEncoding) {
+    super(crate, 'Duration', 'azure_core::time');
+    this.kind = 'duration';
+    this.encoding = encoding;
+  }
+}
+
 export class EncodedBytes implements EncodedBytes {
   constructor(encoding: BytesEncoding, slice: boolean) {
     this.kind = 'encodedBytes';
@@ -799,6 +955,7 @@ export class Enum implements Enum {
     this.visibility = visibility;
     this.values = new Array<EnumValue>();
     this.extensible = extensible;
+    this.nonExhaustive = false;
     this.type = type;
     this.module = module;
     this.docs = {};
@@ -823,8 +980,8 @@ export class Etag extends External implements Etag {
 }
 
 export class ExternalType extends External implements ExternalType {
-  constructor(crate: Crate, name: string, path: string) {
-    super(crate, name, path);
+  constructor(crate: Crate, name: string, path: string, version?: string) {
+    super(crate, name, path, undefined, version);
     this.kind = 'external';
   }
 }
@@ -845,6 +1002,16 @@ export class ImplTrait implements ImplTrait {
   }
 }
 
+export class ItemStream implements ItemStream {
+  constructor(format: StreamFormat, type: WireType, module: ModuleContainer) {
+    this.kind = 'itemStream';
+    this.name = 'ItemStream';
+    this.format = format;
+    this.type = type;
+    this.module = module;
+  }
+}
+
 export class JsonValue extends External implements JsonValue {
   constructor(crate: Crate) {
     super(crate, 'Value', 'azure_core');
@@ -852,6 +1019,16 @@ export class JsonValue extends External implements JsonValue {
   }
 }
 
+export class KeyCredential implements KeyCredential {
+  constructor(location: KeyCredentialLocation, paramName: string, module: ModuleContainer) {
+    this.kind = 'keyCredential';
+    this.name = 'KeyCredential';
+    this.location = location;
+    this.paramName = paramName;
+    this.module = module;
+  }
+}
+
 export class Lifetime implements Lifetime {
   constructor(name: string) {
     this.name = `'${name}`;
@@ -963,6 +1140,24 @@ export class ModelField extends StructFieldBase implements ModelField {
   }
 }
 
+export class MultipartFile implements MultipartFile {
+  constructor(defaultContentType: string, module: ModuleContainer) {
+    this.kind = 'multipartFile';
+    this.name = 'MultipartFile';
+    this.defaultContentType = defaultContentType;
+    this.module = module;
+  }
+}
+
+export class Nullable implements Nullable {
+  constructor(type: WireType, module: ModuleContainer) {
+    this.kind = 'nullable';
+    this.name = 'Nullable';
+    this.type = type;
+    this.module = module;
+  }
+}
+
 export class OffsetDateTime extends External implements OffsetDateTime {
   constructor(crate: Crate, encoding: DateTimeEncoding, utc: boolean) {
     super(crate, 'OffsetDateTime', 'azure_core::time');
@@ -997,6 +1192,20 @@ export class PagerOptions extends External implements PagerOptions {
   }
 }
 
+export class PlainDate extends External implements PlainDate {
+  constructor(crate: Crate) {
+    super(crate, 'Date', 'time', ['parsing']);
+    this.kind = 'plainDate';
+  }
+}
+
+export class PlainTime extends External implements PlainTime {
+  constructor(crate: Crate) {
+    super(crate, 'Time', 'time', ['parsing']);
+    this.kind = 'plainTime';
+  }
+}
+
 export class Poller extends External implements Poller {
   constructor(crate: Crate, statusType: Response<Model, ModelPayloadFormatType>) {
     super(crate, 'Poller', 'azure_core::http');
@@ -1027,6 +1236,14 @@ export class Ref<T> implements Ref<T> {
   }
 }
 
+export class RequestConditions implements RequestConditions {
+  constructor(name: 'MatchConditions' | 'RequestConditions', module: ModuleContainer) {
+    this.kind = 'requestConditions';
+    this.name = name;
+    this.module = module;
+  }
+}
+
 export class RequestContent<T, Format> extends External implements RequestContent<T, Format> {
   constructor(crate: Crate, content: T, format: Format) {
     super(crate, 'RequestContent', 'azure_core::http');
@@ -12,7 +12,7 @@ import { Adapter, AdapterError, ExternalError } from './tcgcadapter/adapter.js';
 import { reportDiagnostic, RustEmitterOptions } from './lib.js';
 import { execSync } from 'child_process';
 import * as fs from 'fs';
-import { mkdir, readdir, writeFile } from 'fs/promises';
+import { mkdir, readdir, readFile, writeFile } from 'fs/promises';
 import * as path from 'path';
 import { EmitContext, NoTarget } from '@typespec/compiler';
 import 'source-map-support/register.js';
@@ -65,19 +65,31 @@ export async function $onEmit(context: EmitContext<RustEmitterOptions>) {
     // we should be calling emitCargoToml() after all the calls to emitContent() above, and not before.
     // https://github.com/Azure/typespec-rust/issues/746
 
-    // don't overwrite an existing Cargo.toml file by default
-    // TODO: consider merging existing dependencies with emitted dependencies when overwriting
-    // https://github.com/Azure/typespec-rust/issues/22
+    // don't overwrite an existing Cargo.toml file by default.
+    // instead, merge any missing dependencies and features into it.
     const cargoTomlPath = path.join(context.emitterOutputDir, 'Cargo.toml');
-    if (fs.existsSync(cargoTomlPath) && context.options['overwrite-cargo-toml'] !== true) {
+    const onUnknownVersion = (name: string): void => {
       context.program.reportDiagnostic({
-        code: 'FileAlreadyExists',
+        code: 'UnknownDependencyVersion',
         severity: 'warning',
-        message: `skip overwriting file ${cargoTomlPath}`,
+        message: `no version is known for dependency ${name}. its version in ${cargoTomlPath} is * and should be updated`,
         target: NoTarget,
       });
+    };
+    if (fs.existsSync(cargoTomlPath) && context.options['overwrite-cargo-toml'] !== true) {
+      const existing = await readFile(cargoTomlPath, { encoding: 'utf-8' });
+      const merged = codegen.mergeCargoToml(existing, onUnknownVersion);
+      if (merged !== existing) {
+        context.program.reportDiagnostic({
+          code: 'CargoTomlMerged',
+          severity: 'warning',
+          message: `merged missing dependencies, dependency features, and features into existing file ${cargoTomlPath}`,
+          target: NoTarget,
+        });
+        await writeFile(cargoTomlPath, merged);
+      }
     } else {
-      await writeFile(cargoTomlPath, codegen.emitCargoToml());
+      await writeFile(cargoTomlPath, codegen.emitCargoToml(onUnknownVersion));
     }
   } catch (error) {
     failed = true;
@@ -21,6 +21,18 @@ export interface RustEmitterOptions {
   'temp-omit-doc-links': boolean;
   /** Whether to emit TryFrom for error types */
   'emit-error-traits': boolean;
+  /** Whether to gate each sub-module behind its own Cargo feature. Defaults to false */
+  'sub-module-features': boolean;
+  /** Whether to emit a Cargo.toml file that doesn't depend on a Cargo workspace. Defaults to false */
+  'standalone-cargo-toml': boolean;
+  /** The Rust edition in a standalone Cargo.toml file. Defaults to 2021 */
+  'standalone-edition': string;
+  /** The SPDX license expression in a standalone Cargo.toml file. When omitted, no license is specified */
+  'standalone-license'?: string;
+  /** The minimum supported Rust version in a standalone Cargo.toml file. Defaults to 1.88 */
+  'standalone-rust-version': string;
+  /** The version of the azure_core dependency in a standalone Cargo.toml file. Defaults to 0.33.0 */
+  'standalone-azure-core-version': string;
 }
 
 const EmitterOptionsSchema: JSONSchemaType<RustEmitterOptions> = {
@@ -67,6 +79,41 @@ const EmitterOptionsSchema: JSONSchemaType<RustEmitterOptions> = {
       default: false,
       description: 'Whether to emit traits for error types. Defaults to false'
     },
+    'sub-module-features': {
+      type: 'boolean',
+      nullable: false,
+      default: false,
+      description: 'Whether to gate each sub-module behind its own Cargo feature. Defaults to false'
+    },
+    'standalone-cargo-toml': {
+      type: 'boolean',
+      nullable: false,
+      default: false,
+      description: 'Whether to emit a Cargo.toml file that doesn\'t depend on a Cargo workspace. Defaults to false'
+    },
+    'standalone-edition': {
+      type: 'string',
+      nullable: false,
+      default: '2021',
+      description: 'The Rust edition in a standalone Cargo.toml file. Defaults to 2021'
+    },
+    'standalone-license': {
+      type: 'string',
+      nullable: true,
+      description: 'The SPDX license expression in a standalone Cargo.toml file. When omitted, no license is specified'
+    },
+    'standalone-rust-version': {
+      type: 'string',
+      nullable: false,
+      default: '1.88',
+      description: 'The minimum supported Rust version in a standalone Cargo.toml file. Defaults to 1.88'
+    },
+    'standalone-azure-core-version': {
+      type: 'string',
+      nullable: false,
+      default: '0.33.0',
+      description: 'The version of the azure_core dependency in a standalone Cargo.toml file. Defaults to 0.33.0'
+    },
   },
   required: [
     'crate-name',
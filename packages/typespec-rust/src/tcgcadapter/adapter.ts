@@ -7,6 +7,8 @@
 
 import * as tsp from '@typespec/compiler';
 import * as http from '@typespec/http';
+import * as streams from '@typespec/streams';
+import * as versioning from '@typespec/versioning';
 import * as helpers from './helpers.js';
 import * as naming from './naming.js';
 import {RustEmitterOptions} from '../lib.js';
@@ -99,12 +101,20 @@ export class Adapter {
   // maps a tcgc model field to the adapted struct field
   private readonly fieldsMap: Map<tcgc.SdkModelPropertyType | tcgc.SdkPathParameter, rust.ModelField>;
 
+  // the tcgc model properties that contain the next link for paged methods
+  private readonly nextLinkProperties: Set<tcgc.SdkModelPropertyType>;
+
+  // the tsp types whose unconverted wire changes have been reported
+  private readonly reportedWireChanges: Set<tsp.Type>;
+
   private constructor(ctx: tcgc.SdkContext, options: RustEmitterOptions) {
     this.types = new Map<string, rust.Type>();
     this.clientMethodParams = new Map<string, rust.MethodParameter>();
     this.fieldsMap = new Map<tcgc.SdkModelPropertyType | tcgc.SdkPathParameter, rust.ModelField>();
     this.ctx = ctx;
     this.options = options;
+    this.nextLinkProperties = getNextLinkProperties(ctx.sdkPackage.clients);
+    this.reportedWireChanges = new Set<tsp.Type>();
 
     // this and adjacent code was taken from
     // https://github.com/microsoft/typespec/blob/c0f464728f60fe3672204dd7f2907ea4c047dfcb/packages/http-client-python/emitter/src/utils.ts#L274
@@ -132,16 +142,92 @@ export class Adapter {
     }
 
     this.crate = new rust.Crate(this.options['crate-name'], this.options['crate-version'], serviceType);
+    this.crate.workspace = this.options['standalone-cargo-toml'] !== true;
+    if (!this.crate.workspace) {
+      this.crate.standalone = {
+        edition: this.options['standalone-edition'] ?? this.crate.standalone.edition,
+        license: this.options['standalone-license'],
+        rustVersion: this.options['standalone-rust-version'] ?? this.crate.standalone.rustVersion,
+        azureCoreVersion: this.options['standalone-azure-core-version'] ?? this.crate.standalone.azureCoreVersion,
+      };
+    }
   }
 
   /** performs all the steps to convert tcgc to a crate */
   tcgcToCrate(): rust.Crate {
     this.adaptTypes();
     this.adaptClients();
+    if (this.options['sub-module-features']) {
+      this.adaptSubModuleFeatures();
+    }
 
     return this.crate;
   }
 
+  /** gates each sub-module behind its own Cargo feature */
+  private adaptSubModuleFeatures(): void {
+    const modules = new Array<rust.ModuleContainer>();
+    const referencedModules = new Map<rust.ModuleContainer, Set<rust.ModuleContainer>>();
+    const collectModules = function (module: rust.ModuleContainer): void {
+      modules.push(module);
+      referencedModules.set(module, getReferencedModules(module));
+      for (const subModule of module.subModules) {
+        collectModules(subModule);
+      }
+    };
+    collectModules(this.crate);
+
+    // the crate root is always compiled so any modules it references
+    // (directly or transitively) can't be gated. the same applies to
+    // their parents as they contain the module declarations.
+    const ungated = new Set<rust.ModuleContainer>();
+    const markUngated = function (module: rust.ModuleContainer): void {
+      if (ungated.has(module)) {
+        return;
+      }
+      ungated.add(module);
+      if (module.kind === 'module') {
+        markUngated(module.parent);
+      }
+      for (const referencedModule of referencedModules.get(module)!) {
+        markUngated(referencedModule);
+      }
+    };
+    markUngated(this.crate);
+
+    // create all the features first so they can enable one another
+    for (const module of modules) {
+      if (module.kind === 'module' && !ungated.has(module)) {
+        // nested modules include their parents in the feature name (e.g. sub1-nested)
+        const chunks = new Array<string>();
+        let cur: rust.ModuleContainer = module;
+        while (cur.kind === 'module') {
+          chunks.unshift(cur.name);
+          cur = cur.parent;
+        }
+        module.feature = new rust.CrateFeature(chunks.join('-'));
+        this.crate.features.push(module.feature);
+      }
+    }
+
+    for (const module of modules) {
+      if (module.kind !== 'module' || !module.feature) {
+        continue;
+      }
+
+      // a nested module requires its parent module
+      if (module.parent.kind === 'module' && module.parent.feature) {
+        module.feature.enables.push(module.parent.feature);
+      }
+
+      for (const referencedModule of referencedModules.get(module)!) {
+        if (referencedModule.kind === 'module' && referencedModule.feature && !module.feature.enables.includes(referencedModule.feature)) {
+          module.feature.enables.push(referencedModule.feature);
+        }
+      }
+    }
+  }
+
   /** converts tcgc docs to formatted rust.Docs */
   private adaptDocs(summary?: string, doc?: string): rust.Docs {
     if (summary) {
@@ -235,10 +321,12 @@ export class Adapter {
       needsCoreAndSerde = true;
     }
 
+    const apiVersionEnum = this.getApiVersionEnum();
     for (const sdkEnum of this.ctx.sdkPackage.enums) {
-      if (<tcgc.UsageFlags>(sdkEnum.usage & tcgc.UsageFlags.ApiVersionEnum) === tcgc.UsageFlags.ApiVersionEnum) {
-        // we skip generating the enums for API
-        // versions as we expose it as a String
+      if (isApiVersionEnum(sdkEnum) && sdkEnum !== apiVersionEnum) {
+        // when the package spans multiple services we skip
+        // generating the enums for API versions as we
+        // expose them as a String
         continue;
       }
 
@@ -296,6 +384,12 @@ export class Adapter {
         continue;
       }
 
+      // file parts in multipart/form-data payloads are
+      // represented by the generated MultipartFile type.
+      if (isHttpFile(model)) {
+        continue;
+      }
+
       needsCoreAndSerde = true;
 
       if (isPolymorphicRoot(model)) {
@@ -325,6 +419,28 @@ export class Adapter {
     }
   }
 
+  /**
+   * returns the API versions enum for the package or undefined if
+   * the package isn't versioned or spans multiple services.
+   * 
+   * @returns the tcgc API versions enum or undefined
+   */
+  private getApiVersionEnum(): tcgc.SdkEnumType | undefined {
+    const apiVersionEnums = this.ctx.sdkPackage.enums.filter(isApiVersionEnum);
+    return apiVersionEnums.length === 1 ? apiVersionEnums[0] : undefined;
+  }
+
+  /**
+   * returns the type for api-version params. this is the ApiVersion
+   * enum when the package has one, else it's a String.
+   * 
+   * @returns the Rust type for api-version params
+   */
+  private getApiVersionType(): rust.Enum | rust.StringType {
+    const apiVersionEnum = this.getApiVersionEnum();
+    return apiVersionEnum ? this.getEnum(apiVersionEnum) : this.getStringType();
+  }
+
   /**
    * converts a tcgc enum to a Rust enum
    * 
@@ -362,13 +478,21 @@ export class Adapter {
         throw new AdapterError('UnsupportedTsp', `unsupported enum underlying type ${sdkEnum.valueType.kind}`, sdkEnum.__raw?.node);
     }
 
-    rustEnum = new rust.Enum(enumName, adaptAccessFlags(sdkEnum.access), !sdkEnum.isFixed, enumType, this.adaptNamespace(sdkEnum.namespace));
+    if (sdkEnum === this.getApiVersionEnum()) {
+      // the API versions enum is always extensible so callers can
+      // target API versions that are newer than the generated code.
+      rustEnum = new rust.Enum('ApiVersion', adaptAccessFlags(sdkEnum.access), true, enumType, this.adaptNamespace(sdkEnum.namespace));
+      rustEnum.nonExhaustive = true;
+    } else {
+      rustEnum = new rust.Enum(enumName, adaptAccessFlags(sdkEnum.access), !sdkEnum.isFixed, enumType, this.adaptNamespace(sdkEnum.namespace));
+    }
     rustEnum.docs = this.adaptDocs(sdkEnum.summary, sdkEnum.doc);
     this.types.set(enumName, rustEnum);
 
     // the first pass is to detect any enum values that coalesce into duplicate entries
     const rustEnumNameToSdkEnumName = new Map<string, Array<tcgc.SdkEnumValueType>>();
     for (const value of sdkEnum.values) {
+      this.reportVersionedAvailability(value.__raw, String(value.value));
       const enumValueName = naming.fixUpEnumValueName(value);
       let existingMapping = rustEnumNameToSdkEnumName.get(enumValueName);
       if (!existingMapping) {
@@ -440,8 +564,9 @@ export class Adapter {
 
     // eti.identity is the fully qualified path to the type.
     // split it into the type name and its import path.
+    // eti.minVersion is the version of its crate when it isn't a member of a workspace.
     const splitAt = eti.identity.lastIndexOf('::');
-    externalType = new rust.ExternalType(this.crate, eti.identity.substring(splitAt + 2), eti.identity.substring(0, splitAt));
+    externalType = new rust.ExternalType(this.crate, eti.identity.substring(splitAt + 2), eti.identity.substring(0, splitAt), eti.minVersion);
     this.types.set(eti.identity, externalType);
     return externalType;
   }
@@ -487,6 +612,10 @@ export class Adapter {
       modelFlags |= rust.ModelFlags.Output;
     }
 
+    if (<tcgc.UsageFlags>(model.usage & tcgc.UsageFlags.MultipartFormData) === tcgc.UsageFlags.MultipartFormData) {
+      modelFlags |= rust.ModelFlags.Multipart;
+    }
+
     rustModel = new rust.Model(modelName, model.access === 'internal' ? 'pubCrate' : 'pub', modelFlags, this.adaptNamespace(model.namespace));
     rustModel.docs = this.adaptDocs(model.summary, model.doc);
     rustModel.xmlName = getXMLName(model.decorators);
@@ -504,6 +633,7 @@ export class Adapter {
 
     let addlProps = model.additionalProperties;
 
+    const outerDiscriminators = new Set<tcgc.SdkModelPropertyType>();
     let parent = model.baseModel;
     while (parent) {
       for (const parentProp of parent.properties) {
@@ -512,8 +642,15 @@ export class Adapter {
           // to ensure that duplicate fields with different types aren't added.
           continue;
         } else if (parentProp.discriminator) {
-          // we don't propagate the discriminator to the child
-          // types as it's not useful (or necessary)
+          if (parentProp.type.kind === 'constant' || parentProp.type.kind === 'enumvalue') {
+            // this is the discriminator value of an intermediate type that
+            // declares its own discriminator (e.g. Fish -> Shark -> SawShark).
+            // it's included so the outer discriminator is also serialized.
+            outerDiscriminators.add(parentProp);
+            allProps.push(parentProp);
+          }
+          // we don't propagate the root discriminator to the
+          // child types as it's not useful (or necessary)
           continue;
         }
         allProps.push(parentProp);
@@ -549,11 +686,19 @@ export class Adapter {
         }
       }
 
-      const structField = this.getModelField(model.usage, property, rustModel.visibility, stack);
+      const structField = this.getModelField(model.usage, property, rustModel.visibility, rustModel.module, stack);
+      if (outerDiscriminators.has(property)) {
+        // serde handles the model's discriminator. outer
+        // discriminators are serialized as regular fields.
+        structField.flags &= ~rust.ModelFieldFlags.Discriminator;
+      }
       rustModel.fields.push(structField);
     }
 
     if (addlProps) {
+      if (<rust.ModelFlags>(rustModel.flags & rust.ModelFlags.Multipart) === rust.ModelFlags.Multipart) {
+        throw new AdapterError('UnsupportedTsp', `multipart model ${model.name} with additional properties is not supported`, model.__raw?.node);
+      }
       const addlPropsType = this.getHashMap(this.typeToWireType(this.getType(addlProps)));
       const addlPropsField = new rust.ModelAdditionalProperties('additional_properties', 'pub', this.getOptionType(addlPropsType));
       addlPropsField.docs.summary = 'Contains unnamed additional properties.';
@@ -592,14 +737,7 @@ export class Adapter {
           throw new AdapterError('InternalError', 'getDiscriminatedUnion called for non-polymorphic model', src.__raw?.node);
         }
 
-        // find the discriminator field
-        let discriminatorProperty: tcgc.SdkModelPropertyType | undefined;
-        for (const prop of src.properties) {
-          if (prop.kind === 'property' && prop.discriminator) {
-            discriminatorProperty = prop;
-            break;
-          }
-        }
+        const discriminatorProperty = getDiscriminatorProperty(src);
         if (!discriminatorProperty) {
           throw new AdapterError('InternalError', `failed to find discriminator field for type ${src.name}`, src.__raw?.node);
         }
@@ -645,6 +783,11 @@ export class Adapter {
           if (!subType.discriminatorValue) {
             throw new AdapterError('InternalError', `model ${subType.name} has no discriminator value`, subType.__raw?.node);
           }
+          if (isNestedDiscriminatedSubtype(src, subType)) {
+            // the sub-type is routed through the intermediate
+            // type that declares its discriminator
+            continue;
+          }
           const unionMemberType = subType.discriminatedSubtypes ? this.getDiscriminatedUnion(subType) : this.getModel(subType);
           const rustUnionMember = new rust.DiscriminatedUnionMember(unionMemberType, subType.discriminatorValue);
           rustUnion.members.push(rustUnionMember);
@@ -922,7 +1065,7 @@ export class Adapter {
    * @param stack is a stack of types used to detect recursive type definitions
    * @returns a Rust model field
    */
-  private getModelField(modelFlags: tcgc.UsageFlags, property: tcgc.SdkModelPropertyType | tcgc.SdkPathParameter, modelVisibility: rust.Visibility, stack: Array<rust.Type>): rust.ModelField {
+  private getModelField(modelFlags: tcgc.UsageFlags, property: tcgc.SdkModelPropertyType | tcgc.SdkPathParameter, modelVisibility: rust.Visibility, modelModule: rust.ModuleContainer, stack: Array<rust.Type>): rust.ModelField {
     const fieldNeedsBoxing = function(fieldType: rust.Type): fieldType is rust.WireType {
       if (fieldType.kind === 'model' && (stack.includes(fieldType))) {
         // if the field's type is a model and it's in the type stack then
@@ -940,7 +1083,19 @@ export class Adapter {
       return false;
     };
 
-    let fieldType = this.getType(property.type, stack);
+    let fieldType: rust.Type;
+    const multipartOpts = property.kind === 'property' ? property.serializationOptions.multipart : undefined;
+    if (multipartOpts?.isFilePart) {
+      // file parts are sent as raw content with optional filename and content type
+      const fileType = this.getMultipartFile(multipartOpts.defaultContentTypes.at(0) ?? 'application/octet-stream', modelModule);
+      fieldType = multipartOpts.isMulti ? this.getVec(fileType) : fileType;
+    } else if (property.kind === 'property' && property.type.kind === 'url' && this.nextLinkProperties.has(property)) {
+      // next links can be relative to the URL of the previous page so they
+      // remain a String and are joined with that URL to fetch the next page
+      fieldType = this.getStringType();
+    } else {
+      fieldType = this.getNullableElements(property.type, this.getType(property.type, stack), modelModule);
+    }
     if (fieldNeedsBoxing(fieldType)) {
       fieldType = this.getBoxType(fieldType);
     }
@@ -949,11 +1104,32 @@ export class Adapter {
     // NOTE: models can be used for both spread and I/O, so when
     // restricting for spread it must be ONLY used for spread.
     const notSpreadOnly = (modelFlags & tcgc.UsageFlags.Spread) === 0 || (modelFlags & tcgc.UsageFlags.Input) || (modelFlags & tcgc.UsageFlags.Output);
-    if (notSpreadOnly || property.optional) {
+    const isMergePatch = <tcgc.UsageFlags>(modelFlags & tcgc.UsageFlags.JsonMergePatch) === tcgc.UsageFlags.JsonMergePatch;
+    if (notSpreadOnly && (isMergePatch || property.type.kind === 'nullable') && fieldSupportsNullable(fieldType)) {
+      // nullable fields and merge-patch models need to distinguish between
+      // an absent field and an explicit null (which removes the value).
+      // for merge-patch this also applies to the values in a map.
+      if (isMergePatch && fieldType.kind === 'hashmap' && fieldType.type.kind !== 'nullable' && supportsNullable(fieldType.type)) {
+        fieldType = this.getHashMap(this.getNullable(fieldType.type, modelModule));
+      }
+      fieldType = this.getNullable(this.typeToWireType(fieldType), modelModule);
+    } else if (notSpreadOnly || property.optional) {
+      if (notSpreadOnly && (isMergePatch || property.type.kind === 'nullable')) {
+        this.ctx.program.reportDiagnostic({
+          code: 'UnsupportedNullable',
+          severity: 'warning',
+          message: `field ${property.name} of kind ${fieldType.kind} can't be sent as an explicit null; it's emitted as Option<T>`,
+          target: property.__raw?.node ?? tsp.NoTarget,
+        });
+      }
       fieldType = this.getOptionType(fieldType.kind === 'box' ? fieldType : this.typeToWireType(fieldType));
     }
 
     const serializedName = this.getSerializedPropertyName(property) ?? property.name;
+    if (notSpreadOnly) {
+      // the properties of spread models are method params which are checked
+      this.reportVersionedAvailability(property.__raw, serializedName);
+    }
 
     const modelField = new rust.ModelField(naming.getEscapedReservedName(utils.snakeCaseName(property.name), 'prop'), serializedName, modelVisibility, fieldType, property.optional);
     modelField.docs = this.adaptDocs(property.summary, property.doc);
@@ -1100,19 +1276,47 @@ export class Adapter {
       }
       case 'dict':
         return this.getHashMap(this.typeToWireType(this.getType(type.valueType, stack)));
-      case 'duration':
-        switch (type.wireType.kind) {
-          case 'float':
-          case 'float32':
-          case 'float64':
-          case 'int32':
-          case 'int64':
-            return this.getScalar(type.wireType.kind, type.wireType.encode);
-          case 'string':
-            return this.getStringType();
+      case 'duration': {
+        let encoding: rust.DurationEncoding;
+        switch (type.encode) {
+          case 'ISO8601':
+            encoding = 'iso8601';
+            break;
+          case 'milliseconds':
+          case 'seconds':
+            switch (type.wireType.kind) {
+              case 'float':
+              case 'float32':
+              case 'float64':
+                encoding = `${type.encode}-float`;
+                break;
+              case 'int16':
+              case 'int32':
+              case 'int64':
+              case 'int8':
+              case 'integer':
+              case 'safeint':
+              case 'uint16':
+              case 'uint32':
+              case 'uint64':
+              case 'uint8':
+                encoding = type.encode;
+                break;
+              default:
+                throw new AdapterError('UnsupportedTsp', `unhandled duration wireType.kind ${type.wireType.kind}`, type.__raw?.node);
+            }
+            break;
           default:
-            throw new AdapterError('UnsupportedTsp', `unhandled duration wireType.kind ${type.wireType.kind}`, type.__raw?.node);
+            throw new AdapterError('UnsupportedTsp', `unhandled duration encoding ${type.encode}`, type.__raw?.node);
+        }
+        const keyName = `duration-${encoding}`;
+        let durationType = this.types.get(keyName);
+        if (!durationType) {
+          durationType = new rust.Duration(this.crate, encoding);
+          this.types.set(keyName, durationType);
         }
+        return durationType;
+      }
       case 'boolean':
       case 'float32':
       case 'float64':
@@ -1142,10 +1346,7 @@ export class Adapter {
         }
         return this.getModel(type, stack);
       case 'endpoint':
-      case 'plainDate':
-      case 'plainTime':
       case 'string':
-      case 'url':
         if (type.kind === 'string' && type.crossLanguageDefinitionId === 'Azure.Core.eTag') {
           return this.getEtag();
         }
@@ -1156,7 +1357,8 @@ export class Adapter {
           // type, we need to propagate the docs to it
           type.type.doc = type.doc;
         }
-        // TODO: workaround until https://github.com/Azure/typespec-rust/issues/42 is fixed
+        // nullable model fields (and their elements) are wrapped in a Nullable<T> by
+        // getModelField. everywhere else (e.g. parameters) null is the same as absent.
         return this.getType(type.type, stack);
       case 'offsetDateTime': {
         const encoding = getDateTimeEncoding(type.encode);
@@ -1169,6 +1371,22 @@ export class Adapter {
         this.types.set(keyName, timeType);
         return timeType;
       }
+      case 'plainDate': {
+        let dateType = this.types.get(type.kind);
+        if (!dateType) {
+          dateType = new rust.PlainDate(this.crate);
+          this.types.set(type.kind, dateType);
+        }
+        return dateType;
+      }
+      case 'plainTime': {
+        let timeType = this.types.get(type.kind);
+        if (!timeType) {
+          timeType = new rust.PlainTime(this.crate);
+          this.types.set(type.kind, timeType);
+        }
+        return timeType;
+      }
       case 'safeint': {
         const keyName = type.kind + (type.encode ? `-${type.encode}` : '');
         let safeint = this.types.get(keyName);
@@ -1180,6 +1398,8 @@ export class Adapter {
       }
       case 'unknown':
         return this.getUnknownValue();
+      case 'url':
+        return this.getUrl();
       case 'utcDateTime': {
         const encoding = getDateTimeEncoding(type.encode);
         const keyName = `offsetDateTime-${encoding}-utc`;
@@ -1237,6 +1457,65 @@ export class Adapter {
     return etagType;
   }
 
+  /** returns a MultipartFile type for the specified module */
+  private getMultipartFile(defaultContentType: string, module: rust.ModuleContainer): rust.MultipartFile {
+    const fileType = new rust.MultipartFile(defaultContentType, module);
+    const keyName = recursiveKeyName('multipartFile', fileType);
+    const cachedType = this.types.get(keyName);
+    if (cachedType) {
+      return <rust.MultipartFile>cachedType;
+    }
+    this.types.set(keyName, fileType);
+    return fileType;
+  }
+
+  /** returns the MatchConditions or RequestConditions type for the specified module */
+  private getRequestConditions(name: 'MatchConditions' | 'RequestConditions', module: rust.ModuleContainer): rust.RequestConditions {
+    const keyName = `requestConditions-${utils.buildImportPath(module, module)}-${name}`;
+    let conditionsType = this.types.get(keyName);
+    if (conditionsType) {
+      return <rust.RequestConditions>conditionsType;
+    }
+    conditionsType = new rust.RequestConditions(name, module);
+    this.types.set(keyName, conditionsType);
+    return conditionsType;
+  }
+
+  /**
+   * wraps the elements of arrays and the values of dictionaries in a
+   * Nullable<T> when they're nullable (e.g. Array<string | null>).
+   * 
+   * @param type the tcgc type that was adapted to rustType
+   * @param rustType the adapted type
+   * @param module the module to which the Nullable<T> belongs
+   * @returns rustType with any nullable elements wrapped in a Nullable<T>
+   */
+  private getNullableElements(type: tcgc.SdkType, rustType: rust.Type, module: rust.ModuleContainer): rust.Type {
+    if (type.kind === 'nullable') {
+      type = type.type;
+    }
+    if ((type.kind === 'array' && rustType.kind === 'Vec') || (type.kind === 'dict' && rustType.kind === 'hashmap')) {
+      let elementType = this.getNullableElements(type.valueType, rustType.type, module);
+      if (type.valueType.kind === 'nullable' && supportsNullable(elementType)) {
+        elementType = this.getNullable(this.typeToWireType(elementType), module);
+      }
+      return rustType.kind === 'Vec' ? this.getVec(this.typeToWireType(elementType)) : this.getHashMap(this.typeToWireType(elementType));
+    }
+    return rustType;
+  }
+
+  /** returns a Nullable<type> for the specified module */
+  private getNullable(type: rust.WireType, module: rust.ModuleContainer): rust.Nullable {
+    const nullableType = new rust.Nullable(type, module);
+    const keyName = recursiveKeyName('nullable', nullableType);
+    const cachedType = this.types.get(keyName);
+    if (cachedType) {
+      return <rust.Nullable>cachedType;
+    }
+    this.types.set(keyName, nullableType);
+    return nullableType;
+  }
+
   /** returns a HashMap<String, type> */
   private getHashMap(type: rust.WireType): rust.HashMap {
     const keyName = recursiveKeyName('hashmap', type);
@@ -1379,6 +1658,18 @@ export class Adapter {
     return unitType;
   }
 
+  /** returns the Url type */
+  private getUrl(): rust.Url {
+    const typeKey = 'Url';
+    let urlType = this.types.get(typeKey);
+    if (urlType) {
+      return <rust.Url>urlType;
+    }
+    urlType = new rust.Url(this.crate);
+    this.types.set(typeKey, urlType);
+    return urlType;
+  }
+
   /** returns a Vec<type> */
   private getVec(type: rust.WireType): rust.Vector {
     const keyName = recursiveKeyName('Vec', type);
@@ -1463,136 +1754,653 @@ export class Adapter {
   }
 
   /**
-   * recursively converts a client and its methods.
-   * this simplifies the case for hierarchical clients.
+   * sets the previous names for header and query params renamed via the @renamedFrom
+   * decorator. the name to send is selected from the client's API version so the
+   * previous names are only set when the API version is a client param or part of
+   * the client's endpoint.
    *
-   * @param client the tcgc client to recursively convert
-   * @param parent contains the parent Rust client when converting a child client
-   * @returns a Rust client
+   * @param rustClient the client that contains the method
+   * @param rustMethod the method whose renamed params to adapt
+   * @param allOpParams the tcgc operation params for the method
    */
-  private recursiveAdaptClient(client: tcgc.SdkClientType<tcgc.SdkHttpOperation>, parent?: rust.Client): rust.Client {
-    let clientName = client.name;
-    // NOTE: if the client has the @clientName decorator applied then use that verbatim
-    if (parent && !hasClientNameDecorator(client.decorators)) {
-      // for hierarchical clients, the child client names are built
-      // from the parent client name. this is because tsp allows subclients
-      // with the same name. consider the following example.
-      //
-      // namespace Chat {
-      //   interface Completions {
-      //     ...
-      //   }
-      // }
-      // interface Completions { ... }
-      //
-      // we want to generate two clients from this,
-      // one name ChatCompletions and the other Completions
+  private adaptRenamedParams(rustClient: rust.Client, rustMethod: MethodType, allOpParams: Array<tcgc.SdkHttpParameter>): void {
+    for (const param of rustMethod.params) {
+      if ((param.kind !== 'headerScalar' && param.kind !== 'queryScalar') || param.isApiVersion) {
+        continue;
+      }
 
-      // strip off the Client suffix from the parent client name
-      clientName = parent.name.substring(0, parent.name.length - 6) + clientName;
-    }
+      const opParamKind = param.kind === 'headerScalar' ? 'header' : 'query';
+      const wireName = param.kind === 'headerScalar' ? param.header : param.key;
+      const raw = allOpParams.find((opParam) => opParam.kind === opParamKind && opParam.serializedName === wireName)?.__raw;
+      if (raw?.kind !== 'ModelProperty' || hasExplicitWireName(raw, opParamKind)) {
+        // names set via @header("name") or @query("name") don't change when the property is renamed
+        continue;
+      }
 
-    if (!clientName.match(/Client$/)) {
-      clientName += 'Client';
+      const getWireName = (name: string): string => opParamKind === 'header' ? getDefaultHeaderName(name) : name;
+      const previousNames = getPreviousNames(this.ctx.program, raw, rustClient.apiVersions, getWireName);
+      if (previousNames.length === 0) {
+        continue;
+      } else if (!this.isApiVersionKnown(rustClient, rustMethod)) {
+        this.ctx.program.reportDiagnostic({
+          code: 'UnsupportedVersionedChange',
+          severity: 'warning',
+          message: `${opParamKind} parameter ${wireName} was renamed from ${previousNames.map((previous) => previous.name).join(', ')} but the api-version isn't known to the client; ${wireName} is sent for all api-versions`,
+          target: raw.node ?? tsp.NoTarget,
+        });
+        continue;
+      }
+      param.previousNames = previousNames;
     }
+  }
 
-    const rustClient = new rust.Client(clientName, this.adaptNamespace(client.namespace));
-    rustClient.docs = this.adaptDocs(client.summary, client.doc);
-    rustClient.parent = parent;
-    rustClient.fields.push(new rust.StructField('pipeline', 'pubCrate', new rust.ExternalType(this.crate, 'Pipeline', 'azure_core::http')));
+  /**
+   * adapts the changes made to the JSON wire format of a method's request and response
+   * bodies via the @renamedFrom, @typeChangedFrom, and @returnTypeChangedFrom decorators.
+   * the bodies are converted to and from the wire format of the client's API version.
+   * changes that can't be converted are reported as diagnostics.
+   *
+   * @param rustClient the client that contains the method
+   * @param rustMethod the method whose wire changes to adapt
+   * @param method the tcgc method
+   * @param allOpParams the tcgc operation params for the method
+   */
+  private adaptWireChanges(rustClient: rust.Client, rustMethod: MethodType, method: tcgc.SdkServiceMethod<tcgc.SdkHttpOperation>, allOpParams: Array<tcgc.SdkHttpParameter>): void {
+    const collect = (type: tcgc.SdkType | undefined): Array<VersionedWireChange> => {
+      const changes = new Array<VersionedWireChange>();
+      if (type) {
+        this.collectWireChanges(type, [], rustClient.apiVersions, changes, new Set<tcgc.SdkModelType>());
+      }
+      return changes;
+    };
 
-    // InitializedByFlags.CustomizeCode means the client is instantiable
-    // but the constructor is to be omitted (i.e. hand-written).
-    if (client.clientInitialization.initializedBy === tcgc.InitializedByFlags.CustomizeCode || client.clientInitialization.initializedBy & tcgc.InitializedByFlags.Individually) {
-      const clientOptionsStruct = new rust.Struct(`${rustClient.name}Options`, 'pub');
-      const clientOptionsField = new rust.StructField('client_options', 'pub', new rust.ExternalType(this.crate, 'ClientOptions', 'azure_core::http'));
-      clientOptionsField.docs.summary = 'Allows customization of the client.';
-      clientOptionsField.defaultValue = 'ClientOptions::default()';
-      clientOptionsStruct.fields.push(clientOptionsField);
-      rustClient.constructable = new rust.ClientConstruction(new rust.ClientOptions(clientOptionsStruct));
-      clientOptionsStruct.docs.summary = `Options used when creating a ${this.asDocLink(rustClient.name, rustClient.name)}`;
+    const requestChanges = collect(method.operation.bodyParam?.type);
+    const responseChanges = collect(method.response.type);
+    if (method.__raw) {
+      this.collectTypeChanges(versioning.getReturnTypeChangedFrom(this.ctx.program, method.__raw), method.response.type, [], rustClient.apiVersions, responseChanges, method.__raw, `return type of ${method.name}`);
+    }
 
-      if (this.options['omit-constructors']) {
-        rustClient.constructable.suppressed = 'yes';
-      } else if (client.clientInitialization.initializedBy === tcgc.InitializedByFlags.CustomizeCode) {
-        rustClient.constructable.suppressed = 'ctor';
+    for (const opParam of allOpParams) {
+      if (opParam.kind !== 'body') {
+        this.reportWireChanges(collect(opParam.type), 'parameters aren\'t converted');
       }
+    }
 
-      // NOTE: per tcgc convention, if there is no param of kind credential
-      // it means that the client doesn't require any kind of authentication.
-      // HOWEVER, if there *is* a credential param, then the client *does not*
-      // automatically support unauthenticated requests. a credential with
-      // the noAuth scheme indicates support for unauthenticated requests.
+    if (requestChanges.length === 0 && responseChanges.length === 0) {
+      return;
+    } else if (!this.isApiVersionKnown(rustClient, rustMethod)) {
+      this.reportWireChanges([...requestChanges, ...responseChanges], 'the api-version isn\'t known to the client');
+      return;
+    } else if (rustMethod.kind !== 'async') {
+      this.reportWireChanges([...requestChanges, ...responseChanges], 'the bodies of pageable and long-running methods aren\'t converted');
+      return;
+    }
 
-      // bit flags for auth types
-      enum AuthTypes {
-        Default = 0, // unspecified
-        NoAuth = 1, // explicit NoAuth
-        OAuth2 = 2, // explicit OAuth2
-        WithAuth = 4, // explicit, unsupported credential
-      }
+    const convert = (changes: Array<VersionedWireChange>): Array<rust.WireChange> => {
+      this.reportWireChanges(changes.filter((change) => !change.change), 'the earlier type can\'t be converted');
+      return changes.flatMap((change) => change.change ? [change.change] : []);
+    };
 
-      let authType = AuthTypes.Default;
+    const bodyParam = rustMethod.params.find((param): param is rust.BodyParameter => param.kind === 'body');
+    if (bodyParam && bodyParam.type.format !== 'JsonFormat') {
+      this.reportWireChanges(requestChanges, `${bodyParam.type.format} request bodies aren't converted`);
+    } else {
+      rustMethod.requestWireChanges = convert(requestChanges);
+    }
 
-      /**
-       * processes a credential, potentially adding its supporting client constructor
-       *
-       * @param rustClient the client for which the constructor will be added
-       * @param param the tsp parameter that contains cred
-       * @param cred the credential type to process
-       * @param constructable the constructable for the current Rust client
-       * @returns the AuthTypes enum for the credential that was handled, or AuthTypes.Default if none were
-       */
-      const processCredential = (rustClient: rust.Client, param: tcgc.SdkCredentialParameter, cred: http.HttpAuth, constructable: rust.ClientConstruction): AuthTypes => {
-        switch (cred.type) {
-          case 'noAuth':
-            return AuthTypes.NoAuth;
-          case 'oauth2': {
-            if ((authType & AuthTypes.OAuth2) === 0) {
-              // tsp can describe multiple oauth2 credential flow in a union.
-              // since each flow is implicitly handled via the credential, we
-              // only need to emit one ctor for the oauth2 type.
-              constructable.constructors.push(this.createTokenCredentialCtor(rustClient, cred));
-            }
-            return AuthTypes.OAuth2;
+    const response = rustMethod.returns.type;
+    if (response.kind !== 'response' || response.format !== 'JsonFormat') {
+      this.reportWireChanges(responseChanges, `${response.kind === 'response' ? response.format : 'streamed'} response bodies aren't converted`);
+    } else {
+      rustMethod.responseWireChanges = convert(responseChanges);
+    }
+  }
+
+  /**
+   * recursively collects the changes made to the JSON wire format of
+   * a type via the @renamedFrom and @typeChangedFrom decorators.
+   *
+   * @param type the type sent on the wire
+   * @param path the path to the type's values from the root of the body
+   * @param apiVersions the API versions of the enclosing client
+   * @param changes the collected changes
+   * @param enclosing the models that enclose the type, used to stop at recursive models
+   */
+  private collectWireChanges(type: tcgc.SdkType, path: Array<string>, apiVersions: Array<string>, changes: Array<VersionedWireChange>, enclosing: Set<tcgc.SdkModelType>): void {
+    const addChange = (change: VersionedWireChange): void => {
+      // discriminated subtypes repeat the properties of their base models
+      if (!changes.some((existing) => JSON.stringify(existing.change) === JSON.stringify(change.change) && existing.raw === change.raw)) {
+        changes.push(change);
+      }
+    };
+
+    switch (type.kind) {
+      case 'array':
+      case 'dict':
+        this.collectWireChanges(type.valueType, [...path, '*'], apiVersions, changes, enclosing);
+        break;
+      case 'enum':
+        for (const value of type.values) {
+          const raw = value.__raw;
+          if (raw?.kind !== 'EnumMember' || raw.value !== undefined) {
+            // explicit values don't change when the member is renamed
+            continue;
           }
-          default:
-            this.ctx.program.reportDiagnostic({
-              code: 'UnsupportedAuthenticationScheme',
-              severity: 'warning',
-              message: `authentication scheme ${cred.type} is not supported`,
-              target: param.__raw?.node ?? tsp.NoTarget,
+          for (const previousName of getPreviousNames(this.ctx.program, raw, apiVersions, (name) => name)) {
+            addChange({
+              change: { kind: 'value', path: path, apiVersions: previousName.apiVersions, value: String(value.value), previousValue: previousName.name },
+              raw: raw,
+              description: `enum value ${String(value.value)} was renamed from ${previousName.name}`,
+              apiVersions: previousName.apiVersions,
             });
-            return AuthTypes.WithAuth;
+          }
         }
-      };
+        break;
+      case 'model':
+        if (enclosing.has(type)) {
+          return;
+        }
+        enclosing.add(type);
+        for (let model: tcgc.SdkModelType | undefined = type; model; model = model.baseModel) {
+          for (const property of model.properties) {
+            if (property.kind !== 'property') {
+              continue;
+            }
 
-      const ctorParams = new Array<rust.ClientParameter>();
-      for (const param of client.clientInitialization.parameters) {
-        switch (param.kind) {
-          case 'credential':
-            switch (param.type.kind) {
-              case 'credential':
-                authType |= processCredential(rustClient, param, param.type.scheme, rustClient.constructable);
-                break;
-              case 'union': {
-                for (const variantType of param.type.variantTypes) {
-                  // if OAuth2 is specified then emit that and skip any unsupported ones.
-                  // this prevents emitting the with_no_credential constructor in cases
-                  // where it might not actually be supported.
-                  authType |= processCredential(rustClient, param, variantType.scheme, rustClient.constructable);
-                }
+            const serializedName = this.getSerializedPropertyName(property) ?? property.name;
+            const raw = property.__raw;
+            // renames only change the wire format when the name isn't explicitly set (e.g. via @encodedName)
+            if (raw?.kind === 'ModelProperty' && raw.name === serializedName) {
+              for (const previousName of getPreviousNames(this.ctx.program, raw, apiVersions, (name) => name)) {
+                addChange({
+                  change: { kind: 'name', path: path, apiVersions: previousName.apiVersions, name: serializedName, previousName: previousName.name },
+                  raw: raw,
+                  description: `property ${serializedName} was renamed from ${previousName.name}`,
+                  apiVersions: previousName.apiVersions,
+                });
               }
             }
-            break;
-          case 'endpoint': {
-            let endpointType: tcgc.SdkEndpointType;
-            switch (param.type.kind) {
-              case 'endpoint':
-                // single endpoint without any supplemental path
-                endpointType = param.type;
-                break;
-              case 'union':
+            if (raw?.kind === 'ModelProperty') {
+              const typeChanges = new Array<VersionedWireChange>();
+              this.collectTypeChanges(versioning.getTypeChangedFrom(this.ctx.program, raw), property.type, [...path, serializedName], apiVersions, typeChanges, raw, `type of property ${serializedName}`);
+              typeChanges.forEach(addChange);
+            }
+            this.collectWireChanges(property.type, [...path, serializedName], apiVersions, changes, enclosing);
+          }
+        }
+        for (const subtype of Object.values(type.discriminatedSubtypes ?? {})) {
+          this.collectWireChanges(subtype, path, apiVersions, changes, enclosing);
+        }
+        enclosing.delete(type);
+        break;
+      case 'nullable':
+        this.collectWireChanges(type.type, path, apiVersions, changes, enclosing);
+        break;
+      case 'union':
+        for (const variantType of type.variantTypes) {
+          this.collectWireChanges(variantType, path, apiVersions, changes, enclosing);
+        }
+        break;
+    }
+  }
+
+  /**
+   * collects the changes made to the JSON wire format of a value via
+   * the @typeChangedFrom or @returnTypeChangedFrom decorators. only
+   * changes between JSON scalar kinds (e.g. number to string) can
+   * be converted; changes to the same kind don't change the wire format.
+   *
+   * @param typeChanges the earlier types keyed by the API version in which the type changed
+   * @param type the type in the latest API version
+   * @param path the path to the value from the root of the body
+   * @param apiVersions the API versions of the enclosing client
+   * @param changes the collected changes
+   * @param raw the tsp type that contains the decorator
+   * @param description describes the value for diagnostics
+   */
+  private collectTypeChanges(typeChanges: Map<versioning.Version, tsp.Type> | undefined, type: tcgc.SdkType | undefined, path: Array<string>, apiVersions: Array<string>, changes: Array<VersionedWireChange>, raw: tsp.Type, description: string): void {
+    if (!typeChanges) {
+      return;
+    }
+
+    const latestKind = getJsonScalarKind(type);
+    const previousTypes = getPreviousValues([...typeChanges.entries()].map(([version, previousType]) => { return { version: version, value: previousType }; }), apiVersions);
+    for (const previousType of previousTypes) {
+      const previousKind = getJsonScalarKind(tcgc.getClientType(this.ctx, previousType.value));
+      if (latestKind && latestKind === previousKind) {
+        // e.g. int32 to int64 which are both sent as a JSON number
+        continue;
+      }
+      changes.push({
+        change: latestKind && previousKind ? { kind: 'type', path: path, apiVersions: previousType.apiVersions, type: latestKind, previousType: previousKind } : undefined,
+        raw: raw,
+        description: `${description} changed`,
+        apiVersions: previousType.apiVersions,
+      });
+    }
+  }
+
+  /**
+   * reports diagnostics for wire changes that won't be converted.
+   * a diagnostic is reported once per decorated type.
+   *
+   * @param changes the changes to report
+   * @param reason the reason the changes won't be converted
+   */
+  private reportWireChanges(changes: Array<VersionedWireChange>, reason: string): void {
+    for (const change of changes) {
+      if (this.reportedWireChanges.has(change.raw)) {
+        continue;
+      }
+      this.reportedWireChanges.add(change.raw);
+      this.ctx.program.reportDiagnostic({
+        code: 'UnsupportedVersionedChange',
+        severity: 'warning',
+        message: `${change.description} in api-version${change.apiVersions.length > 1 ? 's' : ''} ${change.apiVersions.join(', ')} but ${reason}; the latest wire format is used for all api-versions`,
+        target: change.raw.node ?? tsp.NoTarget,
+      });
+    }
+  }
+
+  /**
+   * returns true if the client's API version is known when the method is called.
+   * this is the case when it's a client param or part of the client's endpoint.
+   *
+   * @param rustClient the client that contains the method
+   * @param rustMethod the method to inspect
+   * @returns true if the API version is known
+   */
+  private isApiVersionKnown(rustClient: rust.Client, rustMethod: MethodType): boolean {
+    const apiVersionOnClient = rustMethod.params.some((param) => {
+      return (param.kind === 'headerScalar' || param.kind === 'queryScalar') && param.isApiVersion && param.location === 'client';
+    });
+    return apiVersionOnClient || this.getEndpointApiVersion(rustClient) !== undefined;
+  }
+
+  /**
+   * returns the client field for the API version selected via the endpoint.
+   * the API version is otherwise only used to construct the endpoint, so the
+   * field is created on first use. sub-clients share their parent's field.
+   *
+   * @param rustClient the client for which to get the field
+   * @returns the field or undefined if the API version isn't part of the endpoint
+   */
+  private getEndpointApiVersion(rustClient: rust.Client): rust.StructField | undefined {
+    if (rustClient.endpointApiVersion) {
+      return rustClient.endpointApiVersion;
+    }
+
+    // the endpoint's params are on the client that constructs it
+    const clients = new Array<rust.Client>();
+    let cur: rust.Client | undefined = rustClient;
+    while (cur) {
+      clients.push(cur);
+      if (cur.constructable) {
+        break;
+      }
+      cur = cur.parent;
+    }
+
+    const apiVersionParam = cur?.constructable?.endpoint?.parameters.find((param) => param.isApiVersion);
+    if (!apiVersionParam || apiVersionParam.type.kind === 'option') {
+      return undefined;
+    }
+
+    const apiVersionField = cur?.endpointApiVersion ?? new rust.StructField(apiVersionParam.name, 'pubCrate', apiVersionParam.type);
+    for (const client of clients) {
+      if (!client.endpointApiVersion) {
+        client.endpointApiVersion = apiVersionField;
+        client.fields.push(apiVersionField);
+      }
+    }
+    return apiVersionField;
+  }
+
+  /**
+   * reports diagnostics for @added and @removed decorators on model properties and enum values.
+   * models are serialized into the request content before the method is called, so their use
+   * can't be checked against the client's API version like methods and params are.
+   *
+   * @param raw the tsp type that might contain the decorators
+   * @param wireName the name sent on the wire
+   */
+  private reportVersionedAvailability(raw: tsp.Type | undefined, wireName: string): void {
+    if (raw?.kind !== 'ModelProperty' && raw?.kind !== 'EnumMember') {
+      return;
+    }
+
+    const added = versioning.getAddedOnVersions(this.ctx.program, raw);
+    const removed = versioning.getRemovedOnVersions(this.ctx.program, raw);
+    if (added || removed) {
+      const changes = new Array<string>();
+      if (added) {
+        changes.push(`added in api-version ${added.map((version) => version.value).join(', ')}`);
+      }
+      if (removed) {
+        changes.push(`removed in api-version ${removed.map((version) => version.value).join(', ')}`);
+      }
+      this.ctx.program.reportDiagnostic({
+        code: 'UnsupportedVersionedChange',
+        severity: 'warning',
+        message: `${raw.kind === 'ModelProperty' ? 'property' : 'enum value'} ${wireName} was ${changes.join(' and ')}; its use isn't checked against the client's api-version`,
+        target: raw.node ?? tsp.NoTarget,
+      });
+    }
+  }
+
+  /**
+   * moves the method's optional conditional request headers (e.g. If-Match) from
+   * the method options into a MatchConditions or RequestConditions field.
+   * MatchConditions is used when the method only has ETag conditions.
+   *
+   * @param rustClient the client that contains the method
+   * @param rustMethod the method whose conditional request headers to group
+   */
+  private adaptRequestConditions(rustClient: rust.Client, rustMethod: MethodType): void {
+    const conditionalParams = new Array<rust.HeaderScalarParameter>();
+    let hasDateConditions = false;
+    for (const param of rustMethod.params) {
+      if (param.kind !== 'headerScalar' || !param.optional || param.location !== 'method') {
+        continue;
+      }
+
+      switch (param.header.toLowerCase()) {
+        case 'if-match':
+        case 'if-none-match':
+          if (param.type.kind !== 'String' && param.type.kind !== 'Etag') {
+            continue;
+          }
+          break;
+        case 'if-modified-since':
+        case 'if-unmodified-since':
+          // the conditions types always send dates in the HTTP date format
+          if (param.type.kind !== 'offsetDateTime' || param.type.encoding !== 'rfc7231') {
+            continue;
+          }
+          hasDateConditions = true;
+          break;
+        default:
+          continue;
+      }
+      conditionalParams.push(param);
+    }
+
+    if (conditionalParams.length === 0) {
+      return;
+    }
+
+    const conditions = this.getRequestConditions(hasDateConditions ? 'RequestConditions' : 'MatchConditions', rustClient.module);
+    const optionsFields = rustMethod.options.type.type.fields;
+    for (const param of conditionalParams) {
+      // the header's value comes from the conditions field
+      const fieldIndex = optionsFields.findIndex((field) => field.name === param.name);
+      if (fieldIndex < 0) {
+        throw new AdapterError('InternalError', `didn't find options field ${param.name} for method ${rustMethod.name}`);
+      }
+      optionsFields.splice(fieldIndex, 1);
+      param.name = param.header.toLowerCase().replace(/-/g, '_');
+      if (param.type.kind === 'String') {
+        param.type = this.getEtag();
+      }
+      param.conditions = conditions;
+    }
+
+    const conditionsField = new rust.StructField(utils.snakeCaseName(conditions.name), rustMethod.visibility, conditions);
+    conditionsField.docs.summary = 'Conditions that must be met for the request to proceed.';
+    conditionsField.docs.description = `When the conditions aren't met, the returned error can be converted into a ${this.asDocLink('ConditionalRequestError', `${utils.buildImportPath(rustClient.module, rustClient.module)}::models::ConditionalRequestError`)}.`;
+
+    // the conditions types are shared across methods, so they can contain conditions
+    // this method doesn't send. setting one of those is rejected when the method is called.
+    const conditionsCount = conditions.name === 'RequestConditions' ? 4 : 2;
+    if (conditionalParams.length < conditionsCount) {
+      const supported = conditionalParams.map((param) => `\`${param.name}\``).sort();
+      const last = supported.pop();
+      const conditionsText = supported.length > 0 ? `${supported.join(', ')} and ${last} conditions` : `${last} condition`;
+      conditionsField.docs.description += `\n\nThis method only supports the ${conditionsText}. Setting any other condition returns an error.`;
+    }
+    optionsFields.push(conditionsField);
+  }
+
+  /**
+   * makes the Repeatability-Request-ID and Repeatability-First-Sent headers optional.
+   * when the caller doesn't provide a value, one is generated when the request is created.
+   *
+   * @param rustMethod the method whose repeatability headers to adapt
+   */
+  private adaptRepeatabilityHeaders(rustMethod: MethodType): void {
+    if (rustMethod.httpMethod !== 'post' && rustMethod.httpMethod !== 'put') {
+      return;
+    }
+
+    for (const param of rustMethod.params) {
+      if (param.kind !== 'headerScalar' || param.location !== 'method') {
+        continue;
+      }
+
+      let repeatability: rust.RepeatabilityHeader;
+      let description: string;
+      switch (param.header.toLowerCase()) {
+        case 'repeatability-first-sent':
+          if (param.type.kind !== 'offsetDateTime') {
+            continue;
+          }
+          repeatability = 'firstSent';
+          description = 'When not set, the current time is used.';
+          break;
+        case 'repeatability-request-id':
+          if (param.type.kind !== 'String') {
+            continue;
+          }
+          repeatability = 'requestId';
+          description = 'When not set, a new UUID is generated.';
+          break;
+        default:
+          continue;
+      }
+
+      // the generated value is set on the request before it's
+      // sent so it doesn't change when the request is retried.
+      description += ' The same value is sent when the request is retried.';
+
+      let optionsField = rustMethod.options.type.type.fields.find((field) => field.name === param.name);
+      if (!optionsField) {
+        optionsField = new rust.StructField(param.name, rustMethod.visibility, this.getOptionType(param.type));
+        rustMethod.options.type.type.fields.push(optionsField);
+      }
+      optionsField.docs = {
+        summary: param.docs.summary,
+        description: param.docs.description ? `${param.docs.description}\n\n${description}` : description,
+      };
+
+      param.optional = true;
+      param.repeatability = repeatability;
+    }
+  }
+
+  /**
+   * recursively converts a client and its methods.
+   * this simplifies the case for hierarchical clients.
+   *
+   * @param client the tcgc client to recursively convert
+   * @param parent contains the parent Rust client when converting a child client
+   * @returns a Rust client
+   */
+  private recursiveAdaptClient(client: tcgc.SdkClientType<tcgc.SdkHttpOperation>, parent?: rust.Client): rust.Client {
+    let clientName = client.name;
+    // NOTE: if the client has the @clientName decorator applied then use that verbatim
+    if (parent && !hasClientNameDecorator(client.decorators)) {
+      // for hierarchical clients, the child client names are built
+      // from the parent client name. this is because tsp allows subclients
+      // with the same name. consider the following example.
+      //
+      // namespace Chat {
+      //   interface Completions {
+      //     ...
+      //   }
+      // }
+      // interface Completions { ... }
+      //
+      // we want to generate two clients from this,
+      // one name ChatCompletions and the other Completions
+
+      // strip off the Client suffix from the parent client name
+      clientName = parent.name.substring(0, parent.name.length - 6) + clientName;
+    }
+
+    if (!clientName.match(/Client$/)) {
+      clientName += 'Client';
+    }
+
+    const rustClient = new rust.Client(clientName, this.adaptNamespace(client.namespace));
+    rustClient.docs = this.adaptDocs(client.summary, client.doc);
+    rustClient.parent = parent;
+    rustClient.apiVersions = client.apiVersions;
+    rustClient.fields.push(new rust.StructField('pipeline', 'pubCrate', new rust.ExternalType(this.crate, 'Pipeline', 'azure_core::http')));
+
+    // InitializedByFlags.CustomizeCode means the client is instantiable
+    // but the constructor is to be omitted (i.e. hand-written).
+    if (client.clientInitialization.initializedBy === tcgc.InitializedByFlags.CustomizeCode || client.clientInitialization.initializedBy & tcgc.InitializedByFlags.Individually) {
+      const clientOptionsStruct = new rust.Struct(`${rustClient.name}Options`, 'pub');
+      const clientOptionsField = new rust.StructField('client_options', 'pub', new rust.ExternalType(this.crate, 'ClientOptions', 'azure_core::http'));
+      clientOptionsField.docs.summary = 'Allows customization of the client.';
+      clientOptionsField.defaultValue = 'ClientOptions::default()';
+      clientOptionsStruct.fields.push(clientOptionsField);
+      rustClient.constructable = new rust.ClientConstruction(new rust.ClientOptions(clientOptionsStruct));
+      clientOptionsStruct.docs.summary = `Options used when creating a ${this.asDocLink(rustClient.name, rustClient.name)}`;
+
+      if (this.options['omit-constructors']) {
+        rustClient.constructable.suppressed = 'yes';
+      } else if (client.clientInitialization.initializedBy === tcgc.InitializedByFlags.CustomizeCode) {
+        rustClient.constructable.suppressed = 'ctor';
+      }
+
+      // NOTE: per tcgc convention, if there is no param of kind credential
+      // it means that the client doesn't require any kind of authentication.
+      // HOWEVER, if there *is* a credential param, then the client *does not*
+      // automatically support unauthenticated requests. a credential with
+      // the noAuth scheme indicates support for unauthenticated requests.
+
+      // bit flags for auth types
+      enum AuthTypes {
+        Default = 0, // unspecified
+        NoAuth = 1, // explicit NoAuth
+        OAuth2 = 2, // explicit OAuth2
+        WithAuth = 4, // explicit, unsupported credential
+        KeyCredential = 8, // explicit ApiKey or HTTP scheme that uses a key
+        BasicCredential = 16, // explicit HTTP Basic
+      }
+
+      let authType = AuthTypes.Default;
+
+      /**
+       * reports a key credential scheme that's ignored because the client
+       * already has a with_key_credential constructor for another scheme.
+       *
+       * @param param the credential param for the ignored scheme
+       * @param scheme describes the ignored scheme
+       */
+      const reportDroppedKeyCredential = (param: tcgc.SdkCredentialParameter, scheme: string): void => {
+        this.ctx.program.reportDiagnostic({
+          code: 'UnsupportedAuthenticationScheme',
+          severity: 'warning',
+          message: `authentication scheme ${scheme} is not supported as the client already uses another key credential scheme`,
+          target: param.__raw?.node ?? tsp.NoTarget,
+        });
+      };
+
+      /**
+       * processes a credential, potentially adding its supporting client constructor
+       *
+       * @param rustClient the client for which the constructor will be added
+       * @param param the tsp parameter that contains cred
+       * @param cred the credential type to process
+       * @param constructable the constructable for the current Rust client
+       * @returns the AuthTypes enum for the credential that was handled, or AuthTypes.Default if none were
+       */
+      const processCredential = (rustClient: rust.Client, param: tcgc.SdkCredentialParameter, cred: http.HttpAuth, constructable: rust.ClientConstruction): AuthTypes => {
+        switch (cred.type) {
+          case 'noAuth':
+            return AuthTypes.NoAuth;
+          case 'oauth2': {
+            if ((authType & AuthTypes.OAuth2) === 0) {
+              // tsp can describe multiple oauth2 credential flow in a union.
+              // since each flow is implicitly handled via the credential, we
+              // only need to emit one ctor for the oauth2 type.
+              constructable.constructors.push(this.createTokenCredentialCtor(rustClient, cred));
+            }
+            return AuthTypes.OAuth2;
+          }
+          case 'apiKey': {
+            if (cred.in === 'cookie') {
+              this.ctx.program.reportDiagnostic({
+                code: 'UnsupportedAuthenticationScheme',
+                severity: 'warning',
+                message: 'authentication scheme apiKey in a cookie is not supported',
+                target: param.__raw?.node ?? tsp.NoTarget,
+              });
+              return AuthTypes.WithAuth;
+            }
+            if ((authType & AuthTypes.KeyCredential) === 0) {
+              constructable.constructors.push(this.createKeyCredentialCtor(rustClient, new rust.KeyCredential(cred.in, cred.name, rustClient.module)));
+            } else {
+              reportDroppedKeyCredential(param, `apiKey in ${cred.in} ${cred.name}`);
+            }
+            return AuthTypes.KeyCredential;
+          }
+          case 'http': {
+            if (cred.scheme.toLowerCase() === 'basic') {
+              if ((authType & AuthTypes.BasicCredential) === 0) {
+                constructable.constructors.push(this.createBasicCredentialCtor(rustClient));
+              }
+              return AuthTypes.BasicCredential;
+            }
+            // all other schemes (e.g. Bearer, SharedAccessKey) send the key in the Authorization header
+            if ((authType & AuthTypes.KeyCredential) !== 0) {
+              reportDroppedKeyCredential(param, `http ${cred.scheme}`);
+            } else {
+              const keyCredential = new rust.KeyCredential('authorization', 'authorization', rustClient.module);
+              keyCredential.scheme = cred.scheme;
+              constructable.constructors.push(this.createKeyCredentialCtor(rustClient, keyCredential));
+            }
+            return AuthTypes.KeyCredential;
+          }
+          default:
+            this.ctx.program.reportDiagnostic({
+              code: 'UnsupportedAuthenticationScheme',
+              severity: 'warning',
+              message: `authentication scheme ${cred.type} is not supported`,
+              target: param.__raw?.node ?? tsp.NoTarget,
+            });
+            return AuthTypes.WithAuth;
+        }
+      };
+
+      const ctorParams = new Array<rust.ClientParameter>();
+      for (const param of client.clientInitialization.parameters) {
+        switch (param.kind) {
+          case 'credential':
+            switch (param.type.kind) {
+              case 'credential':
+                authType |= processCredential(rustClient, param, param.type.scheme, rustClient.constructable);
+                break;
+              case 'union': {
+                for (const variantType of param.type.variantTypes) {
+                  // if OAuth2 is specified then emit that and skip any unsupported ones.
+                  // this prevents emitting the with_no_credential constructor in cases
+                  // where it might not actually be supported.
+                  authType |= processCredential(rustClient, param, variantType.scheme, rustClient.constructable);
+                }
+              }
+            }
+            break;
+          case 'endpoint': {
+            let endpointType: tcgc.SdkEndpointType;
+            switch (param.type.kind) {
+              case 'endpoint':
+                // single endpoint without any supplemental path
+                endpointType = param.type;
+                break;
+              case 'union':
                 // this is a union of endpoints. the first is the endpoint plus
                 // the supplemental path. the second is a "raw" endpoint which
                 // requires the caller to provide the complete endpoint. we only
@@ -1612,7 +2420,7 @@ export class Adapter {
                 const adaptedParam = new rust.ClientEndpointParameter(endpointName);
                 adaptedParam.docs = this.adaptDocs(param.summary, param.doc);
                 ctorParams.push(adaptedParam);
-                const endpointField = new rust.StructField(endpointName, 'pubCrate', new rust.Url(this.crate));
+                const endpointField = new rust.StructField(endpointName, 'pubCrate', this.getUrl());
                 rustClient.endpoint = endpointField;
                 rustClient.fields.push(endpointField);
 
@@ -1706,7 +2514,7 @@ export class Adapter {
             throw new AdapterError('InternalError', `didn't find constructable client for apiVersion in client ${rustClient.name}`, prop.__raw?.node);
           }
 
-          rustClient.fields.push(new rust.StructField(utils.snakeCaseName(prop.name), 'pubCrate', this.getStringType()));
+          rustClient.fields.push(new rust.StructField(utils.snakeCaseName(prop.name), 'pubCrate', this.getApiVersionType()));
 
           // we use the client name as a prefix to disambiguate the params in the parent
           const clientParam = this.adaptClientParameter(prop, constructable, utils.deduplicateClientFieldName(rustClient, name));
@@ -1733,17 +2541,6 @@ export class Adapter {
     }
 
     for (const method of client.methods) {
-      if (method.kind === 'lropaging') {
-        // skip Paging LROs for now so that codegen is unblocked
-        // TODO: https://github.com/Azure/typespec-rust/issues/188
-        this.ctx.program.reportDiagnostic({
-          code: 'LroPagingNotSupported',
-          severity: 'warning',
-          message: `skip emitting Paging LRO ${method.name}`,
-          target: method.__raw?.node ?? tsp.NoTarget,
-        });
-        continue;
-      }
       this.adaptMethod(method, rustClient);
     }
 
@@ -1780,6 +2577,43 @@ export class Adapter {
     return ctorTokenCredential;
   }
 
+  /**
+   * creates a client constructor for the KeyCredential type.
+   * the constructor is named with_key_credential.
+   *
+   * @param rustClient the client for which the constructor will be created
+   * @param keyCredential the key credential describing where the key is placed in the request
+   * @returns a client constructor for KeyCredential
+   */
+  private createKeyCredentialCtor(rustClient: rust.Client, keyCredential: rust.KeyCredential): rust.Constructor {
+    // the generated KeyCredentialPolicy implements the async Policy trait
+    this.crate.addDependency(new rust.CrateDependency('async-trait'));
+    const ctorKeyCredential = new rust.Constructor('with_key_credential');
+    const keyCredParam = new rust.ClientCredentialParameter('credential', keyCredential);
+    keyCredParam.docs.summary = 'A [`KeyCredential`] containing the key to use when authenticating.';
+    ctorKeyCredential.params.push(keyCredParam);
+    ctorKeyCredential.docs.summary = `Creates a new ${rustClient.name}, using key authentication.`;
+    return ctorKeyCredential;
+  }
+
+  /**
+   * creates a client constructor for the BasicCredential type.
+   * the constructor is named with_basic_credential.
+   *
+   * @param rustClient the client for which the constructor will be created
+   * @returns a client constructor for BasicCredential
+   */
+  private createBasicCredentialCtor(rustClient: rust.Client): rust.Constructor {
+    // the generated BasicCredentialPolicy implements the async Policy trait
+    this.crate.addDependency(new rust.CrateDependency('async-trait'));
+    const ctorBasicCredential = new rust.Constructor('with_basic_credential');
+    const basicCredParam = new rust.ClientCredentialParameter('credential', new rust.BasicCredential(rustClient.module));
+    basicCredParam.docs.summary = 'A [`BasicCredential`] containing the user name and password to use when authenticating.';
+    ctorBasicCredential.params.push(basicCredParam);
+    ctorBasicCredential.docs.summary = `Creates a new ${rustClient.name}, using HTTP Basic authentication.`;
+    return ctorBasicCredential;
+  }
+
   /**
    * converts a tcgc client parameter to a Rust client parameter
    *
@@ -1789,7 +2623,7 @@ export class Adapter {
    * @returns the Rust client parameter
    */
   private adaptClientParameter(param: tcgc.SdkMethodParameter | tcgc.SdkPathParameter, constructable: rust.ClientConstruction, name?: string): rust.ClientMethodParameter | rust.ClientSupplementalEndpointParameter {
-    let paramType: rust.Type = param.isApiVersionParam ? this.getStringType() : this.getType(param.type);
+    let paramType: rust.Type = param.isApiVersionParam ? this.getApiVersionType() : this.getType(param.type);
     const paramName = name ?? utils.snakeCaseName(param.name);
 
     let optional = false;
@@ -1804,7 +2638,7 @@ export class Adapter {
       constructable.options.type.fields.push(paramField);
       if (param.clientDefaultValue) {
         const constName = `DEFAULT_${paramName.toUpperCase()}`;
-        paramField.defaultValue = `String::from(${constName})`;
+        paramField.defaultValue = paramType.kind === 'enum' ? constName : `String::from(${constName})`;
         paramField.defaultValueConstant = { name: constName, value: <string>param.clientDefaultValue };
       }
     }
@@ -1816,6 +2650,7 @@ export class Adapter {
         break;
       case 'path':
         adaptedParam = new rust.ClientSupplementalEndpointParameter(paramName, paramType, optional, param.serializedName);
+        adaptedParam.isApiVersion = param.isApiVersionParam;
         break;
     }
 
@@ -1904,6 +2739,7 @@ export class Adapter {
         clientMethodOptions = new rust.PagerOptions(this.crate, optionsLifetime, 'nextLink');
         break;
       case 'lro':
+      case 'lropaging':
         clientMethodOptions = new rust.PollerOptions(this.crate, optionsLifetime);
         break;
       default:
@@ -1925,8 +2761,10 @@ export class Adapter {
       case 'paging':
         rustMethod = new rust.PageableMethod(methodName, languageIndependentName, rustClient, pub, methodOptions, httpMethod, method.operation.path);
         break;
-      case 'lro': {
+      case 'lro':
+      case 'lropaging': {
         let lroFinalResultStrategy: rust.LroFinalResultStrategyKind = new rust.LroFinalResultStrategyOriginalUri();
+        let lroStatusMonitor: rust.LroStatusMonitorKind | undefined;
         if (method.lroMetadata.finalStateVia !== FinalStateValue.originalUri) {
           switch (method.lroMetadata.finalStateVia) {
             case FinalStateValue.operationLocation:
@@ -1944,18 +2782,36 @@ export class Adapter {
               // https://github.com/microsoft/typespec/blob/f3d792b252c6f40be0e174496d9f34d453676026/packages/http-client-csharp/emitter/src/type/operation-final-state-via.ts#L23-L27
               lroFinalResultStrategy = new rust.LroFinalResultStrategyHeader('operation-location');
               break;
-            case FinalStateValue.customLink:
-              if (method.lroMetadata.statusMonitorStep?.kind === "nextOperationLink") {
-                const customHeaderName = method.lroMetadata.statusMonitorStep.target.property.name;
-                if (customHeaderName.trim() === '') {
-                  throw new AdapterError('UnsupportedTsp', `lroMetadata.finalStateVia === customLink && customHeaderName === "${customHeaderName}"`, method.__raw?.node);
-                }
+            case FinalStateValue.customLink: {
+              // the final result is read from the status monitor
+              lroFinalResultStrategy = new rust.LroFinalResultStrategyStatusMonitor();
+              const statusMonitorStep = method.lroMetadata.statusMonitorStep;
+              if (statusMonitorStep?.kind === 'nextOperationReference') {
+                // the status monitor is fetched with a polling operation whose URL
+                // is returned in the operation-location header (see customOperationReference)
+                lroStatusMonitor = new rust.LroStatusMonitorHeader('operation-location');
+                break;
+              } else if (statusMonitorStep?.kind !== 'nextOperationLink') {
+                this.ctx.program.reportDiagnostic({
+                  code: 'UnsupportedLroStatusMonitor',
+                  severity: 'warning',
+                  message: `the link to the status monitor of long-running method ${method.name} isn't defined; it's read from the well-known polling headers`,
+                  target: method.__raw?.node ?? tsp.NoTarget,
+                });
+                break;
+              }
 
-                lroFinalResultStrategy = new rust.LroFinalResultStrategyHeader(customHeaderName);
+              // the link to the status monitor is either a response header or a field in the response body
+              const linkProperty = statusMonitorStep.target.property;
+              const linkHeaderName = http.getHeaderFieldName(this.ctx.program, linkProperty);
+              if (linkHeaderName) {
+                // HeaderName::from_static() requires lower-case header names
+                lroStatusMonitor = new rust.LroStatusMonitorHeader(linkHeaderName.toLowerCase());
               } else {
-                throw new AdapterError('UnsupportedTsp', `lroMetadata.finalStateVia === customLink && lroMetadata.statusMonitorStep?.kind === "${method.lroMetadata.statusMonitorStep?.kind}"`, method.__raw?.node);
+                lroStatusMonitor = new rust.LroStatusMonitorBody(tsp.resolveEncodedName(this.ctx.program, linkProperty, 'application/json'));
               }
               break;
+            }
             default:
               /* eslint-disable-next-line @typescript-eslint/restrict-template-expressions */
               throw new AdapterError('UnsupportedTsp', `lroMetadata.finalStateVia ${method.lroMetadata.finalStateVia} NYI`, method.__raw?.node);
@@ -1963,7 +2819,9 @@ export class Adapter {
 
           lroFinalResultStrategy.propertyName = method.lroMetadata.finalResultPath;
         }
-        rustMethod = new rust.LroMethod( methodName, languageIndependentName, rustClient, pub, methodOptions, httpMethod, method.operation.path, lroFinalResultStrategy);
+        const lroMethod = new rust.LroMethod( methodName, languageIndependentName, rustClient, pub, methodOptions, httpMethod, method.operation.path, lroFinalResultStrategy);
+        lroMethod.statusMonitor = lroStatusMonitor;
+        rustMethod = lroMethod;
       }
         break;
       default:
@@ -1971,6 +2829,7 @@ export class Adapter {
     }
 
     rustMethod.docs = this.adaptDocs(method.summary, method.doc);
+    rustMethod.apiVersions = getAvailableApiVersions(rustClient.apiVersions, method.apiVersions);
     rustClient.methods.push(rustMethod);
 
     // stuff all of the operation parameters into one array for easy traversal
@@ -2000,6 +2859,7 @@ export class Adapter {
       }
 
       adaptedParam.docs = this.adaptDocs(methodParam.summary, methodParam.doc);
+      adaptedParam.apiVersions = getAvailableApiVersions(rustMethod.apiVersions ?? rustClient.apiVersions, methodParam.apiVersions);
       rustMethod.params.push(adaptedParam);
 
       // we specially handle an optional content-type header to ensure it's omitted
@@ -2162,6 +3022,10 @@ export class Adapter {
       }
     }
 
+    this.adaptRequestConditions(rustClient, rustMethod);
+    this.adaptRepeatabilityHeaders(rustMethod);
+    this.adaptRenamedParams(rustClient, rustMethod, allOpParams);
+
     const getResponseFormat = (): rust.PayloadFormatType => {
       // fetch the body format from the HTTP responses.
       // they should all have the same type so no need to match responses to type.
@@ -2231,7 +3095,12 @@ export class Adapter {
       }
     }
 
-    const responseFormat = getResponseFormat();
+    let responseFormat = getResponseFormat();
+    const itemStream = this.adaptItemStream(method, rustClient.module);
+    if (!itemStream && method.kind === 'basic' && method.operation.responses.some((httpResp) => getStreamFormat(httpResp.defaultContentType))) {
+      // unsupported streaming responses fall back to the raw response body
+      responseFormat = 'BinaryFormat';
+    }
 
     if (method.kind === 'paging') {
       if (responseFormat !== 'JsonFormat' && responseFormat !== 'XmlFormat') {
@@ -2253,62 +3122,12 @@ export class Adapter {
         modelNs.models.push(synthesizedModel);
       }
 
-      // for the pager response type, remove the Option<T> around the Vec<T> for the page items
-      if (!method.pagingMetadata.pageItemsSegments) {
-        throw new AdapterError('InternalError', `paged method ${method.name} has no pageItemsSegments`, method.__raw?.node);
-      }
-
-      // unwrap all of the segments for the paged response
-      let unwrappedCount = 0;
-      let typeToUnwrap = synthesizedModel;
-      for (const pageItemsSegment of method.pagingMetadata.pageItemsSegments) {
-        const segment = pageItemsSegment;
-        let serde: string;
-        switch (responseFormat) {
-          case 'JsonFormat':
-            if (segment.kind !== 'property' || !segment.serializationOptions.json) {
-              throw new AdapterError('InternalError', `paged method ${method.name} is missing JSON serialization data`, method.__raw?.node);
-            }
-            serde = segment.serializationOptions.json.name;
-            break;
-          case 'XmlFormat':
-            if (segment.kind !== 'property' || !segment.serializationOptions.xml) {
-              throw new AdapterError('InternalError', `paged method ${method.name} is missing XML serialization data`, method.__raw?.node);
-            }
-            serde = segment.serializationOptions.xml.name;
-            break;
-        }
-
-        for (let i = 0; i < typeToUnwrap.fields.length; ++i) {
-          const field = typeToUnwrap.fields[i];
-          if (field.kind === 'additionalProperties') {
-            continue;
-          }
-          if (field.serde === serde) {
-            // check if this has already been unwrapped (e.g. type is shared across operations)
-            if (field.type.kind === 'option') {
-              field.type = <rust.WireType>(field.type).type;
-              field.flags |= rust.ModelFieldFlags.PageItems;
-            }
-
-            // move to the next segment
-            if (field.type.kind === 'model') {
-              typeToUnwrap = field.type;
-            }
-            ++unwrappedCount;
-            break;
-          }
-        }
-      }
-
-      if (unwrappedCount !== method.pagingMetadata.pageItemsSegments.length) {
-        throw new AdapterError('InternalError', `failed to unwrap paged items for method ${method.name}`, method.__raw?.node);
-      }
+      this.unwrapPageItems(method, synthesizedModel, responseFormat);
 
       this.crate.addDependency(new rust.CrateDependency('async-trait'));
       // default to nextLink. will update it as required when we have that info
       rustMethod.returns = new rust.Result(this.crate, new rust.Pager(this.crate, new rust.Response(this.crate, synthesizedModel, responseFormat), 'nextLink'));
-    } else if (method.kind === 'lro') {
+    } else if (method.kind === 'lro' || method.kind === 'lropaging') {
       const pushModels = (
         tcgcType: tcgc.SdkType,
         container: rust.ModuleContainer,
@@ -2341,6 +3160,9 @@ export class Adapter {
       }
 
       const format: rust.ModelPayloadFormatType = responseFormat === 'JsonFormat' || responseFormat === 'XmlFormat' ? responseFormat : 'JsonFormat';
+      if (rustMethod.kind === 'lro' && rustMethod.statusMonitor?.kind === 'body' && format !== 'JsonFormat') {
+        throw new AdapterError('UnsupportedTsp', `LRO method ${method.name} has a status monitor link in a ${format} response body`, method.__raw?.node);
+      }
 
       const statusModel = this.getModel(method.lroMetadata.pollingInfo.responseModel, undefined, `${rustClient.name}${utils.pascalCase(rustMethod.name, false)}OperationStatus`);
       const statusType = this.typeToWireType(statusModel);
@@ -2357,13 +3179,27 @@ export class Adapter {
       pushModels(method.lroMetadata.pollingInfo.responseModel, rustClient.module, statusModel, false);
 
       const poller = new rust.Poller(this.crate, new rust.Response(this.crate, statusType, format));
-      if (method.response.type) {
+      if (method.kind === 'lropaging') {
+        // the final result is the first page of results. like paged methods,
+        // we want the page envelope type instead of the Array<T> from tcgc.
+        const envelopeType = method.lroMetadata.finalResponse?.envelopeResult;
+        if (!envelopeType) {
+          throw new AdapterError('InternalError', `paged LRO method ${method.name} has no final response envelope type`, method.__raw?.node);
+        }
+        const pageModel = this.getModel(envelopeType, new Array<rust.Type>());
+        pushModels(envelopeType, rustClient.module, pageModel);
+        this.unwrapPageItems(method, pageModel, format);
+        this.crate.addDependency(new rust.CrateDependency('async-trait'));
+        poller.resultType = new rust.Response(this.crate, pageModel, format);
+      } else if (method.response.type) {
         const resultType = this.getType(method.response.type);
         pushModels(method.response.type, rustClient.module, resultType);
         poller.resultType = new rust.Response(this.crate, this.typeToWireType(resultType), format);
       }
 
       rustMethod.returns = new rust.Result(this.crate, poller);
+    } else if (itemStream) {
+      rustMethod.returns = new rust.Result(this.crate, itemStream);
     } else if (method.response.type && responseFormat !== 'BinaryFormat') {
       const response = new rust.Response(this.crate, this.typeToWireType(this.getType(method.response.type)), responseFormat);
       rustMethod.returns = new rust.Result(this.crate, response);
@@ -2388,11 +3224,13 @@ export class Adapter {
       rustMethod.returns = new rust.Result(this.crate, new rust.Response(this.crate, this.getUnitType(), responseFormat));
     }
 
+    this.adaptWireChanges(rustClient, rustMethod, method, allOpParams);
+
     // Mark the set of success status codes expected from this method.
     rustMethod.statusCodes = getStatusCodes(method.operation);
 
     // For long running operations, we add 200 OK if not already present for the LRO polling and terminal states.
-    if (method.kind === 'lro') {
+    if (method.kind === 'lro' || method.kind === 'lropaging') {
       if (!rustMethod.statusCodes.includes(200)) {
         rustMethod.statusCodes.push(200);
       }
@@ -2402,12 +3240,22 @@ export class Adapter {
     const responseHeadersMap = this.adaptResponseHeaders(responseHeaders);
     rustMethod.responseHeaders = this.adaptResponseHeadersTrait(rustClient, rustMethod, Array.from(responseHeadersMap.values()));
 
+    if (this.options['emit-error-traits']) {
+      rustMethod.errors = this.adaptMethodErrors(rustClient, rustMethod, method, `${rustClient.name}${utils.pascalCase(srcMethodName, false)}Error`);
+    }
+
     if (method.kind === 'paging') {
       // can't do this until the method has been completely adapted
       const pageableMethod = <rust.PageableMethod>rustMethod;
-      pageableMethod.strategy = this.adaptPageableMethodStrategy(method, paramsMap, responseHeadersMap);
-      if (pageableMethod.strategy?.kind === 'nextLink') {
-        pageableMethod.strategy.reinjectedParams = this.adaptPageableMethodReinjectionParams(method, paramsMap);
+      pageableMethod.strategy = this.adaptPageableMethodStrategy(method, pageableMethod.params, paramsMap, responseHeadersMap);
+      if (pageableMethod.strategy?.kind === 'nextLink' || pageableMethod.strategy?.kind === 'nextLinkOperation') {
+        // the next page operation's query params have already been added to the strategy
+        const reinjectedParams = pageableMethod.strategy.reinjectedParams;
+        for (const reinjectedParam of this.adaptPageableMethodReinjectionParams(method, paramsMap)) {
+          if (!reinjectedParams.includes(reinjectedParam)) {
+            reinjectedParams.push(reinjectedParam);
+          }
+        }
       } else if (pageableMethod.strategy?.kind === 'continuationToken') {
         // set the continuation type to token on the Pager and the PagerOptions field in the method options
         pageableMethod.returns.type.continuation = 'token';
@@ -2417,6 +3265,80 @@ export class Adapter {
           }
         }
       }
+    } else if (method.kind === 'lropaging') {
+      const lroMethod = <rust.LroMethod>rustMethod;
+      const pageStrategy = this.adaptPageableMethodStrategy(method, lroMethod.params, paramsMap, responseHeadersMap);
+      if (pageStrategy?.kind === 'continuationToken') {
+        throw new AdapterError('UnsupportedTsp', `continuation tokens for paged LRO method ${method.name} NYI`, method.__raw?.node);
+      } else if (pageStrategy?.kind === 'nextLinkOperation') {
+        throw new AdapterError('UnsupportedTsp', `next page operation for paged LRO method ${method.name} NYI`, method.__raw?.node);
+      }
+      lroMethod.pageStrategy = pageStrategy;
+      if (pageStrategy) {
+        pageStrategy.reinjectedParams = this.adaptPageableMethodReinjectionParams(method, paramsMap);
+      }
+    }
+  }
+
+  /**
+   * removes the Option<T> around the fields that lead to the page items
+   * in the synthesized paged response type and flags them as page items.
+   * 
+   * @param method the paged method
+   * @param synthesizedModel the paged response envelope type
+   * @param responseFormat the format of the paged response
+   */
+  private unwrapPageItems(method: tcgc.SdkPagingServiceMethod<tcgc.SdkHttpOperation> | tcgc.SdkLroPagingServiceMethod<tcgc.SdkHttpOperation>, synthesizedModel: rust.Model, responseFormat: rust.ModelPayloadFormatType): void {
+    // for the pager response type, remove the Option<T> around the Vec<T> for the page items
+    if (!method.pagingMetadata.pageItemsSegments) {
+      throw new AdapterError('InternalError', `paged method ${method.name} has no pageItemsSegments`, method.__raw?.node);
+    }
+
+    // unwrap all of the segments for the paged response
+    let unwrappedCount = 0;
+    let typeToUnwrap = synthesizedModel;
+    for (const pageItemsSegment of method.pagingMetadata.pageItemsSegments) {
+      const segment = pageItemsSegment;
+      let serde: string;
+      switch (responseFormat) {
+        case 'JsonFormat':
+          if (segment.kind !== 'property' || !segment.serializationOptions.json) {
+            throw new AdapterError('InternalError', `paged method ${method.name} is missing JSON serialization data`, method.__raw?.node);
+          }
+          serde = segment.serializationOptions.json.name;
+          break;
+        case 'XmlFormat':
+          if (segment.kind !== 'property' || !segment.serializationOptions.xml) {
+            throw new AdapterError('InternalError', `paged method ${method.name} is missing XML serialization data`, method.__raw?.node);
+          }
+          serde = segment.serializationOptions.xml.name;
+          break;
+      }
+
+      for (let i = 0; i < typeToUnwrap.fields.length; ++i) {
+        const field = typeToUnwrap.fields[i];
+        if (field.kind === 'additionalProperties') {
+          continue;
+        }
+        if (field.serde === serde) {
+          // check if this has already been unwrapped (e.g. type is shared across operations)
+          if (field.type.kind === 'option') {
+            field.type = <rust.WireType>(field.type).type;
+            field.flags |= rust.ModelFieldFlags.PageItems;
+          }
+
+          // move to the next segment
+          if (field.type.kind === 'model') {
+            typeToUnwrap = field.type;
+          }
+          ++unwrappedCount;
+          break;
+        }
+      }
+    }
+
+    if (unwrappedCount !== method.pagingMetadata.pageItemsSegments.length) {
+      throw new AdapterError('InternalError', `failed to unwrap paged items for method ${method.name}`, method.__raw?.node);
     }
   }
 
@@ -2450,6 +3372,63 @@ export class Adapter {
     return responseHeadersMap;
   }
 
+  /**
+   * returns an ItemStream for methods that return server-sent
+   * events or JSON Lines. for all other methods, undefined is returned.
+   * a warning is reported for streaming responses that aren't supported.
+   * 
+   * @param method the method for which to adapt the streaming response
+   * @param module the module that contains the method's client
+   * @returns an ItemStream or undefined
+   */
+  private adaptItemStream(method: tcgc.SdkServiceMethod<tcgc.SdkHttpOperation>, module: rust.ModuleContainer): rust.ItemStream | undefined {
+    for (const httpResp of method.operation.responses) {
+      const format = getStreamFormat(httpResp.defaultContentType);
+      if (!format) {
+        continue;
+      }
+
+      if (method.kind !== 'basic') {
+        this.ctx.program.reportDiagnostic({
+          code: 'UnsupportedItemStream',
+          severity: 'warning',
+          message: `streaming response for ${method.kind} method ${method.name} isn't supported; the response is adapted as a non-streaming response`,
+          target: method.__raw?.node ?? tsp.NoTarget,
+        });
+        return undefined;
+      }
+
+      // tcgc models the response body as a string. the
+      // item type comes from the @streamOf decorator.
+      const rawType = httpResp.__raw.type;
+      const itemType = rawType.kind === 'Model' ? streams.getStreamOf(this.ctx.program, rawType) : undefined;
+      if (!itemType) {
+        this.ctx.program.reportDiagnostic({
+          code: 'UnsupportedItemStream',
+          severity: 'warning',
+          message: `streaming response for method ${method.name} has no @streamOf item type; the method returns the raw response body`,
+          target: method.__raw?.node ?? tsp.NoTarget,
+        });
+        return undefined;
+      }
+
+      const rustType = this.typeToWireType(this.getType(tcgc.getClientType(this.ctx, itemType)));
+      if (rustType.kind === 'model') {
+        // the item type isn't part of the operation's signature
+        // so it might not have been included as an output type
+        rustType.flags |= rust.ModelFlags.Output;
+        if (!rustType.module.models.includes(rustType)) {
+          rustType.module.models.push(rustType);
+        }
+      }
+
+      this.crate.addDependency(new rust.CrateDependency('futures'));
+      return new rust.ItemStream(format, rustType, module);
+    }
+
+    return undefined;
+  }
+
   /**
    * creates a Rust ResponseHeadersTrait for the specified response headers.
    * if there are no response headers, undefined is returned.
@@ -2511,6 +3490,9 @@ export class Adapter {
             throw new AdapterError('InternalError', `unexpected trait impl content kind ${method.returns.type.content.kind}`);
         }
         break;
+      case 'itemStream':
+        // the stream only yields the items so there's nowhere to surface the headers
+        return undefined;
       case 'asyncResponse':
         switch (method.returns.type.type.kind) {
           case 'marker':
@@ -2532,6 +3514,44 @@ export class Adapter {
     return responseHeadersTrait;
   }
 
+  /**
+   * creates the enum for accessing the typed error responses of a method.
+   * only error responses with a specific status code and error model are included.
+   * 
+   * @param client the client that contains the method
+   * @param method the Rust method for which to create the enum
+   * @param srcMethod the tcgc method that contains the error responses
+   * @param name the name of the enum
+   * @returns the enum or undefined if the method has no typed error responses
+   */
+  private adaptMethodErrors(client: rust.Client, method: MethodType, srcMethod: tcgc.SdkServiceMethod<tcgc.SdkHttpOperation>, name: string): rust.MethodErrors | undefined {
+    const variants = new Array<rust.MethodErrorVariant>();
+    for (const exception of srcMethod.operation.exceptions) {
+      // status code ranges, uncommon status codes, and the default error response are surfaced as untyped errors
+      if (typeof exception.statusCodes !== 'number' || !statusCodeNames[exception.statusCodes] || exception.type?.kind !== 'model') {
+        continue;
+      } else if (exception.type.external || tcgc.isAzureCoreModel(exception.type) || isPolymorphicRoot(exception.type)) {
+        continue;
+      }
+
+      const model = this.getModel(exception.type);
+      if ((model.flags & rust.ModelFlags.Error) === 0) {
+        continue;
+      }
+      variants.push(new rust.MethodErrorVariant(statusCodeNames[exception.statusCodes], exception.statusCodes, model));
+    }
+
+    if (variants.length === 0) {
+      return undefined;
+    }
+
+    // NOTE: the complete doc text will be emitted at codegen time
+    const docs = this.asDocLink(`${client.name}::${method.name}()`, `${utils.buildImportPath(client.module, client.module)}::clients::${client.name}::${method.name}()`);
+    const methodErrors = new rust.MethodErrors(name, docs, method.visibility, client.module);
+    methodErrors.variants.push(...variants.sort((a, b) => a.statusCode - b.statusCode));
+    return methodErrors;
+  }
+
   /**
    * creates the pageable strategy based on the method definition
    * 
@@ -2540,7 +3560,7 @@ export class Adapter {
    * @param respHeadersMap maps tcgc response headers to Rust response headers (needed for continuation token strategy)
    * @returns the pageable strategy
    */
-  private adaptPageableMethodStrategy(method: tcgc.SdkPagingServiceMethod<tcgc.SdkHttpOperation>, paramsMap: Map<tcgc.SdkMethodParameter, rust.HeaderScalarParameter | QueryParamType>, respHeadersMap: Map<tcgc.SdkServiceResponseHeader, rust.ResponseHeader>): rust.PageableStrategyKind | undefined {
+  private adaptPageableMethodStrategy(method: tcgc.SdkPagingServiceMethod<tcgc.SdkHttpOperation> | tcgc.SdkLroPagingServiceMethod<tcgc.SdkHttpOperation>, methodParams: Array<rust.MethodParameter>, paramsMap: Map<tcgc.SdkMethodParameter, rust.HeaderScalarParameter | QueryParamType>, respHeadersMap: Map<tcgc.SdkServiceResponseHeader, rust.ResponseHeader>): rust.PageableStrategyKind | undefined {
     const buildNextLinkPath = (segments: Array<tcgc.SdkServiceResponseHeader | tcgc.SdkModelPropertyType>): Array<rust.ModelField> => {
       // build the field path for the next link segments
       const nextLinkPath = new Array<rust.ModelField>();
@@ -2560,8 +3580,10 @@ export class Adapter {
     };
 
     if (method.pagingMetadata.nextLinkOperation) {
-      // TODO: https://github.com/Azure/autorest.rust/issues/103
-      throw new AdapterError('UnsupportedTsp', 'next page operation NYI', method.__raw?.node);
+      if (!method.pagingMetadata.nextLinkSegments) {
+        throw new AdapterError('InternalError', `missing next link segments for operation ${method.name}`, method.__raw?.node);
+      }
+      return this.adaptNextLinkOperationStrategy(method, method.pagingMetadata.nextLinkOperation, buildNextLinkPath(method.pagingMetadata.nextLinkSegments), methodParams);
     } else if (method.pagingMetadata.nextLinkSegments) {
       return new rust.PageableStrategyNextLink(buildNextLinkPath(method.pagingMetadata.nextLinkSegments));
     } else if (method.pagingMetadata.continuationTokenParameterSegments && method.pagingMetadata.continuationTokenResponseSegments) {
@@ -2600,17 +3622,98 @@ export class Adapter {
           if (tokenHeader.kind !== 'responseHeaderScalar') {
             throw new AdapterError('InternalError', `unexpected response header kind ${tokenHeader.kind}`);
           }
-          responseToken = tokenHeader;
+          responseToken = tokenHeader;
+          break;
+        }
+        default:
+          throw new AdapterError('InternalError', `missing continuation token`, method.__raw?.node);
+      }
+      return new rust.PageableStrategyContinuationToken(requestToken, responseToken);
+    } else {
+      // operation is pageable but doesn't yet support fetching subsequent pages
+      return undefined;
+    }
+  }
+
+  /**
+   * creates the strategy for a pageable method that fetches subsequent pages
+   * with a dedicated operation. the params for the next page operation are
+   * matched to the pageable method's params by their location on the wire.
+   * 
+   * @param method the pageable method
+   * @param nextLinkOperation the operation that fetches subsequent pages
+   * @param nextLinkPath the field path in the response that contains the next link
+   * @param methodParams the adapted params for the pageable method
+   * @returns the next link operation strategy
+   */
+  private adaptNextLinkOperationStrategy(method: tcgc.SdkPagingServiceMethod<tcgc.SdkHttpOperation> | tcgc.SdkLroPagingServiceMethod<tcgc.SdkHttpOperation>, nextLinkOperation: tcgc.SdkServiceMethod<tcgc.SdkHttpOperation>, nextLinkPath: Array<rust.ModelField>, methodParams: Array<rust.MethodParameter>): rust.PageableStrategyNextLinkOperation {
+    const strategy = new rust.PageableStrategyNextLinkOperation(nextLinkPath, nextLinkOperation.operation.verb);
+
+    const nextLinkOpParams = new Array<tcgc.SdkHttpParameter>(...nextLinkOperation.operation.parameters);
+    if (nextLinkOperation.operation.bodyParam) {
+      nextLinkOpParams.push(nextLinkOperation.operation.bodyParam);
+    }
+
+    for (const opParam of nextLinkOpParams) {
+      let matchingParam: rust.MethodParameter | undefined;
+      switch (opParam.kind) {
+        case 'path':
+          // the next link is the complete URL so any path params are already included
+          continue;
+        case 'body':
+          matchingParam = methodParams.find((param) => param.kind === 'body');
+          break;
+        case 'cookie':
+          matchingParam = methodParams.find((param) => param.kind === 'cookieScalar' && param.cookie === opParam.serializedName);
+          break;
+        case 'header':
+          if (opParam.serializedName.toLowerCase() === 'x-ms-client-request-id') {
+            // inserted by a pipeline policy
+            continue;
+          }
+          matchingParam = methodParams.find((param) => (param.kind === 'headerCollection' || param.kind === 'headerHashMap' || param.kind === 'headerScalar') && param.header.toLowerCase() === opParam.serializedName.toLowerCase());
+          break;
+        case 'query':
+          if (opParam.isApiVersionParam) {
+            // the api-version is always reinjected
+            continue;
+          }
+          matchingParam = methodParams.find((param) => (param.kind === 'queryCollection' || param.kind === 'queryHashMap' || param.kind === 'queryScalar') && param.key === opParam.serializedName);
           break;
+      }
+
+      if (!matchingParam && opParam.type.kind === 'constant' && opParam.kind !== 'body') {
+        // constants (e.g. the Accept header) don't need a corresponding param
+        matchingParam = this.adaptMethodParameter(opParam);
+      }
+
+      if (!matchingParam) {
+        if (opParam.optional) {
+          // there's no way for callers to provide this value so omit it
+          continue;
         }
+        throw new AdapterError('UnsupportedTsp', `next page operation ${nextLinkOperation.name} parameter ${opParam.name} has no corresponding parameter in operation ${method.name}`, opParam.__raw?.node);
+      }
+
+      switch (matchingParam.kind) {
+        case 'body':
+        case 'cookieScalar':
+        case 'headerCollection':
+        case 'headerHashMap':
+        case 'headerScalar':
+          strategy.params.push(matchingParam);
+          break;
+        case 'queryCollection':
+        case 'queryHashMap':
+        case 'queryScalar':
+          strategy.reinjectedParams.push(matchingParam);
+          break;
         default:
-          throw new AdapterError('InternalError', `missing continuation token`, method.__raw?.node);
+          throw new AdapterError('InternalError', `unexpected kind ${matchingParam.kind} for next page operation parameter ${opParam.name}`, opParam.__raw?.node);
       }
-      return new rust.PageableStrategyContinuationToken(requestToken, responseToken);
-    } else {
-      // operation is pageable but doesn't yet support fetching subsequent pages
-      return undefined;
     }
+
+    return strategy;
   }
 
   /**
@@ -2621,7 +3724,7 @@ export class Adapter {
    * @param paramsMap maps tcgc method params to Rust params
    * @returns an array containing the method parameters for reinjection
    */
-  private adaptPageableMethodReinjectionParams(method: tcgc.SdkPagingServiceMethod<tcgc.SdkHttpOperation>, paramsMap: Map<tcgc.SdkMethodParameter, rust.HeaderScalarParameter | QueryParamType>): Array<QueryParamType> {
+  private adaptPageableMethodReinjectionParams(method: tcgc.SdkPagingServiceMethod<tcgc.SdkHttpOperation> | tcgc.SdkLroPagingServiceMethod<tcgc.SdkHttpOperation>, paramsMap: Map<tcgc.SdkMethodParameter, rust.HeaderScalarParameter | QueryParamType>): Array<QueryParamType> {
     if (!method.pagingMetadata.nextLinkReInjectedParametersSegments) {
       return [];
     }
@@ -2695,7 +3798,7 @@ export class Adapter {
 
     const paramName = naming.getEscapedReservedName(utils.snakeCaseName(getCorrespondingClientParamName(opParam)), 'param', reservedParams);
     const paramOptional = methodParam ? methodParam.optional : opParam.optional;
-    let paramType = this.getType(methodParam ? methodParam.type : opParam.type);
+    let paramType = opParam.isApiVersionParam ? this.getApiVersionType() : this.getType(methodParam ? methodParam.type : opParam.type);
 
     // for required header/path/query method string params, we might emit them as borrowed types.
     if (!paramOptional && paramLoc !== 'client' && (opParam.kind === 'header' || opParam.kind === 'path' || opParam.kind === 'query')) {
@@ -2722,8 +3825,18 @@ export class Adapter {
         break;
       }
       case 'cookie':
-        // TODO: https://github.com/Azure/typespec-rust/issues/192
-        throw new AdapterError('UnsupportedTsp', 'cookie parameters are not supported', opParam.__raw?.node);
+        paramType = this.typeToWireType(paramType);
+        switch (paramType.kind) {
+          case 'hashmap':
+          case 'jsonValue':
+          case 'model':
+          case 'slice':
+          case 'str':
+          case 'Vec':
+            throw new AdapterError('UnsupportedTsp', `unsupported kind ${paramType.kind} for cookie ${opParam.serializedName}`, opParam.__raw?.node);
+        }
+        adaptedParam = new rust.CookieScalarParameter(paramName, opParam.serializedName, paramLoc, paramOptional, paramType);
+        break;
       case 'header':
         if (opParam.collectionFormat) {
           if (paramType.kind !== 'Vec' && !isRefSlice(paramType)) {
@@ -2789,18 +3902,6 @@ export class Adapter {
           }
 
           adaptedParam = new rust.PathScalarParameter(paramName, opParam.serializedName, paramLoc, paramOptional, paramType, opParam.allowReserved, style);
-          // eslint-disable-next-line @typescript-eslint/no-unsafe-assignment
-          const minLength = methodParam?.decorators.find(
-            d => d.name === 'Azure.ClientGenerator.Core.@clientOption'
-              && d.arguments['name'] === 'minLength'
-              && d.arguments['scope'] === 'rust'
-          )?.arguments['value'];
-          if (minLength !== undefined) {
-            if (minLength !== 0) {
-              throw new AdapterError('UnsupportedTsp', `minLength decorator with a non-zero value (${minLength}).)`);
-            }
-            adaptedParam.minLength = minLength as number;
-          }
         }
       } break;
       case 'query':
@@ -2830,6 +3931,10 @@ export class Adapter {
         break;
     }
 
+    if (adaptedParam.kind === 'headerScalar' || adaptedParam.kind === 'pathScalar' || adaptedParam.kind === 'queryScalar') {
+      this.adaptParamConstraints(methodParam, opParam, adaptedParam);
+    }
+
     adaptedParam.docs = this.adaptDocs(methodParam ? methodParam.summary : opParam.summary, methodParam ? methodParam.doc : opParam.doc);
 
     if (paramLoc === 'client') {
@@ -2839,6 +3944,77 @@ export class Adapter {
     return adaptedParam;
   }
 
+  /**
+   * sets the length and pattern constraints for the specified param from
+   * the @minLength, @maxLength, and @pattern decorators on the param or its
+   * scalar type. any @clientOption decorators scoped to Rust take precedence.
+   * 
+   * @param methodParam the tcgc method param that might contain the client options
+   * @param opParam the tcgc operation param
+   * @param param the Rust param to update
+   */
+  private adaptParamConstraints(methodParam: tcgc.SdkMethodParameter | undefined, opParam: tcgc.SdkHttpParameter, param: rust.HeaderScalarParameter | rust.PathScalarParameter | rust.QueryScalarParameter): void {
+    const node = methodParam?.__raw?.node ?? opParam.__raw?.node;
+    const getClientOption = (name: 'maxLength' | 'minLength' | 'pattern'): unknown => {
+      // eslint-disable-next-line @typescript-eslint/no-unsafe-return
+      return methodParam?.decorators.find(
+        d => d.name === 'Azure.ClientGenerator.Core.@clientOption'
+          && d.arguments['name'] === name
+          && d.arguments['scope'] === 'rust'
+      )?.arguments['value'];
+    };
+
+    /** returns the first value from the param, then its scalar type and the scalars it extends */
+    const getDecoratorValue = <T>(getter: (program: tsp.Program, target: tsp.Type) => T | undefined): T | undefined => {
+      const rawParam = methodParam?.__raw ?? opParam.__raw;
+      if (rawParam?.kind !== 'ModelProperty') {
+        return undefined;
+      }
+      let value = getter(this.ctx.program, rawParam);
+      for (let scalar = rawParam.type.kind === 'Scalar' ? rawParam.type : undefined; value === undefined && scalar; scalar = scalar.baseScalar) {
+        value = getter(this.ctx.program, scalar);
+      }
+      return value;
+    };
+
+    const getLength = (name: 'maxLength' | 'minLength'): number | undefined => {
+      const length = getClientOption(name);
+      if (length === undefined) {
+        return getDecoratorValue(name === 'maxLength' ? tsp.getMaxLength : tsp.getMinLength);
+      } else if (typeof length !== 'number' || !Number.isInteger(length) || length < 0) {
+        throw new AdapterError('UnsupportedTsp', `${name} client option for parameter ${param.name} must be a non-negative integer`, node);
+      }
+      return length;
+    };
+
+    param.minLength = getLength('minLength');
+    param.maxLength = getLength('maxLength');
+    if (param.minLength !== undefined && param.maxLength !== undefined && param.minLength > param.maxLength) {
+      throw new AdapterError('UnsupportedTsp', `minLength for parameter ${param.name} is greater than its maxLength`, node);
+    }
+
+    let pattern = getClientOption('pattern');
+    if (pattern === undefined) {
+      pattern = getDecoratorValue(tsp.getPattern);
+    } else if (typeof pattern !== 'string') {
+      throw new AdapterError('UnsupportedTsp', `pattern client option for parameter ${param.name} must be a string`, node);
+    }
+    if (typeof pattern === 'string' && pattern !== '') {
+      const patternError = validatePattern(pattern);
+      if (patternError) {
+        this.ctx.program.reportDiagnostic({
+          code: 'UnsupportedPattern',
+          severity: 'warning',
+          message: `pattern ${pattern} for parameter ${param.name} ${patternError}; the parameter isn't checked against it`,
+          target: node ?? tsp.NoTarget,
+        });
+      } else {
+        param.pattern = pattern;
+        this.crate.addDependency(new rust.CrateDependency('regex'));
+      }
+    }
+  }
+
   /**
    * updates the specified type to a borrowed type based on its type and kind.
    * if no such transformation is necessary, undefined is returned.
@@ -2891,10 +4067,13 @@ export class Adapter {
       case 'encodedBytes':
         return this.getRefType(this.getEncodedBytes(type.encoding, true), lifetime);
       case 'decimal':
+      case 'duration':
       case 'Etag':
       case 'hashmap':
       case 'jsonValue':
       case 'offsetDateTime':
+      case 'plainDate':
+      case 'plainTime':
       case 'safeint':
       case 'Url':
         // these types all require conversion
@@ -2916,6 +4095,7 @@ export class Adapter {
       case 'bytes':
       case 'decimal':
       case 'discriminatedUnion':
+      case 'duration':
       case 'encodedBytes':
       case 'enum':
       case 'enumValue':
@@ -2925,7 +4105,11 @@ export class Adapter {
       case 'jsonValue':
       case 'literal':
       case 'model':
+      case 'multipartFile':
+      case 'nullable':
       case 'offsetDateTime':
+      case 'plainDate':
+      case 'plainTime':
       case 'ref':
       case 'safeint':
       case 'scalar':
@@ -2986,7 +4170,9 @@ export class Adapter {
   private getPayloadFormatType(type: tcgc.SdkType | undefined, defaultContentType: string): rust.PayloadFormatType {
     if (type?.kind === 'model') {
       const opts = type.serializationOptions;
-      if (opts.json) {
+      if (<tcgc.UsageFlags>(type.usage & tcgc.UsageFlags.MultipartFormData) === tcgc.UsageFlags.MultipartFormData) {
+        return 'MultipartFormat';
+      } else if (opts.json) {
         return 'JsonFormat';
       } else if (opts.xml) {
         this.crate.addDependency(new rust.CrateDependency('azure_core', ['xml']));
@@ -3003,6 +4189,8 @@ export class Adapter {
     // than models.  for those cases, fall back to the header value
     if (defaultContentType.match(/json/i)) {
       return 'JsonFormat';
+    } else if (defaultContentType.match(/multipart\/form-data/i)) {
+      return 'MultipartFormat';
     } else if (defaultContentType.match(/xml/i)) {
       // XML support is disabled by default
       this.crate.addDependency(new rust.CrateDependency('azure_core', ['xml']));
@@ -3013,6 +4201,21 @@ export class Adapter {
   }
 }
 
+/**
+ * returns the stream format for server-sent events and JSON Lines content types
+ * 
+ * @param contentType the content type of the response
+ * @returns the stream format or undefined if the content type isn't streamed
+ */
+function getStreamFormat(contentType?: string): rust.StreamFormat | undefined {
+  if (contentType?.match(/^text\/event-stream/i)) {
+    return 'sse';
+  } else if (contentType?.match(/^application\/(jsonl|x-ndjson)/i)) {
+    return 'jsonl';
+  }
+  return undefined;
+}
+
 /** type guard to determine if type is a Ref<HashMap> */
 function isRefHashMap(type: rust.Type): type is rust.Ref<rust.HashMap> {
   return utils.asTypeOf<rust.Ref<rust.HashMap>>(type, 'hashmap', 'ref') !== undefined;
@@ -3026,6 +4229,21 @@ function isRefSlice(type: rust.Type): type is rust.Ref<rust.Slice> {
 /** method types that send/receive data */
 type MethodType = rust.AsyncMethod | rust.PageableMethod | rust.LroMethod;
 
+/** a change to the JSON wire format of a type along with its source */
+interface VersionedWireChange {
+  /** the change or undefined if it can't be converted */
+  change?: rust.WireChange;
+
+  /** the tsp type that contains the decorator */
+  raw: tsp.Type;
+
+  /** describes the change for diagnostics */
+  description: string;
+
+  /** the API versions in which the previous wire format is used */
+  apiVersions: Array<string>;
+}
+
 /** supported kinds of tcgc scalars */
 type tcgcScalarKind = 'boolean' | 'float' | 'float32' | 'float64' | 'int16' | 'int32' | 'int64' | 'int8' | 'uint16' | 'uint32' | 'uint64' | 'uint8';
 
@@ -3050,6 +4268,8 @@ function recursiveKeyName(root: string, type: rust.Box | rust.Option | rust.Requ
       return recursiveKeyName(`${root}-${type.kind}`, type.type);
     case 'encodedBytes':
       return `${root}-${type.kind}-${type.encoding}${type.slice ? '-slice' : ''}`;
+    case 'duration':
+      return `${root}-${type.kind}-${type.encoding}`;
     case 'enum':
       return `${root}-${type.kind}-${type.name}`;
     case 'enumValue':
@@ -3065,6 +4285,10 @@ function recursiveKeyName(root: string, type: rust.Box | rust.Option | rust.Requ
       return `${root}-${type.kind}-${type.name}`;
     case 'literal':
       return `${recursiveKeyName(`${root}-${type.kind}`, type.valueKind)}-${type.value}`;
+    case 'multipartFile':
+      return `${root}-${type.kind}-${type.module.name}-${type.defaultContentType}`;
+    case 'nullable':
+      return recursiveKeyName(`${root}-${type.kind}-${type.module.name}`, type.type);
     case 'ref':
       return recursiveKeyName(`${root}-${type.kind}${type.lifetime ? `-${type.lifetime.name}` : ''}`, type.type);
     case 'requestContent':
@@ -3081,6 +4305,271 @@ function recursiveKeyName(root: string, type: rust.Box | rust.Option | rust.Requ
   }
 }
 
+/**
+ * returns true if the type can be wrapped in a Nullable<T>.
+ * types that require custom serde helpers are excluded.
+ * 
+ * @param type the type to inspect
+ * @returns true if the type can be wrapped in a Nullable<T>
+ */
+function supportsNullable(type: rust.Type): boolean {
+  switch (type.kind) {
+    case 'decimal':
+      // rust_decimal's default serde impl uses the string encoding
+      return type.stringEncoding;
+    case 'box':
+    case 'duration':
+    case 'encodedBytes':
+    case 'enumValue':
+    case 'literal':
+    case 'offsetDateTime':
+    case 'plainDate':
+    case 'plainTime':
+    case 'Url':
+      return false;
+    case 'hashmap':
+    case 'Vec':
+      return supportsNullable(type.type);
+    case 'safeint':
+    case 'scalar':
+      return !type.stringEncoding;
+    default:
+      return true;
+  }
+}
+
+/**
+ * returns true if the tcgc enum contains the API versions for a service
+ * 
+ * @param sdkEnum the tcgc enum to inspect
+ * @returns true if the enum contains API versions
+ */
+function isApiVersionEnum(sdkEnum: tcgc.SdkEnumType): boolean {
+  return <tcgc.UsageFlags>(sdkEnum.usage & tcgc.UsageFlags.ApiVersionEnum) === tcgc.UsageFlags.ApiVersionEnum;
+}
+
+/**
+ * returns the API versions in which a method or parameter is available.
+ * if it's available in all of the enclosing API versions then undefined
+ * is returned, indicating no version checks are required. the empty
+ * array indicates it isn't available in any of the enclosing API versions.
+ * 
+ * @param enclosing the API versions of the enclosing client or method
+ * @param apiVersions the API versions in which the method or parameter is available
+ * @returns the available API versions or undefined
+ */
+function getAvailableApiVersions(enclosing: Array<string>, apiVersions: Array<string>): Array<string> | undefined {
+  const available = enclosing.filter((version) => apiVersions.includes(version));
+  if (available.length === enclosing.length) {
+    return undefined;
+  }
+  return available;
+}
+
+/**
+ * returns the names a parameter or property was sent with in earlier API
+ * versions as specified by any @renamedFrom decorators. the API versions
+ * in which the latest name is sent aren't included.
+ *
+ * @param program the program that contains the parameter or property
+ * @param raw the tsp type for the parameter or property
+ * @param apiVersions the API versions of the enclosing client
+ * @param getWireName converts a name to the name sent on the wire
+ * @returns the previous names or the empty array
+ */
+function getPreviousNames(program: tsp.Program, raw: tsp.Type, apiVersions: Array<string>, getWireName: (name: string) => string): Array<rust.PreviousName> {
+  const renames = versioning.getRenamedFrom(program, raw) ?? [];
+  return getPreviousValues(renames.map((rename) => { return { version: rename.version, value: getWireName(rename.oldName) }; }), apiVersions).map((previous) => {
+    return { name: previous.value, apiVersions: previous.apiVersions };
+  });
+}
+
+/**
+ * returns the values that were used in earlier API versions before
+ * the specified changes were made. the API versions in which the
+ * latest value is used aren't included.
+ *
+ * @param changes the API versions in which the value changed and the value used before each change
+ * @param apiVersions the API versions of the enclosing client
+ * @returns the previous values or the empty array
+ */
+function getPreviousValues<T>(changes: Array<{ version: versioning.Version, value: T }>, apiVersions: Array<string>): Array<{ value: T, apiVersions: Array<string> }> {
+  const sorted = [...changes].sort((a, b) => a.version.index - b.version.index);
+  const previousValues = new Array<{ value: T, apiVersions: Array<string> }>();
+  for (let i = 0; i < apiVersions.length; ++i) {
+    // an API version uses the old value from the first change made after it
+    const change = sorted.find((change) => i < apiVersions.indexOf(change.version.value));
+    if (!change) {
+      continue;
+    }
+
+    let previousValue = previousValues.find((previousValue) => previousValue.value === change.value);
+    if (!previousValue) {
+      previousValue = { value: change.value, apiVersions: new Array<string>() };
+      previousValues.push(previousValue);
+    }
+    previousValue.apiVersions.push(apiVersions[i]);
+  }
+  return previousValues;
+}
+
+/**
+ * returns the kind of JSON value the type is sent as, or
+ * undefined if the type isn't sent as a JSON scalar.
+ *
+ * @param type the type to inspect
+ * @returns the kind of JSON value or undefined
+ */
+function getJsonScalarKind(type: tcgc.SdkType | undefined): rust.JsonScalarKind | undefined {
+  switch (type?.kind) {
+    case 'boolean':
+      return 'boolean';
+    case 'decimal':
+    case 'decimal128':
+    case 'float':
+    case 'float32':
+    case 'float64':
+    case 'int16':
+    case 'int32':
+    case 'int64':
+    case 'int8':
+    case 'integer':
+    case 'numeric':
+    case 'safeint':
+    case 'uint16':
+    case 'uint32':
+    case 'uint64':
+    case 'uint8':
+      return 'number';
+    case 'bytes':
+    case 'plainDate':
+    case 'plainTime':
+    case 'string':
+    case 'url':
+      return 'string';
+    case 'constant':
+    case 'enum':
+      return getJsonScalarKind(type.valueType);
+    case 'duration':
+    case 'offsetDateTime':
+    case 'utcDateTime':
+      return getJsonScalarKind(type.wireType);
+    case 'nullable':
+      return getJsonScalarKind(type.type);
+    default:
+      return undefined;
+  }
+}
+
+/**
+ * returns the model properties that contain the next link
+ * for the paged methods in the specified clients and their
+ * child clients.
+ *
+ * @param clients the clients to search
+ * @returns the next link model properties
+ */
+function getNextLinkProperties(clients: Array<tcgc.SdkClientType<tcgc.SdkHttpOperation>>): Set<tcgc.SdkModelPropertyType> {
+  const nextLinkProperties = new Set<tcgc.SdkModelPropertyType>();
+  const visit = function (client: tcgc.SdkClientType<tcgc.SdkHttpOperation>): void {
+    for (const method of client.methods) {
+      if (method.kind !== 'paging' && method.kind !== 'lropaging') {
+        continue;
+      }
+      const nextLink = method.pagingMetadata.nextLinkSegments?.at(-1);
+      if (nextLink?.kind === 'property') {
+        nextLinkProperties.add(nextLink);
+      }
+    }
+    for (const child of client.children ?? []) {
+      visit(child);
+    }
+  };
+  for (const client of clients) {
+    visit(client);
+  }
+  return nextLinkProperties;
+}
+
+/**
+ * validates that the ECMAScript pattern for a param can be
+ * emitted as a Rust regex. the regex crate doesn't support
+ * look-around assertions or backreferences.
+ *
+ * @param pattern the pattern to validate
+ * @returns the reason the pattern isn't supported or undefined if it's supported
+ */
+function validatePattern(pattern: string): string | undefined {
+  try {
+    new RegExp(pattern);
+  } catch (err) {
+    return `isn't a valid regular expression: ${(err as Error).message}`;
+  }
+
+  // skip escaped chars so that e.g. \\1 and \(?= aren't matched
+  const unescaped = pattern.replace(/\\[^1-9k]/g, '');
+  if (unescaped.match(/\(\?<?[=!]/) || unescaped.match(/\\([1-9]|k<)/)) {
+    return 'contains look-around assertions or backreferences which aren\'t supported';
+  }
+  return undefined;
+}
+
+/**
+ * returns true if the header or query name was set via
+ * the @header or @query decorator, e.g. @header("x-ms-name")
+ * or @query(#{name: "name"}).
+ *
+ * @param raw the property with the @header or @query decorator
+ * @param kind the kind of decorator
+ * @returns true if the decorator contains the name
+ */
+function hasExplicitWireName(raw: tsp.ModelProperty, kind: 'header' | 'query'): boolean {
+  const decorator = raw.decorators.find((decorator) => decorator.definition?.name === `@${kind}` && decorator.definition.namespace.name === 'Http');
+  const nameOrOptions = decorator?.args[0]?.jsValue;
+  if (typeof nameOrOptions === 'string') {
+    return true;
+  }
+  return typeof nameOrOptions === 'object' && nameOrOptions !== null && !Array.isArray(nameOrOptions) && !('kind' in nameOrOptions) && 'name' in nameOrOptions;
+}
+
+/**
+ * returns the header name used for a property that's annotated
+ * with @header without an explicit name. @typespec/http doesn't
+ * export its naming rule so this applies the same rule as the
+ * @header decorator, e.g. ifMatch becomes if-match.
+ *
+ * @param name the name of the property
+ * @returns the header name
+ */
+function getDefaultHeaderName(name: string): string {
+  return name.replace(/([a-z])([A-Z])/g, '$1-$2').toLowerCase();
+}
+
+/**
+ * returns true if the model field's type can be wrapped in a Nullable<T>.
+ * unlike supportsNullable, this includes the types that require custom
+ * serde helpers as the helpers handle the Nullable<T> wrapper.
+ * 
+ * @param type the model field's type
+ * @returns true if the type can be wrapped in a Nullable<T>
+ */
+function fieldSupportsNullable(type: rust.Type): boolean {
+  switch (type.kind) {
+    case 'duration':
+    case 'encodedBytes':
+    case 'offsetDateTime':
+    case 'plainDate':
+    case 'plainTime':
+    case 'Url':
+      return true;
+    case 'hashmap':
+    case 'Vec':
+      return fieldSupportsNullable(type.type);
+    default:
+      return supportsNullable(type);
+  }
+}
+
 /**
  * returns the XML-specific name based on the provided decorators
  * 
@@ -3149,6 +4638,51 @@ function hasClientNameDecorator(decorators: Array<tcgc.DecoratorInfo>): boolean
   return decorators.find((decorator) => decorator.name === 'Azure.ClientGenerator.Core.@clientName') !== undefined;
 }
 
+// the names of well-known HTTP error status codes.
+// the names MUST match the variants of azure_core::http::StatusCode as they're used in generated match arms.
+const statusCodeNames: Record<number, string> = {
+  400: 'BadRequest',
+  401: 'Unauthorized',
+  402: 'PaymentRequired',
+  403: 'Forbidden',
+  404: 'NotFound',
+  405: 'MethodNotAllowed',
+  406: 'NotAcceptable',
+  407: 'ProxyAuthenticationRequired',
+  408: 'RequestTimeout',
+  409: 'Conflict',
+  410: 'Gone',
+  411: 'LengthRequired',
+  412: 'PreconditionFailed',
+  413: 'PayloadTooLarge',
+  414: 'UriTooLong',
+  415: 'UnsupportedMediaType',
+  416: 'RequestedRangeNotSatisfiable',
+  417: 'ExpectationFailed',
+  418: 'ImATeapot',
+  421: 'MisdirectedRequest',
+  422: 'UnprocessableEntity',
+  423: 'Locked',
+  424: 'FailedDependency',
+  425: 'TooEarly',
+  426: 'UpgradeRequired',
+  428: 'PreconditionRequired',
+  429: 'TooManyRequests',
+  431: 'RequestHeaderFieldsTooLarge',
+  451: 'UnavailableForLegalReasons',
+  500: 'InternalServerError',
+  501: 'NotImplemented',
+  502: 'BadGateway',
+  503: 'ServiceUnavailable',
+  504: 'GatewayTimeout',
+  505: 'HttpVersionNotSupported',
+  506: 'VariantAlsoNegotiates',
+  507: 'InsufficientStorage',
+  508: 'LoopDetected',
+  510: 'NotExtended',
+  511: 'NetworkAuthenticationRequired',
+};
+
 /**
  * narrows statusCode to a HttpStatusCodeRange within the conditional block
  * 
@@ -3243,6 +4777,18 @@ function isLibNamespace(namespace: string): boolean {
   return LIB_NAMESPACE.some((lib) => ns === lib || ns.startsWith(lib + '.'));
 }
 
+/** returns true if model is TypeSpec.Http.File or derives from it */
+function isHttpFile(model: tcgc.SdkModelType): boolean {
+  let current: tcgc.SdkModelType | undefined = model;
+  while (current) {
+    if (current.crossLanguageDefinitionId === 'TypeSpec.Http.File') {
+      return true;
+    }
+    current = current.baseModel;
+  }
+  return false;
+}
+
 /**
  * narrows type to a RefType within the conditional block.
  * this function throws if the narrowing is not possible.
@@ -3252,6 +4798,7 @@ function typeToRefType(type: rust.Type): type is rust.RefType {
     case 'bytes':
     case 'decimal':
     case 'discriminatedUnion':
+    case 'duration':
     case 'encodedBytes':
     case 'enum':
     case 'enumValue':
@@ -3260,8 +4807,12 @@ function typeToRefType(type: rust.Type): type is rust.RefType {
     case 'hashmap':
     case 'jsonValue':
     case 'model':
+    case 'multipartFile':
+    case 'nullable':
     case 'offsetDateTime':
     case 'option':
+    case 'plainDate':
+    case 'plainTime':
     case 'safeint':
     case 'scalar':
     case 'slice':
@@ -3276,6 +4827,162 @@ function typeToRefType(type: rust.Type): type is rust.RefType {
   }
 }
 
+/**
+ * returns the modules, other than module, that contain types referenced by the contents of module.
+ * clients returned from client accessors are excluded as the accessors are gated on their own.
+ *
+ * @param module the module to inspect
+ * @returns the referenced modules. can be empty
+ */
+function getReferencedModules(module: rust.ModuleContainer): Set<rust.ModuleContainer> {
+  const referencedModules = new Set<rust.ModuleContainer>();
+  const addForType = function (type: rust.Type | undefined): void {
+    if (!type) {
+      return;
+    }
+    switch (type.kind) {
+      case 'discriminatedUnion':
+      case 'enum':
+      case 'model':
+      case 'multipartFile':
+      case 'untaggedUnion':
+        if (type.module !== module) {
+          referencedModules.add(type.module);
+        }
+        break;
+      case 'itemStream':
+      case 'nullable':
+        if (type.module !== module) {
+          referencedModules.add(type.module);
+        }
+        addForType(type.type);
+        break;
+      case 'poller':
+        addForType(type.type);
+        addForType(type.resultType);
+        break;
+      case 'requestContent':
+      case 'response':
+        addForType(type.content);
+        break;
+      case 'struct':
+        for (const field of type.fields) {
+          addForType(field.type);
+        }
+        break;
+      case 'arc':
+      case 'asyncResponse':
+      case 'box':
+      case 'enumValue':
+      case 'hashmap':
+      case 'option':
+      case 'pager':
+      case 'ref':
+      case 'result':
+      case 'slice':
+      case 'Vec':
+        addForType(type.type);
+        break;
+    }
+  };
+
+  for (const model of module.models) {
+    if (model.kind === 'model') {
+      for (const field of model.fields) {
+        addForType(field.type);
+      }
+    }
+  }
+
+  for (const union of module.unions) {
+    if (union.kind === 'discriminatedUnion') {
+      for (const member of union.members) {
+        addForType(member.type);
+      }
+      if (union.unionKind?.kind === 'discriminatedUnionBase') {
+        addForType(union.unionKind.baseType);
+      }
+    } else {
+      for (const variant of union.variants) {
+        addForType(variant.type);
+      }
+    }
+  }
+
+  for (const client of module.clients) {
+    for (const field of client.fields) {
+      addForType(field.type);
+    }
+    if (client.constructable) {
+      addForType(client.constructable.options.type);
+      for (const constructor of client.constructable.constructors) {
+        for (const param of constructor.params) {
+          addForType(param.type);
+        }
+      }
+    }
+    for (const method of client.methods) {
+      for (const param of method.params) {
+        addForType(param.type);
+      }
+      if (method.kind !== 'clientaccessor') {
+        for (const param of method.params) {
+          if (param.kind === 'partialBody') {
+            addForType(param.paramType);
+          }
+        }
+        addForType(method.options.type);
+        addForType(method.returns);
+        for (const header of method.responseHeaders?.headers ?? []) {
+          addForType(header.type);
+        }
+        for (const variant of method.errors?.variants ?? []) {
+          addForType(variant.type);
+        }
+      }
+    }
+  }
+
+  return referencedModules;
+}
+
+/**
+ * returns the discriminator property for a polymorphic model.
+ * for intermediate types that declare their own discriminator
+ * (e.g. Shark in Fish -> Shark -> SawShark) this is the declared
+ * discriminator and not the discriminator of the parent type.
+ *
+ * @param model the polymorphic model
+ * @returns the discriminator property or undefined
+ */
+function getDiscriminatorProperty(model: tcgc.SdkModelType): tcgc.SdkModelPropertyType | undefined {
+  if (model.discriminatorProperty) {
+    return model.discriminatorProperty;
+  }
+  return model.properties.find((prop) => prop.kind === 'property' && prop.discriminator);
+}
+
+/**
+ * returns true if subType is a descendant of an intermediate type between
+ * it and root that declares a different discriminator. such sub-types are
+ * members of the intermediate type's union instead of root's union.
+ *
+ * @param root the polymorphic root
+ * @param subType a discriminated sub-type of root
+ * @returns true if the sub-type belongs to a nested discriminated union
+ */
+function isNestedDiscriminatedSubtype(root: tcgc.SdkModelType, subType: tcgc.SdkModelType): boolean {
+  const rootDiscriminator = getDiscriminatorProperty(root)?.name;
+  let parent = subType.baseModel;
+  while (parent && parent !== root) {
+    if (parent.discriminatedSubtypes && getDiscriminatorProperty(parent)?.name !== rootDiscriminator) {
+      return true;
+    }
+    parent = parent.baseModel;
+  }
+  return false;
+}
+
 /**
  * returns true if model is a polymorphic root type.
  *
@@ -131,6 +131,36 @@ export function buildImportPath(dst: rust.ModuleContainer, src: rust.ModuleConta
   }
 }
 
+/**
+ * returns the Cargo feature required for content in dst to reference content in src.
+ * 
+ * @param dst the module that references the content
+ * @param src the module that contains the content being referenced
+ * @returns the required feature or undefined if src is always available to dst
+ */
+export function getFeatureGate(dst: rust.ModuleContainer, src: rust.ModuleContainer): rust.CrateFeature | undefined {
+  if (src.kind === 'crate' || !src.feature) {
+    return undefined;
+  }
+
+  if (dst.kind === 'module' && dst.feature) {
+    // check if src's feature is already enabled by dst's feature
+    const enabled = new Set<rust.CrateFeature>();
+    const enable = function (feature: rust.CrateFeature): void {
+      if (!enabled.has(feature)) {
+        enabled.add(feature);
+        feature.enables.forEach(enable);
+      }
+    };
+    enable(dst.feature);
+    if (enabled.has(src.feature)) {
+      return undefined;
+    }
+  }
+
+  return src.feature;
+}
+
 // the following was copied from @azure-tools/codegen as it's deprecated
 
 /**
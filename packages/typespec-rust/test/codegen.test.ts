@@ -8,7 +8,8 @@
 import * as rust from '../src/codemodel/index.js';
 import { CodeGenerator } from '../src/codegen/codeGenerator.js';
 import * as helpers from '../src/codegen/helpers.js';
-import { strictEqual } from 'assert';
+import { deepStrictEqual, ok, strictEqual, throws } from 'assert';
+import { readFileSync } from 'fs';
 import { describe, it } from 'vitest';
 
 describe('typespec-rust: codegen', () => {
@@ -53,6 +54,303 @@ describe('typespec-rust: codegen', () => {
       const cargoToml = codegen.emitCargoToml();
       strictEqual(cargoToml, expected);
     });
+
+    it('standalone Cargo.toml file with dependencies', () => {
+      const expected = '[package]\n' +
+        'name = "test_crate"\n' +
+        'version = "1.2.3"\n' +
+        'edition = "2021"\n' +
+        'rust-version = "1.88"\n' +
+        '\n' +
+        '[features]\n' +
+        'default = ["azure_core/default"]\n' +
+        '\n' +
+        '[dependencies]\n' +
+        'azure_core = { version = "0.33.0", features = ["xml"] }\n' +
+        'serde = { version = "1.0.228", features = ["derive"] }\n' +
+        'serde_json = "1.0.149"\n';
+
+      const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+      crate.workspace = false;
+      crate.dependencies.push(new rust.CrateDependency('azure_core', ['xml']));
+      crate.dependencies.push(new rust.CrateDependency('serde'));
+      crate.dependencies.push(new rust.CrateDependency('serde_json'));
+      const codegen = new CodeGenerator(crate);
+      const cargoToml = codegen.emitCargoToml();
+      strictEqual(cargoToml, expected);
+    });
+
+    it('standalone Cargo.toml file with decimal and external dependencies', () => {
+      const expected = '[package]\n' +
+        'name = "test_crate"\n' +
+        'version = "1.2.3"\n' +
+        'edition = "2021"\n' +
+        'rust-version = "1.88"\n' +
+        '\n' +
+        '[features]\n' +
+        'default = ["azure_core/default"]\n' +
+        '\n' +
+        '[dependencies]\n' +
+        'azure_core = { version = "0.33.0", features = ["decimal"] }\n' +
+        'rust_decimal = "1.37.2"\n' +
+        'geo-types = "0.7.0"\n';
+
+      const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+      crate.workspace = false;
+      crate.dependencies.push(new rust.CrateDependency('azure_core'));
+      crate.dependencies.push(new rust.CrateDependency('rust_decimal'));
+      crate.dependencies.push(new rust.CrateDependency('geo-types', [], '0.7.0'));
+      const codegen = new CodeGenerator(crate);
+      strictEqual(codegen.emitCargoToml(), expected);
+
+      crate.dependencies.push(new rust.CrateDependency('unknown_crate'));
+      const unknown = new Array<string>();
+      strictEqual(codegen.emitCargoToml((name) => unknown.push(name)), expected + 'unknown_crate = "*"\n');
+      deepStrictEqual(unknown, ['unknown_crate']);
+    });
+
+    it('standalone Cargo.toml file with package options', () => {
+      const expected = '[package]\n' +
+        'name = "test_crate"\n' +
+        'version = "1.2.3"\n' +
+        'edition = "2024"\n' +
+        'license = "Apache-2.0"\n' +
+        'rust-version = "1.90"\n' +
+        '\n' +
+        '[features]\n' +
+        'default = ["azure_core/default"]\n' +
+        '\n' +
+        '[dependencies]\n' +
+        'azure_core = "0.34.0"\n';
+
+      const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+      crate.workspace = false;
+      crate.standalone = {
+        edition: '2024',
+        license: 'Apache-2.0',
+        rustVersion: '1.90',
+        azureCoreVersion: '0.34.0',
+      };
+      crate.dependencies.push(new rust.CrateDependency('azure_core'));
+      const codegen = new CodeGenerator(crate);
+      strictEqual(codegen.emitCargoToml(), expected);
+    });
+
+    it('standalone dependencies match the workspace dependencies', () => {
+      // each third-party workspace dependency in test/Cargo.toml must be emitted exactly as it's
+      // defined there. azure_core is excluded as the workspace uses a git revision and tokio is
+      // only a dev-dependency of the test crates.
+      const lines = readFileSync(new URL('Cargo.toml', import.meta.url), 'utf-8').split(/\r?\n/);
+      const workspaceDeps = new Map<string, string>();
+      for (let i = lines.indexOf('[workspace.dependencies]') + 1; i < lines.length && !lines[i].startsWith('['); ++i) {
+        const entry = lines[i].match(/^([\w-]+)\s*=/);
+        if (entry && entry[1] !== 'azure_core' && entry[1] !== 'tokio') {
+          workspaceDeps.set(entry[1], lines[i]);
+        }
+      }
+      ok(workspaceDeps.size > 0);
+
+      const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+      crate.workspace = false;
+      for (const name of workspaceDeps.keys()) {
+        crate.dependencies.push(new rust.CrateDependency(name));
+      }
+      const codegen = new CodeGenerator(crate);
+      const cargoToml = codegen.emitCargoToml().split('\n');
+      for (const [name, entry] of workspaceDeps) {
+        strictEqual(cargoToml.find((line) => line.startsWith(`${name} =`)), entry);
+      }
+    });
+
+    it('default Cargo.toml file with features', () => {
+      const expected = '[package]\n' +
+        'name = "test_crate"\n' +
+        'version = "1.2.3"\n' +
+        'authors.workspace = true\n' +
+        'edition.workspace = true\n' +
+        'license.workspace = true\n' +
+        'repository.workspace = true\n' +
+        'rust-version.workspace = true\n' +
+        '\n' +
+        '[features]\n' +
+        'default = ["azure_core/default"]\n' +
+        'sub1 = []\n' +
+        'sub1-nested = ["sub1", "sub2"]\n' +
+        'sub2 = []\n';
+
+      const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+      const sub1 = new rust.CrateFeature('sub1');
+      const sub2 = new rust.CrateFeature('sub2');
+      const nested = new rust.CrateFeature('sub1-nested');
+      nested.enables.push(sub2, sub1);
+      crate.features.push(sub2, nested, sub1);
+      const codegen = new CodeGenerator(crate);
+      const cargoToml = codegen.emitCargoToml();
+      strictEqual(cargoToml, expected);
+      // emitting doesn't reorder the code model
+      deepStrictEqual(crate.features, [sub2, nested, sub1]);
+    });
+
+    it('merge Cargo.toml file', () => {
+      const existing = '[package]\n' +
+        'name = "test_crate"\n' +
+        '\n' +
+        '[features]\n' +
+        'default = ["azure_core/default"]\n' +
+        '\n' +
+        '# hand-written dependencies\n' +
+        '[dependencies]\n' +
+        'azure_core = { workspace = true } # core\n' +
+        'serde = "1.0"\n' +
+        'tokio = { workspace = true, features = [\n' +
+        '    "rt",\n' +
+        '] }\n' +
+        'url = { workspace = true }\n';
+
+      const expected = '[package]\n' +
+        'name = "test_crate"\n' +
+        '\n' +
+        '[features]\n' +
+        'default = ["azure_core/default"]\n' +
+        'sub1 = []\n' +
+        '\n' +
+        '# hand-written dependencies\n' +
+        '[dependencies]\n' +
+        'azure_core = { workspace = true, features = ["xml"] } # core\n' +
+        'serde = { version = "1.0", features = ["derive"] }\n' +
+        'tokio = { workspace = true, features = ["rt", "macros"] }\n' +
+        'url = { workspace = true }\n' +
+        'async-trait = { workspace = true }\n';
+
+      const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+      crate.dependencies.push(new rust.CrateDependency('azure_core', ['xml']));
+      crate.dependencies.push(new rust.CrateDependency('serde', ['derive']));
+      crate.dependencies.push(new rust.CrateDependency('tokio', ['macros']));
+      crate.dependencies.push(new rust.CrateDependency('async-trait'));
+      crate.features.push(new rust.CrateFeature('sub1'));
+      const codegen = new CodeGenerator(crate);
+      strictEqual(codegen.mergeCargoToml(existing), expected);
+      strictEqual(codegen.mergeCargoToml(expected), expected);
+    });
+
+    it('merge Cargo.toml file with dotted keys and dependency tables', () => {
+      const existing = '[package]\n' +
+        'name = "test_crate"\n' +
+        '\n' +
+        '[dependencies]\n' +
+        'azure_core.workspace = true\n' +
+        'azure_core.default-features = false\n' +
+        'tokio.workspace = true\n' +
+        'tokio.features = ["rt"]\n' +
+        '\n' +
+        '[dependencies.serde]\n' +
+        'workspace = true\n' +
+        'features = [\n' +
+        '    "rc"\n' +
+        '] # serde features\n' +
+        '\n' +
+        '[target.\'cfg(not(target_arch = "wasm32"))\'.dependencies]\n' +
+        'futures = { workspace = true }\n';
+
+      const expected = '[package]\n' +
+        'name = "test_crate"\n' +
+        '\n' +
+        '[dependencies]\n' +
+        'azure_core.workspace = true\n' +
+        'azure_core.default-features = false\n' +
+        'azure_core.features = ["xml"]\n' +
+        'tokio.workspace = true\n' +
+        'tokio.features = ["rt", "macros"]\n' +
+        '\n' +
+        '[dependencies.serde]\n' +
+        'workspace = true\n' +
+        'features = ["rc", "derive"] # serde features\n' +
+        '\n' +
+        '[target.\'cfg(not(target_arch = "wasm32"))\'.dependencies]\n' +
+        'futures = { workspace = true, features = ["std"] }\n';
+
+      const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+      crate.dependencies.push(new rust.CrateDependency('azure_core', ['xml']));
+      crate.dependencies.push(new rust.CrateDependency('futures', ['std']));
+      crate.dependencies.push(new rust.CrateDependency('serde', ['derive']));
+      crate.dependencies.push(new rust.CrateDependency('tokio', ['macros']));
+      const codegen = new CodeGenerator(crate);
+      strictEqual(codegen.mergeCargoToml(existing), expected);
+      strictEqual(codegen.mergeCargoToml(expected), expected);
+    });
+
+    it('merge standalone Cargo.toml file with CRLF line endings', () => {
+      const existing = '[package]\r\n' +
+        'name = "test_crate"\r\n' +
+        '\r\n' +
+        '[dependencies]\r\n' +
+        'azure_core = { version = "0.30.0", default-features = false }\r\n' +
+        'bytes = "1"\r\n' +
+        'futures = ">=0.3"\r\n' +
+        'serde = { version = "1.0.300", features = [\r\n' +
+        '    "derive",\r\n' +
+        '] }\r\n';
+
+      const expected = '[package]\r\n' +
+        'name = "test_crate"\r\n' +
+        '\r\n' +
+        '[dependencies]\r\n' +
+        'azure_core = { version = "0.33.0", default-features = false, features = ["xml"] }\r\n' +
+        'bytes = "1.11.1"\r\n' +
+        'futures = ">=0.3"\r\n' +
+        'serde = { version = "1.0.300", features = [\r\n' +
+        '    "derive",\r\n' +
+        '] }\r\n' +
+        'serde_json = "1.0.149"\r\n';
+
+      const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+      crate.workspace = false;
+      crate.dependencies.push(new rust.CrateDependency('azure_core', ['xml']));
+      crate.dependencies.push(new rust.CrateDependency('bytes'));
+      crate.dependencies.push(new rust.CrateDependency('futures'));
+      crate.dependencies.push(new rust.CrateDependency('serde', ['derive']));
+      crate.dependencies.push(new rust.CrateDependency('serde_json'));
+      const codegen = new CodeGenerator(crate);
+      strictEqual(codegen.mergeCargoToml(existing), expected);
+      strictEqual(codegen.mergeCargoToml(expected), expected);
+
+      // dependencies that already exist keep their version
+      crate.dependencies.push(new rust.CrateDependency('unknown_crate'));
+      const unknown = new Array<string>();
+      strictEqual(codegen.mergeCargoToml(expected, (name) => unknown.push(name)), expected + 'unknown_crate = "*"\r\n');
+      strictEqual(codegen.mergeCargoToml(expected + 'unknown_crate = "2.0"\r\n', (name) => unknown.push(name)), expected + 'unknown_crate = "2.0"\r\n');
+      deepStrictEqual(unknown, ['unknown_crate']);
+    });
+
+    it('merge Cargo.toml file uses the existing file to determine workspace use', () => {
+      const standalone = '[package]\n' +
+        'name = "test_crate"\n' +
+        'description = "a # isn\'t always a comment"\n' +
+        '\n' +
+        '[dependencies]\n' +
+        '# pinned for reasons\n' +
+        'azure_core = "0.33.0" # core\n';
+
+      const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+      crate.dependencies.push(new rust.CrateDependency('azure_core'));
+      crate.dependencies.push(new rust.CrateDependency('serde'));
+      const codegen = new CodeGenerator(crate);
+      // the crate is a workspace member but the existing file isn't
+      strictEqual(codegen.mergeCargoToml(standalone), standalone + 'serde = { version = "1.0.228", features = ["derive"] }\n');
+
+      const workspace = '[package]\n' +
+        'name = "test_crate"\n' +
+        'edition.workspace = true\n';
+
+      crate.workspace = false;
+      // the crate isn't a workspace member but the existing file is
+      strictEqual(codegen.mergeCargoToml(workspace), workspace + '\n' +
+        '[dependencies]\n' +
+        'azure_core = { workspace = true }\n' +
+        'serde = { workspace = true }\n');
+
+      throws(() => codegen.mergeCargoToml('[package\n'), /isn't valid TOML/);
+    });
   });
 
   describe('helpers', () => {
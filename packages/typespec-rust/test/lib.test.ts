@@ -96,5 +96,11 @@ describe('typespec-rust: lib', () => {
     expect(properties['overwrite-cargo-toml']).toHaveProperty('default', false);
     expect(properties['overwrite-lib-rs']).toHaveProperty('default', false);
     expect(properties['temp-omit-doc-links']).toHaveProperty('default', false);
+    expect(properties['sub-module-features']).toHaveProperty('default', false);
+    expect(properties['standalone-cargo-toml']).toHaveProperty('default', false);
+    expect(properties['standalone-edition']).toHaveProperty('default', '2021');
+    expect(properties['standalone-license']).not.toHaveProperty('default');
+    expect(properties['standalone-rust-version']).toHaveProperty('default', '1.88');
+    expect(properties['standalone-azure-core-version']).toHaveProperty('default', '0.33.0');
   });
 });
@@ -7,6 +7,8 @@ mod header_traits;
 mod method_options;
 #[allow(clippy::module_inception)]
 mod models;
+mod nullable;
 pub use header_traits::*;
 pub use method_options::*;
 pub use models::*;
+pub use nullable::*;
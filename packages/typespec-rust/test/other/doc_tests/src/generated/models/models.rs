@@ -3,6 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+use super::Nullable;
 use azure_core::fmt::SafeDebug;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -31,8 +32,8 @@ pub struct Metadata {
 #[non_exhaustive]
 pub struct MetadataError {
     /// Operational visibility: Read
-    #[serde(skip_serializing)]
-    pub error: Option<MetadataErrorError>,
+    #[serde(default, skip_serializing)]
+    pub error: Nullable<MetadataErrorError>,
 }
 
 /// The inner error.
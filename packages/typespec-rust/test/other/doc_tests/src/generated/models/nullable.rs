@@ -0,0 +1,66 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A value that can be absent, an explicit null, or a value.
+///
+/// Used by nullable fields to distinguish an omitted field from an explicit `null`.
+/// For JSON merge-patch models, sending `null` removes the value
+/// and omitting the field leaves the value unchanged.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Nullable<T> {
+    /// The value is omitted.
+    #[default]
+    Absent,
+
+    /// The value is an explicit null.
+    Null,
+
+    /// The value is present.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    /// Returns `true` if the value is omitted.
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// Returns `true` if the value is an explicit null.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Nullable::Null)
+    }
+
+    /// Returns the value if present.
+    pub fn as_value(&self) -> Option<&T> {
+        match self {
+            Nullable::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<T> for Nullable<T> {
+    fn from(value: T) -> Self {
+        Nullable::Value(value)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Nullable::Value(value) => serializer.serialize_some(value),
+            Nullable::Absent | Nullable::Null => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // missing fields are handled by serde(default) so only null and values are seen here
+        Ok(Option::<T>::deserialize(deserializer)?.map_or(Nullable::Null, Nullable::Value))
+    }
+}
@@ -99,7 +99,6 @@ impl NIClient {
                 };
                 let ctx = poller_options.context.clone();
                 let pipeline = pipeline.clone();
-                let url = url.clone();
                 Box::pin(async move {
                     let rsp = pipeline
                         .send(
@@ -114,15 +113,16 @@ impl NIClient {
                         )
                         .await?;
                     let (status, headers, body) = rsp.deconstruct();
-                    let continuation = if let Some(final_link) =
-                        headers.get_optional_string(&HeaderName::from_static("location"))
-                    {
-                        let final_link = Url::parse(&final_link)?;
+                    let operation_location = json::from_json::<_, azure_core::Value>(&body)
+                        .ok()
+                        .and_then(|value| value.get("location")?.as_str().map(String::from));
+                    let continuation = if let Some(operation_location) = operation_location {
+                        let next_link = Url::parse(&operation_location)?;
                         match continuation {
-                            PollerContinuation::Links { next_link, .. } => {
+                            PollerContinuation::Links { final_link, .. } => {
                                 PollerContinuation::Links {
                                     next_link,
-                                    final_link: Some(final_link),
+                                    final_link,
                                 }
                             }
                             _ => {
@@ -132,20 +132,22 @@ impl NIClient {
                     } else {
                         continuation
                     };
-                    let final_link = match &continuation {
-                        PollerContinuation::Links { final_link, .. } => {
-                            final_link.clone().unwrap_or_else(|| url.clone())
-                        }
-                        _ => {
-                            unreachable!()
-                        }
-                    };
                     let retry_after = get_retry_after(
                         &headers,
                         &[X_MS_RETRY_AFTER_MS, RETRY_AFTER_MS, RETRY_AFTER],
                         &poller_options,
                     );
                     let res: NIClientCustomLinkOperationStatus = json::from_json(&body)?;
+                    let mut final_rsp: Option<RawResponse> = None;
+                    if res.status() == PollerStatus::Succeeded {
+                        let body = azure_core::http::response::ResponseBody::from_bytes(
+                            serde_json::from_str::<azure_core::Value>(
+                                body.clone().into_string()?.as_str(),
+                            )?["result"]
+                                .to_string(),
+                        );
+                        final_rsp = Some(RawResponse::from_bytes(status, headers.clone(), body));
+                    }
                     let rsp = RawResponse::from_bytes(status, headers, body).into();
                     Ok(match res.status() {
                         PollerStatus::InProgress => PollerResult::InProgress {
@@ -157,10 +159,11 @@ impl NIClient {
                             response: rsp,
                             target: Box::new(move || {
                                 Box::pin(async move {
-                                    let mut request = Request::new(final_link, Method::Get);
-                                    request.insert_header("accept", "application/json");
-                                    request.insert_header("content-type", "application/json");
-                                    Ok(pipeline.send(&ctx, &mut request, None).await?.into())
+                                    Ok(final_rsp
+                                        .ok_or_else(|| {
+                                            Error::new(ErrorKind::Other, "missing final response")
+                                        })?
+                                        .into())
                                 })
                             }),
                         },
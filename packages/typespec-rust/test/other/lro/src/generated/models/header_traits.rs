@@ -7,7 +7,7 @@ use super::{NIClientIncorrectCustomOpRefOperationStatus, NIClientPartialBodyOper
 use azure_core::{
     http::{
         headers::{HeaderName, Headers},
-        Response,
+        Response, Url,
     },
     Result,
 };
@@ -31,14 +31,14 @@ const OPERATION_LOCATION: HeaderName = HeaderName::from_static("operation-locati
 /// }
 /// ```
 pub trait NIClientIncorrectCustomOpRefOperationStatusHeaders: private::Sealed {
-    fn operation_location(&self) -> Result<Option<String>>;
+    fn operation_location(&self) -> Result<Option<Url>>;
 }
 
 impl NIClientIncorrectCustomOpRefOperationStatusHeaders
     for Response<NIClientIncorrectCustomOpRefOperationStatus>
 {
     /// The location for monitoring the operation state.
-    fn operation_location(&self) -> Result<Option<String>> {
+    fn operation_location(&self) -> Result<Option<Url>> {
         Headers::get_optional_as(self.headers(), &OPERATION_LOCATION)
     }
 }
@@ -60,12 +60,12 @@ impl NIClientIncorrectCustomOpRefOperationStatusHeaders
 /// }
 /// ```
 pub trait NIClientPartialBodyOperationStatusHeaders: private::Sealed {
-    fn operation_location(&self) -> Result<Option<String>>;
+    fn operation_location(&self) -> Result<Option<Url>>;
 }
 
 impl NIClientPartialBodyOperationStatusHeaders for Response<NIClientPartialBodyOperationStatus> {
     /// The location for monitoring the operation state.
-    fn operation_location(&self) -> Result<Option<String>> {
+    fn operation_location(&self) -> Result<Option<Url>> {
         Headers::get_optional_as(self.headers(), &OPERATION_LOCATION)
     }
 }
@@ -0,0 +1,105 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::{
+    http::{
+        headers::Headers, poller::PollerOptions, AsyncRawResponse, ClientOptions, HttpClient,
+        Method, Request, StatusCode, Transport,
+    },
+    time::Duration,
+    Bytes, Result,
+};
+use lro::{
+    models::{NIClientCustomLinkOptions, PutAsset},
+    NClient, NClientOptions,
+};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+const CUSTOM_LINK_URL: &str =
+    "http://localhost:3000/lro/customLink?api-version=2022-12-01-preview";
+
+/// Records the method and URL of each request and responds with the queued JSON bodies in order.
+#[derive(Debug, Default)]
+struct LroServer {
+    requests: Mutex<Vec<(Method, String)>>,
+    responses: Mutex<VecDeque<&'static str>>,
+}
+
+impl LroServer {
+    fn new(responses: &[&'static str]) -> Arc<Self> {
+        Arc::new(Self {
+            requests: Mutex::default(),
+            responses: Mutex::new(responses.iter().copied().collect()),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for LroServer {
+    async fn execute_request(&self, request: &Request) -> Result<AsyncRawResponse> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((request.method(), request.url().to_string()));
+        let body = self.responses.lock().unwrap().pop_front().unwrap();
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::Ok,
+            Headers::new(),
+            Bytes::from_static(body.as_bytes()),
+        ))
+    }
+}
+
+async fn custom_link(server: Arc<LroServer>) -> Result<PutAsset> {
+    let options = NClientOptions {
+        client_options: ClientOptions {
+            transport: Some(Transport::new(server)),
+            ..Default::default()
+        },
+    };
+    let client = NClient::with_no_credential("http://localhost:3000", Some(options))
+        .unwrap()
+        .get_ni_client();
+    let options = NIClientCustomLinkOptions {
+        method_options: PollerOptions {
+            frequency: Duration::seconds(1),
+            ..Default::default()
+        },
+    };
+    client
+        .custom_link("2022-12-01-preview", "x".to_string(), Some(options))?
+        .await?
+        .into_model()
+}
+
+#[tokio::test]
+async fn custom_link_polls_status_monitor_link() {
+    let server = LroServer::new(&[
+        r#"{"location":"http://localhost:3000/status/1"}"#,
+        r#"{"id":"1","status":"Running"}"#,
+        r#"{"id":"1","status":"Succeeded","result":{"assetId":"asset"}}"#,
+    ]);
+    let asset = custom_link(server.clone()).await.unwrap();
+    assert_eq!(asset.asset_id, Some("asset".to_string()));
+    assert_eq!(
+        *server.requests.lock().unwrap(),
+        vec![
+            (Method::Post, CUSTOM_LINK_URL.to_string()),
+            (Method::Get, "http://localhost:3000/status/1".to_string()),
+            (Method::Get, "http://localhost:3000/status/1".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn custom_link_completes_without_polling() {
+    let server =
+        LroServer::new(&[r#"{"id":"1","status":"Succeeded","result":{"assetId":"asset"}}"#]);
+    let asset = custom_link(server.clone()).await.unwrap();
+    assert_eq!(asset.asset_id, Some("asset".to_string()));
+    assert_eq!(server.requests.lock().unwrap().len(), 1);
+}
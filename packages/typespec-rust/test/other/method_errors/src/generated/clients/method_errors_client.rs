@@ -0,0 +1,169 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    MethodErrorsClientDeleteWidgetOptions, MethodErrorsClientGetWidgetOptions, Widget,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url,
+        UrlExt,
+    },
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct MethodErrorsClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`MethodErrorsClient`](MethodErrorsClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct MethodErrorsClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl MethodErrorsClient {
+    /// Creates a new MethodErrorsClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The service endpoint.
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("MethodErrors")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<MethodErrorsClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Deletes a widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The widget's ID.
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// ## Errors
+    ///
+    /// The returned error can be converted into a [`MethodErrorsClientDeleteWidgetError`], which provides
+    /// access to the following typed error responses:
+    /// * [`NotFound`](crate::generated::models::MethodErrorsClientDeleteWidgetError::NotFound) - 404
+    /// * [`Conflict`](crate::generated::models::MethodErrorsClientDeleteWidgetError::Conflict) - 409
+    ///
+    /// [`MethodErrorsClientDeleteWidgetError`]: crate::generated::models::MethodErrorsClientDeleteWidgetError
+    #[tracing::function("MethodErrors.deleteWidget")]
+    pub async fn delete_widget(
+        &self,
+        id: &str,
+        options: Option<MethodErrorsClientDeleteWidgetOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        if id.is_empty() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter id cannot be empty",
+            ));
+        }
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/widgets/{id}");
+        path = path.replace("{id}", id);
+        url.append_path(&path);
+        let mut request = Request::new(url, Method::Delete);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Gets a widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The widget's ID.
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// ## Errors
+    ///
+    /// The returned error can be converted into a [`MethodErrorsClientGetWidgetError`], which provides
+    /// access to the following typed error responses:
+    /// * [`NotFound`](crate::generated::models::MethodErrorsClientGetWidgetError::NotFound) - 404
+    ///
+    /// [`MethodErrorsClientGetWidgetError`]: crate::generated::models::MethodErrorsClientGetWidgetError
+    #[tracing::function("MethodErrors.getWidget")]
+    pub async fn get_widget(
+        &self,
+        id: &str,
+        options: Option<MethodErrorsClientGetWidgetOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        if id.is_empty() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter id cannot be empty",
+            ));
+        }
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/widgets/{id}");
+        path = path.replace("{id}", id);
+        url.append_path(&path);
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
@@ -0,0 +1,7 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod method_errors_client;
+pub use method_errors_client::*;
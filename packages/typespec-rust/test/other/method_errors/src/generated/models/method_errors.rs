@@ -0,0 +1,69 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{ConflictError, NotFoundError};
+use azure_core::{error::ErrorKind, fmt::SafeDebug, http::StatusCode};
+
+/// Provides access to typed error responses for [`MethodErrorsClient::delete_widget()`](crate::generated::clients::MethodErrorsClient::delete_widget())
+///
+/// Convert the error returned by the method into this type to match on its error responses.
+/// Errors that aren't one of the typed error responses are returned in the `Other` variant.
+#[derive(SafeDebug)]
+pub enum MethodErrorsClientDeleteWidgetError {
+    /// The service returned a 404 status code.
+    NotFound(NotFoundError),
+
+    /// The service returned a 409 status code.
+    Conflict(ConflictError),
+
+    /// Any other error.
+    Other(azure_core::Error),
+}
+
+impl From<azure_core::Error> for MethodErrorsClientDeleteWidgetError {
+    fn from(error: azure_core::Error) -> Self {
+        let typed = match error.kind() {
+            ErrorKind::HttpResponse {
+                status: StatusCode::NotFound,
+                raw_response: Some(raw_response),
+                ..
+            } => raw_response.body().json().map(Self::NotFound).ok(),
+            ErrorKind::HttpResponse {
+                status: StatusCode::Conflict,
+                raw_response: Some(raw_response),
+                ..
+            } => raw_response.body().json().map(Self::Conflict).ok(),
+            _ => None,
+        };
+        typed.unwrap_or(Self::Other(error))
+    }
+}
+
+/// Provides access to typed error responses for [`MethodErrorsClient::get_widget()`](crate::generated::clients::MethodErrorsClient::get_widget())
+///
+/// Convert the error returned by the method into this type to match on its error responses.
+/// Errors that aren't one of the typed error responses are returned in the `Other` variant.
+#[derive(SafeDebug)]
+pub enum MethodErrorsClientGetWidgetError {
+    /// The service returned a 404 status code.
+    NotFound(NotFoundError),
+
+    /// Any other error.
+    Other(azure_core::Error),
+}
+
+impl From<azure_core::Error> for MethodErrorsClientGetWidgetError {
+    fn from(error: azure_core::Error) -> Self {
+        let typed = match error.kind() {
+            ErrorKind::HttpResponse {
+                status: StatusCode::NotFound,
+                raw_response: Some(raw_response),
+                ..
+            } => raw_response.body().json().map(Self::NotFound).ok(),
+            _ => None,
+        };
+        typed.unwrap_or(Self::Other(error))
+    }
+}
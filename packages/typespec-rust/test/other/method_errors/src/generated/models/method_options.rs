@@ -0,0 +1,20 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`MethodErrorsClient::delete_widget()`](crate::generated::clients::MethodErrorsClient::delete_widget())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MethodErrorsClientDeleteWidgetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MethodErrorsClient::get_widget()`](crate::generated::clients::MethodErrorsClient::get_widget())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MethodErrorsClientGetWidgetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
@@ -0,0 +1,13 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod method_errors;
+mod method_options;
+#[allow(clippy::module_inception)]
+mod models;
+mod models_impl;
+pub use method_errors::*;
+pub use method_options::*;
+pub use models::*;
@@ -0,0 +1,43 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+
+/// The widget is in use.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct ConflictError {
+    /// The error code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// The error message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// The widget wasn't found.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct NotFoundError {
+    /// The error code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    /// The ID of the missing widget.
+    #[serde(rename = "widgetId", skip_serializing_if = "Option::is_none")]
+    pub widget_id: Option<String>,
+}
+
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct Widget {
+    /// The widget's ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The widget's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
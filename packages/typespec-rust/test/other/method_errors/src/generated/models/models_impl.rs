@@ -0,0 +1,39 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{ConflictError, NotFoundError};
+use azure_core::error::ErrorKind;
+
+impl TryFrom<azure_core::Error> for ConflictError {
+    type Error = azure_core::Error;
+    fn try_from(error: azure_core::Error) -> std::result::Result<Self, Self::Error> {
+        match error.kind() {
+            ErrorKind::HttpResponse {
+                raw_response: Some(raw_response),
+                ..
+            } => Ok(raw_response.body().json()?),
+            _ => Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                "ErrorKind was not HttpResponse and could not be parsed.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<azure_core::Error> for NotFoundError {
+    type Error = azure_core::Error;
+    fn try_from(error: azure_core::Error) -> std::result::Result<Self, Self::Error> {
+        match error.kind() {
+            ErrorKind::HttpResponse {
+                raw_response: Some(raw_response),
+                ..
+            } => Ok(raw_response.body().json()?),
+            _ => Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                "ErrorKind was not HttpResponse and could not be parsed.",
+            )),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::{
+    http::{
+        headers::Headers, AsyncRawResponse, ClientOptions, HttpClient, Request, StatusCode,
+        Transport,
+    },
+    Bytes, Result,
+};
+use method_errors::{
+    models::{MethodErrorsClientDeleteWidgetError, MethodErrorsClientGetWidgetError},
+    MethodErrorsClient, MethodErrorsClientOptions,
+};
+use std::sync::Arc;
+
+/// Responds to every request with the provided status code and body.
+#[derive(Debug)]
+struct ErrorResponder {
+    status: StatusCode,
+    body: &'static str,
+}
+
+#[async_trait::async_trait]
+impl HttpClient for ErrorResponder {
+    async fn execute_request(&self, _request: &Request) -> Result<AsyncRawResponse> {
+        Ok(AsyncRawResponse::from_bytes(
+            self.status,
+            Headers::new(),
+            Bytes::from_static(self.body.as_bytes()),
+        ))
+    }
+}
+
+fn create_client(status: StatusCode, body: &'static str) -> MethodErrorsClient {
+    let options = MethodErrorsClientOptions {
+        client_options: ClientOptions {
+            transport: Some(Transport::new(Arc::new(ErrorResponder { status, body }))),
+            ..Default::default()
+        },
+    };
+    MethodErrorsClient::with_no_credential("http://localhost:3000", Some(options)).unwrap()
+}
+
+#[tokio::test]
+async fn get_widget_not_found() {
+    let client = create_client(
+        StatusCode::NotFound,
+        r#"{"code":"WidgetNotFound","widgetId":"w1"}"#,
+    );
+    let error = client.get_widget("w1", None).await.unwrap_err();
+    match MethodErrorsClientGetWidgetError::from(error) {
+        MethodErrorsClientGetWidgetError::NotFound(not_found) => {
+            assert_eq!(not_found.code, Some("WidgetNotFound".to_string()));
+            assert_eq!(not_found.widget_id, Some("w1".to_string()));
+        }
+        other => panic!("unexpected error {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn delete_widget_not_found() {
+    let client = create_client(
+        StatusCode::NotFound,
+        r#"{"code":"WidgetNotFound","widgetId":"w1"}"#,
+    );
+    let error = client.delete_widget("w1", None).await.unwrap_err();
+    match MethodErrorsClientDeleteWidgetError::from(error) {
+        MethodErrorsClientDeleteWidgetError::NotFound(not_found) => {
+            assert_eq!(not_found.widget_id, Some("w1".to_string()));
+        }
+        other => panic!("unexpected error {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn delete_widget_conflict() {
+    let client = create_client(
+        StatusCode::Conflict,
+        r#"{"code":"WidgetInUse","message":"the widget is in use"}"#,
+    );
+    let error = client.delete_widget("w1", None).await.unwrap_err();
+    match MethodErrorsClientDeleteWidgetError::from(error) {
+        MethodErrorsClientDeleteWidgetError::Conflict(conflict) => {
+            assert_eq!(conflict.code, Some("WidgetInUse".to_string()));
+            assert_eq!(conflict.message, Some("the widget is in use".to_string()));
+        }
+        other => panic!("unexpected error {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn get_widget_untyped_status() {
+    // 409 isn't a typed error response for get_widget
+    let client = create_client(
+        StatusCode::Conflict,
+        r#"{"code":"WidgetInUse","message":"the widget is in use"}"#,
+    );
+    let error = client.get_widget("w1", None).await.unwrap_err();
+    match MethodErrorsClientGetWidgetError::from(error) {
+        MethodErrorsClientGetWidgetError::Other(error) => {
+            assert_eq!(error.http_status(), Some(StatusCode::Conflict));
+        }
+        other => panic!("unexpected error {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn get_widget_malformed_error_body() {
+    let client = create_client(StatusCode::NotFound, "not json");
+    let error = client.get_widget("w1", None).await.unwrap_err();
+    match MethodErrorsClientGetWidgetError::from(error) {
+        MethodErrorsClientGetWidgetError::Other(error) => {
+            assert_eq!(error.http_status(), Some(StatusCode::NotFound));
+        }
+        other => panic!("unexpected error {other:?}"),
+    }
+}
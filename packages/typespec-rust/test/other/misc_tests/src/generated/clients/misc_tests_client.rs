@@ -4,26 +4,32 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    ChatRequestMessage, Colors, DiscriminatedBaseNoSubTypes, GroupedParams, Indices,
-    LiteralWithInvalidChar, MiscTestsClientAvoidDupeHeadersOneOptions,
-    MiscTestsClientAvoidDupeHeadersOneResult, MiscTestsClientAvoidDupeHeadersTwoOptions,
-    MiscTestsClientAvoidDupeHeadersTwoResult, MiscTestsClientCollidingOptionsParamOptions,
-    MiscTestsClientEtagHeaderParameterOptions, MiscTestsClientGetDiscriminatedNoSubTypesOptions,
-    MiscTestsClientGetUnionsWithCyclesOptions, MiscTestsClientLiteralWithInvalidCharOptions,
-    MiscTestsClientParamGroupOptions, MiscTestsClientRequiredPathParamNoEmptyCheckOptions,
-    MiscTestsClientSpreadParamWithEnumOptions, MiscTestsClientVariousExplodedQueryParamsOptions,
-    MiscTestsClientWithClientParamsOptions, MiscTestsClientWithOptionalClientQueryParamOptions,
-    SpreadWithEnum,
+    encode_cookie_value, jsonl_stream, sse_stream, ChatRequestMessage, Colors,
+    DiscriminatedBaseNoSubTypes, GroupedParams, Indices, ItemStream, LiteralWithInvalidChar,
+    LogEntry, MiscTestsClientAvoidDupeHeadersOneOptions, MiscTestsClientAvoidDupeHeadersOneResult,
+    MiscTestsClientAvoidDupeHeadersTwoOptions, MiscTestsClientAvoidDupeHeadersTwoResult,
+    MiscTestsClientCollidingOptionsParamOptions, MiscTestsClientConstrainedParamsOptions,
+    MiscTestsClientCookieParamsOptions, MiscTestsClientEtagHeaderParameterOptions,
+    MiscTestsClientGetDiscriminatedNoSubTypesOptions, MiscTestsClientGetUnionsWithCyclesOptions,
+    MiscTestsClientListWidgetsNextPageOptions, MiscTestsClientListWidgetsOptions,
+    MiscTestsClientLiteralWithInvalidCharOptions, MiscTestsClientParamGroupOptions,
+    MiscTestsClientRequiredPathParamNoEmptyCheckOptions, MiscTestsClientSpreadParamWithEnumOptions,
+    MiscTestsClientStreamLogEntriesOptions, MiscTestsClientStreamLogEventsOptions,
+    MiscTestsClientVariousExplodedQueryParamsOptions, MiscTestsClientWithClientParamsOptions,
+    MiscTestsClientWithOptionalClientQueryParamOptions, SpreadWithEnum, WidgetPage,
 };
 use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
     http::{
-        ClientOptions, Etag, Method, NoFormat, Pipeline, PipelineSendOptions, Request,
-        RequestContent, Response, Url, UrlExt,
+        pager::{PagerContinuation, PagerResult, PagerState},
+        ClientOptions, Etag, Method, NoFormat, Pager, Pipeline, PipelineSendOptions,
+        PipelineStreamOptions, RawResponse, Request, RequestContent, Response, Url, UrlExt,
     },
-    tracing, Result,
+    json, tracing, Result,
 };
+use regex::Regex;
+use std::sync::LazyLock;
 
 #[tracing::client]
 pub struct MiscTestsClient {
@@ -227,6 +233,122 @@ impl MiscTestsClient {
         Ok(rsp.into())
     }
 
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("MiscTests.constrainedParams")]
+    pub async fn constrained_params(
+        &self,
+        name: &str,
+        options: Option<MiscTestsClientConstrainedParamsOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        if name.chars().count() < 3 {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name must be at least 3 characters",
+            ));
+        }
+        if name.chars().count() > 24 {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name must be at most 24 characters",
+            ));
+        }
+        static NAME_PATTERN: LazyLock<Result<Regex, regex::Error>> =
+            LazyLock::new(|| Regex::new(r"^[a-z][a-z0-9-]*$"));
+        match &*NAME_PATTERN {
+            Ok(name_pattern) => {
+                if !name_pattern.is_match(name) {
+                    return Err(azure_core::Error::with_message(
+                        azure_core::error::ErrorKind::Other,
+                        "parameter name must match the pattern ^[a-z][a-z0-9-]*$",
+                    ));
+                }
+            }
+            Err(err) => {
+                return Err(azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Other,
+                    format!("parameter name has an invalid pattern: {err}"),
+                ));
+            }
+        }
+        if let Some(filter) = options.filter.as_ref() {
+            if filter.chars().count() > 64 {
+                return Err(azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Other,
+                    "parameter filter must be at most 64 characters",
+                ));
+            }
+        }
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/constrained-params/{name}");
+        path = path.replace("{name}", name);
+        url.append_path(&path);
+        let mut query_builder = url.query_builder();
+        if let Some(filter) = options.filter.as_ref() {
+            query_builder.set_pair("filter", filter);
+        }
+        query_builder.build();
+        let mut request = Request::new(url, Method::Get);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("MiscTests.cookieParams")]
+    pub async fn cookie_params(
+        &self,
+        session_id: String,
+        count: i32,
+        options: Option<MiscTestsClientCookieParamsOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/cookie-params");
+        let mut request = Request::new(url, Method::Get);
+        let mut cookies = vec![
+            format!("count={count}"),
+            format!("session-id={}", encode_cookie_value(&session_id)),
+        ];
+        if let Some(theme) = options.theme.as_ref() {
+            cookies.push(format!("theme={}", encode_cookie_value(theme)));
+        }
+        request.insert_header("cookie", cookies.join("; "));
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
     ///
     /// # Arguments
     ///
@@ -321,6 +443,124 @@ impl MiscTestsClient {
         Ok(rsp.into())
     }
 
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("MiscTests.listWidgets")]
+    pub fn list_widgets(
+        &self,
+        tenant: String,
+        options: Option<MiscTestsClientListWidgetsOptions<'_>>,
+    ) -> Result<Pager<WidgetPage>> {
+        let options = options.unwrap_or_default().into_owned();
+        let pipeline = self.pipeline.clone();
+        let mut first_url = self.endpoint.clone();
+        first_url.append_path("/widgets");
+        let mut query_builder = first_url.query_builder();
+        if let Some(filter) = options.filter.as_ref() {
+            query_builder.set_pair("filter", filter);
+        }
+        query_builder.build();
+        Ok(Pager::new(
+            move |next_link: PagerState, pager_options| {
+                let next_page = matches!(next_link, PagerState::More(_));
+                let url = match next_link {
+                    PagerState::More(next_link) => {
+                        let mut next_link: Url = next_link.try_into().expect("expected Url");
+                        let mut query_builder = next_link.query_builder();
+                        if let Some(filter) = options.filter.as_ref() {
+                            query_builder.set_pair("filter", filter);
+                        }
+                        query_builder.build();
+                        next_link
+                    }
+                    PagerState::Initial => first_url.clone(),
+                };
+                let mut request = if next_page {
+                    let mut request = Request::new(url, Method::Post);
+                    request.insert_header("accept", "application/json");
+                    request.insert_header("x-ms-tenant", &tenant);
+                    request
+                } else {
+                    let mut request = Request::new(url, Method::Get);
+                    request.insert_header("accept", "application/json");
+                    request.insert_header("x-ms-tenant", &tenant);
+                    request
+                };
+                let pipeline = pipeline.clone();
+                Box::pin({
+                    let first_url = first_url.clone();
+                    async move {
+                        let rsp = pipeline
+                            .send(
+                                &pager_options.context,
+                                &mut request,
+                                Some(PipelineSendOptions {
+                                    check_success: CheckSuccessOptions {
+                                        success_codes: &[200],
+                                    },
+                                    ..Default::default()
+                                }),
+                            )
+                            .await?;
+                        let (status, headers, body) = rsp.deconstruct();
+                        let res: WidgetPage = json::from_json(&body)?;
+                        let rsp = RawResponse::from_bytes(status, headers, body).into();
+                        Ok(match res.next_link {
+                            Some(next_link) if !next_link.is_empty() => PagerResult::More {
+                                response: rsp,
+                                continuation: PagerContinuation::Link(
+                                    first_url.join(next_link.as_ref())?,
+                                ),
+                            },
+                            _ => PagerResult::Done { response: rsp },
+                        })
+                    }
+                })
+            },
+            Some(options.method_options),
+        ))
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("MiscTests.listWidgetsNextPage")]
+    pub async fn list_widgets_next_page(
+        &self,
+        tenant: String,
+        options: Option<MiscTestsClientListWidgetsNextPageOptions<'_>>,
+    ) -> Result<Response<WidgetPage>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/widgets/next-page");
+        let mut query_builder = url.query_builder();
+        if let Some(filter) = options.filter.as_ref() {
+            query_builder.set_pair("filter", filter);
+        }
+        query_builder.build();
+        let mut request = Request::new(url, Method::Post);
+        request.insert_header("accept", "application/json");
+        request.insert_header("x-ms-tenant", tenant);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
     ///
     /// # Arguments
     ///
@@ -472,6 +712,68 @@ impl MiscTestsClient {
         Ok(rsp.into())
     }
 
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("MiscTests.streamLogEntries")]
+    pub async fn stream_log_entries(
+        &self,
+        options: Option<MiscTestsClientStreamLogEntriesOptions<'_>>,
+    ) -> Result<ItemStream<LogEntry>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/stream-log-entries");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/jsonl");
+        let rsp = self
+            .pipeline
+            .stream(
+                &ctx,
+                &mut request,
+                Some(PipelineStreamOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(jsonl_stream(rsp.into_body()))
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("MiscTests.streamLogEvents")]
+    pub async fn stream_log_events(
+        &self,
+        options: Option<MiscTestsClientStreamLogEventsOptions<'_>>,
+    ) -> Result<ItemStream<LogEntry>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/stream-log-events");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "text/event-stream");
+        let rsp = self
+            .pipeline
+            .stream(
+                &ctx,
+                &mut request,
+                Some(PipelineStreamOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(sse_stream(rsp.into_body()))
+    }
+
     ///
     /// # Arguments
     ///
@@ -0,0 +1,17 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+/// Percent-encodes the UTF-8 bytes of a cookie value that aren't RFC 6265 cookie octets.
+pub(crate) fn encode_cookie_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'"' | b'%' | b',' | b';' | b'\\' => encoded.push_str(&format!("%{b:02X}")),
+            0x21..=0x7E => encoded.push(char::from(b)),
+            _ => encoded.push_str(&format!("%{b:02X}")),
+        }
+    }
+    encoded
+}
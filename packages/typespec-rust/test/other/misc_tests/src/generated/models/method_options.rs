@@ -4,7 +4,10 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use super::{Colors, Indices};
-use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+use azure_core::{
+    fmt::SafeDebug,
+    http::{pager::PagerOptions, ClientMethodOptions},
+};
 
 /// The grouped params.
 #[derive(Clone, SafeDebug)]
@@ -40,6 +43,24 @@ pub struct MiscTestsClientCollidingOptionsParamOptions<'a> {
     pub method_options: ClientMethodOptions<'a>,
 }
 
+/// Options to be passed to [`MiscTestsClient::constrained_params()`](crate::generated::clients::MiscTestsClient::constrained_params())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MiscTestsClientConstrainedParamsOptions<'a> {
+    pub filter: Option<String>,
+
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MiscTestsClient::cookie_params()`](crate::generated::clients::MiscTestsClient::cookie_params())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MiscTestsClientCookieParamsOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+
+    pub theme: Option<String>,
+}
+
 /// Options to be passed to [`MiscTestsClient::etag_header_parameter()`](crate::generated::clients::MiscTestsClient::etag_header_parameter())
 #[derive(Clone, Default, SafeDebug)]
 pub struct MiscTestsClientEtagHeaderParameterOptions<'a> {
@@ -61,6 +82,37 @@ pub struct MiscTestsClientGetUnionsWithCyclesOptions<'a> {
     pub method_options: ClientMethodOptions<'a>,
 }
 
+/// Options to be passed to [`MiscTestsClient::list_widgets_next_page()`](crate::generated::clients::MiscTestsClient::list_widgets_next_page())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MiscTestsClientListWidgetsNextPageOptions<'a> {
+    pub filter: Option<String>,
+
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MiscTestsClient::list_widgets()`](crate::generated::clients::MiscTestsClient::list_widgets())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MiscTestsClientListWidgetsOptions<'a> {
+    pub filter: Option<String>,
+
+    /// Allows customization of the method call.
+    pub method_options: PagerOptions<'a>,
+}
+
+impl MiscTestsClientListWidgetsOptions<'_> {
+    /// Transforms this [`MiscTestsClientListWidgetsOptions`] into a new `MiscTestsClientListWidgetsOptions` that owns the underlying data, cloning it if necessary.
+    pub fn into_owned(self) -> MiscTestsClientListWidgetsOptions<'static> {
+        MiscTestsClientListWidgetsOptions {
+            filter: self.filter,
+            method_options: PagerOptions {
+                context: self.method_options.context.into_owned(),
+                ..self.method_options
+            },
+        }
+    }
+}
+
 /// Options to be passed to [`MiscTestsClient::literal_with_invalid_char()`](crate::generated::clients::MiscTestsClient::literal_with_invalid_char())
 #[derive(Clone, Default, SafeDebug)]
 pub struct MiscTestsClientLiteralWithInvalidCharOptions<'a> {
@@ -93,6 +145,20 @@ pub struct MiscTestsClientSpreadParamWithEnumOptions<'a> {
     pub sides: Option<i32>,
 }
 
+/// Options to be passed to [`MiscTestsClient::stream_log_entries()`](crate::generated::clients::MiscTestsClient::stream_log_entries())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MiscTestsClientStreamLogEntriesOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MiscTestsClient::stream_log_events()`](crate::generated::clients::MiscTestsClient::stream_log_events())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MiscTestsClientStreamLogEventsOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
 /// Options to be passed to [`MiscTestsClient::various_exploded_query_params()`](crate::generated::clients::MiscTestsClient::various_exploded_query_params())
 #[derive(Clone, Default, SafeDebug)]
 pub struct MiscTestsClientVariousExplodedQueryParamsOptions<'a> {
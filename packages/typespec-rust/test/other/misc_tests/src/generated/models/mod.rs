@@ -3,6 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+mod cookies;
 mod enums;
 mod enums_impl;
 mod enums_serde;
@@ -12,10 +13,13 @@ mod method_options;
 mod models;
 mod models_impl;
 mod models_serde;
+mod streams;
 mod unions;
 mod unions_serde;
+pub use cookies::*;
 pub use enums::*;
 pub use header_traits::*;
 pub use method_options::*;
 pub use models::*;
+pub use streams::*;
 pub use unions::*;
@@ -51,6 +51,16 @@ pub struct LiteralWithInvalidChar {
     pub thing: Option<String>,
 }
 
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct LogEntry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
 /// Contains results for [`MiscTestsClient::avoid_dupe_headers_one()`](crate::generated::clients::MiscTestsClient::avoid_dupe_headers_one())
 #[derive(SafeDebug)]
 pub struct MiscTestsClientAvoidDupeHeadersOneResult;
@@ -72,6 +82,23 @@ pub(crate) struct SpreadWithEnum {
     pub(crate) sides: Option<i32>,
 }
 
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct Widget {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct WidgetPage {
+    #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+
+    #[serde(default)]
+    pub value: Vec<Widget>,
+}
+
 #[derive(Serialize)]
 pub(crate) struct UnknownChatMessageContentItemType<'a> {
     #[serde(rename = "type")]
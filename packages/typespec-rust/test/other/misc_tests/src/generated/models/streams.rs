@@ -0,0 +1,105 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    error::{Error, ErrorKind},
+    json::from_json,
+    Bytes, Result,
+};
+use futures::{
+    future::ready,
+    stream::{unfold, Stream, StreamExt, TryStreamExt},
+};
+use serde::de::DeserializeOwned;
+use std::pin::Pin;
+
+/// A stream of items parsed from a streaming response body.
+pub type ItemStream<T> = Pin<Box<dyn Stream<Item = Result<T>> + Send>>;
+
+/// Returns a stream of items parsed from a JSON Lines response body.
+pub(crate) fn jsonl_stream<T, S>(body: S) -> ItemStream<T>
+where
+    T: DeserializeOwned + Send + 'static,
+    S: Stream<Item = Result<Bytes>> + Send + Unpin + 'static,
+{
+    Box::pin(
+        lines(body)
+            .try_filter(|line| ready(!line.trim().is_empty()))
+            .and_then(|line| ready(from_json::<_, T>(line))),
+    )
+}
+
+/// Returns a stream of items parsed from the data fields of a server-sent events response body.
+pub(crate) fn sse_stream<T, S>(body: S) -> ItemStream<T>
+where
+    T: DeserializeOwned + Send + 'static,
+    S: Stream<Item = Result<Bytes>> + Send + Unpin + 'static,
+{
+    Box::pin(unfold(
+        (Box::pin(lines(body)), String::new()),
+        |(mut lines, mut data)| async move {
+            while let Some(line) = lines.next().await {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(err) => return Some((Err(err), (lines, data))),
+                };
+                if line.is_empty() {
+                    // a blank line dispatches the event
+                    if !data.is_empty() {
+                        let event = std::mem::take(&mut data);
+                        return Some((from_json::<_, T>(event), (lines, data)));
+                    }
+                } else if let Some(value) = line.strip_prefix("data:") {
+                    if !data.is_empty() {
+                        data.push('\n');
+                    }
+                    data.push_str(value.strip_prefix(' ').unwrap_or(value));
+                }
+                // comments and the event, id, and retry fields are ignored
+            }
+            // an event that isn't terminated by a blank line is discarded
+            None
+        },
+    ))
+}
+
+/// Splits a response body into lines without their line endings.
+fn lines<S>(body: S) -> impl Stream<Item = Result<String>> + Send
+where
+    S: Stream<Item = Result<Bytes>> + Send + Unpin,
+{
+    unfold(
+        (body, Vec::new(), false),
+        |(mut body, mut buffer, mut done)| async move {
+            loop {
+                if let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+                    let mut line: Vec<u8> = buffer.drain(..=pos).collect();
+                    line.pop();
+                    if line.last() == Some(&b'\r') {
+                        line.pop();
+                    }
+                    return Some((into_string(line), (body, buffer, done)));
+                }
+                if done {
+                    if buffer.is_empty() {
+                        return None;
+                    }
+                    // the final line has no line ending
+                    let line = std::mem::take(&mut buffer);
+                    return Some((into_string(line), (body, buffer, done)));
+                }
+                match body.next().await {
+                    Some(Ok(bytes)) => buffer.extend_from_slice(&bytes),
+                    Some(Err(err)) => return Some((Err(err), (body, Vec::new(), true))),
+                    None => done = true,
+                }
+            }
+        },
+    )
+}
+
+fn into_string(line: Vec<u8>) -> Result<String> {
+    String::from_utf8(line).map_err(|err| Error::new(ErrorKind::DataConversion, err))
+}
@@ -0,0 +1,389 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::{
+    http::{
+        headers::{HeaderName, Headers},
+        AsyncRawResponse, ClientOptions, HttpClient, Method, Request, StatusCode, Transport,
+    },
+    Bytes, Result,
+};
+use futures::{stream, StreamExt, TryStreamExt};
+use misc_tests::{
+    models::{
+        LogEntry, MiscTestsClientConstrainedParamsOptions, MiscTestsClientCookieParamsOptions,
+        MiscTestsClientListWidgetsOptions,
+    },
+    MiscTestsClient, MiscTestsClientOptions,
+};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+/// Records the Cookie header of each request and responds with 204 No Content.
+#[derive(Debug, Default)]
+struct CookieRecorder {
+    cookies: Mutex<Vec<Option<String>>>,
+}
+
+#[async_trait::async_trait]
+impl HttpClient for CookieRecorder {
+    async fn execute_request(&self, request: &Request) -> Result<AsyncRawResponse> {
+        let cookie = request
+            .headers()
+            .get_optional_string(&HeaderName::from_static("cookie"));
+        self.cookies.lock().unwrap().push(cookie);
+        Ok(AsyncRawResponse::from_bytes(StatusCode::NoContent, Headers::new(), Bytes::new()))
+    }
+}
+
+/// Records the method, URL, and x-ms-tenant header of each request
+/// and responds with the queued JSON bodies in order.
+#[derive(Debug, Default)]
+struct WidgetServer {
+    requests: Mutex<Vec<(Method, String, Option<String>)>>,
+    responses: Mutex<VecDeque<&'static str>>,
+}
+
+#[async_trait::async_trait]
+impl HttpClient for WidgetServer {
+    async fn execute_request(&self, request: &Request) -> Result<AsyncRawResponse> {
+        let tenant = request
+            .headers()
+            .get_optional_string(&HeaderName::from_static("x-ms-tenant"));
+        self.requests
+            .lock()
+            .unwrap()
+            .push((request.method(), request.url().to_string(), tenant));
+        let body = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("unexpected request");
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::Ok,
+            Headers::new(),
+            Bytes::from_static(body.as_bytes()),
+        ))
+    }
+}
+
+/// Responds with a body that's streamed in the provided chunks.
+#[derive(Debug)]
+struct ChunkedBody {
+    chunks: Vec<&'static str>,
+}
+
+#[async_trait::async_trait]
+impl HttpClient for ChunkedBody {
+    async fn execute_request(&self, _request: &Request) -> Result<AsyncRawResponse> {
+        let chunks: Vec<Result<Bytes>> = self
+            .chunks
+            .iter()
+            .map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+            .collect();
+        Ok(AsyncRawResponse::new(
+            StatusCode::Ok,
+            Headers::new(),
+            Box::pin(stream::iter(chunks)),
+        ))
+    }
+}
+
+fn create_client(transport: Arc<dyn HttpClient>) -> MiscTestsClient {
+    let options = MiscTestsClientOptions {
+        client_options: ClientOptions {
+            transport: Some(Transport::new(transport)),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    MiscTestsClient::with_no_credential("http://localhost:3000", Some(options)).unwrap()
+}
+
+#[tokio::test]
+async fn constrained_params() {
+    let recorder = Arc::new(CookieRecorder::default());
+    let client = create_client(recorder.clone());
+
+    for (name, message) in [
+        ("", "parameter name must be at least 3 characters"),
+        ("ab", "parameter name must be at least 3 characters"),
+        ("abcdefghijklmnopqrstuvwxy", "parameter name must be at most 24 characters"),
+        ("Abc", "parameter name must match the pattern ^[a-z][a-z0-9-]*$"),
+    ] {
+        let err = client.constrained_params(name, None).await.unwrap_err();
+        assert_eq!(err.to_string(), message);
+    }
+
+    let err = client
+        .constrained_params(
+            "abc",
+            Some(MiscTestsClientConstrainedParamsOptions {
+                filter: Some("f".repeat(65)),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "parameter filter must be at most 64 characters");
+
+    // none of the invalid requests were sent
+    assert!(recorder.cookies.lock().unwrap().is_empty());
+    client
+        .constrained_params(
+            "abc-123",
+            Some(MiscTestsClientConstrainedParamsOptions {
+                filter: Some("f".repeat(64)),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(recorder.cookies.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn cookie_params() {
+    let recorder = Arc::new(CookieRecorder::default());
+    let client = create_client(recorder.clone());
+
+    client
+        .cookie_params("abc123".to_string(), 5, None)
+        .await
+        .unwrap();
+    client
+        .cookie_params(
+            "abc123".to_string(),
+            5,
+            Some(MiscTestsClientCookieParamsOptions {
+                theme: Some("dark".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *recorder.cookies.lock().unwrap(),
+        vec![
+            Some("count=5; session-id=abc123".to_string()),
+            Some("count=5; session-id=abc123; theme=dark".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn cookie_params_encoded() {
+    let recorder = Arc::new(CookieRecorder::default());
+    let client = create_client(recorder.clone());
+
+    client
+        .cookie_params(
+            "a;b,c".to_string(),
+            5,
+            Some(MiscTestsClientCookieParamsOptions {
+                theme: Some("light blue; 100%".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *recorder.cookies.lock().unwrap(),
+        vec![Some(
+            "count=5; session-id=a%3Bb%2Cc; theme=light%20blue%3B%20100%25".to_string()
+        )]
+    );
+}
+
+#[tokio::test]
+async fn cookie_params_encoded_non_ascii() {
+    let recorder = Arc::new(CookieRecorder::default());
+    let client = create_client(recorder.clone());
+
+    client
+        .cookie_params(
+            "a\tb\u{7f}".to_string(),
+            5,
+            Some(MiscTestsClientCookieParamsOptions {
+                theme: Some("café".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *recorder.cookies.lock().unwrap(),
+        vec![Some("count=5; session-id=a%09b%7F; theme=caf%C3%A9".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn list_widgets_next_page_operation() {
+    let server = Arc::new(WidgetServer {
+        responses: Mutex::new(VecDeque::from([
+            r#"{"value":[{"name":"a"}],"nextLink":"http://localhost:3000/supplemental-path/widgets/next-page"}"#,
+            r#"{"value":[{"name":"b"}]}"#,
+        ])),
+        ..Default::default()
+    });
+    let client = create_client(server.clone());
+
+    let mut pager = client
+        .list_widgets(
+            "contoso".to_string(),
+            Some(MiscTestsClientListWidgetsOptions {
+                filter: Some("blue".to_string()),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let mut names = Vec::new();
+    while let Some(widget) = pager.next().await {
+        names.push(widget.unwrap().name.unwrap());
+    }
+    assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+
+    // the second page is fetched with the next page operation and keeps its parameters
+    assert_eq!(
+        *server.requests.lock().unwrap(),
+        vec![
+            (
+                Method::Get,
+                "http://localhost:3000/supplemental-path/widgets?filter=blue".to_string(),
+                Some("contoso".to_string()),
+            ),
+            (
+                Method::Post,
+                "http://localhost:3000/supplemental-path/widgets/next-page?filter=blue".to_string(),
+                Some("contoso".to_string()),
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn list_widgets_relative_next_link() {
+    let server = Arc::new(WidgetServer {
+        responses: Mutex::new(VecDeque::from([
+            r#"{"value":[{"name":"a"}],"nextLink":"/supplemental-path/widgets/next-page"}"#,
+            r#"{"value":[{"name":"b"}]}"#,
+        ])),
+        ..Default::default()
+    });
+    let client = create_client(server.clone());
+
+    let mut pager = client
+        .list_widgets(
+            "contoso".to_string(),
+            Some(MiscTestsClientListWidgetsOptions {
+                filter: Some("blue".to_string()),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+    let mut names = Vec::new();
+    while let Some(widget) = pager.next().await {
+        names.push(widget.unwrap().name.unwrap());
+    }
+    assert_eq!(names, vec!["a".to_string(), "b".to_string()]);
+
+    // the relative next link is resolved against the URL of the first page
+    let urls: Vec<String> = server
+        .requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(_, url, _)| url.clone())
+        .collect();
+    assert_eq!(
+        urls,
+        vec![
+            "http://localhost:3000/supplemental-path/widgets?filter=blue".to_string(),
+            "http://localhost:3000/supplemental-path/widgets/next-page?filter=blue".to_string(),
+        ]
+    );
+}
+
+fn log_entries(entries: Vec<LogEntry>) -> Vec<(String, String)> {
+    entries
+        .into_iter()
+        .map(|entry| (entry.level.unwrap(), entry.message.unwrap()))
+        .collect()
+}
+
+#[tokio::test]
+async fn stream_log_entries() {
+    // lines are split across chunks, end with CRLF or LF, and the last one has no line ending
+    let client = create_client(Arc::new(ChunkedBody {
+        chunks: vec![
+            "{\"level\":\"info\",\"message\":\"starting\"}\r\n{\"level\":",
+            "\"warn\",\"message\":\"slow\"}\n\n",
+            "{\"level\":\"info\",\"message\":\"done\"}",
+        ],
+    }));
+
+    let entries: Vec<LogEntry> = client
+        .stream_log_entries(None)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        log_entries(entries),
+        vec![
+            ("info".to_string(), "starting".to_string()),
+            ("warn".to_string(), "slow".to_string()),
+            ("info".to_string(), "done".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn stream_log_entries_invalid_line() {
+    let client = create_client(Arc::new(ChunkedBody {
+        chunks: vec!["{\"level\":\"info\",\"message\":\"starting\"}\nnot json\n"],
+    }));
+
+    let mut entries = client.stream_log_entries(None).await.unwrap();
+    assert!(entries.next().await.unwrap().is_ok());
+    assert!(entries.next().await.unwrap().is_err());
+}
+
+#[tokio::test]
+async fn stream_log_events() {
+    // comments and non-data fields are ignored, multiple data fields are joined
+    // with a newline, and an event without a terminating blank line is discarded
+    let client = create_client(Arc::new(ChunkedBody {
+        chunks: vec![
+            ": keep-alive\n\nevent: log\nid: 1\ndata: {\"level\":\"info\",",
+            "\"message\":\"starting\"}\r\n\r\ndata:{\"level\":\"warn\",\n",
+            "data: \"message\":\"slow\"}\n\nretry: 1000\n\n",
+            "data: {\"level\":\"info\",\"message\":\"dropped\"}\n",
+        ],
+    }));
+
+    let entries: Vec<LogEntry> = client
+        .stream_log_events(None)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(
+        log_entries(entries),
+        vec![
+            ("info".to_string(), "starting".to_string()),
+            ("warn".to_string(), "slow".to_string()),
+        ]
+    );
+}
@@ -10,8 +10,10 @@ mod enums_serde;
 mod models;
 mod models_impl;
 mod models_serde;
+mod nullable;
 mod unions;
 mod unions_serde;
 pub use enums::*;
 pub use models::*;
+pub use nullable::*;
 pub use unions::*;
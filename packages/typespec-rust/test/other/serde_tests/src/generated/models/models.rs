@@ -3,7 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use super::{models_serde, ExtensibleValues};
+use super::{models_serde, ExtensibleValues, Nullable};
 use azure_core::{base64, fmt::SafeDebug, time::OffsetDateTime, Value};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -214,6 +214,16 @@ pub struct MiddleType {
     pub value: Option<RecursiveType>,
 }
 
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct NullableElements {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counts: Option<HashMap<String, Nullable<i32>>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<Nullable<String>>>,
+}
+
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 #[non_exhaustive]
 pub struct OffsetDateTimeMixed {
@@ -414,8 +424,8 @@ pub struct OuterType {
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 #[non_exhaustive]
 pub struct OuterWithNullable {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<OuterWithNullableValue>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub value: Nullable<OuterWithNullableValue>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
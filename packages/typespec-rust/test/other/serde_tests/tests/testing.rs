@@ -9,7 +9,7 @@ use azure_core::{
 };
 use serde_tests::models::{
     AddlPropsInt, AddlPropsString, AddlPropsUnknown, BaseType, Derivedtype, ExtensibleValues,
-    WithNumericEnum,
+    Nullable, NullableElements, WithNumericEnum,
 };
 use std::collections::HashMap;
 
@@ -137,3 +137,28 @@ async fn test_derived_type_se() {
     // time is RFC 7231 formatted
     assert_eq!(v["time"], "Thu, 01 Jan 1970 00:00:00 GMT");
 }
+
+#[tokio::test]
+async fn test_nullable_elements_de() {
+    let json_data = r#"{"counts":{"one":1,"none":null},"values":["a",null]}"#;
+    let resp: Response<NullableElements> =
+        RawResponse::from_bytes(StatusCode::Ok, Headers::new(), json_data).into();
+    let nullable_elements = resp.into_model().unwrap();
+    assert_eq!(
+        nullable_elements.values,
+        Some(vec![Nullable::Value("a".to_string()), Nullable::Null])
+    );
+    let counts = nullable_elements.counts.unwrap();
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts["one"], Nullable::Value(1));
+    assert_eq!(counts["none"], Nullable::Null);
+}
+
+#[tokio::test]
+async fn test_nullable_elements_se() {
+    let mut nullable_elements = NullableElements::default();
+    nullable_elements.counts = Some(HashMap::from([("none".to_string(), Nullable::Null)]));
+    nullable_elements.values = Some(vec![Nullable::Null, Nullable::Value("a".to_string())]);
+    let json_body = to_json(&nullable_elements).unwrap();
+    assert_eq!(json_body, r#"{"counts":{"none":null},"values":[null,"a"]}"#);
+}
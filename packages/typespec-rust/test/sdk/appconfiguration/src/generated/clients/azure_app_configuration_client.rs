@@ -189,11 +189,11 @@ impl AzureAppConfigurationClient {
         if let Some(accept_datetime) = options.accept_datetime.as_ref() {
             request.insert_header("accept-datetime", accept_datetime);
         }
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
         if let Some(sync_token) = options.sync_token.as_ref() {
             request.insert_header("sync-token", sync_token);
@@ -291,11 +291,11 @@ impl AzureAppConfigurationClient {
         if let Some(accept_datetime) = options.accept_datetime.as_ref() {
             request.insert_header("accept-datetime", accept_datetime);
         }
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
         if let Some(sync_token) = options.sync_token.as_ref() {
             request.insert_header("sync-token", sync_token);
@@ -613,6 +613,12 @@ impl AzureAppConfigurationClient {
             ));
         }
         let options = options.unwrap_or_default();
+        if name.chars().count() > 256 {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name must be at most 256 characters",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/snapshots/{name}");
@@ -622,11 +628,11 @@ impl AzureAppConfigurationClient {
         query_builder.set_pair("api-version", &self.api_version);
         query_builder.build();
         let mut request = Request::new(url, Method::Head);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
         if let Some(sync_token) = options.sync_token.as_ref() {
             request.insert_header("sync-token", sync_token);
@@ -765,6 +771,12 @@ impl AzureAppConfigurationClient {
         options: Option<AzureAppConfigurationClientCreateSnapshotOptions<'_>>,
     ) -> Result<Poller<AzureAppConfigurationClientCreateSnapshotOperationStatus>> {
         let options = options.unwrap_or_default().into_owned();
+        if name.chars().count() > 256 {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name must be at most 256 characters",
+            ));
+        }
         let pipeline = self.pipeline.clone();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/snapshots/{name}");
@@ -947,6 +959,12 @@ impl AzureAppConfigurationClient {
             ));
         }
         let options = options.unwrap_or_default();
+        if options.match_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method delete_key_value",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/kv/{key}");
@@ -960,8 +978,8 @@ impl AzureAppConfigurationClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         request.insert_header("accept", accept);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
         if let Some(sync_token) = options.sync_token.as_ref() {
             request.insert_header("sync-token", sync_token);
@@ -1048,11 +1066,11 @@ impl AzureAppConfigurationClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         request.insert_header("accept", accept);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
         if let Some(sync_token) = options.sync_token.as_ref() {
             request.insert_header("sync-token", sync_token);
@@ -1158,11 +1176,11 @@ impl AzureAppConfigurationClient {
         if let Some(accept_datetime) = options.accept_datetime.as_ref() {
             request.insert_header("accept-datetime", accept_datetime);
         }
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
         if let Some(sync_token) = options.sync_token.as_ref() {
             request.insert_header("sync-token", sync_token);
@@ -1298,11 +1316,11 @@ impl AzureAppConfigurationClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", accept);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
         if let Some(sync_token) = options.sync_token.as_ref() {
             request.insert_header("sync-token", sync_token);
@@ -1419,11 +1437,11 @@ impl AzureAppConfigurationClient {
                 if let Some(accept_datetime) = options.accept_datetime.as_ref() {
                     request.insert_header("accept-datetime", accept_datetime);
                 }
-                if let Some(if_match) = options.if_match.as_ref() {
-                    request.insert_header("if-match", if_match);
+                if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+                    request.insert_header("if-match", if_match.to_string());
                 }
-                if let Some(if_none_match) = options.if_none_match.as_ref() {
-                    request.insert_header("if-none-match", if_none_match);
+                if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+                    request.insert_header("if-none-match", if_none_match.to_string());
                 }
                 if let Some(sync_token) = options.sync_token.as_ref() {
                     request.insert_header("sync-token", sync_token);
@@ -2017,11 +2035,11 @@ impl AzureAppConfigurationClient {
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", accept);
         request.insert_header("content-type", content_type.to_string());
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
         if let Some(sync_token) = options.sync_token.as_ref() {
             request.insert_header("sync-token", sync_token);
@@ -2111,11 +2129,11 @@ impl AzureAppConfigurationClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", accept);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
         if let Some(sync_token) = options.sync_token.as_ref() {
             request.insert_header("sync-token", sync_token);
@@ -2194,6 +2212,12 @@ impl AzureAppConfigurationClient {
             ));
         }
         let options = options.unwrap_or_default();
+        if name.chars().count() > 256 {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name must be at most 256 characters",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/snapshots/{name}");
@@ -2205,11 +2229,11 @@ impl AzureAppConfigurationClient {
         let mut request = Request::new(url, Method::Patch);
         request.insert_header("accept", accept);
         request.insert_header("content-type", content_type.to_string());
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
         if let Some(sync_token) = options.sync_token.as_ref() {
             request.insert_header("sync-token", sync_token);
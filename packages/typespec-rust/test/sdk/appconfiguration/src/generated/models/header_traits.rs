@@ -17,7 +17,7 @@ use super::{
 use azure_core::{
     http::{
         headers::{HeaderName, Headers},
-        Etag, NoFormat, Response,
+        Etag, NoFormat, Response, Url,
     },
     Result,
 };
@@ -303,7 +303,7 @@ pub trait AzureAppConfigurationClientCreateSnapshotOperationStatusHeaders: priva
     fn content_type(&self) -> Result<Option<GetSnapshotResponseContentType>>;
     fn etag_header(&self) -> Result<Option<Etag>>;
     fn link(&self) -> Result<Option<String>>;
-    fn operation_location(&self) -> Result<Option<String>>;
+    fn operation_location(&self) -> Result<Option<Url>>;
     fn sync_token(&self) -> Result<Option<String>>;
 }
 
@@ -326,7 +326,7 @@ impl AzureAppConfigurationClientCreateSnapshotOperationStatusHeaders
     }
 
     /// The location for monitoring the operation state.
-    fn operation_location(&self) -> Result<Option<String>> {
+    fn operation_location(&self) -> Result<Option<Url>> {
         Headers::get_optional_as(self.headers(), &OPERATION_LOCATION)
     }
 
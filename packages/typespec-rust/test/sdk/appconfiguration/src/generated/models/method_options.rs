@@ -3,7 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use super::{KeyValue, KeyValueFields, LabelFields, SnapshotFields, SnapshotStatus};
+use super::{KeyValue, KeyValueFields, LabelFields, MatchConditions, SnapshotFields, SnapshotStatus};
 use azure_core::{
     fmt::SafeDebug,
     http::{pager::PagerOptions, poller::PollerOptions, ClientMethodOptions, RequestContent},
@@ -16,17 +16,14 @@ pub struct AzureAppConfigurationClientCheckKeyValueOptions<'a> {
     /// time.
     pub accept_datetime: Option<String>,
 
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
-    /// Used to perform an operation only if the targeted resource's etag does not
-    /// match the value provided.
-    pub if_none_match: Option<String>,
-
     /// The label of the key-value to retrieve.
     pub label: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
+
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
@@ -52,14 +49,6 @@ pub struct AzureAppConfigurationClientCheckKeyValuesOptions<'a> {
     /// to by the specified token.
     pub after: Option<String>,
 
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
-    /// Used to perform an operation only if the targeted resource's etag does not
-    /// match the value provided.
-    pub if_none_match: Option<String>,
-
     /// A filter used to match keys. Syntax reference:
     /// <https://aka.ms/azconfig/docs/keyvaluefiltering>
     pub key: Option<String>,
@@ -68,6 +57,11 @@ pub struct AzureAppConfigurationClientCheckKeyValuesOptions<'a> {
     /// <https://aka.ms/azconfig/docs/keyvaluefiltering>
     pub label: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
+
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
@@ -167,13 +161,10 @@ pub struct AzureAppConfigurationClientCheckRevisionsOptions<'a> {
 /// Options to be passed to [`AzureAppConfigurationClient::check_snapshot()`](crate::generated::clients::AzureAppConfigurationClient::check_snapshot())
 #[derive(Clone, Default, SafeDebug)]
 pub struct AzureAppConfigurationClientCheckSnapshotOptions<'a> {
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
-    /// Used to perform an operation only if the targeted resource's etag does not
-    /// match the value provided.
-    pub if_none_match: Option<String>,
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
@@ -222,13 +213,14 @@ impl AzureAppConfigurationClientCreateSnapshotOptions<'_> {
 /// Options to be passed to [`AzureAppConfigurationClient::delete_key_value()`](crate::generated::clients::AzureAppConfigurationClient::delete_key_value())
 #[derive(Clone, Default, SafeDebug)]
 pub struct AzureAppConfigurationClientDeleteKeyValueOptions<'a> {
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
     /// The label of the key-value to delete.
     pub label: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
+
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
@@ -239,17 +231,14 @@ pub struct AzureAppConfigurationClientDeleteKeyValueOptions<'a> {
 /// Options to be passed to [`AzureAppConfigurationClient::delete_lock()`](crate::generated::clients::AzureAppConfigurationClient::delete_lock())
 #[derive(Clone, Default, SafeDebug)]
 pub struct AzureAppConfigurationClientDeleteLockOptions<'a> {
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
-    /// Used to perform an operation only if the targeted resource's etag does not
-    /// match the value provided.
-    pub if_none_match: Option<String>,
-
     /// The label, if any, of the key-value to unlock.
     pub label: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
+
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
@@ -264,17 +253,14 @@ pub struct AzureAppConfigurationClientGetKeyValueOptions<'a> {
     /// time.
     pub accept_datetime: Option<String>,
 
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
-    /// Used to perform an operation only if the targeted resource's etag does not
-    /// match the value provided.
-    pub if_none_match: Option<String>,
-
     /// The label of the key-value to retrieve.
     pub label: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
+
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
@@ -299,13 +285,10 @@ pub struct AzureAppConfigurationClientGetOperationDetailsOptions<'a> {
 /// Options to be passed to [`AzureAppConfigurationClient::get_snapshot()`](crate::generated::clients::AzureAppConfigurationClient::get_snapshot())
 #[derive(Clone, Default, SafeDebug)]
 pub struct AzureAppConfigurationClientGetSnapshotOptions<'a> {
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
-    /// Used to perform an operation only if the targeted resource's etag does not
-    /// match the value provided.
-    pub if_none_match: Option<String>,
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
@@ -328,14 +311,6 @@ pub struct AzureAppConfigurationClientListKeyValuesOptions<'a> {
     /// to by the specified token.
     pub after: Option<String>,
 
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
-    /// Used to perform an operation only if the targeted resource's etag does not
-    /// match the value provided.
-    pub if_none_match: Option<String>,
-
     /// A filter used to match keys. Syntax reference:
     /// <https://aka.ms/azconfig/docs/keyvaluefiltering>
     pub key: Option<String>,
@@ -344,6 +319,11 @@ pub struct AzureAppConfigurationClientListKeyValuesOptions<'a> {
     /// <https://aka.ms/azconfig/docs/keyvaluefiltering>
     pub label: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
+
     /// Allows customization of the method call.
     pub method_options: PagerOptions<'a>,
 
@@ -368,10 +348,9 @@ impl AzureAppConfigurationClientListKeyValuesOptions<'_> {
         AzureAppConfigurationClientListKeyValuesOptions {
             accept_datetime: self.accept_datetime,
             after: self.after,
-            if_match: self.if_match,
-            if_none_match: self.if_none_match,
             key: self.key,
             label: self.label,
+            match_conditions: self.match_conditions,
             method_options: PagerOptions {
                 context: self.method_options.context.into_owned(),
                 ..self.method_options
@@ -560,17 +539,14 @@ pub struct AzureAppConfigurationClientPutKeyValueOptions<'a> {
     /// The key-value to create.
     pub entity: Option<RequestContent<KeyValue>>,
 
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
-    /// Used to perform an operation only if the targeted resource's etag does not
-    /// match the value provided.
-    pub if_none_match: Option<String>,
-
     /// The label of the key-value to create.
     pub label: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
+
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
@@ -581,17 +557,14 @@ pub struct AzureAppConfigurationClientPutKeyValueOptions<'a> {
 /// Options to be passed to [`AzureAppConfigurationClient::put_lock()`](crate::generated::clients::AzureAppConfigurationClient::put_lock())
 #[derive(Clone, Default, SafeDebug)]
 pub struct AzureAppConfigurationClientPutLockOptions<'a> {
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
-    /// Used to perform an operation only if the targeted resource's etag does not
-    /// match the value provided.
-    pub if_none_match: Option<String>,
-
     /// The label, if any, of the key-value to lock.
     pub label: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
+
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
@@ -602,13 +575,10 @@ pub struct AzureAppConfigurationClientPutLockOptions<'a> {
 /// Options to be passed to [`AzureAppConfigurationClient::update_snapshot()`](crate::generated::clients::AzureAppConfigurationClient::update_snapshot())
 #[derive(Clone, Default, SafeDebug)]
 pub struct AzureAppConfigurationClientUpdateSnapshotOptions<'a> {
-    /// Used to perform an operation only if the targeted resource's etag matches the
-    /// value provided.
-    pub if_match: Option<String>,
-
-    /// Used to perform an operation only if the targeted resource's etag does not
-    /// match the value provided.
-    pub if_none_match: Option<String>,
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub match_conditions: MatchConditions,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
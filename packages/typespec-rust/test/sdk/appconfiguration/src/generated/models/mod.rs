@@ -11,7 +11,9 @@ mod method_options;
 #[allow(clippy::module_inception)]
 mod models;
 mod models_impl;
+mod request_conditions;
 pub use enums::*;
 pub use header_traits::*;
 pub use method_options::*;
 pub use models::*;
+pub use request_conditions::*;
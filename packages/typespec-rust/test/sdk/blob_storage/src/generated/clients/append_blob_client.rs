@@ -4,10 +4,10 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    AppendBlobClientAppendBlockFromUrlOptions, AppendBlobClientAppendBlockFromUrlResult,
-    AppendBlobClientAppendBlockOptions, AppendBlobClientAppendBlockResult,
-    AppendBlobClientCreateOptions, AppendBlobClientCreateResult, AppendBlobClientSealOptions,
-    AppendBlobClientSealResult,
+    ApiVersion, AppendBlobClientAppendBlockFromUrlOptions,
+    AppendBlobClientAppendBlockFromUrlResult, AppendBlobClientAppendBlockOptions,
+    AppendBlobClientAppendBlockResult, AppendBlobClientCreateOptions, AppendBlobClientCreateResult,
+    AppendBlobClientSealOptions, AppendBlobClientSealResult,
 };
 use azure_core::{
     base64,
@@ -25,7 +25,7 @@ use azure_core::{
 pub struct AppendBlobClient {
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
-    pub(crate) version: String,
+    pub(crate) version: ApiVersion,
 }
 
 /// Options used when creating a `AppendBlobClient`
@@ -34,7 +34,7 @@ pub struct AppendBlobClientOptions {
     /// Allows customization of the client.
     pub client_options: ClientOptions,
     /// Specifies the version of the operation to use for this request.
-    pub version: String,
+    pub version: ApiVersion,
 }
 
 impl AppendBlobClient {
@@ -109,16 +109,16 @@ impl AppendBlobClient {
             request.insert_header("content-md5", base64::encode(transactional_content_md5));
         }
         request.insert_header("content-type", "application/octet-stream");
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(append_position) = options.append_position {
@@ -163,7 +163,7 @@ impl AppendBlobClient {
                 structured_content_length.to_string(),
             );
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         request.set_body(body);
         let rsp = self
             .pipeline
@@ -247,16 +247,16 @@ impl AppendBlobClient {
         if let Some(transactional_content_md5) = options.transactional_content_md5 {
             request.insert_header("content-md5", base64::encode(transactional_content_md5));
         }
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(append_position) = options.append_position {
@@ -341,7 +341,7 @@ impl AppendBlobClient {
         if let Some(source_range) = options.source_range.as_ref() {
             request.insert_header("x-ms-source-range", source_range);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -413,16 +413,16 @@ impl AppendBlobClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("content-length", "0");
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(blob_cache_control) = options.blob_cache_control.as_ref() {
@@ -488,7 +488,7 @@ impl AppendBlobClient {
         if let Some(blob_tags_string) = options.blob_tags_string.as_ref() {
             request.insert_header("x-ms-tags", blob_tags_string);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -557,16 +557,16 @@ impl AppendBlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(append_position) = options.append_position {
@@ -575,7 +575,7 @@ impl AppendBlobClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -594,13 +594,13 @@ impl AppendBlobClient {
 }
 
 /// Default value for [`AppendBlobClientOptions::version`].
-pub(crate) const DEFAULT_VERSION: &str = "2026-04-06";
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2026_04_06;
 
 impl Default for AppendBlobClientOptions {
     fn default() -> Self {
         Self {
             client_options: ClientOptions::default(),
-            version: String::from(DEFAULT_VERSION),
+            version: DEFAULT_VERSION,
         }
     }
 }
@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    AccessTier, BlobClientAcquireLeaseOptions, BlobClientAcquireLeaseResult,
+    AccessTier, ApiVersion, BlobClientAcquireLeaseOptions, BlobClientAcquireLeaseResult,
     BlobClientBreakLeaseOptions, BlobClientBreakLeaseResult, BlobClientChangeLeaseOptions,
     BlobClientChangeLeaseResult, BlobClientCreateSnapshotOptions, BlobClientCreateSnapshotResult,
     BlobClientDeleteImmutabilityPolicyOptions, BlobClientDeleteOptions,
@@ -33,7 +33,7 @@ use std::collections::HashMap;
 pub struct BlobClient {
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
-    pub(crate) version: String,
+    pub(crate) version: ApiVersion,
 }
 
 /// Options used when creating a `BlobClient`
@@ -42,7 +42,7 @@ pub struct BlobClientOptions {
     /// Allows customization of the client.
     pub client_options: ClientOptions,
     /// Specifies the version of the operation to use for this request.
-    pub version: String,
+    pub version: ApiVersion,
 }
 
 impl BlobClient {
@@ -106,16 +106,16 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(if_tags) = options.if_tags.as_ref() {
@@ -126,7 +126,7 @@ impl BlobClient {
         if let Some(proposed_lease_id) = options.proposed_lease_id.as_ref() {
             request.insert_header("x-ms-proposed-lease-id", proposed_lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -195,16 +195,16 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(if_tags) = options.if_tags.as_ref() {
@@ -214,7 +214,7 @@ impl BlobClient {
         if let Some(break_period) = options.break_period {
             request.insert_header("x-ms-lease-break-period", break_period.to_string());
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -287,16 +287,16 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(if_tags) = options.if_tags.as_ref() {
@@ -305,7 +305,7 @@ impl BlobClient {
         request.insert_header("x-ms-lease-action", "change");
         request.insert_header("x-ms-lease-id", lease_id);
         request.insert_header("x-ms-proposed-lease-id", proposed_lease_id);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -375,16 +375,16 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(encryption_algorithm) = options.encryption_algorithm.as_ref() {
@@ -413,7 +413,7 @@ impl BlobClient {
                 request.insert_header(format!("x-ms-meta-{k}"), v);
             }
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -466,16 +466,16 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(access_tier_if_modified_since) = options.access_tier_if_modified_since {
@@ -499,7 +499,7 @@ impl BlobClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -542,7 +542,7 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -652,16 +652,16 @@ impl BlobClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/octet-stream");
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(range) = options.range.as_ref() {
@@ -700,7 +700,7 @@ impl BlobClient {
         if let Some(structured_body_type) = options.structured_body_type.as_ref() {
             request.insert_header("x-ms-structured-body", structured_body_type);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .stream(
@@ -770,7 +770,7 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -885,16 +885,16 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Head);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(encryption_algorithm) = options.encryption_algorithm.as_ref() {
@@ -915,7 +915,7 @@ impl BlobClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -959,19 +959,19 @@ impl BlobClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/xml");
-        if let Some(if_match) = options.if_match.as_ref() {
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
             request.insert_header("x-ms-blob-if-match", if_match);
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header(
                 "x-ms-blob-if-modified-since",
                 to_rfc7231(&if_modified_since),
             );
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
             request.insert_header("x-ms-blob-if-none-match", if_none_match);
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header(
                 "x-ms-blob-if-unmodified-since",
                 to_rfc7231(&if_unmodified_since),
@@ -983,7 +983,7 @@ impl BlobClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -1051,16 +1051,16 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(if_tags) = options.if_tags.as_ref() {
@@ -1068,7 +1068,7 @@ impl BlobClient {
         }
         request.insert_header("x-ms-lease-action", "release");
         request.insert_header("x-ms-lease-id", lease_id);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -1139,16 +1139,16 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(if_tags) = options.if_tags.as_ref() {
@@ -1156,7 +1156,7 @@ impl BlobClient {
         }
         request.insert_header("x-ms-lease-action", "renew");
         request.insert_header("x-ms-lease-id", lease_id);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -1186,6 +1186,24 @@ impl BlobClient {
         options: Option<BlobClientSetImmutabilityPolicyOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method set_immutability_policy",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method set_immutability_policy",
+            ));
+        }
+        if options.request_conditions.if_modified_since.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_modified_since isn't supported by method set_immutability_policy",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut query_builder = url.query_builder();
@@ -1201,7 +1219,7 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(immutability_policy_mode) = options.immutability_policy_mode.as_ref() {
@@ -1211,7 +1229,7 @@ impl BlobClient {
             );
         }
         request.insert_header("x-ms-immutability-policy-until-date", to_rfc7231(expiry));
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -1257,7 +1275,7 @@ impl BlobClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("x-ms-legal-hold", legal_hold.to_string());
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -1296,16 +1314,16 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(encryption_algorithm) = options.encryption_algorithm.as_ref() {
@@ -1332,7 +1350,7 @@ impl BlobClient {
         for (k, v) in metadata {
             request.insert_header(format!("x-ms-meta-{k}"), v);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -1369,16 +1387,16 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(blob_cache_control) = options.blob_cache_control.as_ref() {
@@ -1405,7 +1423,7 @@ impl BlobClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -1451,19 +1469,19 @@ impl BlobClient {
             request.insert_header("content-md5", base64::encode(transactional_content_md5));
         }
         request.insert_header("content-type", "application/xml");
-        if let Some(if_match) = options.if_match.as_ref() {
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
             request.insert_header("x-ms-blob-if-match", if_match);
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header(
                 "x-ms-blob-if-modified-since",
                 to_rfc7231(&if_modified_since),
             );
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
             request.insert_header("x-ms-blob-if-none-match", if_none_match);
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header(
                 "x-ms-blob-if-unmodified-since",
                 to_rfc7231(&if_unmodified_since),
@@ -1481,7 +1499,7 @@ impl BlobClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         request.set_body(tags);
         let rsp = self
             .pipeline
@@ -1539,7 +1557,7 @@ impl BlobClient {
         if let Some(rehydrate_priority) = options.rehydrate_priority.as_ref() {
             request.insert_header("x-ms-rehydrate-priority", rehydrate_priority.to_string());
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -1576,7 +1594,7 @@ impl BlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -1595,13 +1613,13 @@ impl BlobClient {
 }
 
 /// Default value for [`BlobClientOptions::version`].
-pub(crate) const DEFAULT_VERSION: &str = "2026-04-06";
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2026_04_06;
 
 impl Default for BlobClientOptions {
     fn default() -> Self {
         Self {
             client_options: ClientOptions::default(),
-            version: String::from(DEFAULT_VERSION),
+            version: DEFAULT_VERSION,
         }
     }
 }
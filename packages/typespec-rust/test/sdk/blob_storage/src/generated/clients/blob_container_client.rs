@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    BlobContainerClientAcquireLeaseOptions, BlobContainerClientAcquireLeaseResult,
+    ApiVersion, BlobContainerClientAcquireLeaseOptions, BlobContainerClientAcquireLeaseResult,
     BlobContainerClientBreakLeaseOptions, BlobContainerClientBreakLeaseResult,
     BlobContainerClientChangeLeaseOptions, BlobContainerClientChangeLeaseResult,
     BlobContainerClientCreateOptions, BlobContainerClientDeleteOptions,
@@ -33,7 +33,7 @@ use std::collections::HashMap;
 pub struct BlobContainerClient {
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
-    pub(crate) version: String,
+    pub(crate) version: ApiVersion,
 }
 
 /// Options used when creating a `BlobContainerClient`
@@ -42,7 +42,7 @@ pub struct BlobContainerClientOptions {
     /// Allows customization of the client.
     pub client_options: ClientOptions,
     /// Specifies the version of the operation to use for this request.
-    pub version: String,
+    pub version: ApiVersion,
 }
 
 impl BlobContainerClient {
@@ -97,6 +97,18 @@ impl BlobContainerClient {
         options: Option<BlobContainerClientAcquireLeaseOptions<'_>>,
     ) -> Result<Response<BlobContainerClientAcquireLeaseResult, NoFormat>> {
         let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method acquire_lease",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method acquire_lease",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut query_builder = url.query_builder();
@@ -108,10 +120,10 @@ impl BlobContainerClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         request.insert_header("x-ms-lease-action", "acquire");
@@ -119,7 +131,7 @@ impl BlobContainerClient {
         if let Some(proposed_lease_id) = options.proposed_lease_id.as_ref() {
             request.insert_header("x-ms-proposed-lease-id", proposed_lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -179,6 +191,18 @@ impl BlobContainerClient {
         options: Option<BlobContainerClientBreakLeaseOptions<'_>>,
     ) -> Result<Response<BlobContainerClientBreakLeaseResult, NoFormat>> {
         let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method break_lease",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method break_lease",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut query_builder = url.query_builder();
@@ -190,17 +214,17 @@ impl BlobContainerClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         request.insert_header("x-ms-lease-action", "break");
         if let Some(break_period) = options.break_period {
             request.insert_header("x-ms-lease-break-period", break_period.to_string());
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -264,6 +288,18 @@ impl BlobContainerClient {
         options: Option<BlobContainerClientChangeLeaseOptions<'_>>,
     ) -> Result<Response<BlobContainerClientChangeLeaseResult, NoFormat>> {
         let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method change_lease",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method change_lease",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut query_builder = url.query_builder();
@@ -275,16 +311,16 @@ impl BlobContainerClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         request.insert_header("x-ms-lease-action", "change");
         request.insert_header("x-ms-lease-id", lease_id);
         request.insert_header("x-ms-proposed-lease-id", proposed_lease_id);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -339,7 +375,7 @@ impl BlobContainerClient {
                 request.insert_header(format!("x-ms-meta-{k}"), v);
             }
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -368,6 +404,18 @@ impl BlobContainerClient {
         options: Option<BlobContainerClientDeleteOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method delete",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method delete",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut query_builder = url.query_builder();
@@ -377,16 +425,16 @@ impl BlobContainerClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -446,7 +494,7 @@ impl BlobContainerClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/xml");
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -520,7 +568,7 @@ impl BlobContainerClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -590,7 +638,7 @@ impl BlobContainerClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -671,7 +719,7 @@ impl BlobContainerClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -742,7 +790,7 @@ impl BlobContainerClient {
                 }
                 let mut request = Request::new(url, Method::Get);
                 request.insert_header("accept", "application/xml");
-                request.insert_header("x-ms-version", &version);
+                request.insert_header("x-ms-version", version.to_string());
                 let pipeline = pipeline.clone();
                 Box::pin(async move {
                     let rsp = pipeline
@@ -815,6 +863,18 @@ impl BlobContainerClient {
         options: Option<BlobContainerClientReleaseLeaseOptions<'_>>,
     ) -> Result<Response<BlobContainerClientReleaseLeaseResult, NoFormat>> {
         let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method release_lease",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method release_lease",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut query_builder = url.query_builder();
@@ -826,15 +886,15 @@ impl BlobContainerClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         request.insert_header("x-ms-lease-action", "release");
         request.insert_header("x-ms-lease-id", lease_id);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -896,6 +956,18 @@ impl BlobContainerClient {
         options: Option<BlobContainerClientRenewLeaseOptions<'_>>,
     ) -> Result<Response<BlobContainerClientRenewLeaseResult, NoFormat>> {
         let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method renew_lease",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method renew_lease",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut query_builder = url.query_builder();
@@ -907,15 +979,15 @@ impl BlobContainerClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         request.insert_header("x-ms-lease-action", "renew");
         request.insert_header("x-ms-lease-id", lease_id);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -946,6 +1018,18 @@ impl BlobContainerClient {
         options: Option<BlobContainerClientSetAccessPolicyOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method set_access_policy",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method set_access_policy",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut query_builder = url.query_builder();
@@ -958,10 +1042,10 @@ impl BlobContainerClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("content-type", "application/xml");
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(access) = options.access.as_ref() {
@@ -970,7 +1054,7 @@ impl BlobContainerClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         request.set_body(container_acl);
         let rsp = self
             .pipeline
@@ -1001,6 +1085,24 @@ impl BlobContainerClient {
         options: Option<BlobContainerClientSetMetadataOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method set_metadata",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method set_metadata",
+            ));
+        }
+        if options.request_conditions.if_unmodified_since.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_unmodified_since isn't supported by method set_metadata",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut query_builder = url.query_builder();
@@ -1012,7 +1114,7 @@ impl BlobContainerClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
         if let Some(lease_id) = options.lease_id.as_ref() {
@@ -1021,7 +1123,7 @@ impl BlobContainerClient {
         for (k, v) in metadata {
             request.insert_header(format!("x-ms-meta-{k}"), v);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -1040,13 +1142,13 @@ impl BlobContainerClient {
 }
 
 /// Default value for [`BlobContainerClientOptions::version`].
-pub(crate) const DEFAULT_VERSION: &str = "2026-04-06";
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2026_04_06;
 
 impl Default for BlobContainerClientOptions {
     fn default() -> Self {
         Self {
             client_options: ClientOptions::default(),
-            version: String::from(DEFAULT_VERSION),
+            version: DEFAULT_VERSION,
         }
     }
 }
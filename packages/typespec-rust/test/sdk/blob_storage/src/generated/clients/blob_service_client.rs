@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    BlobServiceClientFindBlobsByTagsOptions, BlobServiceClientGetAccountInfoOptions,
+    ApiVersion, BlobServiceClientFindBlobsByTagsOptions, BlobServiceClientGetAccountInfoOptions,
     BlobServiceClientGetAccountInfoResult, BlobServiceClientGetPropertiesOptions,
     BlobServiceClientGetStatisticsOptions, BlobServiceClientListContainersOptions,
     BlobServiceClientSetPropertiesOptions, BlobServiceProperties, FilterBlobSegment,
@@ -25,7 +25,7 @@ use azure_core::{
 pub struct BlobServiceClient {
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
-    pub(crate) version: String,
+    pub(crate) version: ApiVersion,
 }
 
 /// Options used when creating a `BlobServiceClient`
@@ -34,7 +34,7 @@ pub struct BlobServiceClientOptions {
     /// Allows customization of the client.
     pub client_options: ClientOptions,
     /// Specifies the version of the operation to use for this request.
-    pub version: String,
+    pub version: ApiVersion,
 }
 
 impl BlobServiceClient {
@@ -83,7 +83,7 @@ impl BlobServiceClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/xml");
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -153,7 +153,7 @@ impl BlobServiceClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -194,7 +194,7 @@ impl BlobServiceClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/xml");
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -235,7 +235,7 @@ impl BlobServiceClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/xml");
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -301,7 +301,7 @@ impl BlobServiceClient {
                 }
                 let mut request = Request::new(url, Method::Get);
                 request.insert_header("accept", "application/xml");
-                request.insert_header("x-ms-version", &version);
+                request.insert_header("x-ms-version", version.to_string());
                 let pipeline = pipeline.clone();
                 Box::pin(async move {
                     let rsp = pipeline
@@ -358,7 +358,7 @@ impl BlobServiceClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("content-type", "application/xml");
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         request.set_body(storage_service_properties);
         let rsp = self
             .pipeline
@@ -378,13 +378,13 @@ impl BlobServiceClient {
 }
 
 /// Default value for [`BlobServiceClientOptions::version`].
-pub(crate) const DEFAULT_VERSION: &str = "2026-04-06";
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2026_04_06;
 
 impl Default for BlobServiceClientOptions {
     fn default() -> Self {
         Self {
             client_options: ClientOptions::default(),
-            version: String::from(DEFAULT_VERSION),
+            version: DEFAULT_VERSION,
         }
     }
 }
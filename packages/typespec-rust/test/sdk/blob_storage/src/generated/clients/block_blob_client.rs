@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    BlockBlobClientCommitBlockListOptions, BlockBlobClientCommitBlockListResult,
+    ApiVersion, BlockBlobClientCommitBlockListOptions, BlockBlobClientCommitBlockListResult,
     BlockBlobClientGetBlockListOptions, BlockBlobClientStageBlockFromUrlOptions,
     BlockBlobClientStageBlockFromUrlResult, BlockBlobClientStageBlockOptions,
     BlockBlobClientStageBlockResult, BlockBlobClientUploadBlobFromUrlOptions,
@@ -27,7 +27,7 @@ use azure_core::{
 pub struct BlockBlobClient {
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
-    pub(crate) version: String,
+    pub(crate) version: ApiVersion,
 }
 
 /// Options used when creating a `BlockBlobClient`
@@ -36,7 +36,7 @@ pub struct BlockBlobClientOptions {
     /// Allows customization of the client.
     pub client_options: ClientOptions,
     /// Specifies the version of the operation to use for this request.
-    pub version: String,
+    pub version: ApiVersion,
 }
 
 impl BlockBlobClient {
@@ -112,16 +112,16 @@ impl BlockBlobClient {
             request.insert_header("content-md5", base64::encode(transactional_content_md5));
         }
         request.insert_header("content-type", "application/xml");
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(tier) = options.tier.as_ref() {
@@ -195,7 +195,7 @@ impl BlockBlobClient {
         if let Some(blob_tags_string) = options.blob_tags_string.as_ref() {
             request.insert_header("x-ms-tags", blob_tags_string);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         request.set_body(blocks);
         let rsp = self
             .pipeline
@@ -278,7 +278,7 @@ impl BlockBlobClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -395,7 +395,7 @@ impl BlockBlobClient {
                 structured_content_length.to_string(),
             );
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         request.set_body(body);
         let rsp = self
             .pipeline
@@ -550,7 +550,7 @@ impl BlockBlobClient {
         if let Some(source_range) = options.source_range.as_ref() {
             request.insert_header("x-ms-source-range", source_range);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -632,16 +632,16 @@ impl BlockBlobClient {
         if let Some(transactional_content_md5) = options.transactional_content_md5 {
             request.insert_header("content-md5", base64::encode(transactional_content_md5));
         }
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(tier) = options.tier.as_ref() {
@@ -753,7 +753,7 @@ impl BlockBlobClient {
         if let Some(blob_tags_string) = options.blob_tags_string.as_ref() {
             request.insert_header("x-ms-tags", blob_tags_string);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -836,16 +836,16 @@ impl BlockBlobClient {
             request.insert_header("content-md5", base64::encode(transactional_content_md5));
         }
         request.insert_header("content-type", "application/octet-stream");
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(tier) = options.tier.as_ref() {
@@ -929,7 +929,7 @@ impl BlockBlobClient {
         if let Some(blob_tags_string) = options.blob_tags_string.as_ref() {
             request.insert_header("x-ms-tags", blob_tags_string);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         request.set_body(body);
         let rsp = self
             .pipeline
@@ -949,13 +949,13 @@ impl BlockBlobClient {
 }
 
 /// Default value for [`BlockBlobClientOptions::version`].
-pub(crate) const DEFAULT_VERSION: &str = "2026-04-06";
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2026_04_06;
 
 impl Default for BlockBlobClientOptions {
     fn default() -> Self {
         Self {
             client_options: ClientOptions::default(),
-            version: String::from(DEFAULT_VERSION),
+            version: DEFAULT_VERSION,
         }
     }
 }
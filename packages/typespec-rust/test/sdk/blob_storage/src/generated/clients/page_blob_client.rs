@@ -4,12 +4,13 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    PageBlobClientClearPagesOptions, PageBlobClientClearPagesResult, PageBlobClientCreateOptions,
-    PageBlobClientCreateResult, PageBlobClientGetPageRangesOptions, PageBlobClientResizeOptions,
-    PageBlobClientResizeResult, PageBlobClientSetSequenceNumberOptions,
-    PageBlobClientSetSequenceNumberResult, PageBlobClientUploadPagesFromUrlOptions,
-    PageBlobClientUploadPagesFromUrlResult, PageBlobClientUploadPagesOptions,
-    PageBlobClientUploadPagesResult, PageList, SequenceNumberActionType,
+    ApiVersion, PageBlobClientClearPagesOptions, PageBlobClientClearPagesResult,
+    PageBlobClientCreateOptions, PageBlobClientCreateResult, PageBlobClientGetPageRangesOptions,
+    PageBlobClientResizeOptions, PageBlobClientResizeResult,
+    PageBlobClientSetSequenceNumberOptions, PageBlobClientSetSequenceNumberResult,
+    PageBlobClientUploadPagesFromUrlOptions, PageBlobClientUploadPagesFromUrlResult,
+    PageBlobClientUploadPagesOptions, PageBlobClientUploadPagesResult, PageList,
+    SequenceNumberActionType,
 };
 use azure_core::{
     base64,
@@ -27,7 +28,7 @@ use azure_core::{
 pub struct PageBlobClient {
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
-    pub(crate) version: String,
+    pub(crate) version: ApiVersion,
 }
 
 /// Options used when creating a `PageBlobClient`
@@ -36,7 +37,7 @@ pub struct PageBlobClientOptions {
     /// Allows customization of the client.
     pub client_options: ClientOptions,
     /// Specifies the version of the operation to use for this request.
-    pub version: String,
+    pub version: ApiVersion,
 }
 
 impl PageBlobClient {
@@ -101,16 +102,16 @@ impl PageBlobClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("content-length", "0");
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         request.insert_header("range", range);
@@ -156,7 +157,7 @@ impl PageBlobClient {
             request.insert_header("x-ms-lease-id", lease_id);
         }
         request.insert_header("x-ms-page-write", "clear");
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -231,16 +232,16 @@ impl PageBlobClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("content-length", "0");
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(tier) = options.tier.as_ref() {
@@ -316,7 +317,7 @@ impl PageBlobClient {
         if let Some(blob_tags_string) = options.blob_tags_string.as_ref() {
             request.insert_header("x-ms-tags", blob_tags_string);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -394,16 +395,16 @@ impl PageBlobClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/xml");
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(range) = options.range.as_ref() {
@@ -415,7 +416,7 @@ impl PageBlobClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -486,16 +487,16 @@ impl PageBlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         request.insert_header("x-ms-blob-content-length", size.to_string());
@@ -520,7 +521,7 @@ impl PageBlobClient {
         if let Some(lease_id) = options.lease_id.as_ref() {
             request.insert_header("x-ms-lease-id", lease_id);
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -592,16 +593,16 @@ impl PageBlobClient {
         }
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         if let Some(blob_sequence_number) = options.blob_sequence_number {
@@ -620,7 +621,7 @@ impl PageBlobClient {
             "x-ms-sequence-number-action",
             sequence_number_action.to_string(),
         );
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -704,16 +705,16 @@ impl PageBlobClient {
             request.insert_header("content-md5", base64::encode(transactional_content_md5));
         }
         request.insert_header("content-type", "application/octet-stream");
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         request.insert_header("range", range);
@@ -774,7 +775,7 @@ impl PageBlobClient {
                 structured_content_length.to_string(),
             );
         }
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         request.set_body(body);
         let rsp = self
             .pipeline
@@ -859,16 +860,16 @@ impl PageBlobClient {
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("content-length", content_length.to_string());
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         request.insert_header("x-ms-copy-source", source_url);
@@ -967,7 +968,7 @@ impl PageBlobClient {
             );
         }
         request.insert_header("x-ms-source-range", source_range);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -986,13 +987,13 @@ impl PageBlobClient {
 }
 
 /// Default value for [`PageBlobClientOptions::version`].
-pub(crate) const DEFAULT_VERSION: &str = "2026-04-06";
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2026_04_06;
 
 impl Default for PageBlobClientOptions {
     fn default() -> Self {
         Self {
             client_options: ClientOptions::default(),
-            version: String::from(DEFAULT_VERSION),
+            version: DEFAULT_VERSION,
         }
     }
 }
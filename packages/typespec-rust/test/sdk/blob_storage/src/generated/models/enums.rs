@@ -77,6 +77,23 @@ pub enum AccountKind {
     StorageV2,
 }
 
+/// The Azure.Storage.Blob service versions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    /// The 2025-11-05 version of the Azure.Storage.Blob service.
+    V2025_11_05,
+
+    /// The 2026-02-06 version of the Azure.Storage.Blob service.
+    V2026_02_06,
+
+    /// The 2026-04-06 version of the Azure.Storage.Blob service.
+    V2026_04_06,
+
+    /// Any other value not defined in `ApiVersion`.
+    UnknownValue(String),
+}
+
 /// The archive status.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ArchiveStatus {
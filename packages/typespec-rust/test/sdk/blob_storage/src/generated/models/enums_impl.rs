@@ -4,8 +4,8 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use super::{
-    AccessTier, AccountKind, ArchiveStatus, BlobCopySourceTags, BlobDeleteType, BlobType,
-    BlockListType, CopyStatus, DeleteSnapshotsOptionType, EncryptionAlgorithmType,
+    AccessTier, AccountKind, ApiVersion, ArchiveStatus, BlobCopySourceTags, BlobDeleteType,
+    BlobType, BlockListType, CopyStatus, DeleteSnapshotsOptionType, EncryptionAlgorithmType,
     FileShareTokenIntent, FilterBlobsIncludeItem, GeoReplicationStatusType, ImmutabilityPolicyMode,
     LeaseDuration, LeaseState, LeaseStatus, ListBlobsIncludeItem, ListContainersIncludeType,
     PremiumPageBlobAccessTier, PublicAccessType, RehydratePriority, SequenceNumberActionType,
@@ -157,6 +157,51 @@ impl Display for AccountKind {
     }
 }
 
+impl<'a> From<&'a ApiVersion> for &'a str {
+    fn from(e: &'a ApiVersion) -> Self {
+        match e {
+            ApiVersion::V2025_11_05 => "2025-11-05",
+            ApiVersion::V2026_02_06 => "2026-02-06",
+            ApiVersion::V2026_04_06 => "2026-04-06",
+            ApiVersion::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "2025-11-05" => ApiVersion::V2025_11_05,
+            "2026-02-06" => ApiVersion::V2026_02_06,
+            "2026-04-06" => ApiVersion::V2026_04_06,
+            _ => ApiVersion::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for ApiVersion {
+    fn as_ref(&self) -> &str {
+        match self {
+            ApiVersion::V2025_11_05 => "2025-11-05",
+            ApiVersion::V2026_02_06 => "2026-02-06",
+            ApiVersion::V2026_04_06 => "2026-04-06",
+            ApiVersion::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            ApiVersion::V2025_11_05 => f.write_str("2025-11-05"),
+            ApiVersion::V2026_02_06 => f.write_str("2026-02-06"),
+            ApiVersion::V2026_04_06 => f.write_str("2026-04-06"),
+            ApiVersion::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
+
 impl<'a> From<&'a ArchiveStatus> for &'a str {
     fn from(e: &'a ArchiveStatus) -> Self {
         match e {
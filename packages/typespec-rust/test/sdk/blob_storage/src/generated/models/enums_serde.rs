@@ -4,8 +4,8 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use super::{
-    AccessTier, AccountKind, ArchiveStatus, BlobCopySourceTags, BlobDeleteType, BlobType,
-    BlockListType, CopyStatus, DeleteSnapshotsOptionType, EncryptionAlgorithmType,
+    AccessTier, AccountKind, ApiVersion, ArchiveStatus, BlobCopySourceTags, BlobDeleteType,
+    BlobType, BlockListType, CopyStatus, DeleteSnapshotsOptionType, EncryptionAlgorithmType,
     FileShareTokenIntent, FilterBlobsIncludeItem, GeoReplicationStatusType, ImmutabilityPolicyMode,
     LeaseDuration, LeaseState, LeaseStatus, ListBlobsIncludeItem, ListContainersIncludeType,
     PremiumPageBlobAccessTier, PublicAccessType, RehydratePriority, SequenceNumberActionType,
@@ -51,6 +51,25 @@ impl Serialize for AccountKind {
     }
 }
 
+impl<'de> Deserialize<'de> for ApiVersion {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for ApiVersion {
+    fn serialize<S>(&self, s: S) -> ::core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(self.as_ref())
+    }
+}
+
 impl<'de> Deserialize<'de> for ArchiveStatus {
     fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
     where
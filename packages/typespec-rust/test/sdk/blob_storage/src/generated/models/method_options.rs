@@ -7,7 +7,7 @@ use super::{
     AccessTier, BlobCopySourceTags, BlobDeleteType, DeleteSnapshotsOptionType,
     EncryptionAlgorithmType, FileShareTokenIntent, FilterBlobsIncludeItem, ImmutabilityPolicyMode,
     ListBlobsIncludeItem, ListContainersIncludeType, PremiumPageBlobAccessTier, PublicAccessType,
-    RehydratePriority,
+    RehydratePriority, RequestConditions,
 };
 use azure_core::{
     fmt::SafeDebug,
@@ -47,21 +47,9 @@ pub struct AppendBlobClientAppendBlockFromUrlOptions<'a> {
     /// Valid value is backup
     pub file_request_intent: Option<FileShareTokenIntent>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
@@ -73,6 +61,11 @@ pub struct AppendBlobClientAppendBlockFromUrlOptions<'a> {
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// Specify the crc64 calculated for the range of bytes that must be read from the copy source.
     pub source_content_crc64: Option<Vec<u8>>,
 
@@ -137,21 +130,9 @@ pub struct AppendBlobClientAppendBlockOptions<'a> {
     /// If not specified, the request will be encrypted with the root account key.
     pub encryption_scope: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
@@ -163,6 +144,11 @@ pub struct AppendBlobClientAppendBlockOptions<'a> {
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// Required if the request body is a structured message. Specifies the message schema version and properties.
     pub structured_body_type: Option<String>,
 
@@ -227,21 +213,9 @@ pub struct AppendBlobClientCreateOptions<'a> {
     /// If not specified, the request will be encrypted with the root account key.
     pub encryption_scope: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Specifies the date time when the blobs immutability policy is set to expire.
     pub immutability_policy_expiry: Option<OffsetDateTime>,
 
@@ -260,6 +234,11 @@ pub struct AppendBlobClientCreateOptions<'a> {
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -272,24 +251,17 @@ pub struct AppendBlobClientSealOptions<'a> {
     /// the AppendPositionConditionNotMet error (HTTP status code 412 - Precondition Failed).
     pub append_position: Option<i64>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -297,27 +269,20 @@ pub struct AppendBlobClientSealOptions<'a> {
 /// Options to be passed to `BlobClient::acquire_lease()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobClientAcquireLeaseOptions<'a> {
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
     /// Optional. The proposed lease ID for the container.
     pub proposed_lease_id: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -332,24 +297,17 @@ pub struct BlobClientBreakLeaseOptions<'a> {
     /// the remaining lease period elapses, and an infinite lease breaks immediately.
     pub break_period: Option<i32>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -357,24 +315,17 @@ pub struct BlobClientBreakLeaseOptions<'a> {
 /// Options to be passed to `BlobClient::change_lease()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobClientChangeLeaseOptions<'a> {
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -399,21 +350,9 @@ pub struct BlobClientCreateSnapshotOptions<'a> {
     /// If not specified, the request will be encrypted with the root account key.
     pub encryption_scope: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
@@ -423,6 +362,11 @@ pub struct BlobClientCreateSnapshotOptions<'a> {
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -461,27 +405,20 @@ pub struct BlobClientDeleteOptions<'a> {
     /// and all of its snapshots. only: Delete only the blob's snapshots and not the blob itself
     pub delete_snapshots: Option<DeleteSnapshotsOptionType>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The snapshot parameter is an opaque DateTime value that, when present, specifies the blob snapshot to retrieve. For more
     /// information on working with blob snapshots, see [Creating a Snapshot of a Blob.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/creating-a-snapshot-of-a-blob)
     pub snapshot: Option<String>,
@@ -510,21 +447,9 @@ pub struct BlobClientDownloadInternalOptions<'a> {
     /// with a client token, this header should be specified using the SHA256 hash of the encryption key.
     pub encryption_key_sha256: Option<String>,
 
-    /// The request should only proceed if an entity matches this string.
-    pub if_match: Option<String>,
-
-    /// The request should only proceed if the entity was modified after this time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// The request should only proceed if no entity matches this string.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// The request should only proceed if the entity was not modified after this time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
@@ -542,6 +467,11 @@ pub struct BlobClientDownloadInternalOptions<'a> {
     /// range is less than or equal to 4 MB in size.
     pub range_get_content_md5: Option<bool>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The snapshot parameter is an opaque DateTime value that, when present, specifies the blob snapshot to retrieve. For more
     /// information on working with blob snapshots, see [Creating a Snapshot of a Blob.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/creating-a-snapshot-of-a-blob)
     pub snapshot: Option<String>,
@@ -584,27 +514,20 @@ pub struct BlobClientGetPropertiesOptions<'a> {
     /// with a client token, this header should be specified using the SHA256 hash of the encryption key.
     pub encryption_key_sha256: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The snapshot parameter is an opaque DateTime value that, when present, specifies the blob snapshot to retrieve. For more
     /// information on working with blob snapshots, see [Creating a Snapshot of a Blob.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/creating-a-snapshot-of-a-blob)
     pub snapshot: Option<String>,
@@ -620,27 +543,20 @@ pub struct BlobClientGetPropertiesOptions<'a> {
 /// Options to be passed to `BlobClient::get_tags()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobClientGetTagsOptions<'a> {
-    /// Specify an ETag value to operate only on blobs with a matching value.
-    pub if_match: Option<String>,
-
-    /// Specify this header value to operate only on a blob if it has been modified since the specified date/time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// Specify an ETag value to operate only on blobs without a matching value.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// Specify this header value to operate only on a blob if it has not been modified since the specified date/time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The snapshot parameter is an opaque DateTime value that, when present, specifies the blob snapshot to retrieve. For more
     /// information on working with blob snapshots, see [Creating a Snapshot of a Blob.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/creating-a-snapshot-of-a-blob)
     pub snapshot: Option<String>,
@@ -656,24 +572,17 @@ pub struct BlobClientGetTagsOptions<'a> {
 /// Options to be passed to `BlobClient::release_lease()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobClientReleaseLeaseOptions<'a> {
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -681,24 +590,17 @@ pub struct BlobClientReleaseLeaseOptions<'a> {
 /// Options to be passed to `BlobClient::renew_lease()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobClientRenewLeaseOptions<'a> {
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -706,15 +608,19 @@ pub struct BlobClientRenewLeaseOptions<'a> {
 /// Options to be passed to `BlobClient::set_immutability_policy()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobClientSetImmutabilityPolicyOptions<'a> {
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Specifies the immutability policy mode to set on the blob.
     pub immutability_policy_mode: Option<ImmutabilityPolicyMode>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    ///
+    /// This method only supports the `if_unmodified_since` condition. Setting any other condition returns an error.
+    pub request_conditions: RequestConditions,
+
     /// The snapshot parameter is an opaque DateTime value that, when present, specifies the blob snapshot to retrieve. For more
     /// information on working with blob snapshots, see [Creating a Snapshot of a Blob.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/creating-a-snapshot-of-a-blob)
     pub snapshot: Option<String>,
@@ -765,27 +671,20 @@ pub struct BlobClientSetMetadataOptions<'a> {
     /// If not specified, the request will be encrypted with the root account key.
     pub encryption_scope: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -816,27 +715,20 @@ pub struct BlobClientSetPropertiesOptions<'a> {
     /// Optional. Sets the blob's content type. If specified, this property is stored with the blob and returned with a read request.
     pub blob_content_type: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -844,27 +736,20 @@ pub struct BlobClientSetPropertiesOptions<'a> {
 /// Options to be passed to `BlobClient::set_tags()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobClientSetTagsOptions<'a> {
-    /// Specify an ETag value to operate only on blobs with a matching value.
-    pub if_match: Option<String>,
-
-    /// Specify this header value to operate only on a blob if it has been modified since the specified date/time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// Specify an ETag value to operate only on blobs without a matching value.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// Specify this header value to operate only on a blob if it has not been modified since the specified date/time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 
@@ -921,18 +806,20 @@ pub struct BlobClientUndeleteOptions<'a> {
 /// Options to be passed to `BlobContainerClient::acquire_lease()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobContainerClientAcquireLeaseOptions<'a> {
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
     /// Optional. The proposed lease ID for the container.
     pub proposed_lease_id: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    ///
+    /// This method only supports the `if_modified_since` and `if_unmodified_since` conditions. Setting any other condition returns
+    /// an error.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -947,15 +834,17 @@ pub struct BlobContainerClientBreakLeaseOptions<'a> {
     /// the remaining lease period elapses, and an infinite lease breaks immediately.
     pub break_period: Option<i32>,
 
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    ///
+    /// This method only supports the `if_modified_since` and `if_unmodified_since` conditions. Setting any other condition returns
+    /// an error.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -963,15 +852,17 @@ pub struct BlobContainerClientBreakLeaseOptions<'a> {
 /// Options to be passed to `BlobContainerClient::change_lease()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobContainerClientChangeLeaseOptions<'a> {
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    ///
+    /// This method only supports the `if_modified_since` and `if_unmodified_since` conditions. Setting any other condition returns
+    /// an error.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -1003,18 +894,20 @@ pub struct BlobContainerClientCreateOptions<'a> {
 /// Options to be passed to `BlobContainerClient::delete()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobContainerClientDeleteOptions<'a> {
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    ///
+    /// This method only supports the `if_modified_since` and `if_unmodified_since` conditions. Setting any other condition returns
+    /// an error.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -1129,15 +1022,17 @@ impl BlobContainerClientListBlobsOptions<'_> {
 /// Options to be passed to `BlobContainerClient::release_lease()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobContainerClientReleaseLeaseOptions<'a> {
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    ///
+    /// This method only supports the `if_modified_since` and `if_unmodified_since` conditions. Setting any other condition returns
+    /// an error.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -1145,15 +1040,17 @@ pub struct BlobContainerClientReleaseLeaseOptions<'a> {
 /// Options to be passed to `BlobContainerClient::renew_lease()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobContainerClientRenewLeaseOptions<'a> {
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    ///
+    /// This method only supports the `if_modified_since` and `if_unmodified_since` conditions. Setting any other condition returns
+    /// an error.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -1164,18 +1061,20 @@ pub struct BlobContainerClientSetAccessPolicyOptions<'a> {
     /// The public access setting for the container.
     pub access: Option<PublicAccessType>,
 
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    ///
+    /// This method only supports the `if_modified_since` and `if_unmodified_since` conditions. Setting any other condition returns
+    /// an error.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -1183,15 +1082,19 @@ pub struct BlobContainerClientSetAccessPolicyOptions<'a> {
 /// Options to be passed to `BlobContainerClient::set_metadata()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct BlobContainerClientSetMetadataOptions<'a> {
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    ///
+    /// This method only supports the `if_modified_since` condition. Setting any other condition returns an error.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -1348,21 +1251,9 @@ pub struct BlockBlobClientCommitBlockListOptions<'a> {
     /// If not specified, the request will be encrypted with the root account key.
     pub encryption_scope: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Specifies the date time when the blobs immutability policy is set to expire.
     pub immutability_policy_expiry: Option<OffsetDateTime>,
 
@@ -1381,6 +1272,11 @@ pub struct BlockBlobClientCommitBlockListOptions<'a> {
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The tier to be set on the blob.
     pub tier: Option<AccessTier>,
 
@@ -1584,21 +1480,9 @@ pub struct BlockBlobClientUploadBlobFromUrlOptions<'a> {
     /// Valid value is backup
     pub file_request_intent: Option<FileShareTokenIntent>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
@@ -1608,6 +1492,11 @@ pub struct BlockBlobClientUploadBlobFromUrlOptions<'a> {
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// Specify the md5 calculated for the range of bytes that must be read from the copy source.
     pub source_content_md5: Option<Vec<u8>>,
 
@@ -1693,21 +1582,9 @@ pub struct BlockBlobClientUploadInternalOptions<'a> {
     /// If not specified, the request will be encrypted with the root account key.
     pub encryption_scope: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Specifies the date time when the blobs immutability policy is set to expire.
     pub immutability_policy_expiry: Option<OffsetDateTime>,
 
@@ -1726,6 +1603,11 @@ pub struct BlockBlobClientUploadInternalOptions<'a> {
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// Required if the request body is a structured message. Specifies the message schema version and properties.
     pub structured_body_type: Option<String>,
 
@@ -1767,15 +1649,6 @@ pub struct PageBlobClientClearPagesOptions<'a> {
     /// If not specified, the request will be encrypted with the root account key.
     pub encryption_scope: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify this header value to operate only on a blob if it has the specified sequence number.
     pub if_sequence_number_equal_to: Option<i64>,
 
@@ -1788,15 +1661,17 @@ pub struct PageBlobClientClearPagesOptions<'a> {
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -1851,21 +1726,9 @@ pub struct PageBlobClientCreateOptions<'a> {
     /// If not specified, the request will be encrypted with the root account key.
     pub encryption_scope: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Specifies the date time when the blobs immutability policy is set to expire.
     pub immutability_policy_expiry: Option<OffsetDateTime>,
 
@@ -1884,6 +1747,11 @@ pub struct PageBlobClientCreateOptions<'a> {
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// Optional. Indicates the tier to be set on the page blob.
     pub tier: Option<PremiumPageBlobAccessTier>,
 
@@ -1894,21 +1762,9 @@ pub struct PageBlobClientCreateOptions<'a> {
 /// Options to be passed to `PageBlobClient::get_page_ranges()`
 #[derive(Clone, Default, SafeDebug)]
 pub struct PageBlobClientGetPageRangesOptions<'a> {
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
@@ -1928,6 +1784,11 @@ pub struct PageBlobClientGetPageRangesOptions<'a> {
     /// Return only the bytes of the blob in the specified range.
     pub range: Option<String>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The snapshot parameter is an opaque DateTime value that, when present, specifies the blob snapshot to retrieve. For more
     /// information on working with blob snapshots, see [Creating a Snapshot of a Blob.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/creating-a-snapshot-of-a-blob)
     pub snapshot: Option<String>,
@@ -1956,27 +1817,20 @@ pub struct PageBlobClientResizeOptions<'a> {
     /// If not specified, the request will be encrypted with the root account key.
     pub encryption_scope: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -1988,27 +1842,20 @@ pub struct PageBlobClientSetSequenceNumberOptions<'a> {
     /// of the sequence number must be between 0 and 2^63 - 1.
     pub blob_sequence_number: Option<i64>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 }
@@ -2039,15 +1886,6 @@ pub struct PageBlobClientUploadPagesFromUrlOptions<'a> {
     /// Valid value is backup
     pub file_request_intent: Option<FileShareTokenIntent>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify this header value to operate only on a blob if it has the specified sequence number.
     pub if_sequence_number_equal_to: Option<i64>,
 
@@ -2060,15 +1898,17 @@ pub struct PageBlobClientUploadPagesFromUrlOptions<'a> {
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// Specify the crc64 calculated for the range of bytes that must be read from the copy source.
     pub source_content_crc64: Option<Vec<u8>>,
 
@@ -2121,15 +1961,6 @@ pub struct PageBlobClientUploadPagesOptions<'a> {
     /// If not specified, the request will be encrypted with the root account key.
     pub encryption_scope: Option<String>,
 
-    /// A condition that must be met in order for the request to be processed.
-    pub if_match: Option<String>,
-
-    /// A date-time value. A request is made under the condition that the resource has been modified since the specified date-time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// A condition that must be met in order for the request to be processed.
-    pub if_none_match: Option<String>,
-
     /// Specify this header value to operate only on a blob if it has the specified sequence number.
     pub if_sequence_number_equal_to: Option<i64>,
 
@@ -2142,15 +1973,17 @@ pub struct PageBlobClientUploadPagesOptions<'a> {
     /// Specify a SQL where clause on blob tags to operate only on blobs with a matching value.
     pub if_tags: Option<String>,
 
-    /// A date-time value. A request is made under the condition that the resource has not been modified since the specified date-time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
     pub lease_id: Option<String>,
 
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a `ConditionalRequestError`.
+    pub request_conditions: RequestConditions,
+
     /// Required if the request body is a structured message. Specifies the message schema version and properties.
     pub structured_body_type: Option<String>,
 
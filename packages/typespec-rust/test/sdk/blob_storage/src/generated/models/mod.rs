@@ -12,8 +12,10 @@ mod method_options;
 mod models;
 mod models_impl;
 mod models_serde;
+mod request_conditions;
 mod xml_helpers;
 pub use enums::*;
 pub use header_traits::*;
 pub use method_options::*;
 pub use models::*;
+pub use request_conditions::*;
@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    BackupKeyResult, CreateKeyParameters, DeletedKey, GetRandomBytesParameters,
+    ApiVersion, BackupKeyResult, CreateKeyParameters, DeletedKey, GetRandomBytesParameters,
     ImportKeyParameters, Key, KeyClientBackupKeyOptions, KeyClientCreateKeyOptions,
     KeyClientDecryptOptions, KeyClientDeleteKeyOptions, KeyClientEncryptOptions,
     KeyClientGetDeletedKeyOptions, KeyClientGetKeyAttestationOptions, KeyClientGetKeyOptions,
@@ -29,11 +29,13 @@ use azure_core::{
     },
     json, tracing, Result,
 };
+use regex::Regex;
+use std::sync::LazyLock;
 
 /// The key vault client performs cryptographic key operations and vault operations against the Key Vault service.
 #[tracing::client]
 pub struct KeyClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -78,7 +80,7 @@ impl KeyClient {
         path = path.replace("{key-name}", key_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -124,13 +126,31 @@ impl KeyClient {
             ));
         }
         let options = options.unwrap_or_default();
+        static KEY_NAME_PATTERN: LazyLock<Result<Regex, regex::Error>> =
+            LazyLock::new(|| Regex::new(r"^[0-9a-zA-Z-]+$"));
+        match &*KEY_NAME_PATTERN {
+            Ok(key_name_pattern) => {
+                if !key_name_pattern.is_match(key_name) {
+                    return Err(azure_core::Error::with_message(
+                        azure_core::error::ErrorKind::Other,
+                        "parameter key_name must match the pattern ^[0-9a-zA-Z-]+$",
+                    ));
+                }
+            }
+            Err(err) => {
+                return Err(azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Other,
+                    format!("parameter key_name has an invalid pattern: {err}"),
+                ));
+            }
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/keys/{key-name}/create");
         path = path.replace("{key-name}", key_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -193,7 +213,7 @@ impl KeyClient {
         path = path.replace("{key-version}", key_version);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -244,7 +264,7 @@ impl KeyClient {
         path = path.replace("{key-name}", key_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         request.insert_header("accept", "application/json");
@@ -304,7 +324,7 @@ impl KeyClient {
         path = path.replace("{key-version}", key_version);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -354,7 +374,7 @@ impl KeyClient {
         path = path.replace("{key-name}", key_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -406,7 +426,7 @@ impl KeyClient {
         };
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -448,6 +468,12 @@ impl KeyClient {
             ));
         }
         let options = options.unwrap_or_default();
+        if [ApiVersion::V7_5].contains(&self.api_version) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "method get_key_attestation is only available in api-versions 7.6-preview.2, 7.6, 2025-06-01-preview, 2025-07-01",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/keys/{key-name}/{key-version}/attestation");
@@ -458,7 +484,7 @@ impl KeyClient {
         };
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -506,7 +532,7 @@ impl KeyClient {
         path = path.replace("{key-name}", key_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -545,7 +571,7 @@ impl KeyClient {
         let mut url = self.endpoint.clone();
         url.append_path("/rng");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -592,13 +618,31 @@ impl KeyClient {
             ));
         }
         let options = options.unwrap_or_default();
+        static KEY_NAME_PATTERN: LazyLock<Result<Regex, regex::Error>> =
+            LazyLock::new(|| Regex::new(r"^[0-9a-zA-Z-]+$"));
+        match &*KEY_NAME_PATTERN {
+            Ok(key_name_pattern) => {
+                if !key_name_pattern.is_match(key_name) {
+                    return Err(azure_core::Error::with_message(
+                        azure_core::error::ErrorKind::Other,
+                        "parameter key_name must match the pattern ^[0-9a-zA-Z-]+$",
+                    ));
+                }
+            }
+            Err(err) => {
+                return Err(azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Other,
+                    format!("parameter key_name has an invalid pattern: {err}"),
+                ));
+            }
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/keys/{key-name}");
         path = path.replace("{key-name}", key_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", "application/json");
@@ -640,7 +684,7 @@ impl KeyClient {
         let mut first_url = self.endpoint.clone();
         first_url.append_path("/deletedkeys");
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         if let Some(maxresults) = options.maxresults {
             query_builder.set_pair("maxresults", maxresults.to_string());
         }
@@ -652,7 +696,7 @@ impl KeyClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -714,7 +758,7 @@ impl KeyClient {
         let mut first_url = self.endpoint.clone();
         first_url.append_path("/keys");
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         if let Some(maxresults) = options.maxresults {
             query_builder.set_pair("maxresults", maxresults.to_string());
         }
@@ -726,7 +770,7 @@ impl KeyClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -796,7 +840,7 @@ impl KeyClient {
         path = path.replace("{key-name}", key_name);
         first_url.append_path(&path);
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         if let Some(maxresults) = options.maxresults {
             query_builder.set_pair("maxresults", maxresults.to_string());
         }
@@ -808,7 +852,7 @@ impl KeyClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -879,7 +923,7 @@ impl KeyClient {
         path = path.replace("{key-name}", key_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         let rsp = self
@@ -927,7 +971,7 @@ impl KeyClient {
         path = path.replace("{key-name}", key_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -981,7 +1025,7 @@ impl KeyClient {
         };
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -1029,7 +1073,7 @@ impl KeyClient {
         let mut url = self.endpoint.clone();
         url.append_path("/keys/restore");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -1072,13 +1116,31 @@ impl KeyClient {
             ));
         }
         let options = options.unwrap_or_default();
+        static KEY_NAME_PATTERN: LazyLock<Result<Regex, regex::Error>> =
+            LazyLock::new(|| Regex::new(r"^[0-9a-zA-Z-]+$"));
+        match &*KEY_NAME_PATTERN {
+            Ok(key_name_pattern) => {
+                if !key_name_pattern.is_match(key_name) {
+                    return Err(azure_core::Error::with_message(
+                        azure_core::error::ErrorKind::Other,
+                        "parameter key_name must match the pattern ^[0-9a-zA-Z-]+$",
+                    ));
+                }
+            }
+            Err(err) => {
+                return Err(azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Other,
+                    format!("parameter key_name has an invalid pattern: {err}"),
+                ));
+            }
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/keys/{key-name}/rotate");
         path = path.replace("{key-name}", key_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -1134,7 +1196,7 @@ impl KeyClient {
         path = path.replace("{key-version}", key_version);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -1193,7 +1255,7 @@ impl KeyClient {
         path = path.replace("{key-version}", key_version);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -1250,7 +1312,7 @@ impl KeyClient {
         };
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Patch);
         request.insert_header("accept", "application/json");
@@ -1301,7 +1363,7 @@ impl KeyClient {
         path = path.replace("{key-name}", key_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", "application/json");
@@ -1361,7 +1423,7 @@ impl KeyClient {
         path = path.replace("{key-version}", key_version);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -1422,7 +1484,7 @@ impl KeyClient {
         path = path.replace("{key-version}", key_version);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -1452,4 +1514,4 @@ impl KeyClient {
 /// SDK author provides a custom options type and should reference this constant
 /// in their `Default` implementation rather than hardcoding the value.
 #[allow(dead_code)]
-pub(crate) const DEFAULT_API_VERSION: &str = "2025-07-01";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2025_07_01;
@@ -3,6 +3,29 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+/// The available API versions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    /// The 2025-06-01-preview API version.
+    V2025_06_01Preview,
+
+    /// The 2025-07-01 API version.
+    V2025_07_01,
+
+    /// The 7.5 API version.
+    V7_5,
+
+    /// The 7.6 API version.
+    V7_6,
+
+    /// The 7.6-preview.2 API version.
+    V7_6Preview2,
+
+    /// Any other value not defined in `ApiVersion`.
+    UnknownValue(String),
+}
+
 /// Elliptic curve name. For valid values, see JsonWebKeyCurveName.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CurveName {
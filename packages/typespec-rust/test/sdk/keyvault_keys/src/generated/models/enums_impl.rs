@@ -4,8 +4,8 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use super::{
-    CurveName, DeletionRecoveryLevel, EncryptionAlgorithm, KeyEncryptionAlgorithm, KeyOperation,
-    KeyRotationPolicyAction, KeyType, SignatureAlgorithm,
+    ApiVersion, CurveName, DeletionRecoveryLevel, EncryptionAlgorithm, KeyEncryptionAlgorithm,
+    KeyOperation, KeyRotationPolicyAction, KeyType, SignatureAlgorithm,
 };
 use azure_core::error::{Error, ErrorKind};
 use std::{
@@ -14,6 +14,59 @@ use std::{
     str::FromStr,
 };
 
+impl<'a> From<&'a ApiVersion> for &'a str {
+    fn from(e: &'a ApiVersion) -> Self {
+        match e {
+            ApiVersion::V2025_06_01Preview => "2025-06-01-preview",
+            ApiVersion::V2025_07_01 => "2025-07-01",
+            ApiVersion::V7_5 => "7.5",
+            ApiVersion::V7_6 => "7.6",
+            ApiVersion::V7_6Preview2 => "7.6-preview.2",
+            ApiVersion::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "2025-06-01-preview" => ApiVersion::V2025_06_01Preview,
+            "2025-07-01" => ApiVersion::V2025_07_01,
+            "7.5" => ApiVersion::V7_5,
+            "7.6" => ApiVersion::V7_6,
+            "7.6-preview.2" => ApiVersion::V7_6Preview2,
+            _ => ApiVersion::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for ApiVersion {
+    fn as_ref(&self) -> &str {
+        match self {
+            ApiVersion::V2025_06_01Preview => "2025-06-01-preview",
+            ApiVersion::V2025_07_01 => "2025-07-01",
+            ApiVersion::V7_5 => "7.5",
+            ApiVersion::V7_6 => "7.6",
+            ApiVersion::V7_6Preview2 => "7.6-preview.2",
+            ApiVersion::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            ApiVersion::V2025_06_01Preview => f.write_str("2025-06-01-preview"),
+            ApiVersion::V2025_07_01 => f.write_str("2025-07-01"),
+            ApiVersion::V7_5 => f.write_str("7.5"),
+            ApiVersion::V7_6 => f.write_str("7.6"),
+            ApiVersion::V7_6Preview2 => f.write_str("7.6-preview.2"),
+            ApiVersion::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
+
 impl<'a> From<&'a CurveName> for &'a str {
     fn from(e: &'a CurveName) -> Self {
         match e {
@@ -10,6 +10,8 @@ mod method_options;
 #[allow(clippy::module_inception)]
 mod models;
 mod models_impl;
+mod nullable;
 pub use enums::*;
 pub use method_options::*;
 pub use models::*;
+pub use nullable::*;
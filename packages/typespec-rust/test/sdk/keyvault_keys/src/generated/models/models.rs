@@ -5,7 +5,7 @@
 
 use super::{
     CurveName, DeletionRecoveryLevel, EncryptionAlgorithm, KeyEncryptionAlgorithm, KeyOperation,
-    KeyRotationPolicyAction, KeyType, SignatureAlgorithm,
+    KeyRotationPolicyAction, KeyType, Nullable, SignatureAlgorithm,
 };
 use azure_core::{base64, fmt::SafeDebug, time::OffsetDateTime};
 use serde::{Deserialize, Serialize};
@@ -685,8 +685,8 @@ pub struct KeyVaultError {
     /// The key vault server error.
     ///
     /// Operational visibility: Read
-    #[serde(skip_serializing)]
-    pub error: Option<KeyVaultErrorError>,
+    #[serde(default, skip_serializing)]
+    pub error: Nullable<KeyVaultErrorError>,
 }
 
 /// The key vault server error.
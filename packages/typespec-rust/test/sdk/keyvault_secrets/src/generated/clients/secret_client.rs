@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    BackupSecretResult, DeletedSecret, ListDeletedSecretPropertiesResult,
+    ApiVersion, BackupSecretResult, DeletedSecret, ListDeletedSecretPropertiesResult,
     ListSecretPropertiesResult, RestoreSecretParameters, Secret, SecretClientBackupSecretOptions,
     SecretClientDeleteSecretOptions, SecretClientGetDeletedSecretOptions,
     SecretClientGetSecretOptions, SecretClientListDeletedSecretPropertiesOptions,
@@ -22,11 +22,13 @@ use azure_core::{
     },
     json, tracing, Result,
 };
+use regex::Regex;
+use std::sync::LazyLock;
 
 /// The key vault client performs cryptographic key operations and vault operations against the Key Vault service.
 #[tracing::client]
 pub struct SecretClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -65,7 +67,7 @@ impl SecretClient {
         path = path.replace("{secret-name}", secret_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -113,7 +115,7 @@ impl SecretClient {
         path = path.replace("{secret-name}", secret_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         request.insert_header("accept", "application/json");
@@ -161,7 +163,7 @@ impl SecretClient {
         path = path.replace("{secret-name}", secret_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -202,6 +204,15 @@ impl SecretClient {
             ));
         }
         let options = options.unwrap_or_default();
+        if options.out_content_type.is_some()
+            && [ApiVersion::V7_5, ApiVersion::V7_6Preview2, ApiVersion::V7_6]
+                .contains(&self.api_version)
+        {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter out_content_type is only available in api-version 2025-06-01-preview",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/secrets/{secret-name}/{secret-version}");
@@ -212,7 +223,7 @@ impl SecretClient {
         };
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         if let Some(out_content_type) = options.out_content_type.as_ref() {
             query_builder.set_pair("outContentType", out_content_type.as_ref());
         }
@@ -253,7 +264,7 @@ impl SecretClient {
         let mut first_url = self.endpoint.clone();
         first_url.append_path("/deletedsecrets");
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         if let Some(maxresults) = options.maxresults {
             query_builder.set_pair("maxresults", maxresults.to_string());
         }
@@ -265,7 +276,7 @@ impl SecretClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -327,7 +338,7 @@ impl SecretClient {
         let mut first_url = self.endpoint.clone();
         first_url.append_path("/secrets");
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         if let Some(maxresults) = options.maxresults {
             query_builder.set_pair("maxresults", maxresults.to_string());
         }
@@ -339,7 +350,7 @@ impl SecretClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -410,7 +421,7 @@ impl SecretClient {
         path = path.replace("{secret-name}", secret_name);
         first_url.append_path(&path);
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         if let Some(maxresults) = options.maxresults {
             query_builder.set_pair("maxresults", maxresults.to_string());
         }
@@ -422,7 +433,7 @@ impl SecretClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -493,7 +504,7 @@ impl SecretClient {
         path = path.replace("{secret-name}", secret_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         let rsp = self
@@ -540,7 +551,7 @@ impl SecretClient {
         path = path.replace("{secret-name}", secret_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -579,7 +590,7 @@ impl SecretClient {
         let mut url = self.endpoint.clone();
         url.append_path("/secrets/restore");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -626,13 +637,31 @@ impl SecretClient {
             ));
         }
         let options = options.unwrap_or_default();
+        static SECRET_NAME_PATTERN: LazyLock<Result<Regex, regex::Error>> =
+            LazyLock::new(|| Regex::new(r"^[0-9a-zA-Z-]+$"));
+        match &*SECRET_NAME_PATTERN {
+            Ok(secret_name_pattern) => {
+                if !secret_name_pattern.is_match(secret_name) {
+                    return Err(azure_core::Error::with_message(
+                        azure_core::error::ErrorKind::Other,
+                        "parameter secret_name must match the pattern ^[0-9a-zA-Z-]+$",
+                    ));
+                }
+            }
+            Err(err) => {
+                return Err(azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Other,
+                    format!("parameter secret_name has an invalid pattern: {err}"),
+                ));
+            }
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/secrets/{secret-name}");
         path = path.replace("{secret-name}", secret_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", "application/json");
@@ -688,7 +717,7 @@ impl SecretClient {
         };
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Patch);
         request.insert_header("accept", "application/json");
@@ -718,4 +747,4 @@ impl SecretClient {
 /// SDK author provides a custom options type and should reference this constant
 /// in their `Default` implementation rather than hardcoding the value.
 #[allow(dead_code)]
-pub(crate) const DEFAULT_API_VERSION: &str = "2025-06-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2025_06_01Preview;
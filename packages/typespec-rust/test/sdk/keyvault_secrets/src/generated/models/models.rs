@@ -3,7 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use super::DeletionRecoveryLevel;
+use super::{DeletionRecoveryLevel, Nullable};
 use azure_core::{base64, fmt::SafeDebug, time::OffsetDateTime};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -152,8 +152,8 @@ pub struct KeyVaultError {
     /// The key vault server error.
     ///
     /// Operational visibility: Read
-    #[serde(skip_serializing)]
-    pub error: Option<KeyVaultErrorError>,
+    #[serde(default, skip_serializing)]
+    pub error: Nullable<KeyVaultErrorError>,
 }
 
 /// The key vault server error.
@@ -3,16 +3,20 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::models::{ApiKeyClientInvalidOptions, ApiKeyClientValidOptions};
+use crate::generated::{
+    clients::{KeyCredential, KeyCredentialLocation, KeyCredentialPolicy},
+    models::{ApiKeyClientInvalidOptions, ApiKeyClientValidOptions},
+};
 use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
     http::{
-        ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url,
-        UrlExt,
+        policies::Policy, ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions,
+        Request, Response, Url, UrlExt,
     },
     tracing, Result,
 };
+use std::sync::Arc;
 
 /// Illustrates clients generated with ApiKey authentication.
 #[tracing::client]
@@ -29,6 +33,44 @@ pub struct ApiKeyClientOptions {
 }
 
 impl ApiKeyClient {
+    /// Creates a new ApiKeyClient, using key authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `credential` - A [`KeyCredential`] containing the key to use when authenticating.
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Authentication.ApiKey")]
+    pub fn with_key_credential(
+        endpoint: &str,
+        credential: KeyCredential,
+        options: Option<ApiKeyClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        let auth_policy: Arc<dyn Policy> = Arc::new(KeyCredentialPolicy::new(
+            credential,
+            KeyCredentialLocation::Header("x-ms-api-key"),
+        ));
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                vec![auth_policy],
+                None,
+            ),
+        })
+    }
+
     /// Returns the Url associated with this client.
     pub fn endpoint(&self) -> &Url {
         &self.endpoint
@@ -4,4 +4,6 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 mod api_key_client;
+mod credentials;
 pub use api_key_client::*;
+pub use credentials::*;
@@ -7,4 +7,4 @@
 pub mod clients;
 /// Contains all the data structures and types used by the client library.
 pub mod models;
-pub use clients::{ApiKeyClient, ApiKeyClientOptions};
+pub use clients::{ApiKeyClient, ApiKeyClientOptions, KeyCredential};
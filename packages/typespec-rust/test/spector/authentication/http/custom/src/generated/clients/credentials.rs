@@ -0,0 +1,73 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use async_trait::async_trait;
+use azure_core::{
+    credentials::Secret,
+    fmt::SafeDebug,
+    http::{
+        policies::{Policy, PolicyResult},
+        Context, Request,
+    },
+};
+use std::sync::Arc;
+
+/// Contains a secret key used to authenticate requests.
+#[derive(Clone, SafeDebug)]
+pub struct KeyCredential {
+    key: Secret,
+}
+
+impl KeyCredential {
+    /// Creates a new `KeyCredential` from the provided key.
+    pub fn new(key: String) -> Self {
+        Self {
+            key: Secret::new(key),
+        }
+    }
+}
+
+/// Indicates where in the request a [`KeyCredential`] is placed.
+#[derive(Debug)]
+pub(crate) enum KeyCredentialLocation {
+    /// The key is sent in the Authorization header, prefixed with the specified scheme.
+    Authorization(&'static str),
+}
+
+/// Adds the key from a [`KeyCredential`] to each request.
+#[derive(Debug)]
+pub(crate) struct KeyCredentialPolicy {
+    credential: KeyCredential,
+    location: KeyCredentialLocation,
+}
+
+impl KeyCredentialPolicy {
+    pub(crate) fn new(credential: KeyCredential, location: KeyCredentialLocation) -> Self {
+        Self {
+            credential,
+            location,
+        }
+    }
+}
+
+#[async_trait]
+impl Policy for KeyCredentialPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        match self.location {
+            KeyCredentialLocation::Authorization(scheme) => {
+                request.insert_header(
+                    "authorization",
+                    format!("{scheme} {}", self.credential.key.secret()),
+                );
+            }
+        }
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
@@ -3,16 +3,20 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::models::{CustomClientInvalidOptions, CustomClientValidOptions};
+use crate::generated::{
+    clients::{KeyCredential, KeyCredentialLocation, KeyCredentialPolicy},
+    models::{CustomClientInvalidOptions, CustomClientValidOptions},
+};
 use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
     http::{
-        ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url,
-        UrlExt,
+        policies::Policy, ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions,
+        Request, Response, Url, UrlExt,
     },
     tracing, Result,
 };
+use std::sync::Arc;
 
 /// Illustrates clients generated with generic HTTP auth.
 #[tracing::client]
@@ -29,6 +33,44 @@ pub struct CustomClientOptions {
 }
 
 impl CustomClient {
+    /// Creates a new CustomClient, using key authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `credential` - A [`KeyCredential`] containing the key to use when authenticating.
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Authentication.Http.Custom")]
+    pub fn with_key_credential(
+        endpoint: &str,
+        credential: KeyCredential,
+        options: Option<CustomClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        let auth_policy: Arc<dyn Policy> = Arc::new(KeyCredentialPolicy::new(
+            credential,
+            KeyCredentialLocation::Authorization("SharedAccessKey"),
+        ));
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                vec![auth_policy],
+                None,
+            ),
+        })
+    }
+
     /// Returns the Url associated with this client.
     pub fn endpoint(&self) -> &Url {
         &self.endpoint
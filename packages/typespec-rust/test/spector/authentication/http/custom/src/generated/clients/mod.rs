@@ -3,5 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+mod credentials;
 mod custom_client;
+pub use credentials::*;
 pub use custom_client::*;
@@ -3,7 +3,10 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::models::{UnionClientValidKeyOptions, UnionClientValidTokenOptions};
+use crate::generated::{
+    clients::{KeyCredential, KeyCredentialLocation, KeyCredentialPolicy},
+    models::{UnionClientValidKeyOptions, UnionClientValidTokenOptions},
+};
 use azure_core::{
     credentials::TokenCredential,
     error::CheckSuccessOptions,
@@ -32,6 +35,44 @@ pub struct UnionClientOptions {
 }
 
 impl UnionClient {
+    /// Creates a new UnionClient, using key authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `credential` - A [`KeyCredential`] containing the key to use when authenticating.
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Authentication.Union")]
+    pub fn with_key_credential(
+        endpoint: &str,
+        credential: KeyCredential,
+        options: Option<UnionClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        let auth_policy: Arc<dyn Policy> = Arc::new(KeyCredentialPolicy::new(
+            credential,
+            KeyCredentialLocation::Header("x-ms-api-key"),
+        ));
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                vec![auth_policy],
+                None,
+            ),
+        })
+    }
+
     /// Creates a new UnionClient, using Entra ID authentication.
     ///
     /// # Arguments
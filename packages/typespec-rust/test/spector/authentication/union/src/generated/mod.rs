@@ -7,4 +7,4 @@
 pub mod clients;
 /// Contains all the data structures and types used by the client library.
 pub mod models;
-pub use clients::{UnionClient, UnionClientOptions};
+pub use clients::{KeyCredential, UnionClient, UnionClientOptions};
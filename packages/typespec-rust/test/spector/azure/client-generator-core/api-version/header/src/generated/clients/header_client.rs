@@ -3,7 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::models::HeaderClientHeaderApiVersionOptions;
+use crate::generated::models::{ApiVersion, HeaderClientHeaderApiVersionOptions};
 use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
@@ -18,7 +18,7 @@ use azure_core::{
 pub struct HeaderClient {
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
-    pub(crate) version: String,
+    pub(crate) version: ApiVersion,
 }
 
 /// Options used when creating a [`HeaderClient`](HeaderClient)
@@ -26,7 +26,7 @@ pub struct HeaderClient {
 pub struct HeaderClientOptions {
     /// Allows customization of the client.
     pub client_options: ClientOptions,
-    pub version: String,
+    pub version: ApiVersion,
 }
 
 impl HeaderClient {
@@ -83,7 +83,7 @@ impl HeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/azure/client-generator-core/api-version/header");
         let mut request = Request::new(url, Method::Post);
-        request.insert_header("x-ms-version", &self.version);
+        request.insert_header("x-ms-version", self.version.to_string());
         let rsp = self
             .pipeline
             .send(
@@ -102,13 +102,13 @@ impl HeaderClient {
 }
 
 /// Default value for [`HeaderClientOptions::version`].
-pub(crate) const DEFAULT_VERSION: &str = "2025-01-01";
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2025_01_01;
 
 impl Default for HeaderClientOptions {
     fn default() -> Self {
         Self {
             client_options: ClientOptions::default(),
-            version: String::from(DEFAULT_VERSION),
+            version: DEFAULT_VERSION,
         }
     }
 }
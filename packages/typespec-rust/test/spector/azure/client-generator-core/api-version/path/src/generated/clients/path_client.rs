@@ -3,7 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::models::PathClientPathApiVersionOptions;
+use crate::generated::models::{ApiVersion, PathClientPathApiVersionOptions};
 use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
@@ -18,7 +18,7 @@ use azure_core::{
 pub struct PathClient {
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
-    pub(crate) version: String,
+    pub(crate) version: ApiVersion,
 }
 
 /// Options used when creating a [`PathClient`](PathClient)
@@ -26,7 +26,7 @@ pub struct PathClient {
 pub struct PathClientOptions {
     /// Allows customization of the client.
     pub client_options: ClientOptions,
-    pub version: String,
+    pub version: ApiVersion,
 }
 
 impl PathClient {
@@ -79,7 +79,7 @@ impl PathClient {
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/azure/client-generator-core/api-version/path/{version}");
-        path = path.replace("{version}", &self.version);
+        path = path.replace("{version}", self.version.as_ref());
         url.append_path(&path);
         let mut request = Request::new(url, Method::Post);
         let rsp = self
@@ -100,13 +100,13 @@ impl PathClient {
 }
 
 /// Default value for [`PathClientOptions::version`].
-pub(crate) const DEFAULT_VERSION: &str = "2025-01-01";
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2025_01_01;
 
 impl Default for PathClientOptions {
     fn default() -> Self {
         Self {
             client_options: ClientOptions::default(),
-            version: String::from(DEFAULT_VERSION),
+            version: DEFAULT_VERSION,
         }
     }
 }
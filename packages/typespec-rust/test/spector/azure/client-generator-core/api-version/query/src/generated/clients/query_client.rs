@@ -3,7 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::models::QueryClientQueryApiVersionOptions;
+use crate::generated::models::{ApiVersion, QueryClientQueryApiVersionOptions};
 use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
@@ -18,7 +18,7 @@ use azure_core::{
 pub struct QueryClient {
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
-    pub(crate) version: String,
+    pub(crate) version: ApiVersion,
 }
 
 /// Options used when creating a [`QueryClient`](QueryClient)
@@ -26,7 +26,7 @@ pub struct QueryClient {
 pub struct QueryClientOptions {
     /// Allows customization of the client.
     pub client_options: ClientOptions,
-    pub version: String,
+    pub version: ApiVersion,
 }
 
 impl QueryClient {
@@ -80,7 +80,7 @@ impl QueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/azure/client-generator-core/api-version/query");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("version", &self.version);
+        query_builder.set_pair("version", self.version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         let rsp = self
@@ -101,13 +101,13 @@ impl QueryClient {
 }
 
 /// Default value for [`QueryClientOptions::version`].
-pub(crate) const DEFAULT_VERSION: &str = "2025-01-01";
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2025_01_01;
 
 impl Default for QueryClientOptions {
     fn default() -> Self {
         Self {
             client_options: ClientOptions::default(),
-            version: String::from(DEFAULT_VERSION),
+            version: DEFAULT_VERSION,
         }
     }
 }
@@ -4,9 +4,9 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    BasicClientCreateOrReplaceOptions, BasicClientCreateOrUpdateOptions, BasicClientDeleteOptions,
-    BasicClientExportAllUsersOptions, BasicClientExportOptions, BasicClientGetOptions,
-    BasicClientListOptions, PagedUser, User, UserList,
+    ApiVersion, BasicClientCreateOrReplaceOptions, BasicClientCreateOrUpdateOptions,
+    BasicClientDeleteOptions, BasicClientExportAllUsersOptions, BasicClientExportOptions,
+    BasicClientGetOptions, BasicClientListOptions, PagedUser, User, UserList,
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -22,7 +22,7 @@ use azure_core::{
 /// Illustrates bodies templated with Azure Core
 #[tracing::client]
 pub struct BasicClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -31,7 +31,7 @@ pub struct BasicClient {
 #[derive(Clone, SafeDebug)]
 pub struct BasicClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -95,7 +95,7 @@ impl BasicClient {
         path = path.replace("{id}", &id.to_string());
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", "application/json");
@@ -140,7 +140,7 @@ impl BasicClient {
         path = path.replace("{id}", &id.to_string());
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Patch);
         request.insert_header("accept", "application/json");
@@ -183,7 +183,7 @@ impl BasicClient {
         path = path.replace("{id}", &id.to_string());
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         let rsp = self
@@ -225,7 +225,7 @@ impl BasicClient {
         path = path.replace("{id}", &id.to_string());
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.set_pair("format", format);
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
@@ -265,7 +265,7 @@ impl BasicClient {
         let mut url = self.endpoint.clone();
         url.append_path("/azure/core/basic/users:exportallusers");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.set_pair("format", format);
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
@@ -307,7 +307,7 @@ impl BasicClient {
         path = path.replace("{id}", &id.to_string());
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -341,7 +341,7 @@ impl BasicClient {
         let mut first_url = self.endpoint.clone();
         first_url.append_path("/azure/core/basic/users");
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         if let Some(expand) = options.expand.as_ref() {
             for e in expand.iter() {
                 query_builder.append_pair("expand", e);
@@ -377,7 +377,7 @@ impl BasicClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -422,12 +422,12 @@ impl BasicClient {
 }
 
 /// Default value for [`BasicClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2022-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2022_12_01Preview;
 
 impl Default for BasicClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
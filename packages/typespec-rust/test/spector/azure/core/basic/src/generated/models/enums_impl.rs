@@ -0,0 +1,48 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::ApiVersion;
+use std::{
+    convert::{AsRef, From, Infallible},
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+impl<'a> From<&'a ApiVersion> for &'a str {
+    fn from(e: &'a ApiVersion) -> Self {
+        match e {
+            ApiVersion::V2022_12_01Preview => "2022-12-01-preview",
+            ApiVersion::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "2022-12-01-preview" => ApiVersion::V2022_12_01Preview,
+            _ => ApiVersion::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for ApiVersion {
+    fn as_ref(&self) -> &str {
+        match self {
+            ApiVersion::V2022_12_01Preview => "2022-12-01-preview",
+            ApiVersion::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            ApiVersion::V2022_12_01Preview => f.write_str("2022-12-01-preview"),
+            ApiVersion::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
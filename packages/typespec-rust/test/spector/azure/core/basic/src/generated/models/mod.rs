@@ -3,9 +3,13 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+mod enums;
+mod enums_impl;
+mod enums_serde;
 mod method_options;
 #[allow(clippy::module_inception)]
 mod models;
 mod models_impl;
+pub use enums::*;
 pub use method_options::*;
 pub use models::*;
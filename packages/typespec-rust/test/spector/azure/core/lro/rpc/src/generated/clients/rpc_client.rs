@@ -4,7 +4,8 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    GenerationOptions, RpcClientLongRunningRpcOperationStatus, RpcClientLongRunningRpcOptions,
+    ApiVersion, GenerationOptions, RpcClientLongRunningRpcOperationStatus,
+    RpcClientLongRunningRpcOptions,
 };
 use azure_core::{
     error::{CheckSuccessOptions, Error, ErrorKind},
@@ -24,7 +25,7 @@ use azure_core::{
 /// Illustrates bodies templated with Azure Core with long-running RPC operation
 #[tracing::client]
 pub struct RpcClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -33,7 +34,7 @@ pub struct RpcClient {
 #[derive(Clone, SafeDebug)]
 pub struct RpcClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -116,7 +117,7 @@ impl RpcClient {
         let mut url = self.endpoint.clone();
         url.append_path("/azure/core/lro/rpc/generations:submit");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Poller::new(
@@ -133,7 +134,7 @@ impl RpcClient {
                             }
                         };
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         let mut request = Request::new(next_link.clone(), Method::Get);
                         request.insert_header("accept", "application/json");
@@ -239,12 +240,12 @@ impl RpcClient {
 }
 
 /// Default value for [`RpcClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2022-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2022_12_01Preview;
 
 impl Default for RpcClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
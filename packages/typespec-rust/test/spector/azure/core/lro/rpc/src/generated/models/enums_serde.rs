@@ -3,9 +3,28 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use super::OperationState;
+use super::{ApiVersion, OperationState};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+impl<'de> Deserialize<'de> for ApiVersion {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for ApiVersion {
+    fn serialize<S>(&self, s: S) -> ::core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(self.as_ref())
+    }
+}
+
 impl<'de> Deserialize<'de> for OperationState {
     fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
     where
@@ -7,7 +7,7 @@ use super::RpcClientLongRunningRpcOperationStatus;
 use azure_core::{
     http::{
         headers::{HeaderName, Headers},
-        Response,
+        Response, Url,
     },
     Result,
 };
@@ -31,14 +31,14 @@ const OPERATION_LOCATION: HeaderName = HeaderName::from_static("operation-locati
 /// }
 /// ```
 pub trait RpcClientLongRunningRpcOperationStatusHeaders: private::Sealed {
-    fn operation_location(&self) -> Result<Option<String>>;
+    fn operation_location(&self) -> Result<Option<Url>>;
 }
 
 impl RpcClientLongRunningRpcOperationStatusHeaders
     for Response<RpcClientLongRunningRpcOperationStatus>
 {
     /// The location for monitoring the operation state.
-    fn operation_location(&self) -> Result<Option<String>> {
+    fn operation_location(&self) -> Result<Option<Url>> {
         Headers::get_optional_as(self.headers(), &OPERATION_LOCATION)
     }
 }
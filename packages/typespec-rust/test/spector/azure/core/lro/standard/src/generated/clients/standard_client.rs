@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    StandardClientCreateOrReplaceOperationStatus, StandardClientCreateOrReplaceOptions,
+    ApiVersion, StandardClientCreateOrReplaceOperationStatus, StandardClientCreateOrReplaceOptions,
     StandardClientDeleteOperationStatus, StandardClientDeleteOptions,
     StandardClientExportOperationStatus, StandardClientExportOptions, User,
 };
@@ -26,7 +26,7 @@ use azure_core::{
 /// Illustrates bodies templated with Azure Core with long-running operation
 #[tracing::client]
 pub struct StandardClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -35,7 +35,7 @@ pub struct StandardClient {
 #[derive(Clone, SafeDebug)]
 pub struct StandardClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -125,7 +125,7 @@ impl StandardClient {
         path = path.replace("{name}", name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Poller::new(
@@ -142,7 +142,7 @@ impl StandardClient {
                             }
                         };
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         let mut request = Request::new(next_link.clone(), Method::Get);
                         request.insert_header("accept", "application/json");
@@ -280,7 +280,7 @@ impl StandardClient {
         path = path.replace("{name}", name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Poller::new(
@@ -297,7 +297,7 @@ impl StandardClient {
                             }
                         };
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         let mut request = Request::new(next_link.clone(), Method::Get);
                         request.insert_header("accept", "application/json");
@@ -442,7 +442,7 @@ impl StandardClient {
         path = path.replace("{name}", name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.set_pair("format", format);
         query_builder.build();
         let api_version = self.api_version.clone();
@@ -460,7 +460,7 @@ impl StandardClient {
                             }
                         };
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         let mut request = Request::new(next_link.clone(), Method::Get);
                         request.insert_header("accept", "application/json");
@@ -563,12 +563,12 @@ impl StandardClient {
 }
 
 /// Default value for [`StandardClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2022-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2022_12_01Preview;
 
 impl Default for StandardClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
@@ -10,7 +10,7 @@ use super::{
 use azure_core::{
     http::{
         headers::{HeaderName, Headers},
-        Response,
+        Response, Url,
     },
     Result,
 };
@@ -34,14 +34,14 @@ const OPERATION_LOCATION: HeaderName = HeaderName::from_static("operation-locati
 /// }
 /// ```
 pub trait StandardClientCreateOrReplaceOperationStatusHeaders: private::Sealed {
-    fn operation_location(&self) -> Result<Option<String>>;
+    fn operation_location(&self) -> Result<Option<Url>>;
 }
 
 impl StandardClientCreateOrReplaceOperationStatusHeaders
     for Response<StandardClientCreateOrReplaceOperationStatus>
 {
     /// The location for monitoring the operation state.
-    fn operation_location(&self) -> Result<Option<String>> {
+    fn operation_location(&self) -> Result<Option<Url>> {
         Headers::get_optional_as(self.headers(), &OPERATION_LOCATION)
     }
 }
@@ -63,12 +63,12 @@ impl StandardClientCreateOrReplaceOperationStatusHeaders
 /// }
 /// ```
 pub trait StandardClientDeleteOperationStatusHeaders: private::Sealed {
-    fn operation_location(&self) -> Result<Option<String>>;
+    fn operation_location(&self) -> Result<Option<Url>>;
 }
 
 impl StandardClientDeleteOperationStatusHeaders for Response<StandardClientDeleteOperationStatus> {
     /// The location for monitoring the operation state.
-    fn operation_location(&self) -> Result<Option<String>> {
+    fn operation_location(&self) -> Result<Option<Url>> {
         Headers::get_optional_as(self.headers(), &OPERATION_LOCATION)
     }
 }
@@ -90,12 +90,12 @@ impl StandardClientDeleteOperationStatusHeaders for Response<StandardClientDelet
 /// }
 /// ```
 pub trait StandardClientExportOperationStatusHeaders: private::Sealed {
-    fn operation_location(&self) -> Result<Option<String>>;
+    fn operation_location(&self) -> Result<Option<Url>>;
 }
 
 impl StandardClientExportOperationStatusHeaders for Response<StandardClientExportOperationStatus> {
     /// The location for monitoring the operation state.
-    fn operation_location(&self) -> Result<Option<String>> {
+    fn operation_location(&self) -> Result<Option<Url>> {
         Headers::get_optional_as(self.headers(), &OPERATION_LOCATION)
     }
 }
@@ -6,10 +6,10 @@
 use crate::generated::{
     clients::PageTwoModelsAsPageItemClient,
     models::{
-        ListItemInputBody, PageClientListWithCustomPageModelOptions, PageClientListWithPageOptions,
-        PageClientListWithParameterizedNextLinkOptions, PageClientListWithParametersOptions,
-        PageClientListWithRelativeNextLinkOptions, PagedUser, ParameterizedNextLinkPagingResult,
-        UserListResults,
+        ApiVersion, ListItemInputBody, PageClientListWithCustomPageModelOptions,
+        PageClientListWithPageOptions, PageClientListWithParameterizedNextLinkOptions,
+        PageClientListWithParametersOptions, PageClientListWithRelativeNextLinkOptions, PagedUser,
+        ParameterizedNextLinkPagingResult, UserListResults,
     },
 };
 use azure_core::{
@@ -26,7 +26,7 @@ use azure_core::{
 /// Illustrates bodies templated with Azure Core with paging support
 #[tracing::client]
 pub struct PageClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -35,7 +35,7 @@ pub struct PageClient {
 #[derive(Clone, SafeDebug)]
 pub struct PageClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -101,7 +101,7 @@ impl PageClient {
         let mut first_url = self.endpoint.clone();
         first_url.append_path("/azure/core/page/custom-page");
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Pager::new(
@@ -110,7 +110,7 @@ impl PageClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -168,7 +168,7 @@ impl PageClient {
         let mut first_url = self.endpoint.clone();
         first_url.append_path("/azure/core/page/page");
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Pager::new(
@@ -177,7 +177,7 @@ impl PageClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -312,7 +312,7 @@ impl PageClient {
         if let Some(another) = options.another.as_ref() {
             query_builder.set_pair("another", another.as_ref());
         }
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Pager::new(
@@ -321,7 +321,7 @@ impl PageClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -425,12 +425,12 @@ impl PageClient {
 }
 
 /// Default value for [`PageClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2022-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2022_12_01Preview;
 
 impl Default for PageClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
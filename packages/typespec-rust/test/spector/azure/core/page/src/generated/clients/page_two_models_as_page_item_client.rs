@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    PageTwoModelsAsPageItemClientListFirstItemOptions,
+    ApiVersion, PageTwoModelsAsPageItemClientListFirstItemOptions,
     PageTwoModelsAsPageItemClientListSecondItemOptions, PagedFirstItem, PagedSecondItem,
 };
 use azure_core::{
@@ -18,7 +18,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct PageTwoModelsAsPageItemClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -44,7 +44,7 @@ impl PageTwoModelsAsPageItemClient {
         let mut first_url = self.endpoint.clone();
         first_url.append_path("/azure/core/page/first-item");
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Pager::new(
@@ -53,7 +53,7 @@ impl PageTwoModelsAsPageItemClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -111,7 +111,7 @@ impl PageTwoModelsAsPageItemClient {
         let mut first_url = self.endpoint.clone();
         first_url.append_path("/azure/core/page/second-item");
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Pager::new(
@@ -120,7 +120,7 @@ impl PageTwoModelsAsPageItemClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
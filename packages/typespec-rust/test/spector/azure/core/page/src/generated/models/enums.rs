@@ -3,6 +3,15 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    V2022_12_01Preview,
+
+    /// Any other value not defined in `ApiVersion`.
+    UnknownValue(String),
+}
+
 /// An extensible enum input parameter.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ListItemInputExtensibleEnum {
@@ -3,14 +3,51 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use super::ListItemInputExtensibleEnum;
+use super::{ApiVersion, ListItemInputExtensibleEnum};
 use azure_core::error::{Error, ErrorKind};
 use std::{
-    convert::AsRef,
+    convert::{AsRef, From, Infallible},
     fmt::{Display, Formatter},
     str::FromStr,
 };
 
+impl<'a> From<&'a ApiVersion> for &'a str {
+    fn from(e: &'a ApiVersion) -> Self {
+        match e {
+            ApiVersion::V2022_12_01Preview => "2022-12-01-preview",
+            ApiVersion::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "2022-12-01-preview" => ApiVersion::V2022_12_01Preview,
+            _ => ApiVersion::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for ApiVersion {
+    fn as_ref(&self) -> &str {
+        match self {
+            ApiVersion::V2022_12_01Preview => "2022-12-01-preview",
+            ApiVersion::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            ApiVersion::V2022_12_01Preview => f.write_str("2022-12-01-preview"),
+            ApiVersion::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
+
 impl FromStr for ListItemInputExtensibleEnum {
     type Err = Error;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
@@ -4,8 +4,8 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    TraitsClientRepeatableActionOptions, TraitsClientSmokeTestOptions, User, UserActionParam,
-    UserActionResponse,
+    ApiVersion, TraitsClientRepeatableActionOptions, TraitsClientSmokeTestOptions, User,
+    UserActionParam, UserActionResponse,
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -14,14 +14,14 @@ use azure_core::{
         ClientOptions, Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response,
         Url, UrlExt,
     },
-    time::to_rfc7231,
-    tracing, Result,
+    time::{to_rfc7231, OffsetDateTime},
+    tracing, Result, Uuid,
 };
 
 /// Illustrates Azure Core operation customizations by traits
 #[tracing::client]
 pub struct TraitsClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -30,7 +30,7 @@ pub struct TraitsClient {
 #[derive(Clone, SafeDebug)]
 pub struct TraitsClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -118,20 +118,20 @@ impl TraitsClient {
         path = path.replace("{id}", &id.to_string());
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
         request.insert_header("content-type", "application/json");
-        if let Some(repeatability_first_sent) = options.repeatability_first_sent {
-            request.insert_header(
-                "repeatability-first-sent",
-                to_rfc7231(&repeatability_first_sent),
-            );
-        }
-        if let Some(repeatability_request_id) = options.repeatability_request_id.as_ref() {
-            request.insert_header("repeatability-request-id", repeatability_request_id);
-        }
+        let repeatability_first_sent = options
+            .repeatability_first_sent
+            .unwrap_or_else(OffsetDateTime::now_utc);
+        request.insert_header("repeatability-first-sent", to_rfc7231(&repeatability_first_sent));
+        let repeatability_request_id = options
+            .repeatability_request_id
+            .clone()
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        request.insert_header("repeatability-request-id", repeatability_request_id);
         request.set_body(body);
         let rsp = self
             .pipeline
@@ -201,20 +201,20 @@ impl TraitsClient {
         path = path.replace("{id}", &id.to_string());
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
-        if let Some(if_match) = options.if_match.as_ref() {
-            request.insert_header("if-match", if_match);
+        if let Some(if_match) = options.request_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
         }
-        if let Some(if_modified_since) = options.if_modified_since {
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
             request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
         }
-        if let Some(if_none_match) = options.if_none_match.as_ref() {
-            request.insert_header("if-none-match", if_none_match);
+        if let Some(if_none_match) = options.request_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
         }
-        if let Some(if_unmodified_since) = options.if_unmodified_since {
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
             request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
         }
         request.insert_header("foo", foo);
@@ -236,12 +236,12 @@ impl TraitsClient {
 }
 
 /// Default value for [`TraitsClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2022-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2022_12_01Preview;
 
 impl Default for TraitsClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
@@ -3,6 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+use super::RequestConditions;
 use azure_core::{fmt::SafeDebug, http::ClientMethodOptions, time::OffsetDateTime};
 
 /// Options to be passed to [`TraitsClient::repeatable_action()`](crate::generated::clients::TraitsClient::repeatable_action())
@@ -12,27 +13,24 @@ pub struct TraitsClientRepeatableActionOptions<'a> {
     pub method_options: ClientMethodOptions<'a>,
 
     /// Specifies the date and time at which the request was first created.
+    ///
+    /// When not set, the current time is used. The same value is sent when the request is retried.
     pub repeatability_first_sent: Option<OffsetDateTime>,
 
     /// An opaque, globally-unique, client-generated string identifier for the request.
+    ///
+    /// When not set, a new UUID is generated. The same value is sent when the request is retried.
     pub repeatability_request_id: Option<String>,
 }
 
 /// Options to be passed to [`TraitsClient::smoke_test()`](crate::generated::clients::TraitsClient::smoke_test())
 #[derive(Clone, Default, SafeDebug)]
 pub struct TraitsClientSmokeTestOptions<'a> {
-    /// The request should only proceed if an entity matches this string.
-    pub if_match: Option<String>,
-
-    /// The request should only proceed if the entity was modified after this time.
-    pub if_modified_since: Option<OffsetDateTime>,
-
-    /// The request should only proceed if no entity matches this string.
-    pub if_none_match: Option<String>,
-
-    /// The request should only proceed if the entity was not modified after this time.
-    pub if_unmodified_since: Option<OffsetDateTime>,
-
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
+
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    pub request_conditions: RequestConditions,
 }
@@ -4,7 +4,10 @@
 
 use azure_core::time::OffsetDateTime;
 use spector_coretraits::{
-    models::{TraitsClientRepeatableActionOptions, TraitsClientSmokeTestOptions, UserActionParam},
+    models::{
+        RequestConditions, TraitsClientRepeatableActionOptions, TraitsClientSmokeTestOptions,
+        UserActionParam,
+    },
     TraitsClient,
 };
 use time::{Date, Month, Time};
@@ -51,10 +54,12 @@ async fn smoke_test() {
             1,
             "123".to_string(),
             Some(TraitsClientSmokeTestOptions {
-                if_match: Some("\"valid\"".to_string()),
-                if_modified_since: Some(modified_since),
-                if_none_match: Some("\"invalid\"".to_string()),
-                if_unmodified_since: Some(unmodified_since),
+                request_conditions: RequestConditions {
+                    if_match: Some("\"valid\"".into()),
+                    if_modified_since: Some(modified_since),
+                    if_none_match: Some("\"invalid\"".into()),
+                    if_unmodified_since: Some(unmodified_since),
+                },
                 ..Default::default()
             }),
         )
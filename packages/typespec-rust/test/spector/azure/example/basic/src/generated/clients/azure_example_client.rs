@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    ActionRequest, ActionResponse, AzureExampleClientBasicActionOptions,
+    ActionRequest, ActionResponse, ApiVersion, AzureExampleClientBasicActionOptions,
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -18,7 +18,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct AzureExampleClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -27,7 +27,7 @@ pub struct AzureExampleClient {
 #[derive(Clone, SafeDebug)]
 pub struct AzureExampleClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -88,7 +88,7 @@ impl AzureExampleClient {
         let mut url = self.endpoint.clone();
         url.append_path("/azure/example/basic/basic");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.set_pair("query-param", query_param);
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
@@ -114,12 +114,12 @@ impl AzureExampleClient {
 }
 
 /// Default value for [`AzureExampleClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2022-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2022_12_01Preview;
 
 impl Default for AzureExampleClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
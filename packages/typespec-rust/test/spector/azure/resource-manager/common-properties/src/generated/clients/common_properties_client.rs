@@ -3,8 +3,9 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::clients::{
-    CommonPropertiesErrorClient, CommonPropertiesManagedIdentityClient,
+use crate::generated::{
+    clients::{CommonPropertiesErrorClient, CommonPropertiesManagedIdentityClient},
+    models::ApiVersion,
 };
 use azure_core::{
     credentials::TokenCredential,
@@ -20,7 +21,7 @@ use std::sync::Arc;
 /// Arm Managed Identity Provider management API.
 #[tracing::client]
 pub struct CommonPropertiesClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -30,7 +31,7 @@ pub struct CommonPropertiesClient {
 #[derive(Clone, SafeDebug)]
 pub struct CommonPropertiesClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -110,12 +111,12 @@ impl CommonPropertiesClient {
 }
 
 /// Default value for [`CommonPropertiesClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2023-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2023_12_01Preview;
 
 impl Default for CommonPropertiesClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
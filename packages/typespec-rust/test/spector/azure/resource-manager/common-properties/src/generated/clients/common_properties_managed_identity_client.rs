@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    CommonPropertiesManagedIdentityClientCreateWithSystemAssignedOptions,
+    ApiVersion, CommonPropertiesManagedIdentityClientCreateWithSystemAssignedOptions,
     CommonPropertiesManagedIdentityClientGetOptions,
     CommonPropertiesManagedIdentityClientUpdateWithUserAssignedAndSystemAssignedOptions,
     ManagedIdentityTrackedResource,
@@ -17,7 +17,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct CommonPropertiesManagedIdentityClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -71,7 +71,7 @@ impl CommonPropertiesManagedIdentityClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", "application/json");
@@ -131,7 +131,7 @@ impl CommonPropertiesManagedIdentityClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -193,7 +193,7 @@ impl CommonPropertiesManagedIdentityClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Patch);
         request.insert_header("accept", "application/json");
@@ -3,7 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::clients::LargeHeaderLargeHeadersClient;
+use crate::generated::{clients::LargeHeaderLargeHeadersClient, models::ApiVersion};
 use azure_core::{
     credentials::TokenCredential,
     fmt::SafeDebug,
@@ -18,7 +18,7 @@ use std::sync::Arc;
 /// Arm Resource Provider management API.
 #[tracing::client]
 pub struct LargeHeaderClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -28,7 +28,7 @@ pub struct LargeHeaderClient {
 #[derive(Clone, SafeDebug)]
 pub struct LargeHeaderClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -95,12 +95,12 @@ impl LargeHeaderClient {
 }
 
 /// Default value for [`LargeHeaderClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2023-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2023_12_01Preview;
 
 impl Default for LargeHeaderClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
@@ -4,7 +4,8 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    LargeHeaderLargeHeadersClientTwo6KOperationStatus, LargeHeaderLargeHeadersClientTwo6KOptions,
+    ApiVersion, LargeHeaderLargeHeadersClientTwo6KOperationStatus,
+    LargeHeaderLargeHeadersClientTwo6KOptions,
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -21,7 +22,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct LargeHeaderLargeHeadersClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -87,7 +88,7 @@ impl LargeHeaderLargeHeadersClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Poller::new(
@@ -104,7 +105,7 @@ impl LargeHeaderLargeHeadersClient {
                             }
                         };
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         let mut request = Request::new(next_link.clone(), Method::Get);
                         request.insert_header("accept", "application/json");
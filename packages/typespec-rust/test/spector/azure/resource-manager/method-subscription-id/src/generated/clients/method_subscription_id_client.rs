@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::{
-    generated::clients::MethodSubscriptionIdOperationsClient,
+    generated::{clients::MethodSubscriptionIdOperationsClient, models::ApiVersion},
     mixed_subscription_placement::clients::MethodSubscriptionIdMixedSubscriptionPlacementClient,
     two_subscription_resources_method_level::clients::MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelClient,
 };
@@ -22,7 +22,7 @@ use std::sync::Arc;
 /// Test for ARM method level subscription ID parameter placement
 #[tracing::client]
 pub struct MethodSubscriptionIdClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -32,7 +32,7 @@ pub struct MethodSubscriptionIdClient {
 #[derive(Clone, SafeDebug)]
 pub struct MethodSubscriptionIdClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -125,12 +125,12 @@ impl MethodSubscriptionIdClient {
 }
 
 /// Default value for [`MethodSubscriptionIdClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2023-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2023_12_01Preview;
 
 impl Default for MethodSubscriptionIdClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
@@ -13,6 +13,15 @@ pub enum ActionType {
     UnknownValue(String),
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    V2023_12_01Preview,
+
+    /// Any other value not defined in `ApiVersion`.
+    UnknownValue(String),
+}
+
 /// The kind of entity that created the resource.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CreatedByType {
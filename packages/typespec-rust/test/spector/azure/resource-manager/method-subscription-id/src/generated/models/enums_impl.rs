@@ -3,7 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use super::{ActionType, CreatedByType, Origin, ResourceProvisioningState};
+use super::{ActionType, ApiVersion, CreatedByType, Origin, ResourceProvisioningState};
 use std::{
     convert::{AsRef, From, Infallible},
     fmt::{Display, Formatter},
@@ -47,6 +47,43 @@ impl Display for ActionType {
     }
 }
 
+impl<'a> From<&'a ApiVersion> for &'a str {
+    fn from(e: &'a ApiVersion) -> Self {
+        match e {
+            ApiVersion::V2023_12_01Preview => "2023-12-01-preview",
+            ApiVersion::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "2023-12-01-preview" => ApiVersion::V2023_12_01Preview,
+            _ => ApiVersion::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for ApiVersion {
+    fn as_ref(&self) -> &str {
+        match self {
+            ApiVersion::V2023_12_01Preview => "2023-12-01-preview",
+            ApiVersion::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            ApiVersion::V2023_12_01Preview => f.write_str("2023-12-01-preview"),
+            ApiVersion::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
+
 impl<'a> From<&'a CreatedByType> for &'a str {
     fn from(e: &'a CreatedByType) -> Self {
         match e {
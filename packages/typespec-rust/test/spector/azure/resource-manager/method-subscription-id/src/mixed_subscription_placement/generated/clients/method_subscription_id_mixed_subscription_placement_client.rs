@@ -3,9 +3,12 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::mixed_subscription_placement::generated::clients::{
-    MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClient,
-    MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClient,
+use crate::{
+    mixed_subscription_placement::generated::clients::{
+        MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClient,
+        MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClient,
+    },
+    models::ApiVersion,
 };
 use azure_core::{
     http::{Pipeline, Url},
@@ -28,7 +31,7 @@ use azure_core::{
 /// - ResourceGroupResource operations should not have subscriptionId as method-level parameter (uses client-level)
 #[tracing::client]
 pub struct MethodSubscriptionIdMixedSubscriptionPlacementClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
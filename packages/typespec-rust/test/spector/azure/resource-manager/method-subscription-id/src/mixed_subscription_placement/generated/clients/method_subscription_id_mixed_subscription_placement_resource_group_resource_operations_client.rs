@@ -3,11 +3,14 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::mixed_subscription_placement::generated::models::{
-    MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientDeleteOptions,
-    MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientGetOptions,
-    MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientPutOptions,
-    ResourceGroupResource,
+use crate::{
+    mixed_subscription_placement::generated::models::{
+        MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientDeleteOptions,
+        MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientGetOptions,
+        MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientPutOptions,
+        ResourceGroupResource,
+    },
+    models::ApiVersion,
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -20,7 +23,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -67,7 +70,7 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperatio
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         let rsp = self
@@ -121,7 +124,7 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperatio
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -178,7 +181,7 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperatio
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", "application/json");
@@ -3,11 +3,14 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::mixed_subscription_placement::generated::models::{
-    MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientDeleteOptions,
-    MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientGetOptions,
-    MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientPutOptions,
-    SubscriptionResource,
+use crate::{
+    mixed_subscription_placement::generated::models::{
+        MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientDeleteOptions,
+        MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientGetOptions,
+        MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientPutOptions,
+        SubscriptionResource,
+    },
+    models::ApiVersion,
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -20,7 +23,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -65,7 +68,7 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperation
         path = path.replace("{subscriptionResourceName}", subscription_resource_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         let rsp = self
@@ -118,7 +121,7 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperation
         path = path.replace("{subscriptionResourceName}", subscription_resource_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -174,7 +177,7 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperation
         path = path.replace("{subscriptionResourceName}", subscription_resource_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", "application/json");
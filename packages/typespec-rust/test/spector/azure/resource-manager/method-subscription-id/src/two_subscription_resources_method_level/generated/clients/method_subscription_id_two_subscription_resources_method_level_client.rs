@@ -3,9 +3,12 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::two_subscription_resources_method_level::generated::clients::{
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource1OperationsClient,
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClient,
+use crate::{
+    models::ApiVersion,
+    two_subscription_resources_method_level::generated::clients::{
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource1OperationsClient,
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClient,
+    },
 };
 use azure_core::{
     http::{Pipeline, Url},
@@ -21,7 +24,7 @@ use azure_core::{
 /// using
 #[tracing::client]
 pub struct MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -3,11 +3,14 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::two_subscription_resources_method_level::generated::models::{
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource1OperationsClientDeleteOptions,
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource1OperationsClientGetOptions,
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource1OperationsClientPutOptions,
-    SubscriptionResource1,
+use crate::{
+    models::ApiVersion,
+    two_subscription_resources_method_level::generated::models::{
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource1OperationsClientDeleteOptions,
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource1OperationsClientGetOptions,
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource1OperationsClientPutOptions,
+        SubscriptionResource1,
+    },
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -21,7 +24,7 @@ use azure_core::{
 #[tracing::client]
 pub struct MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource1OperationsClient
 {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -66,7 +69,7 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource
         path = path.replace("{subscriptionResource1Name}", subscription_resource1_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         let rsp = self
@@ -119,7 +122,7 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource
         path = path.replace("{subscriptionResource1Name}", subscription_resource1_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -175,7 +178,7 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource
         path = path.replace("{subscriptionResource1Name}", subscription_resource1_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", "application/json");
@@ -3,11 +3,14 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::two_subscription_resources_method_level::generated::models::{
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientDeleteOptions,
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientGetOptions,
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientPutOptions,
-    SubscriptionResource2,
+use crate::{
+    models::ApiVersion,
+    two_subscription_resources_method_level::generated::models::{
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientDeleteOptions,
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientGetOptions,
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientPutOptions,
+        SubscriptionResource2,
+    },
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -21,7 +24,7 @@ use azure_core::{
 #[tracing::client]
 pub struct MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClient
 {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -66,7 +69,7 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource
         path = path.replace("{subscriptionResource2Name}", subscription_resource2_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         let rsp = self
@@ -119,7 +122,7 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource
         path = path.replace("{subscriptionResource2Name}", subscription_resource2_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -175,7 +178,7 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource
         path = path.replace("{subscriptionResource2Name}", subscription_resource2_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", "application/json");
@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    NonResource, NonResourceNonResourceOperationsClientCreateOptions,
+    ApiVersion, NonResource, NonResourceNonResourceOperationsClientCreateOptions,
     NonResourceNonResourceOperationsClientGetOptions,
 };
 use azure_core::{
@@ -16,7 +16,7 @@ use azure_core::{
 /// Operations on non resource model should not be marked as `@armResourceOperations`.
 #[tracing::client]
 pub struct NonResourceNonResourceOperationsClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -64,7 +64,7 @@ impl NonResourceNonResourceOperationsClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Put);
         request.insert_header("accept", "application/json");
@@ -120,7 +120,7 @@ impl NonResourceNonResourceOperationsClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
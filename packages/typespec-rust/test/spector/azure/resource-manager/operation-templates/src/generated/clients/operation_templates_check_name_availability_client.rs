@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    CheckNameAvailabilityRequest, CheckNameAvailabilityResponse,
+    ApiVersion, CheckNameAvailabilityRequest, CheckNameAvailabilityResponse,
     OperationTemplatesCheckNameAvailabilityClientCheckGlobalOptions,
     OperationTemplatesCheckNameAvailabilityClientCheckLocalOptions,
 };
@@ -16,7 +16,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct OperationTemplatesCheckNameAvailabilityClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -49,7 +49,7 @@ impl OperationTemplatesCheckNameAvailabilityClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -101,7 +101,7 @@ impl OperationTemplatesCheckNameAvailabilityClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -3,10 +3,13 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::clients::{
-    OperationTemplatesCheckNameAvailabilityClient, OperationTemplatesLroClient,
-    OperationTemplatesLroPagingClient, OperationTemplatesOperationsClient,
-    OperationTemplatesOptionalBodyClient,
+use crate::generated::{
+    clients::{
+        OperationTemplatesCheckNameAvailabilityClient, OperationTemplatesLroClient,
+        OperationTemplatesLroPagingClient, OperationTemplatesOperationsClient,
+        OperationTemplatesOptionalBodyClient,
+    },
+    models::ApiVersion,
 };
 use azure_core::{
     credentials::TokenCredential,
@@ -22,7 +25,7 @@ use std::sync::Arc;
 /// Arm Resource Provider management API.
 #[tracing::client]
 pub struct OperationTemplatesClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -32,7 +35,7 @@ pub struct OperationTemplatesClient {
 #[derive(Clone, SafeDebug)]
 pub struct OperationTemplatesClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -146,12 +149,12 @@ impl OperationTemplatesClient {
 }
 
 /// Default value for [`OperationTemplatesClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2023-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2023_12_01Preview;
 
 impl Default for OperationTemplatesClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
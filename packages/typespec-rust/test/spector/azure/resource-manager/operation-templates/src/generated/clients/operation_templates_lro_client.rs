@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    ExportRequest, OperationTemplatesLroClientCreateOrReplaceOperationStatus,
+    ApiVersion, ExportRequest, OperationTemplatesLroClientCreateOrReplaceOperationStatus,
     OperationTemplatesLroClientCreateOrReplaceOptions,
     OperationTemplatesLroClientDeleteOperationStatus, OperationTemplatesLroClientDeleteOptions,
     OperationTemplatesLroClientExportArrayOperationStatus,
@@ -28,7 +28,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct OperationTemplatesLroClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -92,7 +92,7 @@ impl OperationTemplatesLroClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Poller::new(
@@ -109,7 +109,7 @@ impl OperationTemplatesLroClient {
                             }
                         };
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         let mut request = Request::new(next_link.clone(), Method::Get);
                         request.insert_header("accept", "application/json");
@@ -280,7 +280,7 @@ impl OperationTemplatesLroClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Poller::new(
@@ -297,7 +297,7 @@ impl OperationTemplatesLroClient {
                             }
                         };
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         let request = Request::new(next_link.clone(), Method::Get);
                         (
@@ -460,7 +460,7 @@ impl OperationTemplatesLroClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Poller::new(
@@ -477,7 +477,7 @@ impl OperationTemplatesLroClient {
                             }
                         };
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         let mut request = Request::new(next_link.clone(), Method::Get);
                         request.insert_header("accept", "application/json");
@@ -650,7 +650,7 @@ impl OperationTemplatesLroClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Poller::new(
@@ -667,7 +667,7 @@ impl OperationTemplatesLroClient {
                             }
                         };
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         let mut request = Request::new(next_link.clone(), Method::Get);
                         request.insert_header("accept", "application/json");
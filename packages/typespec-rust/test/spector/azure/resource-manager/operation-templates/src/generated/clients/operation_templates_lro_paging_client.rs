@@ -3,14 +3,29 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+use crate::generated::models::{
+    ApiVersion, OperationTemplatesLroPagingClientPostPagingLroOperationStatus,
+    OperationTemplatesLroPagingClientPostPagingLroOptions, ProductListResult,
+};
 use azure_core::{
-    http::{Pipeline, Url},
-    tracing,
+    error::{CheckSuccessOptions, Error, ErrorKind},
+    http::{
+        headers::{HeaderName, RETRY_AFTER, RETRY_AFTER_MS, X_MS_RETRY_AFTER_MS},
+        pager::{Pager, PagerContinuation, PagerResult, PagerState},
+        poller::{
+            get_retry_after, PollerContinuation, PollerResult, PollerState, PollerStatus,
+            StatusMonitor,
+        },
+        Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request, StatusCode, Url,
+        UrlExt,
+    },
+    json, tracing, Result,
 };
+use std::sync::{Arc, Mutex, PoisonError};
 
 #[tracing::client]
 pub struct OperationTemplatesLroPagingClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -21,4 +36,226 @@ impl OperationTemplatesLroPagingClient {
     pub fn endpoint(&self) -> &Url {
         &self.endpoint
     }
+
+    /// A long-running resource action.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_group_name` - The name of the resource group. The name is case insensitive.
+    /// * `product_name` - The name of the Product
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Azure.ResourceManager.OperationTemplates.LroPaging.postPagingLro")]
+    pub fn post_paging_lro(
+        &self,
+        resource_group_name: &str,
+        product_name: &str,
+        options: Option<OperationTemplatesLroPagingClientPostPagingLroOptions<'_>>,
+    ) -> Result<Pager<ProductListResult>> {
+        let options = options.unwrap_or_default().into_owned();
+        let pipeline = self.pipeline.clone();
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/products/{productName}/postPagingLro");
+        path = path.replace("{productName}", product_name);
+        path = path.replace("{resourceGroupName}", resource_group_name);
+        path = path.replace("{subscriptionId}", &self.subscription_id);
+        url.append_path(&path);
+        let mut query_builder = url.query_builder();
+        query_builder.set_pair("api-version", self.api_version.as_ref());
+        query_builder.build();
+        let api_version = self.api_version.clone();
+        let poller = Poller::new(
+            move |poller_state: PollerState, poller_options| {
+                let (mut request, continuation) = match poller_state {
+                    PollerState::More(continuation) => {
+                        let (mut next_link, final_link) = match continuation.clone() {
+                            PollerContinuation::Links {
+                                next_link,
+                                final_link,
+                            } => (next_link, final_link),
+                            _ => {
+                                unreachable!()
+                            }
+                        };
+                        let mut query_builder = next_link.query_builder();
+                        query_builder.set_pair("api-version", api_version.as_ref());
+                        query_builder.build();
+                        let mut request = Request::new(next_link.clone(), Method::Get);
+                        request.insert_header("accept", "application/json");
+                        (
+                            request,
+                            PollerContinuation::Links {
+                                next_link,
+                                final_link,
+                            },
+                        )
+                    }
+                    PollerState::Initial => {
+                        let mut request = Request::new(url.clone(), Method::Post);
+                        request.insert_header("accept", "application/json");
+                        (
+                            request,
+                            PollerContinuation::Links {
+                                next_link: url.clone(),
+                                final_link: None,
+                            },
+                        )
+                    }
+                };
+                let ctx = poller_options.context.clone();
+                let pipeline = pipeline.clone();
+                Box::pin(async move {
+                    let rsp = pipeline
+                        .send(
+                            &ctx,
+                            &mut request,
+                            Some(PipelineSendOptions {
+                                check_success: CheckSuccessOptions {
+                                    success_codes: &[200, 202],
+                                },
+                                ..Default::default()
+                            }),
+                        )
+                        .await?;
+                    let (status, headers, mut body) = rsp.deconstruct();
+                    if body.is_empty() {
+                        body = azure_core::http::response::ResponseBody::from_bytes("{}");
+                    }
+                    let mut final_body = None;
+                    if status == StatusCode::Ok {
+                        final_body = Some(body);
+                        body = azure_core::http::response::ResponseBody::from_bytes(
+                            "{\"status\":\"Succeeded\"}",
+                        );
+                    }
+                    let continuation = if let Some(operation_location) =
+                        headers.get_optional_string(&HeaderName::from_static("location"))
+                    {
+                        let next_link = Url::parse(&operation_location)?;
+                        match continuation {
+                            PollerContinuation::Links { final_link, .. } => {
+                                PollerContinuation::Links {
+                                    next_link,
+                                    final_link,
+                                }
+                            }
+                            _ => {
+                                unreachable!()
+                            }
+                        }
+                    } else {
+                        continuation
+                    };
+                    let retry_after = get_retry_after(
+                        &headers,
+                        &[X_MS_RETRY_AFTER_MS, RETRY_AFTER_MS, RETRY_AFTER],
+                        &poller_options,
+                    );
+                    let res: OperationTemplatesLroPagingClientPostPagingLroOperationStatus =
+                        json::from_json(&body)?;
+                    let mut final_rsp: Option<RawResponse> = None;
+                    if res.status() == PollerStatus::Succeeded {
+                        final_rsp = Some(RawResponse::from_bytes(
+                            status,
+                            headers.clone(),
+                            if let Some(final_body) = final_body {
+                                final_body
+                            } else {
+                                body.clone()
+                            },
+                        ));
+                    }
+                    let rsp = RawResponse::from_bytes(status, headers, body).into();
+                    Ok(match res.status() {
+                        PollerStatus::InProgress => PollerResult::InProgress {
+                            response: rsp,
+                            retry_after,
+                            continuation,
+                        },
+                        PollerStatus::Succeeded => PollerResult::Succeeded {
+                            response: rsp,
+                            target: Box::new(move || {
+                                Box::pin(async move {
+                                    Ok(final_rsp
+                                        .ok_or_else(|| {
+                                            Error::new(ErrorKind::Other, "missing final response")
+                                        })?
+                                        .into())
+                                })
+                            }),
+                        },
+                        _ => PollerResult::Done { response: rsp },
+                    })
+                })
+            },
+            Some(options.method_options),
+        );
+        let poller = Arc::new(Mutex::new(Some(poller)));
+        let pipeline = self.pipeline.clone();
+        let endpoint = self.endpoint.clone();
+        let api_version = self.api_version.clone();
+        Ok(Pager::new(
+            move |next_link: PagerState, pager_options| {
+                let request = match next_link {
+                    PagerState::More(next_link) => {
+                        let mut next_link: Url = next_link.try_into().expect("expected Url");
+                        let mut query_builder = next_link.query_builder();
+                        query_builder.set_pair("api-version", api_version.as_ref());
+                        query_builder.build();
+                        let mut request = Request::new(next_link, Method::Get);
+                        request.insert_header("accept", "application/json");
+                        Some(request)
+                    }
+                    PagerState::Initial => None,
+                };
+                let pipeline = pipeline.clone();
+                let endpoint = endpoint.clone();
+                let poller = poller.clone();
+                Box::pin(async move {
+                    let rsp = match request {
+                        Some(mut request) => {
+                            pipeline
+                                .send(
+                                    &pager_options.context,
+                                    &mut request,
+                                    Some(PipelineSendOptions {
+                                        check_success: CheckSuccessOptions {
+                                            success_codes: &[200, 202],
+                                        },
+                                        ..Default::default()
+                                    }),
+                                )
+                                .await?
+                        }
+                        None => {
+                            let poller = poller
+                                .lock()
+                                .unwrap_or_else(PoisonError::into_inner)
+                                .take()
+                                .ok_or_else(|| {
+                                    Error::new(
+                                        ErrorKind::Other,
+                                        "the long-running operation has already been polled",
+                                    )
+                                })?;
+                            let (status, headers, body) = poller.await?.deconstruct();
+                            RawResponse::from_bytes(status, headers, body)
+                        }
+                    };
+                    let (status, headers, body) = rsp.deconstruct();
+                    let res: ProductListResult = json::from_json(&body)?;
+                    let rsp = RawResponse::from_bytes(status, headers, body).into();
+                    Ok(match res.next_link {
+                        Some(next_link) if !next_link.is_empty() => PagerResult::More {
+                            response: rsp,
+                            continuation: PagerContinuation::Link(
+                                endpoint.join(next_link.as_ref())?,
+                            ),
+                        },
+                        _ => PagerResult::Done { response: rsp },
+                    })
+                })
+            },
+            None,
+        ))
+    }
 }
@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    OperationListResult, OperationTemplatesOperationsClientListOptions,
+    ApiVersion, OperationListResult, OperationTemplatesOperationsClientListOptions,
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -17,7 +17,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct OperationTemplatesOperationsClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -43,7 +43,7 @@ impl OperationTemplatesOperationsClient {
         let mut first_url = self.endpoint.clone();
         first_url.append_path("/providers/Azure.ResourceManager.OperationTemplates/operations");
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Pager::new(
@@ -52,7 +52,7 @@ impl OperationTemplatesOperationsClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -4,7 +4,8 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    ActionResult, ChangeAllowanceResult, OperationTemplatesOptionalBodyClientGetOptions,
+    ActionResult, ApiVersion, ChangeAllowanceResult,
+    OperationTemplatesOptionalBodyClientGetOptions,
     OperationTemplatesOptionalBodyClientPatchOptions,
     OperationTemplatesOptionalBodyClientPostOptions,
     OperationTemplatesOptionalBodyClientProviderPostOptions, Widget,
@@ -17,7 +18,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct OperationTemplatesOptionalBodyClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -64,7 +65,7 @@ impl OperationTemplatesOptionalBodyClient {
         path = path.replace("{widgetName}", widget_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -119,7 +120,7 @@ impl OperationTemplatesOptionalBodyClient {
         path = path.replace("{widgetName}", widget_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Patch);
         request.insert_header("accept", "application/json");
@@ -178,7 +179,7 @@ impl OperationTemplatesOptionalBodyClient {
         path = path.replace("{widgetName}", widget_name);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -218,7 +219,7 @@ impl OperationTemplatesOptionalBodyClient {
         path = path.replace("{subscriptionId}", &self.subscription_id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Post);
         request.insert_header("accept", "application/json");
@@ -13,6 +13,15 @@ pub enum ActionType {
     UnknownValue(String),
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    V2023_12_01Preview,
+
+    /// Any other value not defined in `ApiVersion`.
+    UnknownValue(String),
+}
+
 /// Possible reasons for a name not being available.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum CheckNameAvailabilityReason {
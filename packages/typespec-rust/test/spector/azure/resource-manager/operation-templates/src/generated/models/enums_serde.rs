@@ -4,7 +4,8 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use super::{
-    ActionType, CheckNameAvailabilityReason, CreatedByType, Origin, ResourceProvisioningState,
+    ActionType, ApiVersion, CheckNameAvailabilityReason, CreatedByType, Origin,
+    ResourceProvisioningState,
 };
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -27,6 +28,25 @@ impl Serialize for ActionType {
     }
 }
 
+impl<'de> Deserialize<'de> for ApiVersion {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for ApiVersion {
+    fn serialize<S>(&self, s: S) -> ::core::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(self.as_ref())
+    }
+}
+
 impl<'de> Deserialize<'de> for CheckNameAvailabilityReason {
     fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
     where
@@ -8,6 +8,7 @@ use super::{
     OperationTemplatesLroClientDeleteOperationStatus,
     OperationTemplatesLroClientExportArrayOperationStatus,
     OperationTemplatesLroClientExportOperationStatus,
+    OperationTemplatesLroPagingClientPostPagingLroOperationStatus,
 };
 use azure_core::{
     http::{
@@ -193,12 +194,51 @@ impl OperationTemplatesLroClientExportOperationStatusHeaders
     }
 }
 
+/// Provides access to typed response headers for [`OperationTemplatesLroPagingClient::post_paging_lro()`](crate::generated::clients::OperationTemplatesLroPagingClient::post_paging_lro())
+///
+/// # Examples
+///
+/// ```no_run
+/// use azure_core::{Result, http::Response};
+/// use spector_armoptemplates::models::{OperationTemplatesLroPagingClientPostPagingLroOperationStatus, OperationTemplatesLroPagingClientPostPagingLroOperationStatusHeaders};
+/// async fn example() -> Result<()> {
+///     let response: Response<OperationTemplatesLroPagingClientPostPagingLroOperationStatus> = unimplemented!();
+///     // Access response headers
+///     if let Some(location) = response.location()? {
+///         println!("location: {:?}", location);
+///     }
+///     if let Some(retry_after) = response.retry_after()? {
+///         println!("retry-after: {:?}", retry_after);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait OperationTemplatesLroPagingClientPostPagingLroOperationStatusHeaders: private::Sealed {
+    fn location(&self) -> Result<Option<String>>;
+    fn retry_after(&self) -> Result<Option<i32>>;
+}
+
+impl OperationTemplatesLroPagingClientPostPagingLroOperationStatusHeaders
+    for Response<OperationTemplatesLroPagingClientPostPagingLroOperationStatus>
+{
+    /// The Location header contains the URL where the status of the long running operation can be checked.
+    fn location(&self) -> Result<Option<String>> {
+        Headers::get_optional_as(self.headers(), &LOCATION)
+    }
+
+    /// The Retry-After header can indicate how long the client should wait before polling the operation status.
+    fn retry_after(&self) -> Result<Option<i32>> {
+        Headers::get_optional_as(self.headers(), &RETRY_AFTER)
+    }
+}
+
 mod private {
     use super::{
         OperationTemplatesLroClientCreateOrReplaceOperationStatus,
         OperationTemplatesLroClientDeleteOperationStatus,
         OperationTemplatesLroClientExportArrayOperationStatus,
         OperationTemplatesLroClientExportOperationStatus,
+        OperationTemplatesLroPagingClientPostPagingLroOperationStatus,
     };
     use azure_core::http::Response;
 
@@ -208,4 +248,5 @@ mod private {
     impl Sealed for Response<OperationTemplatesLroClientDeleteOperationStatus> {}
     impl Sealed for Response<OperationTemplatesLroClientExportArrayOperationStatus> {}
     impl Sealed for Response<OperationTemplatesLroClientExportOperationStatus> {}
+    impl Sealed for Response<OperationTemplatesLroPagingClientPostPagingLroOperationStatus> {}
 }
@@ -99,6 +99,25 @@ impl OperationTemplatesLroClientExportOptions<'_> {
     }
 }
 
+/// Options to be passed to [`OperationTemplatesLroPagingClient::post_paging_lro()`](crate::generated::clients::OperationTemplatesLroPagingClient::post_paging_lro())
+#[derive(Clone, Default, SafeDebug)]
+pub struct OperationTemplatesLroPagingClientPostPagingLroOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: PollerOptions<'a>,
+}
+
+impl OperationTemplatesLroPagingClientPostPagingLroOptions<'_> {
+    /// Transforms this [`OperationTemplatesLroPagingClientPostPagingLroOptions`] into a new `OperationTemplatesLroPagingClientPostPagingLroOptions` that owns the underlying data, cloning it if necessary.
+    pub fn into_owned(self) -> OperationTemplatesLroPagingClientPostPagingLroOptions<'static> {
+        OperationTemplatesLroPagingClientPostPagingLroOptions {
+            method_options: PollerOptions {
+                context: self.method_options.context.into_owned(),
+                ..self.method_options
+            },
+        }
+    }
+}
+
 /// Options to be passed to [`OperationTemplatesOperationsClient::list()`](crate::generated::clients::OperationTemplatesOperationsClient::list())
 #[derive(Clone, Default, SafeDebug)]
 pub struct OperationTemplatesOperationsClientListOptions<'a> {
@@ -456,6 +456,60 @@ pub struct OperationTemplatesLroClientExportOperationStatus {
     pub status: Option<ResourceProvisioningState>,
 }
 
+/// Standard Azure Resource Manager operation status response, used as the response
+/// body for `GetResourceOperationStatus`.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct OperationTemplatesLroPagingClientPostPagingLroOperationStatus {
+    /// Operation complete time
+    ///
+    /// Operational visibility: Read
+    #[serde(
+        default,
+        rename = "endTime",
+        skip_serializing,
+        with = "azure_core::time::rfc3339::option"
+    )]
+    pub end_time: Option<OffsetDateTime>,
+
+    /// Errors that occurred if the operation ended with Canceled or Failed status
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub error: Option<ErrorDetail>,
+
+    /// The unique identifier for the operationStatus resource
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The name of the operationStatus resource
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub name: Option<String>,
+
+    /// The progress made toward completing the operation
+    ///
+    /// Operational visibility: Read
+    #[serde(rename = "percentComplete", skip_serializing)]
+    pub percent_complete: Option<f64>,
+
+    /// Operation start time
+    ///
+    /// Operational visibility: Read
+    #[serde(
+        default,
+        rename = "startTime",
+        skip_serializing,
+        with = "azure_core::time::rfc3339::option"
+    )]
+    pub start_time: Option<OffsetDateTime>,
+
+    /// The operation status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ResourceProvisioningState>,
+}
+
 /// Concrete tracked resource types can be created by aliasing this type using a specific property type.
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct Order {
@@ -567,8 +621,8 @@ pub struct ProductListResult {
     pub next_link: Option<String>,
 
     /// The Product items on this page
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<Vec<Product>>,
+    #[serde(default)]
+    pub value: Vec<Product>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
@@ -9,7 +9,9 @@ use super::{
     OperationTemplatesLroClientCreateOrReplaceOperationStatus,
     OperationTemplatesLroClientDeleteOperationStatus,
     OperationTemplatesLroClientExportArrayOperationStatus,
-    OperationTemplatesLroClientExportOperationStatus, Order, Widget,
+    OperationTemplatesLroClientExportOperationStatus,
+    OperationTemplatesLroPagingClientPostPagingLroOperationStatus, Order, Product,
+    ProductListResult, Widget,
 };
 use async_trait::async_trait;
 use azure_core::{
@@ -31,6 +33,15 @@ impl Page for OperationListResult {
     }
 }
 
+#[async_trait]
+impl Page for ProductListResult {
+    type Item = Product;
+    type IntoIter = <Vec<Product> as IntoIterator>::IntoIter;
+    async fn into_items(self) -> Result<Self::IntoIter> {
+        Ok(self.value.into_iter())
+    }
+}
+
 impl StatusMonitor for OperationTemplatesLroClientCreateOrReplaceOperationStatus {
     type Output = Order;
     type Format = JsonFormat;
@@ -75,6 +86,17 @@ impl StatusMonitor for OperationTemplatesLroClientExportOperationStatus {
     }
 }
 
+impl StatusMonitor for OperationTemplatesLroPagingClientPostPagingLroOperationStatus {
+    type Output = ProductListResult;
+    type Format = JsonFormat;
+    fn status(&self) -> PollerStatus {
+        match &self.status {
+            Some(v) => PollerStatus::from(v.as_ref()),
+            None => PollerStatus::InProgress,
+        }
+    }
+}
+
 impl TryFrom<ActionRequest> for RequestContent<ActionRequest> {
     type Error = azure_core::Error;
     fn try_from(value: ActionRequest) -> Result<Self> {
@@ -0,0 +1,36 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+mod common;
+
+use azure_core::{http::poller::PollerOptions, time::Duration};
+use futures::StreamExt;
+use spector_armoptemplates::models::OperationTemplatesLroPagingClientPostPagingLroOptions;
+
+#[tokio::test]
+async fn post_paging_lro() {
+    let client = common::create_client().get_operation_templates_lro_paging_client();
+
+    let options = Some(OperationTemplatesLroPagingClientPostPagingLroOptions {
+        method_options: PollerOptions {
+            frequency: Duration::seconds(1),
+            ..Default::default()
+        },
+    });
+
+    // the LRO is polled to completion when the first page is requested
+    let mut pager = client.post_paging_lro("test-rg", "default", options).unwrap();
+
+    let mut item_count = 0;
+    while let Some(item) = pager.next().await {
+        item_count += 1;
+        let item = item.unwrap();
+        match item_count {
+            1 => assert_eq!(item.name, Some("product1".to_string())),
+            2 => assert_eq!(item.name, Some("product2".to_string())),
+            _ => panic!("unexpected item number"),
+        }
+    }
+    assert_eq!(item_count, 2);
+}
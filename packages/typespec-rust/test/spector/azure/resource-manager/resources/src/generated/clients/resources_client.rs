@@ -3,9 +3,12 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::clients::{
-    ResourcesExtensionsResourcesClient, ResourcesLocationResourcesClient, ResourcesNestedClient,
-    ResourcesSingletonClient, ResourcesTopLevelClient,
+use crate::generated::{
+    clients::{
+        ResourcesExtensionsResourcesClient, ResourcesLocationResourcesClient, ResourcesNestedClient,
+        ResourcesSingletonClient, ResourcesTopLevelClient,
+    },
+    models::ApiVersion,
 };
 use azure_core::{
     credentials::TokenCredential,
@@ -21,7 +24,7 @@ use std::sync::Arc;
 /// Arm Resource Provider management API.
 #[tracing::client]
 pub struct ResourcesClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
     pub(crate) subscription_id: String,
@@ -31,7 +34,7 @@ pub struct ResourcesClient {
 #[derive(Clone, SafeDebug)]
 pub struct ResourcesClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -141,12 +144,12 @@ impl ResourcesClient {
 }
 
 /// Default value for [`ResourcesClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2023-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2023_12_01Preview;
 
 impl Default for ResourcesClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
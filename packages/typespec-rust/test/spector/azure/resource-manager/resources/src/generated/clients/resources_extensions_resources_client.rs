@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    ExtensionsResource, ExtensionsResourceListResult,
+    ApiVersion, ExtensionsResource, ExtensionsResourceListResult,
     ResourcesExtensionsResourcesClientCreateOrUpdateOperationStatus,
     ResourcesExtensionsResourcesClientCreateOrUpdateOptions,
     ResourcesExtensionsResourcesClientDeleteOptions, ResourcesExtensionsResourcesClientGetOptions,
@@ -30,7 +30,7 @@ use azure_core::{
 /// it contains 4 kinds of scopes (resource, resource group, subscription and tenant)
 #[tracing::client]
 pub struct ResourcesExtensionsResourcesClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -92,7 +92,7 @@ impl ResourcesExtensionsResourcesClient {
         path = path.replace("{resourceUri}", resource_uri);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Poller::new(
@@ -109,7 +109,7 @@ impl ResourcesExtensionsResourcesClient {
                             }
                         };
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         let mut request = Request::new(next_link.clone(), Method::Get);
                         request.insert_header("accept", "application/json");
@@ -264,7 +264,7 @@ impl ResourcesExtensionsResourcesClient {
         path = path.replace("{resourceUri}", resource_uri);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Delete);
         let rsp = self
@@ -317,7 +317,7 @@ impl ResourcesExtensionsResourcesClient {
         path = path.replace("{resourceUri}", resource_uri);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -364,7 +364,7 @@ impl ResourcesExtensionsResourcesClient {
         path = path.replace("{resourceUri}", resource_uri);
         first_url.append_path(&path);
         let mut query_builder = first_url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let api_version = self.api_version.clone();
         Ok(Pager::new(
@@ -373,7 +373,7 @@ impl ResourcesExtensionsResourcesClient {
                     PagerState::More(next_link) => {
                         let mut next_link: Url = next_link.try_into().expect("expected Url");
                         let mut query_builder = next_link.query_builder();
-                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.set_pair("api-version", api_version.as_ref());
                         query_builder.build();
                         next_link
                     }
@@ -452,7 +452,7 @@ impl ResourcesExtensionsResourcesClient {
         path = path.replace("{resourceUri}", resource_uri);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Patch);
         request.insert_header("accept", "application/json");
@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    ListWidgetsResponse, PreviewVersionClientGetWidgetOptions,
+    ApiVersion, ListWidgetsResponse, PreviewVersionClientGetWidgetOptions,
     PreviewVersionClientListWidgetsOptions, PreviewVersionClientUpdateWidgetColorOptions,
     UpdateWidgetColorRequest, Widget,
 };
@@ -20,7 +20,7 @@ use azure_core::{
 
 #[tracing::client]
 pub struct PreviewVersionClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -29,7 +29,7 @@ pub struct PreviewVersionClient {
 #[derive(Clone, SafeDebug)]
 pub struct PreviewVersionClientOptions {
     /// The API version to use for this operation.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -97,7 +97,7 @@ impl PreviewVersionClient {
         path = path.replace("{id}", id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         request.insert_header("accept", "application/json");
@@ -128,11 +128,19 @@ impl PreviewVersionClient {
         options: Option<PreviewVersionClientListWidgetsOptions<'_>>,
     ) -> Result<Response<ListWidgetsResponse>> {
         let options = options.unwrap_or_default();
+        if options.color.is_some()
+            && [ApiVersion::V2024_01_01, ApiVersion::V2024_06_01].contains(&self.api_version)
+        {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter color is only available in api-version 2024-12-01-preview",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         url.append_path("/azure/versioning/previewVersion/widgets");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         if let Some(color) = options.color.as_ref() {
             query_builder.set_pair("color", color);
         }
@@ -177,13 +185,19 @@ impl PreviewVersionClient {
             ));
         }
         let options = options.unwrap_or_default();
+        if [ApiVersion::V2024_01_01, ApiVersion::V2024_06_01].contains(&self.api_version) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "method update_widget_color is only available in api-version 2024-12-01-preview",
+            ));
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         let mut path = String::from("/azure/versioning/previewVersion/widgets/{id}/color");
         path = path.replace("{id}", id);
         url.append_path(&path);
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Patch);
         request.insert_header("accept", "application/json");
@@ -207,12 +221,12 @@ impl PreviewVersionClient {
 }
 
 /// Default value for [`PreviewVersionClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2024-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2024_12_01Preview;
 
 impl Default for PreviewVersionClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
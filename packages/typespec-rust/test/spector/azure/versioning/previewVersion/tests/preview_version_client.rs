@@ -3,7 +3,7 @@
 // Licensed under the MIT License. See License.txt in the project root for license information.
 
 use spector_azpreviewversion::{
-    models::{PreviewVersionClientListWidgetsOptions, UpdateWidgetColorRequest},
+    models::{ApiVersion, PreviewVersionClientListWidgetsOptions, UpdateWidgetColorRequest},
     PreviewVersionClient, PreviewVersionClientOptions,
 };
 
@@ -22,7 +22,7 @@ async fn list_widgets() {
     let client = PreviewVersionClient::with_no_credential(
         "http://localhost:3000",
         Some(PreviewVersionClientOptions {
-            api_version: "2024-06-01".to_string(),
+            api_version: ApiVersion::V2024_06_01,
             ..Default::default()
         }),
     )
@@ -56,3 +56,26 @@ async fn update_widget_color() {
     assert_eq!(resp.id, Some("widget-123".to_string()));
     assert_eq!(resp.name, Some("Sample Widget".to_string()));
 }
+
+#[tokio::test]
+async fn update_widget_color_unsupported_version() {
+    let client = PreviewVersionClient::with_no_credential(
+        "http://localhost:3000",
+        Some(PreviewVersionClientOptions {
+            api_version: ApiVersion::V2024_06_01,
+            ..Default::default()
+        }),
+    )
+    .unwrap();
+    let color_update = UpdateWidgetColorRequest {
+        color: Some("red".to_string()),
+    };
+    let err = client
+        .update_widget_color("widget-123", color_update.try_into().unwrap(), None)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "method update_widget_color is only available in api-version 2024-12-01-preview"
+    );
+}
@@ -4,20 +4,21 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::header::generated::models::{
-    DurationHeaderClientDefaultOptions, DurationHeaderClientFloat64MillisecondsOptions,
-    DurationHeaderClientFloat64SecondsOptions,
+    to_iso8601_duration, to_milliseconds_duration, to_milliseconds_float_duration,
+    to_seconds_duration, to_seconds_float_duration, DurationHeaderClientDefaultOptions,
+    DurationHeaderClientFloat64MillisecondsOptions, DurationHeaderClientFloat64SecondsOptions,
     DurationHeaderClientFloatMillisecondsLargerUnitOptions,
-    DurationHeaderClientFloatMillisecondsOptions,
-    DurationHeaderClientFloatSecondsLargerUnitOptions, DurationHeaderClientFloatSecondsOptions,
-    DurationHeaderClientInt32MillisecondsArrayOptions,
+    DurationHeaderClientFloatMillisecondsOptions, DurationHeaderClientFloatSecondsLargerUnitOptions,
+    DurationHeaderClientFloatSecondsOptions, DurationHeaderClientInt32MillisecondsArrayOptions,
     DurationHeaderClientInt32MillisecondsLargerUnitOptions,
-    DurationHeaderClientInt32MillisecondsOptions,
-    DurationHeaderClientInt32SecondsLargerUnitOptions, DurationHeaderClientInt32SecondsOptions,
-    DurationHeaderClientIso8601ArrayOptions, DurationHeaderClientIso8601Options,
+    DurationHeaderClientInt32MillisecondsOptions, DurationHeaderClientInt32SecondsLargerUnitOptions,
+    DurationHeaderClientInt32SecondsOptions, DurationHeaderClientIso8601ArrayOptions,
+    DurationHeaderClientIso8601Options,
 };
 use azure_core::{
     error::CheckSuccessOptions,
     http::{Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url, UrlExt},
+    time::Duration,
     tracing, Result,
 };
 
@@ -40,7 +41,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.default")]
     pub async fn default(
         &self,
-        duration: String,
+        duration: &Duration,
         options: Option<DurationHeaderClientDefaultOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -48,7 +49,7 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/default");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration);
+        request.insert_header("duration", to_iso8601_duration(duration));
         let rsp = self
             .pipeline
             .send(
@@ -72,7 +73,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.float64Milliseconds")]
     pub async fn float64_milliseconds(
         &self,
-        duration: f64,
+        duration: &Duration,
         options: Option<DurationHeaderClientFloat64MillisecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -80,7 +81,7 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/float64-milliseconds");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.to_string());
+        request.insert_header("duration", to_milliseconds_float_duration(duration).to_string());
         let rsp = self
             .pipeline
             .send(
@@ -104,7 +105,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.float64Seconds")]
     pub async fn float64_seconds(
         &self,
-        duration: f64,
+        duration: &Duration,
         options: Option<DurationHeaderClientFloat64SecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -112,7 +113,7 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/float64-seconds");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.to_string());
+        request.insert_header("duration", to_seconds_float_duration(duration).to_string());
         let rsp = self
             .pipeline
             .send(
@@ -136,7 +137,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.floatMilliseconds")]
     pub async fn float_milliseconds(
         &self,
-        duration: f32,
+        duration: &Duration,
         options: Option<DurationHeaderClientFloatMillisecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -144,7 +145,7 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/float-milliseconds");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.to_string());
+        request.insert_header("duration", to_milliseconds_float_duration(duration).to_string());
         let rsp = self
             .pipeline
             .send(
@@ -168,7 +169,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.floatMillisecondsLargerUnit")]
     pub async fn float_milliseconds_larger_unit(
         &self,
-        duration: f32,
+        duration: &Duration,
         options: Option<DurationHeaderClientFloatMillisecondsLargerUnitOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -176,7 +177,7 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/float-milliseconds-larger-unit");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.to_string());
+        request.insert_header("duration", to_milliseconds_float_duration(duration).to_string());
         let rsp = self
             .pipeline
             .send(
@@ -200,7 +201,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.floatSeconds")]
     pub async fn float_seconds(
         &self,
-        duration: f32,
+        duration: &Duration,
         options: Option<DurationHeaderClientFloatSecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -208,7 +209,7 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/float-seconds");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.to_string());
+        request.insert_header("duration", to_seconds_float_duration(duration).to_string());
         let rsp = self
             .pipeline
             .send(
@@ -232,7 +233,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.floatSecondsLargerUnit")]
     pub async fn float_seconds_larger_unit(
         &self,
-        duration: f32,
+        duration: &Duration,
         options: Option<DurationHeaderClientFloatSecondsLargerUnitOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -240,7 +241,7 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/float-seconds-larger-unit");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.to_string());
+        request.insert_header("duration", to_seconds_float_duration(duration).to_string());
         let rsp = self
             .pipeline
             .send(
@@ -264,15 +265,16 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.int32Milliseconds")]
     pub async fn int32_milliseconds(
         &self,
-        duration: i32,
+        duration: &Duration,
         options: Option<DurationHeaderClientInt32MillisecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
+        to_milliseconds_duration(duration)?;
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/int32-milliseconds");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.to_string());
+        request.insert_header("duration", duration.whole_milliseconds().to_string());
         let rsp = self
             .pipeline
             .send(
@@ -296,10 +298,13 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.int32MillisecondsArray")]
     pub async fn int32_milliseconds_array(
         &self,
-        duration: &[i32],
+        duration: &[Duration],
         options: Option<DurationHeaderClientInt32MillisecondsArrayOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
+        for item in duration.iter() {
+            to_milliseconds_duration(item)?;
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/int32-milliseconds-array");
@@ -308,7 +313,7 @@ impl DurationHeaderClient {
             "duration",
             duration
                 .iter()
-                .map(|i| i.to_string())
+                .map(|i| i.whole_milliseconds().to_string())
                 .collect::<Vec<String>>()
                 .join(","),
         );
@@ -335,15 +340,16 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.int32MillisecondsLargerUnit")]
     pub async fn int32_milliseconds_larger_unit(
         &self,
-        duration: i32,
+        duration: &Duration,
         options: Option<DurationHeaderClientInt32MillisecondsLargerUnitOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
+        to_milliseconds_duration(duration)?;
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/int32-milliseconds-larger-unit");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.to_string());
+        request.insert_header("duration", duration.whole_milliseconds().to_string());
         let rsp = self
             .pipeline
             .send(
@@ -367,7 +373,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.int32Seconds")]
     pub async fn int32_seconds(
         &self,
-        duration: i32,
+        duration: &Duration,
         options: Option<DurationHeaderClientInt32SecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -375,7 +381,7 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/int32-seconds");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.to_string());
+        request.insert_header("duration", to_seconds_duration(duration).to_string());
         let rsp = self
             .pipeline
             .send(
@@ -399,7 +405,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.int32SecondsLargerUnit")]
     pub async fn int32_seconds_larger_unit(
         &self,
-        duration: i32,
+        duration: &Duration,
         options: Option<DurationHeaderClientInt32SecondsLargerUnitOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -407,7 +413,7 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/int32-seconds-larger-unit");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.to_string());
+        request.insert_header("duration", to_seconds_duration(duration).to_string());
         let rsp = self
             .pipeline
             .send(
@@ -431,7 +437,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.iso8601")]
     pub async fn iso8601(
         &self,
-        duration: String,
+        duration: &Duration,
         options: Option<DurationHeaderClientIso8601Options<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -439,7 +445,7 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/iso8601");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration);
+        request.insert_header("duration", to_iso8601_duration(duration));
         let rsp = self
             .pipeline
             .send(
@@ -463,7 +469,7 @@ impl DurationHeaderClient {
     #[tracing::function("Encode.Duration.Header.iso8601Array")]
     pub async fn iso8601_array(
         &self,
-        duration: &[&str],
+        duration: &[Duration],
         options: Option<DurationHeaderClientIso8601ArrayOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -471,7 +477,14 @@ impl DurationHeaderClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/header/iso8601-array");
         let mut request = Request::new(url, Method::Get);
-        request.insert_header("duration", duration.join(","));
+        request.insert_header(
+            "duration",
+            duration
+                .iter()
+                .map(to_iso8601_duration)
+                .collect::<Vec<String>>()
+                .join(","),
+        );
         let rsp = self
             .pipeline
             .send(
@@ -0,0 +1,68 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    error::{Error, ErrorKind},
+    time::Duration,
+    Result,
+};
+
+/// Formats a Duration as an ISO 8601 duration (e.g. P1DT2H30M).
+pub(crate) fn to_iso8601_duration(value: &Duration) -> String {
+    let mut result = String::from(if value.is_negative() { "-P" } else { "P" });
+    let value = value.abs();
+    let (days, hours, minutes, seconds, nanoseconds) = (
+        value.whole_days(),
+        value.whole_hours() % 24,
+        value.whole_minutes() % 60,
+        value.whole_seconds() % 60,
+        value.subsec_nanoseconds(),
+    );
+    if days > 0 {
+        result.push_str(&format!("{days}D"));
+    }
+    if days > 0 && hours == 0 && minutes == 0 && seconds == 0 && nanoseconds == 0 {
+        return result;
+    }
+    result.push('T');
+    if hours > 0 {
+        result.push_str(&format!("{hours}H"));
+    }
+    if minutes > 0 {
+        result.push_str(&format!("{minutes}M"));
+    }
+    if nanoseconds > 0 {
+        let fraction = format!("{nanoseconds:09}");
+        result.push_str(&format!("{seconds}.{}S", fraction.trim_end_matches('0')));
+    } else if seconds > 0 || (hours == 0 && minutes == 0) {
+        result.push_str(&format!("{seconds}S"));
+    }
+    result
+}
+
+/// Converts a Duration into a whole number of milliseconds.
+pub(crate) fn to_milliseconds_duration(value: &Duration) -> Result<i64> {
+    i64::try_from(value.whole_milliseconds()).map_err(|_| {
+        Error::with_message(
+            ErrorKind::DataConversion,
+            format!("duration {value} is too large to send as milliseconds"),
+        )
+    })
+}
+
+/// Converts a Duration into a number of milliseconds.
+pub(crate) fn to_milliseconds_float_duration(value: &Duration) -> f64 {
+    value.as_seconds_f64() * 1000.0
+}
+
+/// Converts a Duration into a whole number of seconds.
+pub(crate) fn to_seconds_duration(value: &Duration) -> i64 {
+    value.whole_seconds()
+}
+
+/// Converts a Duration into a number of seconds.
+pub(crate) fn to_seconds_float_duration(value: &Duration) -> f64 {
+    value.as_seconds_f64()
+}
@@ -3,5 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+mod durations;
 mod method_options;
+pub use durations::*;
 pub use method_options::*;
@@ -3,89 +3,146 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use azure_core::fmt::SafeDebug;
+use super::models_serde;
+use azure_core::{fmt::SafeDebug, time::Duration};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct DefaultDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_iso8601"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct Float64MillisecondsDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_milliseconds_float"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct Float64SecondsDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<f64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_seconds_float"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct FloatMillisecondsDurationArrayProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<Vec<f32>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_vec_duration_milliseconds_float"
+    )]
+    pub value: Option<Vec<Duration>>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct FloatMillisecondsDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<f32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_milliseconds_float"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct FloatMillisecondsLargerUnitDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<f32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_milliseconds_float"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct FloatSecondsDurationArrayProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<Vec<f32>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_vec_duration_seconds_float"
+    )]
+    pub value: Option<Vec<Duration>>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct FloatSecondsDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<f32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_seconds_float"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct FloatSecondsLargerUnitDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<f32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_seconds_float"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct ISO8601DurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_iso8601"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct Int32MillisecondsDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<i32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_milliseconds"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct Int32MillisecondsLargerUnitDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<i32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_milliseconds"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct Int32SecondsDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<i32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_seconds"
+    )]
+    pub value: Option<Duration>,
 }
 
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct Int32SecondsLargerUnitDurationProperty {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<i32>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_duration_seconds"
+    )]
+    pub value: Option<Duration>,
 }
@@ -0,0 +1,272 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+pub mod option_duration_iso8601 {
+    #![allow(clippy::type_complexity)]
+    use crate::property::generated::models::{parse_iso8601_duration, to_iso8601_duration};
+    use azure_core::time::Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<String>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let decoded0 =
+                    parse_iso8601_duration(&to_deserialize).map_err(serde::de::Error::custom)?;
+                Ok(Some(decoded0))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn serialize<S>(to_serialize: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(to_serialize) = to_serialize {
+            let encoded0 = to_iso8601_duration(to_serialize);
+            <Option<String>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+pub mod option_duration_milliseconds {
+    #![allow(clippy::type_complexity)]
+    use crate::property::generated::models::{parse_milliseconds_duration, to_milliseconds_duration};
+    use azure_core::time::Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<i64>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let decoded0 =
+                    parse_milliseconds_duration(to_deserialize).map_err(serde::de::Error::custom)?;
+                Ok(Some(decoded0))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn serialize<S>(to_serialize: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(to_serialize) = to_serialize {
+            let encoded0 =
+                to_milliseconds_duration(to_serialize).map_err(serde::ser::Error::custom)?;
+            <Option<i64>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+pub mod option_duration_milliseconds_float {
+    #![allow(clippy::type_complexity)]
+    use crate::property::generated::models::{
+        parse_milliseconds_float_duration, to_milliseconds_float_duration,
+    };
+    use azure_core::time::Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<f64>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let decoded0 = parse_milliseconds_float_duration(to_deserialize)
+                    .map_err(serde::de::Error::custom)?;
+                Ok(Some(decoded0))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn serialize<S>(to_serialize: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(to_serialize) = to_serialize {
+            let encoded0 = to_milliseconds_float_duration(to_serialize);
+            <Option<f64>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+pub mod option_duration_seconds {
+    #![allow(clippy::type_complexity)]
+    use crate::property::generated::models::{parse_seconds_duration, to_seconds_duration};
+    use azure_core::time::Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<i64>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let decoded0 =
+                    parse_seconds_duration(to_deserialize).map_err(serde::de::Error::custom)?;
+                Ok(Some(decoded0))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn serialize<S>(to_serialize: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(to_serialize) = to_serialize {
+            let encoded0 = to_seconds_duration(to_serialize);
+            <Option<i64>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+pub mod option_duration_seconds_float {
+    #![allow(clippy::type_complexity)]
+    use crate::property::generated::models::{
+        parse_seconds_float_duration, to_seconds_float_duration,
+    };
+    use azure_core::time::Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<f64>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let decoded0 =
+                    parse_seconds_float_duration(to_deserialize).map_err(serde::de::Error::custom)?;
+                Ok(Some(decoded0))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn serialize<S>(to_serialize: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(to_serialize) = to_serialize {
+            let encoded0 = to_seconds_float_duration(to_serialize);
+            <Option<f64>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+pub mod option_vec_duration_milliseconds_float {
+    #![allow(clippy::type_complexity)]
+    use crate::property::generated::models::{
+        parse_milliseconds_float_duration, to_milliseconds_float_duration,
+    };
+    use azure_core::time::Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<Duration>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<Vec<f64>>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let mut decoded0 = <Vec<Duration>>::new();
+                for v in to_deserialize {
+                    decoded0.push(
+                        parse_milliseconds_float_duration(v).map_err(serde::de::Error::custom)?,
+                    );
+                }
+                Ok(Some(decoded0))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn serialize<S>(
+        to_serialize: &Option<Vec<Duration>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(to_serialize) = to_serialize {
+            let encoded0 = to_serialize.iter().map(to_milliseconds_float_duration).collect();
+            <Option<Vec<f64>>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+pub mod option_vec_duration_seconds_float {
+    #![allow(clippy::type_complexity)]
+    use crate::property::generated::models::{
+        parse_seconds_float_duration, to_seconds_float_duration,
+    };
+    use azure_core::time::Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<Duration>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<Vec<f64>>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let mut decoded0 = <Vec<Duration>>::new();
+                for v in to_deserialize {
+                    decoded0.push(
+                        parse_seconds_float_duration(v).map_err(serde::de::Error::custom)?,
+                    );
+                }
+                Ok(Some(decoded0))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn serialize<S>(
+        to_serialize: &Option<Vec<Duration>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(to_serialize) = to_serialize {
+            let encoded0 = to_serialize.iter().map(to_seconds_float_duration).collect();
+            <Option<Vec<f64>>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
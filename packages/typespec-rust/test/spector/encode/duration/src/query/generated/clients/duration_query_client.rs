@@ -4,8 +4,9 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::query::generated::models::{
-    DurationQueryClientDefaultOptions, DurationQueryClientFloat64MillisecondsOptions,
-    DurationQueryClientFloat64SecondsOptions,
+    to_iso8601_duration, to_milliseconds_duration, to_milliseconds_float_duration,
+    to_seconds_duration, to_seconds_float_duration, DurationQueryClientDefaultOptions,
+    DurationQueryClientFloat64MillisecondsOptions, DurationQueryClientFloat64SecondsOptions,
     DurationQueryClientFloatMillisecondsLargerUnitOptions,
     DurationQueryClientFloatMillisecondsOptions, DurationQueryClientFloatSecondsLargerUnitOptions,
     DurationQueryClientFloatSecondsOptions, DurationQueryClientInt32MillisecondsArrayOptions,
@@ -17,6 +18,7 @@ use crate::query::generated::models::{
 use azure_core::{
     error::CheckSuccessOptions,
     http::{Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url, UrlExt},
+    time::Duration,
     tracing, Result,
 };
 
@@ -39,7 +41,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.default")]
     pub async fn default(
         &self,
-        input: &str,
+        input: &Duration,
         options: Option<DurationQueryClientDefaultOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -47,7 +49,7 @@ impl DurationQueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/default");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input);
+        query_builder.set_pair("input", to_iso8601_duration(input));
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -73,7 +75,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.float64Milliseconds")]
     pub async fn float64_milliseconds(
         &self,
-        input: f64,
+        input: &Duration,
         options: Option<DurationQueryClientFloat64MillisecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -81,7 +83,7 @@ impl DurationQueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/float64-milliseconds");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input.to_string());
+        query_builder.set_pair("input", to_milliseconds_float_duration(input).to_string());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -107,7 +109,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.float64Seconds")]
     pub async fn float64_seconds(
         &self,
-        input: f64,
+        input: &Duration,
         options: Option<DurationQueryClientFloat64SecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -115,7 +117,7 @@ impl DurationQueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/float64-seconds");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input.to_string());
+        query_builder.set_pair("input", to_seconds_float_duration(input).to_string());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -141,7 +143,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.floatMilliseconds")]
     pub async fn float_milliseconds(
         &self,
-        input: f32,
+        input: &Duration,
         options: Option<DurationQueryClientFloatMillisecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -149,7 +151,7 @@ impl DurationQueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/float-milliseconds");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input.to_string());
+        query_builder.set_pair("input", to_milliseconds_float_duration(input).to_string());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -175,7 +177,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.floatMillisecondsLargerUnit")]
     pub async fn float_milliseconds_larger_unit(
         &self,
-        input: f32,
+        input: &Duration,
         options: Option<DurationQueryClientFloatMillisecondsLargerUnitOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -183,7 +185,7 @@ impl DurationQueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/float-milliseconds-larger-unit");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input.to_string());
+        query_builder.set_pair("input", to_milliseconds_float_duration(input).to_string());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -209,7 +211,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.floatSeconds")]
     pub async fn float_seconds(
         &self,
-        input: f32,
+        input: &Duration,
         options: Option<DurationQueryClientFloatSecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -217,7 +219,7 @@ impl DurationQueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/float-seconds");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input.to_string());
+        query_builder.set_pair("input", to_seconds_float_duration(input).to_string());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -243,7 +245,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.floatSecondsLargerUnit")]
     pub async fn float_seconds_larger_unit(
         &self,
-        input: f32,
+        input: &Duration,
         options: Option<DurationQueryClientFloatSecondsLargerUnitOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -251,7 +253,7 @@ impl DurationQueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/float-seconds-larger-unit");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input.to_string());
+        query_builder.set_pair("input", to_seconds_float_duration(input).to_string());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -277,15 +279,16 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.int32Milliseconds")]
     pub async fn int32_milliseconds(
         &self,
-        input: i32,
+        input: &Duration,
         options: Option<DurationQueryClientInt32MillisecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
+        to_milliseconds_duration(input)?;
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/int32-milliseconds");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input.to_string());
+        query_builder.set_pair("input", input.whole_milliseconds().to_string());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -311,10 +314,13 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.int32MillisecondsArray")]
     pub async fn int32_milliseconds_array(
         &self,
-        input: &[i32],
+        input: &[Duration],
         options: Option<DurationQueryClientInt32MillisecondsArrayOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
+        for item in input.iter() {
+            to_milliseconds_duration(item)?;
+        }
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/int32-milliseconds-array");
@@ -323,7 +329,7 @@ impl DurationQueryClient {
             "input",
             input
                 .iter()
-                .map(|i| i.to_string())
+                .map(|i| i.whole_milliseconds().to_string())
                 .collect::<Vec<String>>()
                 .join(","),
         );
@@ -352,15 +358,16 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.int32MillisecondsLargerUnit")]
     pub async fn int32_milliseconds_larger_unit(
         &self,
-        input: i32,
+        input: &Duration,
         options: Option<DurationQueryClientInt32MillisecondsLargerUnitOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
+        to_milliseconds_duration(input)?;
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/int32-milliseconds-larger-unit");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input.to_string());
+        query_builder.set_pair("input", input.whole_milliseconds().to_string());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -386,7 +393,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.int32Seconds")]
     pub async fn int32_seconds(
         &self,
-        input: i32,
+        input: &Duration,
         options: Option<DurationQueryClientInt32SecondsOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -394,7 +401,7 @@ impl DurationQueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/int32-seconds");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input.to_string());
+        query_builder.set_pair("input", to_seconds_duration(input).to_string());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -420,7 +427,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.int32SecondsArray")]
     pub async fn int32_seconds_array(
         &self,
-        input: &[i32],
+        input: &[Duration],
         options: Option<DurationQueryClientInt32SecondsArrayOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -432,7 +439,7 @@ impl DurationQueryClient {
             "input",
             input
                 .iter()
-                .map(|i| i.to_string())
+                .map(|i| to_seconds_duration(i).to_string())
                 .collect::<Vec<String>>()
                 .join(","),
         );
@@ -461,7 +468,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.int32SecondsLargerUnit")]
     pub async fn int32_seconds_larger_unit(
         &self,
-        input: i32,
+        input: &Duration,
         options: Option<DurationQueryClientInt32SecondsLargerUnitOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -469,7 +476,7 @@ impl DurationQueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/int32-seconds-larger-unit");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input.to_string());
+        query_builder.set_pair("input", to_seconds_duration(input).to_string());
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -495,7 +502,7 @@ impl DurationQueryClient {
     #[tracing::function("Encode.Duration.Query.iso8601")]
     pub async fn iso8601(
         &self,
-        input: &str,
+        input: &Duration,
         options: Option<DurationQueryClientIso8601Options<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -503,7 +510,7 @@ impl DurationQueryClient {
         let mut url = self.endpoint.clone();
         url.append_path("/encode/duration/query/iso8601");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("input", input);
+        query_builder.set_pair("input", to_iso8601_duration(input));
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
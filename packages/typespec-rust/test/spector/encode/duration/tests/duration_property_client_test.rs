@@ -2,6 +2,7 @@
 //
 // Licensed under the MIT License. See License.txt in the project root for license information.
 
+use azure_core::{http::RequestContent, json::from_json, time::Duration};
 use spector_duration::{
     property::models::{
         DefaultDurationProperty, Float64MillisecondsDurationProperty,
@@ -19,7 +20,7 @@ use spector_duration::{
 async fn default() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     let body = DefaultDurationProperty {
-        value: Some("P40D".to_string()),
+        value: Some(Duration::days(40)),
     };
     let resp = client
         .get_duration_property_client()
@@ -27,14 +28,14 @@ async fn default() {
         .await
         .unwrap();
     let result: DefaultDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some("P40D".to_string()));
+    assert_eq!(result.value, Some(Duration::days(40)));
 }
 
 #[tokio::test]
 async fn float64_milliseconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     let body = Float64MillisecondsDurationProperty {
-        value: Some(35625.0),
+        value: Some(Duration::milliseconds(35625)),
     };
     let resp = client
         .get_duration_property_client()
@@ -42,14 +43,14 @@ async fn float64_milliseconds() {
         .await
         .unwrap();
     let result: Float64MillisecondsDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(35625.0));
+    assert_eq!(result.value, Some(Duration::milliseconds(35625)));
 }
 
 #[tokio::test]
 async fn float64_seconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     let body = Float64SecondsDurationProperty {
-        value: Some(35.625),
+        value: Some(Duration::milliseconds(35625)),
     };
     let resp = client
         .get_duration_property_client()
@@ -57,14 +58,14 @@ async fn float64_seconds() {
         .await
         .unwrap();
     let result: Float64SecondsDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(35.625));
+    assert_eq!(result.value, Some(Duration::milliseconds(35625)));
 }
 
 #[tokio::test]
 async fn float_milliseconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     let body = FloatMillisecondsDurationProperty {
-        value: Some(35625.0),
+        value: Some(Duration::milliseconds(35625)),
     };
     let resp = client
         .get_duration_property_client()
@@ -72,14 +73,17 @@ async fn float_milliseconds() {
         .await
         .unwrap();
     let result: FloatMillisecondsDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(35625.0));
+    assert_eq!(result.value, Some(Duration::milliseconds(35625)));
 }
 
 #[tokio::test]
 async fn float_milliseconds_array() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     let body = FloatMillisecondsDurationArrayProperty {
-        value: Some(vec![35625.0, 46750.0]),
+        value: Some(vec![
+            Duration::milliseconds(35625),
+            Duration::milliseconds(46750),
+        ]),
     };
     let resp = client
         .get_duration_property_client()
@@ -87,14 +91,20 @@ async fn float_milliseconds_array() {
         .await
         .unwrap();
     let result: FloatMillisecondsDurationArrayProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(vec![35625.0, 46750.0]));
+    assert_eq!(
+        result.value,
+        Some(vec![
+            Duration::milliseconds(35625),
+            Duration::milliseconds(46750),
+        ])
+    );
 }
 
 #[tokio::test]
 async fn float_seconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     let body = FloatSecondsDurationProperty {
-        value: Some(35.625),
+        value: Some(Duration::milliseconds(35625)),
     };
     let resp = client
         .get_duration_property_client()
@@ -102,14 +112,17 @@ async fn float_seconds() {
         .await
         .unwrap();
     let result: FloatSecondsDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(35.625));
+    assert_eq!(result.value, Some(Duration::milliseconds(35625)));
 }
 
 #[tokio::test]
 async fn float_seconds_array() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     let body = FloatSecondsDurationArrayProperty {
-        value: Some(vec![35.625, 46.75]),
+        value: Some(vec![
+            Duration::milliseconds(35625),
+            Duration::milliseconds(46750),
+        ]),
     };
     let resp = client
         .get_duration_property_client()
@@ -117,40 +130,60 @@ async fn float_seconds_array() {
         .await
         .unwrap();
     let result: FloatSecondsDurationArrayProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(vec![35.625, 46.75]));
+    assert_eq!(
+        result.value,
+        Some(vec![
+            Duration::milliseconds(35625),
+            Duration::milliseconds(46750),
+        ])
+    );
 }
 
 #[tokio::test]
 async fn int32_milliseconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
-    let body = Int32MillisecondsDurationProperty { value: Some(36000) };
+    let body = Int32MillisecondsDurationProperty {
+        value: Some(Duration::seconds(36)),
+    };
     let resp = client
         .get_duration_property_client()
         .int32_milliseconds(body.try_into().unwrap(), None)
         .await
         .unwrap();
     let result: Int32MillisecondsDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(36000));
+    assert_eq!(result.value, Some(Duration::seconds(36)));
+}
+
+#[test]
+fn int32_milliseconds_out_of_range() {
+    let body = Int32MillisecondsDurationProperty {
+        value: Some(Duration::MAX),
+    };
+    let content: azure_core::Result<RequestContent<Int32MillisecondsDurationProperty>> =
+        body.try_into();
+    assert!(content.is_err());
 }
 
 #[tokio::test]
 async fn int32_seconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
-    let body = Int32SecondsDurationProperty { value: Some(36) };
+    let body = Int32SecondsDurationProperty {
+        value: Some(Duration::seconds(36)),
+    };
     let resp = client
         .get_duration_property_client()
         .int32_seconds(body.try_into().unwrap(), None)
         .await
         .unwrap();
     let result: Int32SecondsDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(36));
+    assert_eq!(result.value, Some(Duration::seconds(36)));
 }
 
 #[tokio::test]
 async fn iso8601() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     let body = ISO8601DurationProperty {
-        value: Some("P40D".to_string()),
+        value: Some(Duration::days(40)),
     };
     let resp = client
         .get_duration_property_client()
@@ -158,40 +191,82 @@ async fn iso8601() {
         .await
         .unwrap();
     let result: ISO8601DurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some("P40D".to_string()));
+    assert_eq!(result.value, Some(Duration::days(40)));
+}
+
+#[test]
+fn iso8601_parse() {
+    for (value, expected) in [
+        ("P1DT2H30M", Duration::days(1) + Duration::hours(2) + Duration::minutes(30)),
+        ("PT1.5S", Duration::milliseconds(1500)),
+        ("PT0,5S", Duration::milliseconds(500)),
+        ("-P2W", -Duration::weeks(2)),
+        ("PT0.5H", Duration::minutes(30)),
+        ("PT1.000000001S", Duration::new(1, 1)),
+        // large components don't lose precision
+        ("P100000000000DT0.000000001S", Duration::new(8_640_000_000_000_000, 1)),
+    ] {
+        let json = format!(r#"{{"value":"{value}"}}"#);
+        let result: ISO8601DurationProperty = from_json(json).unwrap();
+        assert_eq!(result.value, Some(expected), "{value}");
+    }
+
+    // malformed durations, including a time designator without any time
+    // components, and years or months (which have no fixed length) are rejected
+    for value in [
+        "",
+        "P",
+        "PT",
+        "P1DT",
+        "P1D2H",
+        "PT1D",
+        "P1Y",
+        "P1M",
+        "PT1H30",
+        "PT.S",
+        "PT1.2.3S",
+        "P99999999999999999999W",
+    ] {
+        let json = format!(r#"{{"value":"{value}"}}"#);
+        assert!(from_json::<_, ISO8601DurationProperty>(json).is_err(), "{value}");
+    }
 }
 
 #[tokio::test]
 async fn int32_seconds_larger_unit() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
-    let body = Int32SecondsLargerUnitDurationProperty { value: Some(120) };
+    let body = Int32SecondsLargerUnitDurationProperty {
+        value: Some(Duration::minutes(2)),
+    };
     let resp = client
         .get_duration_property_client()
         .int32_seconds_larger_unit(body.try_into().unwrap(), None)
         .await
         .unwrap();
     let result: Int32SecondsLargerUnitDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(120));
+    assert_eq!(result.value, Some(Duration::minutes(2)));
 }
 
 #[tokio::test]
 async fn float_seconds_larger_unit() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
-    let body = FloatSecondsLargerUnitDurationProperty { value: Some(150.0) };
+    let body = FloatSecondsLargerUnitDurationProperty {
+        value: Some(Duration::seconds(150)),
+    };
     let resp = client
         .get_duration_property_client()
         .float_seconds_larger_unit(body.try_into().unwrap(), None)
         .await
         .unwrap();
     let result: FloatSecondsLargerUnitDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(150.0));
+    assert_eq!(result.value, Some(Duration::seconds(150)));
 }
 
 #[tokio::test]
 async fn int32_milliseconds_larger_unit() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     let body = Int32MillisecondsLargerUnitDurationProperty {
-        value: Some(180000),
+        value: Some(Duration::minutes(3)),
     };
     let resp = client
         .get_duration_property_client()
@@ -199,14 +274,14 @@ async fn int32_milliseconds_larger_unit() {
         .await
         .unwrap();
     let result: Int32MillisecondsLargerUnitDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(180000));
+    assert_eq!(result.value, Some(Duration::minutes(3)));
 }
 
 #[tokio::test]
 async fn float_milliseconds_larger_unit() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     let body = FloatMillisecondsLargerUnitDurationProperty {
-        value: Some(210000.0),
+        value: Some(Duration::seconds(210)),
     };
     let resp = client
         .get_duration_property_client()
@@ -214,5 +289,5 @@ async fn float_milliseconds_larger_unit() {
         .await
         .unwrap();
     let result: FloatMillisecondsLargerUnitDurationProperty = resp.into_model().unwrap();
-    assert_eq!(result.value, Some(210000.0));
+    assert_eq!(result.value, Some(Duration::seconds(210)));
 }
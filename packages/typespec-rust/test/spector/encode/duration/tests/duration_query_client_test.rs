@@ -2,6 +2,7 @@
 //
 // Licensed under the MIT License. See License.txt in the project root for license information.
 
+use azure_core::time::Duration;
 use spector_duration::DurationClient;
 
 #[tokio::test]
@@ -9,7 +10,7 @@ async fn default() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .default("P40D", None)
+        .default(&Duration::days(40), None)
         .await
         .unwrap();
 }
@@ -19,7 +20,7 @@ async fn float64_milliseconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .float64_milliseconds(35625.0, None)
+        .float64_milliseconds(&Duration::milliseconds(35625), None)
         .await
         .unwrap();
 }
@@ -29,7 +30,7 @@ async fn float64_seconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .float64_seconds(35.625, None)
+        .float64_seconds(&Duration::milliseconds(35625), None)
         .await
         .unwrap();
 }
@@ -39,7 +40,7 @@ async fn float_milliseconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .float_milliseconds(35625.0, None)
+        .float_milliseconds(&Duration::milliseconds(35625), None)
         .await
         .unwrap();
 }
@@ -49,7 +50,7 @@ async fn float_seconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .float_seconds(35.625, None)
+        .float_seconds(&Duration::milliseconds(35625), None)
         .await
         .unwrap();
 }
@@ -59,7 +60,7 @@ async fn int32_milliseconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .int32_milliseconds(36000, None)
+        .int32_milliseconds(&Duration::seconds(36), None)
         .await
         .unwrap();
 }
@@ -69,7 +70,7 @@ async fn int32_milliseconds_array() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .int32_milliseconds_array(&[36000, 47000], None)
+        .int32_milliseconds_array(&[Duration::seconds(36), Duration::seconds(47)], None)
         .await
         .unwrap();
 }
@@ -79,7 +80,7 @@ async fn int32_seconds() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .int32_seconds(36, None)
+        .int32_seconds(&Duration::seconds(36), None)
         .await
         .unwrap();
 }
@@ -89,7 +90,7 @@ async fn int32_seconds_array() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .int32_seconds_array(&[36, 47], None)
+        .int32_seconds_array(&[Duration::seconds(36), Duration::seconds(47)], None)
         .await
         .unwrap();
 }
@@ -99,7 +100,7 @@ async fn iso8601() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .iso8601("P40D", None)
+        .iso8601(&Duration::days(40), None)
         .await
         .unwrap();
 }
@@ -109,7 +110,7 @@ async fn int32_seconds_larger_unit() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .int32_seconds_larger_unit(120, None)
+        .int32_seconds_larger_unit(&Duration::minutes(2), None)
         .await
         .unwrap();
 }
@@ -119,7 +120,7 @@ async fn float_seconds_larger_unit() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .float_seconds_larger_unit(150.0, None)
+        .float_seconds_larger_unit(&Duration::seconds(150), None)
         .await
         .unwrap();
 }
@@ -129,7 +130,7 @@ async fn int32_milliseconds_larger_unit() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .int32_milliseconds_larger_unit(180000, None)
+        .int32_milliseconds_larger_unit(&Duration::minutes(3), None)
         .await
         .unwrap();
 }
@@ -139,7 +140,7 @@ async fn float_milliseconds_larger_unit() {
     let client = DurationClient::with_no_credential("http://localhost:3000", None).unwrap();
     client
         .get_duration_query_client()
-        .float_milliseconds_larger_unit(210000.0, None)
+        .float_milliseconds_larger_unit(&Duration::seconds(210), None)
         .await
         .unwrap();
 }
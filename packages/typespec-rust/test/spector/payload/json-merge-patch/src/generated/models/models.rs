@@ -3,6 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+use super::Nullable;
 use azure_core::fmt::SafeDebug;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -10,11 +11,11 @@ use std::collections::HashMap;
 /// It is the model used by Resource model
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct InnerModel {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
 }
 
 /// Details about a resource.
@@ -48,24 +49,24 @@ pub struct Resource {
 /// Details about a resource for patch operation.
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct ResourcePatch {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub array: Option<Vec<InnerModel>>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub array: Nullable<Vec<InnerModel>>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
 
-    #[serde(rename = "floatValue", skip_serializing_if = "Option::is_none")]
-    pub float_value: Option<f32>,
+    #[serde(default, rename = "floatValue", skip_serializing_if = "Nullable::is_absent")]
+    pub float_value: Nullable<f32>,
 
-    #[serde(rename = "innerModel", skip_serializing_if = "Option::is_none")]
-    pub inner_model: Option<InnerModel>,
+    #[serde(default, rename = "innerModel", skip_serializing_if = "Nullable::is_absent")]
+    pub inner_model: Nullable<InnerModel>,
 
-    #[serde(rename = "intArray", skip_serializing_if = "Option::is_none")]
-    pub int_array: Option<Vec<i32>>,
+    #[serde(default, rename = "intArray", skip_serializing_if = "Nullable::is_absent")]
+    pub int_array: Nullable<Vec<i32>>,
 
-    #[serde(rename = "intValue", skip_serializing_if = "Option::is_none")]
-    pub int_value: Option<i32>,
+    #[serde(default, rename = "intValue", skip_serializing_if = "Nullable::is_absent")]
+    pub int_value: Nullable<i32>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub map: Option<HashMap<String, InnerModel>>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub map: Nullable<HashMap<String, Nullable<InnerModel>>>,
 }
@@ -4,7 +4,8 @@
 
 use spector_jmergepatch::{
     models::{
-        InnerModel, JsonMergePatchClientUpdateOptionalResourceOptions, Resource, ResourcePatch,
+        InnerModel, JsonMergePatchClientUpdateOptionalResourceOptions, Nullable, Resource,
+        ResourcePatch,
     },
     JsonMergePatchClient,
 };
@@ -14,8 +15,8 @@ use std::collections::HashMap;
 async fn create_resource() {
     let client = JsonMergePatchClient::with_no_credential("http://localhost:3000", None).unwrap();
     let inner_model = InnerModel {
-        name: Some(String::from("InnerMadge")),
-        description: Some(String::from("innerDesc")),
+        name: String::from("InnerMadge").into(),
+        description: String::from("innerDesc").into(),
     };
 
     let resource = Resource {
@@ -40,57 +41,96 @@ async fn create_resource() {
 
     let value_map = value.map.unwrap();
     let map_val = value_map.get("key").unwrap();
-    assert_eq!(map_val.name, Some(String::from("InnerMadge")));
-    assert_eq!(map_val.description, Some(String::from("innerDesc")));
+    assert_eq!(map_val.name, Nullable::Value(String::from("InnerMadge")));
+    assert_eq!(map_val.description, Nullable::Value(String::from("innerDesc")));
 
     let array_val = value.array.unwrap();
     assert_eq!(array_val.len(), 1);
-    assert_eq!(array_val[0].name, Some(String::from("InnerMadge")));
-    assert_eq!(array_val[0].description, Some(String::from("innerDesc")));
+    assert_eq!(array_val[0].name, Nullable::Value(String::from("InnerMadge")));
+    assert_eq!(
+        array_val[0].description,
+        Nullable::Value(String::from("innerDesc"))
+    );
 
     assert_eq!(value.int_value, Some(1));
     assert_eq!(value.float_value, Some(1.25));
 
     let inner_model_resp = value.inner_model.unwrap();
-    assert_eq!(inner_model_resp.name, Some(String::from("InnerMadge")));
+    assert_eq!(
+        inner_model_resp.name,
+        Nullable::Value(String::from("InnerMadge"))
+    );
     assert_eq!(
         inner_model_resp.description,
-        Some(String::from("innerDesc"))
+        Nullable::Value(String::from("innerDesc"))
     );
 
     let int_array_val = value.int_array;
     assert_eq!(int_array_val, Some(vec![1, 2, 3]));
 }
 
+fn clear_all_fields() -> ResourcePatch {
+    ResourcePatch {
+        description: Nullable::Null,
+        map: HashMap::from([
+            (
+                String::from("key"),
+                InnerModel {
+                    description: Nullable::Null,
+                    ..Default::default()
+                }
+                .into(),
+            ),
+            (String::from("key2"), Nullable::Null),
+        ])
+        .into(),
+        array: Nullable::Null,
+        int_value: Nullable::Null,
+        float_value: Nullable::Null,
+        inner_model: Nullable::Null,
+        int_array: Nullable::Null,
+    }
+}
+
+fn verify_response(value: Resource) {
+    assert_eq!(value.name, Some(String::from("Madge")));
+    assert!(value.description.is_none());
+    let value_map = value.map.unwrap();
+    let map_val = value_map.get("key").unwrap();
+    assert_eq!(map_val.name, Nullable::Value(String::from("InnerMadge")));
+    assert!(map_val.description.is_absent());
+    assert!(value.array.is_none());
+    assert!(value.int_value.is_none());
+    assert!(value.float_value.is_none());
+    assert!(value.inner_model.is_none());
+    assert!(value.int_array.is_none());
+}
+
 #[tokio::test]
-#[should_panic]
 async fn update_optional_resource() {
     let client = JsonMergePatchClient::with_no_credential("http://localhost:3000", None).unwrap();
 
-    let resource_patch = ResourcePatch::default();
-    // TODO: https://github.com/Azure/azure-sdk-for-rust/issues/1649 to send JSON nulls
-
     let options = JsonMergePatchClientUpdateOptionalResourceOptions {
-        body: Some(resource_patch.try_into().unwrap()),
+        body: Some(clear_all_fields().try_into().unwrap()),
         ..Default::default()
     };
 
-    let _resp = client
+    let resp = client
         .update_optional_resource(Some(options))
         .await
         .unwrap();
+
+    verify_response(resp.into_model().unwrap());
 }
 
 #[tokio::test]
-#[should_panic]
 async fn update_resource() {
     let client = JsonMergePatchClient::with_no_credential("http://localhost:3000", None).unwrap();
 
-    let resource_patch = ResourcePatch::default();
-    // TODO: https://github.com/Azure/azure-sdk-for-rust/issues/1649 to send JSON nulls
-
-    let _resp = client
-        .update_resource(resource_patch.try_into().unwrap(), None)
+    let resp = client
+        .update_resource(clear_all_fields().try_into().unwrap(), None)
         .await
         .unwrap();
+
+    verify_response(resp.into_model().unwrap());
 }
@@ -0,0 +1,294 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::{
+    form_data::{
+        generated::models::{
+            MultiPartFormDataClientAnonymousModelOptions, MultiPartFormDataClientBasicOptions,
+            MultiPartFormDataClientBinaryArrayPartsOptions,
+            MultiPartFormDataClientCheckFileNameAndContentTypeOptions,
+            MultiPartFormDataClientFileArrayAndBasicOptions, MultiPartFormDataClientJsonPartOptions,
+            MultiPartFormDataClientMultiBinaryPartsOptions,
+        },
+        http_parts::clients::MultiPartFormDataHttpPartsClient,
+    },
+    generated::models::MultipartForm,
+    models::{
+        AnonymousModelRequest, BinaryArrayPartsRequest, ComplexPartsRequest, JsonPartRequest,
+        MultiBinaryPartsRequest, MultiPartRequest,
+    },
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    http::{Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url, UrlExt},
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct MultiPartFormDataClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+impl MultiPartFormDataClient {
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Test content-type: multipart/form-data
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.anonymousModel")]
+    pub async fn anonymous_model(
+        &self,
+        body: AnonymousModelRequest,
+        options: Option<MultiPartFormDataClientAnonymousModelOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/anonymous-model");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Test content-type: multipart/form-data
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.basic")]
+    pub async fn basic(
+        &self,
+        body: MultiPartRequest,
+        options: Option<MultiPartFormDataClientBasicOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/mixed-parts");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Test content-type: multipart/form-data for scenario contains multi binary parts
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.binaryArrayParts")]
+    pub async fn binary_array_parts(
+        &self,
+        body: BinaryArrayPartsRequest,
+        options: Option<MultiPartFormDataClientBinaryArrayPartsOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/binary-array-parts");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Test content-type: multipart/form-data
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.checkFileNameAndContentType")]
+    pub async fn check_file_name_and_content_type(
+        &self,
+        body: MultiPartRequest,
+        options: Option<MultiPartFormDataClientCheckFileNameAndContentTypeOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/check-filename-and-content-type");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Test content-type: multipart/form-data for mixed scenarios
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.fileArrayAndBasic")]
+    pub async fn file_array_and_basic(
+        &self,
+        body: ComplexPartsRequest,
+        options: Option<MultiPartFormDataClientFileArrayAndBasicOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/complex-parts");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Test content-type: multipart/form-data for scenario contains json part and binary part
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.jsonPart")]
+    pub async fn json_part(
+        &self,
+        body: JsonPartRequest,
+        options: Option<MultiPartFormDataClientJsonPartOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/json-part");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Test content-type: multipart/form-data for scenario contains multi binary parts
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.multiBinaryParts")]
+    pub async fn multi_binary_parts(
+        &self,
+        body: MultiBinaryPartsRequest,
+        options: Option<MultiPartFormDataClientMultiBinaryPartsOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/multi-binary-parts");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Returns a new instance of MultiPartFormDataHttpPartsClient.
+    #[tracing::subclient]
+    pub fn get_multi_part_form_data_http_parts_client(&self) -> MultiPartFormDataHttpPartsClient {
+        MultiPartFormDataHttpPartsClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`MultiPartFormDataClient::anonymous_model()`](crate::form_data::generated::clients::MultiPartFormDataClient::anonymous_model())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartFormDataClientAnonymousModelOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MultiPartFormDataClient::basic()`](crate::form_data::generated::clients::MultiPartFormDataClient::basic())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartFormDataClientBasicOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MultiPartFormDataClient::binary_array_parts()`](crate::form_data::generated::clients::MultiPartFormDataClient::binary_array_parts())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartFormDataClientBinaryArrayPartsOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MultiPartFormDataClient::check_file_name_and_content_type()`](crate::form_data::generated::clients::MultiPartFormDataClient::check_file_name_and_content_type())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartFormDataClientCheckFileNameAndContentTypeOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MultiPartFormDataClient::file_array_and_basic()`](crate::form_data::generated::clients::MultiPartFormDataClient::file_array_and_basic())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartFormDataClientFileArrayAndBasicOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MultiPartFormDataClient::json_part()`](crate::form_data::generated::clients::MultiPartFormDataClient::json_part())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartFormDataClientJsonPartOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MultiPartFormDataClient::multi_binary_parts()`](crate::form_data::generated::clients::MultiPartFormDataClient::multi_binary_parts())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartFormDataClientMultiBinaryPartsOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
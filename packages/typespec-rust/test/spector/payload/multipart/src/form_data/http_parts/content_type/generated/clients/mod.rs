@@ -0,0 +1,7 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod multi_part_form_data_http_parts_content_type_client;
+pub use multi_part_form_data_http_parts_content_type_client::*;
@@ -0,0 +1,140 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::{
+    form_data::http_parts::content_type::generated::models::{
+        MultiPartFormDataHttpPartsContentTypeClientImageJpegContentTypeOptions,
+        MultiPartFormDataHttpPartsContentTypeClientOptionalContentTypeOptions,
+        MultiPartFormDataHttpPartsContentTypeClientRequiredContentTypeOptions,
+    },
+    generated::models::MultipartForm,
+    models::{
+        FileWithHttpPartOptionalContentTypeRequest, FileWithHttpPartRequiredContentTypeRequest,
+        FileWithHttpPartSpecificContentTypeRequest,
+    },
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    http::{Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url, UrlExt},
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct MultiPartFormDataHttpPartsContentTypeClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+impl MultiPartFormDataHttpPartsContentTypeClient {
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Test content-type: multipart/form-data
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.HttpParts.ContentType.imageJpegContentType")]
+    pub async fn image_jpeg_content_type(
+        &self,
+        body: FileWithHttpPartSpecificContentTypeRequest,
+        options: Option<MultiPartFormDataHttpPartsContentTypeClientImageJpegContentTypeOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/check-filename-and-specific-content-type-with-httppart");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Test content-type: multipart/form-data
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.HttpParts.ContentType.optionalContentType")]
+    pub async fn optional_content_type(
+        &self,
+        body: FileWithHttpPartOptionalContentTypeRequest,
+        options: Option<MultiPartFormDataHttpPartsContentTypeClientOptionalContentTypeOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/file-with-http-part-optional-content-type");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Test content-type: multipart/form-data
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.HttpParts.ContentType.requiredContentType")]
+    pub async fn required_content_type(
+        &self,
+        body: FileWithHttpPartRequiredContentTypeRequest,
+        options: Option<MultiPartFormDataHttpPartsContentTypeClientRequiredContentTypeOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/check-filename-and-required-content-type-with-httppart");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`MultiPartFormDataHttpPartsContentTypeClient::image_jpeg_content_type()`](crate::form_data::http_parts::content_type::generated::clients::MultiPartFormDataHttpPartsContentTypeClient::image_jpeg_content_type())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartFormDataHttpPartsContentTypeClientImageJpegContentTypeOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MultiPartFormDataHttpPartsContentTypeClient::optional_content_type()`](crate::form_data::http_parts::content_type::generated::clients::MultiPartFormDataHttpPartsContentTypeClient::optional_content_type())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartFormDataHttpPartsContentTypeClientOptionalContentTypeOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`MultiPartFormDataHttpPartsContentTypeClient::required_content_type()`](crate::form_data::http_parts::content_type::generated::clients::MultiPartFormDataHttpPartsContentTypeClient::required_content_type())
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartFormDataHttpPartsContentTypeClientRequiredContentTypeOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
@@ -0,0 +1,89 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::{
+    form_data::http_parts::{
+        content_type::clients::MultiPartFormDataHttpPartsContentTypeClient,
+        generated::models::MultiPartFormDataHttpPartsClientJsonArrayAndFileArrayOptions,
+        non_string::clients::MultiPartFormDataHttpPartsNonStringClient,
+    },
+    generated::models::MultipartForm,
+    models::ComplexHttpPartsModelRequest,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    http::{Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url, UrlExt},
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct MultiPartFormDataHttpPartsClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+impl MultiPartFormDataHttpPartsClient {
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Test content-type: multipart/form-data for mixed scenarios
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.HttpParts.jsonArrayAndFileArray")]
+    pub async fn json_array_and_file_array(
+        &self,
+        body: ComplexHttpPartsModelRequest,
+        options: Option<MultiPartFormDataHttpPartsClientJsonArrayAndFileArrayOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/complex-parts-with-httppart");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Returns a new instance of MultiPartFormDataHttpPartsContentTypeClient.
+    #[tracing::subclient]
+    pub fn get_multi_part_form_data_http_parts_content_type_client(
+        &self,
+    ) -> MultiPartFormDataHttpPartsContentTypeClient {
+        MultiPartFormDataHttpPartsContentTypeClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of MultiPartFormDataHttpPartsNonStringClient.
+    #[tracing::subclient]
+    pub fn get_multi_part_form_data_http_parts_non_string_client(
+        &self,
+    ) -> MultiPartFormDataHttpPartsNonStringClient {
+        MultiPartFormDataHttpPartsNonStringClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+}
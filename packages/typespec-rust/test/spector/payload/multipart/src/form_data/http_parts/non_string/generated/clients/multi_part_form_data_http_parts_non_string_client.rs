@@ -0,0 +1,63 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::{
+    form_data::http_parts::non_string::generated::models::MultiPartFormDataHttpPartsNonStringClientFloatOptions,
+    generated::models::MultipartForm,
+    models::FloatRequest,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    http::{Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url, UrlExt},
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct MultiPartFormDataHttpPartsNonStringClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+impl MultiPartFormDataHttpPartsNonStringClient {
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Test content-type: multipart/form-data for non string
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Payload.MultiPart.FormData.HttpParts.NonString.float")]
+    pub async fn float(
+        &self,
+        body: FloatRequest,
+        options: Option<MultiPartFormDataHttpPartsNonStringClientFloatOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/multipart/form-data/non-string-float");
+        let mut request = Request::new(url, Method::Post);
+        let form: MultipartForm = body.try_into()?;
+        request.insert_header("content-type", form.content_type());
+        request.set_body(form.into_body());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
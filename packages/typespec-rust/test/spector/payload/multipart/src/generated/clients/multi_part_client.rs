@@ -0,0 +1,73 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::form_data::clients::MultiPartFormDataClient;
+use azure_core::{
+    fmt::SafeDebug,
+    http::{ClientOptions, Pipeline, Url},
+    tracing, Result,
+};
+
+/// Test for multipart
+#[tracing::client]
+pub struct MultiPartClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`MultiPartClient`](MultiPartClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl MultiPartClient {
+    /// Creates a new MultiPartClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Payload.MultiPart")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<MultiPartClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Returns a new instance of MultiPartFormDataClient.
+    #[tracing::subclient]
+    pub fn get_multi_part_form_data_client(&self) -> MultiPartFormDataClient {
+        MultiPartFormDataClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+/// Clients used to communicate with the service.
+pub mod clients;
+/// Contains all the data structures and types used by the client library.
+pub mod models;
+pub use clients::{MultiPartClient, MultiPartClientOptions};
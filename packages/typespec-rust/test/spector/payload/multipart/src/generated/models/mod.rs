@@ -0,0 +1,10 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+#[allow(clippy::module_inception)]
+mod models;
+mod multipart;
+pub use models::*;
+pub use multipart::*;
@@ -0,0 +1,91 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::MultipartFile;
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct Address {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct AnonymousModelRequest {
+    pub profile_image: Option<MultipartFile>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct BinaryArrayPartsRequest {
+    pub id: Option<String>,
+
+    pub pictures: Option<Vec<MultipartFile>>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct ComplexHttpPartsModelRequest {
+    pub address: Option<Address>,
+
+    pub id: Option<String>,
+
+    pub pictures: Option<Vec<MultipartFile>>,
+
+    pub previous_addresses: Option<Vec<Address>>,
+
+    pub profile_image: Option<MultipartFile>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct ComplexPartsRequest {
+    pub address: Option<Address>,
+
+    pub id: Option<String>,
+
+    pub pictures: Option<Vec<MultipartFile>>,
+
+    pub profile_image: Option<MultipartFile>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct FileWithHttpPartOptionalContentTypeRequest {
+    pub profile_image: Option<MultipartFile>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct FileWithHttpPartRequiredContentTypeRequest {
+    pub profile_image: Option<MultipartFile>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct FileWithHttpPartSpecificContentTypeRequest {
+    pub profile_image: Option<MultipartFile>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct FloatRequest {
+    pub temperature: Option<f64>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct JsonPartRequest {
+    pub address: Option<Address>,
+
+    pub profile_image: Option<MultipartFile>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiBinaryPartsRequest {
+    pub picture: Option<MultipartFile>,
+
+    pub profile_image: Option<MultipartFile>,
+}
+
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultiPartRequest {
+    pub id: Option<String>,
+
+    pub profile_image: Option<MultipartFile>,
+}
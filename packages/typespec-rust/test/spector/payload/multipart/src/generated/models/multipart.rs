@@ -0,0 +1,296 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{
+    AnonymousModelRequest, BinaryArrayPartsRequest, ComplexHttpPartsModelRequest,
+    ComplexPartsRequest, FileWithHttpPartOptionalContentTypeRequest,
+    FileWithHttpPartRequiredContentTypeRequest, FileWithHttpPartSpecificContentTypeRequest,
+    FloatRequest, JsonPartRequest, MultiBinaryPartsRequest, MultiPartRequest,
+};
+use azure_core::{
+    error::{Error, ErrorKind},
+    fmt::SafeDebug,
+    json::to_json,
+    Bytes, Result,
+};
+use serde::Serialize;
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A file sent as a part in a multipart/form-data request body.
+#[derive(Clone, Default, SafeDebug)]
+pub struct MultipartFile {
+    /// The contents of the file.
+    pub content: Bytes,
+
+    /// The content type of the file. When `None`, the service's default content type is sent.
+    pub content_type: Option<String>,
+
+    /// The name of the file. When `None`, the name of the part is sent.
+    pub filename: Option<String>,
+}
+
+impl MultipartFile {
+    /// Creates a new `MultipartFile` from the provided content.
+    pub fn new(content: impl Into<Bytes>) -> Self {
+        Self {
+            content: content.into(),
+            content_type: None,
+            filename: None,
+        }
+    }
+}
+
+/// Builds a multipart/form-data request body.
+pub(crate) struct MultipartForm {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl MultipartForm {
+    pub(crate) fn new() -> Self {
+        // the boundary only needs to be unique within a request
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        Self {
+            boundary: format!("----AzureSdkFormBoundary{nanos:x}{count:x}"),
+            body: Vec::new(),
+        }
+    }
+
+    /// Returns the value of the Content-Type header for this form.
+    pub(crate) fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    pub(crate) fn add_file(
+        &mut self,
+        name: &str,
+        file: &MultipartFile,
+        default_content_type: &str,
+    ) -> Result<()> {
+        let filename = file.filename.as_deref().unwrap_or(name);
+        let content_type = file.content_type.as_deref().unwrap_or(default_content_type);
+        // the content type is sent as-is so it can't contain line breaks
+        if content_type.chars().any(|c| c.is_ascii_control()) {
+            return Err(Error::with_message(
+                ErrorKind::DataConversion,
+                format!("content type for part {name} contains control characters"),
+            ));
+        }
+        self.add_part(name, Some(filename), content_type, &file.content);
+        Ok(())
+    }
+
+    pub(crate) fn add_json<T: Serialize + ?Sized>(&mut self, name: &str, value: &T) -> Result<()> {
+        self.add_part(name, None, "application/json", &to_json(value)?);
+        Ok(())
+    }
+
+    pub(crate) fn add_text(&mut self, name: &str, value: impl ToString) {
+        self.add_part(name, None, "text/plain", value.to_string().as_bytes());
+    }
+
+    fn add_part(&mut self, name: &str, filename: Option<&str>, content_type: &str, content: &[u8]) {
+        let mut disposition = format!("form-data; name=\"{}\"", quote(name));
+        if let Some(filename) = filename {
+            disposition.push_str(&format!("; filename=\"{}\"", quote(filename)));
+        }
+        self.body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: {disposition}\r\nContent-Type: {content_type}\r\n\r\n",
+                self.boundary
+            )
+            .as_bytes(),
+        );
+        self.body.extend_from_slice(content);
+        self.body.extend_from_slice(b"\r\n");
+    }
+
+    /// Consumes the form, returning the complete request body.
+    pub(crate) fn into_body(mut self) -> Bytes {
+        self.body.extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.body.into()
+    }
+}
+
+/// Percent-encodes double quotes, backslashes, and control characters in a Content-Disposition parameter value.
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c == '"' || c == '\\' || c.is_ascii_control() {
+            quoted.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            quoted.push(c);
+        }
+    }
+    quoted
+}
+
+impl TryFrom<AnonymousModelRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: AnonymousModelRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(profile_image) = &value.profile_image {
+            form.add_file("profileImage", profile_image, "application/octet-stream")?;
+        }
+        Ok(form)
+    }
+}
+
+impl TryFrom<BinaryArrayPartsRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: BinaryArrayPartsRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(id) = &value.id {
+            form.add_text("id", id);
+        }
+        if let Some(pictures) = &value.pictures {
+            for file in pictures {
+                form.add_file("pictures", file, "application/octet-stream")?;
+            }
+        }
+        Ok(form)
+    }
+}
+
+impl TryFrom<ComplexHttpPartsModelRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: ComplexHttpPartsModelRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(address) = &value.address {
+            form.add_json("address", address)?;
+        }
+        if let Some(id) = &value.id {
+            form.add_text("id", id);
+        }
+        if let Some(pictures) = &value.pictures {
+            for file in pictures {
+                form.add_file("pictures", file, "*/*")?;
+            }
+        }
+        if let Some(previous_addresses) = &value.previous_addresses {
+            form.add_json("previousAddresses", previous_addresses)?;
+        }
+        if let Some(profile_image) = &value.profile_image {
+            form.add_file("profileImage", profile_image, "*/*")?;
+        }
+        Ok(form)
+    }
+}
+
+impl TryFrom<ComplexPartsRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: ComplexPartsRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(address) = &value.address {
+            form.add_json("address", address)?;
+        }
+        if let Some(id) = &value.id {
+            form.add_text("id", id);
+        }
+        if let Some(pictures) = &value.pictures {
+            for file in pictures {
+                form.add_file("pictures", file, "application/octet-stream")?;
+            }
+        }
+        if let Some(profile_image) = &value.profile_image {
+            form.add_file("profileImage", profile_image, "application/octet-stream")?;
+        }
+        Ok(form)
+    }
+}
+
+impl TryFrom<FileWithHttpPartOptionalContentTypeRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: FileWithHttpPartOptionalContentTypeRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(profile_image) = &value.profile_image {
+            form.add_file("profileImage", profile_image, "*/*")?;
+        }
+        Ok(form)
+    }
+}
+
+impl TryFrom<FileWithHttpPartRequiredContentTypeRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: FileWithHttpPartRequiredContentTypeRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(profile_image) = &value.profile_image {
+            form.add_file("profileImage", profile_image, "*/*")?;
+        }
+        Ok(form)
+    }
+}
+
+impl TryFrom<FileWithHttpPartSpecificContentTypeRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: FileWithHttpPartSpecificContentTypeRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(profile_image) = &value.profile_image {
+            form.add_file("profileImage", profile_image, "image/jpg")?;
+        }
+        Ok(form)
+    }
+}
+
+impl TryFrom<FloatRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: FloatRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(temperature) = &value.temperature {
+            form.add_text("temperature", temperature);
+        }
+        Ok(form)
+    }
+}
+
+impl TryFrom<JsonPartRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: JsonPartRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(address) = &value.address {
+            form.add_json("address", address)?;
+        }
+        if let Some(profile_image) = &value.profile_image {
+            form.add_file("profileImage", profile_image, "application/octet-stream")?;
+        }
+        Ok(form)
+    }
+}
+
+impl TryFrom<MultiBinaryPartsRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: MultiBinaryPartsRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(picture) = &value.picture {
+            form.add_file("picture", picture, "application/octet-stream")?;
+        }
+        if let Some(profile_image) = &value.profile_image {
+            form.add_file("profileImage", profile_image, "application/octet-stream")?;
+        }
+        Ok(form)
+    }
+}
+
+impl TryFrom<MultiPartRequest> for MultipartForm {
+    type Error = azure_core::Error;
+    fn try_from(value: MultiPartRequest) -> Result<Self> {
+        let mut form = MultipartForm::new();
+        if let Some(id) = &value.id {
+            form.add_text("id", id);
+        }
+        if let Some(profile_image) = &value.profile_image {
+            form.add_file("profileImage", profile_image, "application/octet-stream")?;
+        }
+        Ok(form)
+    }
+}
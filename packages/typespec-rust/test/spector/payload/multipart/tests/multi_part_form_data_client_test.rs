@@ -0,0 +1,218 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::{
+    http::{
+        headers::Headers, AsyncRawResponse, Body, ClientOptions, HttpClient, Request, StatusCode,
+        Transport,
+    },
+    Bytes, Result,
+};
+use spector_multipart::{
+    models::{
+        Address, AnonymousModelRequest, BinaryArrayPartsRequest, ComplexPartsRequest,
+        JsonPartRequest, MultiBinaryPartsRequest, MultiPartRequest, MultipartFile,
+    },
+    MultiPartClient, MultiPartClientOptions,
+};
+use std::{
+    fs,
+    sync::{Arc, Mutex},
+};
+
+/// Records the body of the last request and responds with 204 No Content.
+#[derive(Debug, Default)]
+struct BodyRecorder(Mutex<Vec<u8>>);
+
+#[async_trait::async_trait]
+impl HttpClient for BodyRecorder {
+    async fn execute_request(&self, request: &Request) -> Result<AsyncRawResponse> {
+        if let Body::Bytes(bytes) = request.body() {
+            *self.0.lock().unwrap() = bytes.to_vec();
+        }
+        Ok(AsyncRawResponse::from_bytes(StatusCode::NoContent, Headers::new(), Bytes::new()))
+    }
+}
+
+fn create_recording_client(recorder: Arc<BodyRecorder>) -> MultiPartClient {
+    let options = MultiPartClientOptions {
+        client_options: ClientOptions {
+            transport: Some(Transport::new(recorder)),
+            ..Default::default()
+        },
+    };
+    MultiPartClient::with_no_credential("http://localhost:3000", Some(options)).unwrap()
+}
+
+fn jpg() -> MultipartFile {
+    MultipartFile::new(
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.jpg").unwrap(),
+    )
+}
+
+fn png() -> MultipartFile {
+    MultipartFile::new(
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.png").unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn anonymous_model() {
+    let client = MultiPartClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = AnonymousModelRequest {
+        profile_image: Some(jpg()),
+    };
+    client
+        .get_multi_part_form_data_client()
+        .anonymous_model(body, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn basic() {
+    let client = MultiPartClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = MultiPartRequest {
+        id: Some("123".to_string()),
+        profile_image: Some(jpg()),
+    };
+    client
+        .get_multi_part_form_data_client()
+        .basic(body, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn binary_array_parts() {
+    let client = MultiPartClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = BinaryArrayPartsRequest {
+        id: Some("123".to_string()),
+        pictures: Some(vec![png(), png()]),
+    };
+    client
+        .get_multi_part_form_data_client()
+        .binary_array_parts(body, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn check_file_name_and_content_type() {
+    let client = MultiPartClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = MultiPartRequest {
+        id: Some("123".to_string()),
+        profile_image: Some(MultipartFile {
+            content_type: Some("image/jpg".to_string()),
+            filename: Some("hello.jpg".to_string()),
+            ..jpg()
+        }),
+    };
+    client
+        .get_multi_part_form_data_client()
+        .check_file_name_and_content_type(body, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn file_array_and_basic() {
+    let client = MultiPartClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = ComplexPartsRequest {
+        address: Some(Address {
+            city: Some("X".to_string()),
+        }),
+        id: Some("123".to_string()),
+        pictures: Some(vec![png(), png()]),
+        profile_image: Some(jpg()),
+    };
+    client
+        .get_multi_part_form_data_client()
+        .file_array_and_basic(body, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn json_part() {
+    let client = MultiPartClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = JsonPartRequest {
+        address: Some(Address {
+            city: Some("X".to_string()),
+        }),
+        profile_image: Some(jpg()),
+    };
+    client
+        .get_multi_part_form_data_client()
+        .json_part(body, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn multi_binary_parts() {
+    let client = MultiPartClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let form_data_client = client.get_multi_part_form_data_client();
+
+    let body = MultiBinaryPartsRequest {
+        profile_image: Some(jpg()),
+        ..Default::default()
+    };
+    form_data_client
+        .multi_binary_parts(body, None)
+        .await
+        .unwrap();
+
+    let body = MultiBinaryPartsRequest {
+        picture: Some(png()),
+        profile_image: Some(jpg()),
+    };
+    form_data_client
+        .multi_binary_parts(body, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn control_characters_in_filename_are_percent_encoded() {
+    let recorder = Arc::new(BodyRecorder::default());
+    let client = create_recording_client(recorder.clone());
+    let body = MultiPartRequest {
+        id: Some("123".to_string()),
+        profile_image: Some(MultipartFile {
+            filename: Some("a\"b\r\nX-Injected: true.jpg".to_string()),
+            ..MultipartFile::new(b"content".to_vec())
+        }),
+    };
+    client
+        .get_multi_part_form_data_client()
+        .basic(body, None)
+        .await
+        .unwrap();
+
+    let sent = String::from_utf8(recorder.0.lock().unwrap().clone()).unwrap();
+    assert!(sent.contains(
+        "Content-Disposition: form-data; name=\"profileImage\"; filename=\"a%22b%0D%0AX-Injected: true.jpg\"\r\n"
+    ));
+    assert!(!sent.contains("\r\nX-Injected"));
+}
+
+#[tokio::test]
+async fn control_characters_in_content_type_are_rejected() {
+    let recorder = Arc::new(BodyRecorder::default());
+    let client = create_recording_client(recorder.clone());
+    let body = MultiPartRequest {
+        id: Some("123".to_string()),
+        profile_image: Some(MultipartFile {
+            content_type: Some("image/jpg\r\nX-Injected: true".to_string()),
+            ..MultipartFile::new(b"content".to_vec())
+        }),
+    };
+    client
+        .get_multi_part_form_data_client()
+        .basic(body, None)
+        .await
+        .unwrap_err();
+    assert!(recorder.0.lock().unwrap().is_empty());
+}
@@ -0,0 +1,48 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_multipart::{
+    models::{Address, ComplexHttpPartsModelRequest, MultipartFile},
+    MultiPartClient,
+};
+use std::fs;
+
+fn file(name: &str, content_type: &str) -> MultipartFile {
+    let path = format!("../../../../node_modules/@typespec/http-specs/assets/{name}");
+    MultipartFile {
+        content_type: Some(content_type.to_string()),
+        filename: Some(format!("hello.{}", name.rsplit('.').next().unwrap())),
+        ..MultipartFile::new(fs::read(path).unwrap())
+    }
+}
+
+#[tokio::test]
+async fn json_array_and_file_array() {
+    let client = MultiPartClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = ComplexHttpPartsModelRequest {
+        address: Some(Address {
+            city: Some("X".to_string()),
+        }),
+        id: Some("123".to_string()),
+        pictures: Some(vec![
+            file("image.png", "image/png"),
+            file("image.png", "image/png"),
+        ]),
+        previous_addresses: Some(vec![
+            Address {
+                city: Some("Y".to_string()),
+            },
+            Address {
+                city: Some("Z".to_string()),
+            },
+        ]),
+        profile_image: Some(file("image.jpg", "image/jpg")),
+    };
+    client
+        .get_multi_part_form_data_client()
+        .get_multi_part_form_data_http_parts_client()
+        .json_array_and_file_array(body, None)
+        .await
+        .unwrap();
+}
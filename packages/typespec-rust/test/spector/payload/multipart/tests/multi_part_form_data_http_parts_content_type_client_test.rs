@@ -0,0 +1,75 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_multipart::{
+    form_data::http_parts::content_type::clients::MultiPartFormDataHttpPartsContentTypeClient,
+    models::{
+        FileWithHttpPartOptionalContentTypeRequest, FileWithHttpPartRequiredContentTypeRequest,
+        FileWithHttpPartSpecificContentTypeRequest, MultipartFile,
+    },
+    MultiPartClient,
+};
+use std::fs;
+
+fn get_client() -> MultiPartFormDataHttpPartsContentTypeClient {
+    MultiPartClient::with_no_credential("http://localhost:3000", None)
+        .unwrap()
+        .get_multi_part_form_data_client()
+        .get_multi_part_form_data_http_parts_client()
+        .get_multi_part_form_data_http_parts_content_type_client()
+}
+
+fn jpg() -> MultipartFile {
+    MultipartFile {
+        filename: Some("hello.jpg".to_string()),
+        ..MultipartFile::new(
+            fs::read("../../../../node_modules/@typespec/http-specs/assets/image.jpg").unwrap(),
+        )
+    }
+}
+
+#[tokio::test]
+async fn image_jpeg_content_type() {
+    // the part's default content type of image/jpg is sent
+    let body = FileWithHttpPartSpecificContentTypeRequest {
+        profile_image: Some(jpg()),
+    };
+    get_client()
+        .image_jpeg_content_type(body, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn optional_content_type() {
+    let client = get_client();
+
+    // omitting the content type sends the part's default
+    let body = FileWithHttpPartOptionalContentTypeRequest {
+        profile_image: Some(jpg()),
+    };
+    client.optional_content_type(body, None).await.unwrap();
+
+    let body = FileWithHttpPartOptionalContentTypeRequest {
+        profile_image: Some(MultipartFile {
+            content_type: Some("image/jpg".to_string()),
+            ..jpg()
+        }),
+    };
+    client.optional_content_type(body, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn required_content_type() {
+    let body = FileWithHttpPartRequiredContentTypeRequest {
+        profile_image: Some(MultipartFile {
+            content_type: Some("image/jpg".to_string()),
+            ..jpg()
+        }),
+    };
+    get_client()
+        .required_content_type(body, None)
+        .await
+        .unwrap();
+}
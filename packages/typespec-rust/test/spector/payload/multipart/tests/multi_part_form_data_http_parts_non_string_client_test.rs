@@ -0,0 +1,20 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_multipart::{models::FloatRequest, MultiPartClient};
+
+#[tokio::test]
+async fn float() {
+    let client = MultiPartClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = FloatRequest {
+        temperature: Some(0.5),
+    };
+    client
+        .get_multi_part_form_data_client()
+        .get_multi_part_form_data_http_parts_client()
+        .get_multi_part_form_data_http_parts_non_string_client()
+        .float(body, None)
+        .await
+        .unwrap();
+}
@@ -4,7 +4,8 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    ResiliencyServiceDrivenClientAddOperationOptions, ResiliencyServiceDrivenClientFromNoneOptions,
+    ApiVersion, ResiliencyServiceDrivenClientAddOperationOptions,
+    ResiliencyServiceDrivenClientFromNoneOptions,
     ResiliencyServiceDrivenClientFromOneOptionalOptions,
     ResiliencyServiceDrivenClientFromOneRequiredOptions,
 };
@@ -41,7 +42,7 @@ pub struct ResiliencyServiceDrivenClient {
 #[derive(Clone, SafeDebug)]
 pub struct ResiliencyServiceDrivenClientOptions {
     /// Pass in either 'v1' or 'v2'. This represents the API version of a service.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -72,7 +73,7 @@ impl ResiliencyServiceDrivenClient {
         }
         let mut host = String::from("resiliency/service-driven/client:v2/service:{serviceDeploymentVersion}/api-version:{apiVersion}");
         host = host.replace("{serviceDeploymentVersion}", &service_deployment_version);
-        host = host.replace("{apiVersion}", &options.api_version);
+        host = host.replace("{apiVersion}", options.api_version.as_ref());
         endpoint = endpoint.join(&host)?;
         Ok(Self {
             endpoint,
@@ -239,12 +240,12 @@ impl ResiliencyServiceDrivenClient {
 }
 
 /// Default value for [`ResiliencyServiceDrivenClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "v2";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2;
 
 impl Default for ResiliencyServiceDrivenClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
@@ -4,7 +4,7 @@
 
 use spector_srvdrivennew::{
     models::{
-        ResiliencyServiceDrivenClientFromNoneOptions,
+        ApiVersion, ResiliencyServiceDrivenClientFromNoneOptions,
         ResiliencyServiceDrivenClientFromOneOptionalOptions,
         ResiliencyServiceDrivenClientFromOneRequiredOptions,
     },
@@ -28,7 +28,7 @@ async fn from_none_v1() {
         "http://localhost:3000",
         "v2".to_string(),
         Some(ResiliencyServiceDrivenClientOptions {
-            api_version: "v1".to_string(),
+            api_version: ApiVersion::V1,
             ..Default::default()
         }),
     )
@@ -48,7 +48,7 @@ async fn from_one_optional_v1() {
         "http://localhost:3000",
         "v2".to_string(),
         Some(ResiliencyServiceDrivenClientOptions {
-            api_version: "v1".to_string(),
+            api_version: ApiVersion::V1,
             ..Default::default()
         }),
     )
@@ -69,7 +69,7 @@ async fn from_one_required_v1() {
         "http://localhost:3000",
         "v2".to_string(),
         Some(ResiliencyServiceDrivenClientOptions {
-            api_version: "v1".to_string(),
+            api_version: ApiVersion::V1,
             ..Default::default()
         }),
     )
@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    ResiliencyServiceDrivenClientFromNoneOptions,
+    ApiVersion, ResiliencyServiceDrivenClientFromNoneOptions,
     ResiliencyServiceDrivenClientFromOneOptionalOptions,
     ResiliencyServiceDrivenClientFromOneRequiredOptions,
 };
@@ -30,7 +30,7 @@ pub struct ResiliencyServiceDrivenClient {
 pub struct ResiliencyServiceDrivenClientOptions {
     /// Pass in 'v1'. This represents the API version of the service. Will grow up in the next deployment to be both 'v1' and
     /// 'v2'
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -61,7 +61,7 @@ impl ResiliencyServiceDrivenClient {
         }
         let mut host = String::from("resiliency/service-driven/client:v1/service:{serviceDeploymentVersion}/api-version:{apiVersion}");
         host = host.replace("{serviceDeploymentVersion}", &service_deployment_version);
-        host = host.replace("{apiVersion}", &options.api_version);
+        host = host.replace("{apiVersion}", options.api_version.as_ref());
         endpoint = endpoint.join(&host)?;
         Ok(Self {
             endpoint,
@@ -188,12 +188,12 @@ impl ResiliencyServiceDrivenClient {
 }
 
 /// Default value for [`ResiliencyServiceDrivenClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "v1";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V1;
 
 impl Default for ResiliencyServiceDrivenClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
@@ -4,7 +4,8 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    MultipleClientNoOperationParamsOptions, MultipleClientWithOperationPathParamOptions,
+    ApiVersion, MultipleClientNoOperationParamsOptions,
+    MultipleClientWithOperationPathParamOptions,
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -26,7 +27,7 @@ pub struct MultipleClient {
 #[derive(Clone, SafeDebug)]
 pub struct MultipleClientOptions {
     /// Pass in v1.0 for API version.
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -52,7 +53,7 @@ impl MultipleClient {
             ));
         }
         let mut host = String::from("server/path/multiple/{apiVersion}");
-        host = host.replace("{apiVersion}", &options.api_version);
+        host = host.replace("{apiVersion}", options.api_version.as_ref());
         endpoint = endpoint.join(&host)?;
         Ok(Self {
             endpoint,
@@ -142,12 +143,12 @@ impl MultipleClient {
 }
 
 /// Default value for [`MultipleClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "v1.0";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V1_0;
 
 impl Default for MultipleClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
@@ -3,5 +3,9 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+mod enums;
+mod enums_impl;
+mod enums_serde;
 mod method_options;
+pub use enums::*;
 pub use method_options::*;
@@ -4,8 +4,9 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    VersionedClientWithPathApiVersionOptions, VersionedClientWithQueryApiVersionOptions,
-    VersionedClientWithQueryOldApiVersionOptions, VersionedClientWithoutApiVersionOptions,
+    ApiVersion, VersionedClientWithPathApiVersionOptions,
+    VersionedClientWithQueryApiVersionOptions, VersionedClientWithQueryOldApiVersionOptions,
+    VersionedClientWithoutApiVersionOptions,
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -20,7 +21,7 @@ use azure_core::{
 /// Illustrates versioned server.
 #[tracing::client]
 pub struct VersionedClient {
-    pub(crate) api_version: String,
+    pub(crate) api_version: ApiVersion,
     pub(crate) endpoint: Url,
     pub(crate) pipeline: Pipeline,
 }
@@ -28,7 +29,7 @@ pub struct VersionedClient {
 /// Options used when creating a [`VersionedClient`](VersionedClient)
 #[derive(Clone, SafeDebug)]
 pub struct VersionedClientOptions {
-    pub api_version: String,
+    pub api_version: ApiVersion,
     /// Allows customization of the client.
     pub client_options: ClientOptions,
 }
@@ -86,7 +87,7 @@ impl VersionedClient {
         let mut url = self.endpoint.clone();
         let mut path =
             String::from("/server/versions/versioned/with-path-api-version/{apiVersion}");
-        path = path.replace("{apiVersion}", &self.api_version);
+        path = path.replace("{apiVersion}", self.api_version.as_ref());
         url.append_path(&path);
         let mut request = Request::new(url, Method::Head);
         let rsp = self
@@ -119,7 +120,7 @@ impl VersionedClient {
         let mut url = self.endpoint.clone();
         url.append_path("/server/versions/versioned/with-query-api-version");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Head);
         let rsp = self
@@ -152,7 +153,7 @@ impl VersionedClient {
         let mut url = self.endpoint.clone();
         url.append_path("/server/versions/versioned/with-query-old-api-version");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.set_pair("api-version", self.api_version.as_ref());
         query_builder.build();
         let mut request = Request::new(url, Method::Head);
         let rsp = self
@@ -203,12 +204,12 @@ impl VersionedClient {
 }
 
 /// Default value for [`VersionedClientOptions::api_version`].
-pub(crate) const DEFAULT_API_VERSION: &str = "2022-12-01-preview";
+pub(crate) const DEFAULT_API_VERSION: ApiVersion = ApiVersion::V2022_12_01Preview;
 
 impl Default for VersionedClientOptions {
     fn default() -> Self {
         Self {
-            api_version: String::from(DEFAULT_API_VERSION),
+            api_version: DEFAULT_API_VERSION,
             client_options: ClientOptions::default(),
         }
     }
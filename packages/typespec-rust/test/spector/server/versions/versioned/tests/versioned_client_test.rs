@@ -2,7 +2,7 @@
 //
 // Licensed under the MIT License. See License.txt in the project root for license information.
 
-use spector_versioned::{VersionedClient, VersionedClientOptions};
+use spector_versioned::{models::ApiVersion, VersionedClient, VersionedClientOptions};
 
 #[tokio::test]
 async fn with_path_api_version() {
@@ -21,7 +21,7 @@ async fn with_query_old_api_version() {
     let client = VersionedClient::with_no_credential(
         "http://localhost:3000",
         Some(VersionedClientOptions {
-            api_version: "2021-01-01-preview".to_string(),
+            api_version: ApiVersion::V2021_01_01Preview,
             ..Default::default()
         }),
     )
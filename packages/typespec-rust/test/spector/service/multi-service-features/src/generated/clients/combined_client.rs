@@ -0,0 +1,112 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    fmt::SafeDebug,
+    http::{ClientOptions, Pipeline, Url},
+    tracing, Result,
+};
+#[cfg(feature = "service_a")]
+use crate::service_a::clients::CombinedFooClient;
+#[cfg(feature = "service_b")]
+use crate::service_b::clients::CombinedBarClient;
+
+#[tracing::client]
+pub struct CombinedClient {
+    pub(crate) combined_bar_api_version: String,
+    pub(crate) combined_foo_api_version: String,
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`CombinedClient`](CombinedClient)
+#[derive(Clone, SafeDebug)]
+pub struct CombinedClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+    pub combined_bar_api_version: String,
+    pub combined_foo_api_version: String,
+}
+
+impl CombinedClient {
+    /// Creates a new CombinedClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Service.MultiService.Combined")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<CombinedClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            combined_bar_api_version: options.combined_bar_api_version,
+            combined_foo_api_version: options.combined_foo_api_version,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Returns a new instance of CombinedBarClient.
+    #[cfg(feature = "service_b")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "service_b")))]
+    #[tracing::subclient]
+    pub fn get_combined_bar_client(&self) -> CombinedBarClient {
+        CombinedBarClient {
+            api_version: self.combined_bar_api_version.clone(),
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of CombinedFooClient.
+    #[cfg(feature = "service_a")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "service_a")))]
+    #[tracing::subclient]
+    pub fn get_combined_foo_client(&self) -> CombinedFooClient {
+        CombinedFooClient {
+            api_version: self.combined_foo_api_version.clone(),
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+}
+
+/// Default value for [`CombinedClientOptions::combined_bar_api_version`].
+pub(crate) const DEFAULT_COMBINED_BAR_API_VERSION: &str = "bv2";
+
+/// Default value for [`CombinedClientOptions::combined_foo_api_version`].
+pub(crate) const DEFAULT_COMBINED_FOO_API_VERSION: &str = "av2";
+
+impl Default for CombinedClientOptions {
+    fn default() -> Self {
+        Self {
+            client_options: ClientOptions::default(),
+            combined_bar_api_version: String::from(DEFAULT_COMBINED_BAR_API_VERSION),
+            combined_foo_api_version: String::from(DEFAULT_COMBINED_FOO_API_VERSION),
+        }
+    }
+}
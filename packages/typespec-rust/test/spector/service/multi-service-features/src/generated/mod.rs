@@ -0,0 +1,8 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+/// Clients used to communicate with the service.
+pub mod clients;
+pub use clients::{CombinedClient, CombinedClientOptions};
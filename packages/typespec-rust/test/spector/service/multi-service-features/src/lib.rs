@@ -0,0 +1,15 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod generated;
+pub use generated::*;
+#[cfg(feature = "service_a")]
+#[cfg_attr(docsrs, doc(cfg(feature = "service_a")))]
+pub mod service_a;
+#[cfg(feature = "service_b")]
+#[cfg_attr(docsrs, doc(cfg(feature = "service_b")))]
+pub mod service_b;
@@ -37,7 +37,7 @@ impl CombinedFooClient {
         let mut url = self.endpoint.clone();
         url.append_path("/service/multi-service/service-a/foo/test");
         let mut query_builder = url.query_builder();
-        query_builder.set_pair("api-version", self.api_version.as_str());
+        query_builder.set_pair("api-version", &self.api_version);
         query_builder.build();
         let mut request = Request::new(url, Method::Get);
         let rsp = self
@@ -0,0 +1,257 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    ConditionalRequestClientHeadIfModifiedSinceOptions, ConditionalRequestClientPostIfMatchOptions,
+    ConditionalRequestClientPostIfNoneMatchOptions,
+    ConditionalRequestClientPostIfUnmodifiedSinceOptions,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url,
+        UrlExt,
+    },
+    time::to_rfc7231,
+    tracing, Result,
+};
+
+/// Illustrates conditional request headers
+#[tracing::client]
+pub struct ConditionalRequestClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`ConditionalRequestClient`](ConditionalRequestClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct ConditionalRequestClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl ConditionalRequestClient {
+    /// Creates a new ConditionalRequestClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("SpecialHeaders.ConditionalRequest")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<ConditionalRequestClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Check when only If-Modified-Since in header is defined.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("SpecialHeaders.ConditionalRequest.headIfModifiedSince")]
+    pub async fn head_if_modified_since(
+        &self,
+        options: Option<ConditionalRequestClientHeadIfModifiedSinceOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method head_if_modified_since",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method head_if_modified_since",
+            ));
+        }
+        if options.request_conditions.if_unmodified_since.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_unmodified_since isn't supported by method head_if_modified_since",
+            ));
+        }
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/special-headers/conditional-request/if-modified-since");
+        let mut request = Request::new(url, Method::Head);
+        if let Some(if_modified_since) = options.request_conditions.if_modified_since {
+            request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
+        }
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Check when only If-Match in header is defined.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("SpecialHeaders.ConditionalRequest.postIfMatch")]
+    pub async fn post_if_match(
+        &self,
+        options: Option<ConditionalRequestClientPostIfMatchOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        if options.match_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method post_if_match",
+            ));
+        }
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/special-headers/conditional-request/if-match");
+        let mut request = Request::new(url, Method::Post);
+        if let Some(if_match) = options.match_conditions.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
+        }
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Check when only If-None-Match in header is defined.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("SpecialHeaders.ConditionalRequest.postIfNoneMatch")]
+    pub async fn post_if_none_match(
+        &self,
+        options: Option<ConditionalRequestClientPostIfNoneMatchOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        if options.match_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method post_if_none_match",
+            ));
+        }
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/special-headers/conditional-request/if-none-match");
+        let mut request = Request::new(url, Method::Post);
+        if let Some(if_none_match) = options.match_conditions.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
+        }
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Check when only If-Unmodified-Since in header is defined.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("SpecialHeaders.ConditionalRequest.postIfUnmodifiedSince")]
+    pub async fn post_if_unmodified_since(
+        &self,
+        options: Option<ConditionalRequestClientPostIfUnmodifiedSinceOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        if options.request_conditions.if_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_match isn't supported by method post_if_unmodified_since",
+            ));
+        }
+        if options.request_conditions.if_modified_since.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_modified_since isn't supported by method post_if_unmodified_since",
+            ));
+        }
+        if options.request_conditions.if_none_match.is_some() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "request condition if_none_match isn't supported by method post_if_unmodified_since",
+            ));
+        }
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/special-headers/conditional-request/if-unmodified-since");
+        let mut request = Request::new(url, Method::Post);
+        if let Some(if_unmodified_since) = options.request_conditions.if_unmodified_since {
+            request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
+        }
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
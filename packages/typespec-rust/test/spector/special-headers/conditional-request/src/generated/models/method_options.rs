@@ -0,0 +1,63 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{MatchConditions, RequestConditions};
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`ConditionalRequestClient::head_if_modified_since()`](crate::generated::clients::ConditionalRequestClient::head_if_modified_since())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ConditionalRequestClientHeadIfModifiedSinceOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    ///
+    /// This method only supports the `if_modified_since` condition. Setting any other condition returns an error.
+    pub request_conditions: RequestConditions,
+}
+
+/// Options to be passed to [`ConditionalRequestClient::post_if_match()`](crate::generated::clients::ConditionalRequestClient::post_if_match())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ConditionalRequestClientPostIfMatchOptions<'a> {
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    ///
+    /// This method only supports the `if_match` condition. Setting any other condition returns an error.
+    pub match_conditions: MatchConditions,
+
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`ConditionalRequestClient::post_if_none_match()`](crate::generated::clients::ConditionalRequestClient::post_if_none_match())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ConditionalRequestClientPostIfNoneMatchOptions<'a> {
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    ///
+    /// This method only supports the `if_none_match` condition. Setting any other condition returns an error.
+    pub match_conditions: MatchConditions,
+
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`ConditionalRequestClient::post_if_unmodified_since()`](crate::generated::clients::ConditionalRequestClient::post_if_unmodified_since())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ConditionalRequestClientPostIfUnmodifiedSinceOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+
+    /// Conditions that must be met for the request to proceed.
+    ///
+    /// When the conditions aren't met, the returned error can be converted into a [`ConditionalRequestError`](crate::generated::models::ConditionalRequestError).
+    ///
+    /// This method only supports the `if_unmodified_since` condition. Setting any other condition returns an error.
+    pub request_conditions: RequestConditions,
+}
@@ -0,0 +1,68 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    error::ErrorKind,
+    fmt::SafeDebug,
+    http::{Etag, StatusCode},
+    time::OffsetDateTime,
+};
+
+/// The reason a request with conditions failed.
+///
+/// Convert the error returned by a method with request conditions into this type to determine if the conditions weren't met.
+#[derive(SafeDebug)]
+pub enum ConditionalRequestError {
+    /// The resource wasn't modified. The service returned a 304 status code.
+    NotModified,
+
+    /// The conditions weren't met. The service returned a 412 status code.
+    PreconditionFailed,
+
+    /// Any other error.
+    Other(azure_core::Error),
+}
+
+impl From<azure_core::Error> for ConditionalRequestError {
+    fn from(error: azure_core::Error) -> Self {
+        match error.kind() {
+            ErrorKind::HttpResponse {
+                status: StatusCode::NotModified,
+                ..
+            } => Self::NotModified,
+            ErrorKind::HttpResponse {
+                status: StatusCode::PreconditionFailed,
+                ..
+            } => Self::PreconditionFailed,
+            _ => Self::Other(error),
+        }
+    }
+}
+
+/// Conditions on the ETag of a resource that must be met for the request to proceed.
+#[derive(Clone, Default, SafeDebug)]
+pub struct MatchConditions {
+    /// The request should only proceed if the resource's ETag matches this value.
+    pub if_match: Option<Etag>,
+
+    /// The request should only proceed if the resource's ETag doesn't match this value.
+    pub if_none_match: Option<Etag>,
+}
+
+/// Conditions on the ETag and modification time of a resource that must be met for the request to proceed.
+#[derive(Clone, Default, SafeDebug)]
+pub struct RequestConditions {
+    /// The request should only proceed if the resource's ETag matches this value.
+    pub if_match: Option<Etag>,
+
+    /// The request should only proceed if the resource was modified after this time.
+    pub if_modified_since: Option<OffsetDateTime>,
+
+    /// The request should only proceed if the resource's ETag doesn't match this value.
+    pub if_none_match: Option<Etag>,
+
+    /// The request should only proceed if the resource wasn't modified after this time.
+    pub if_unmodified_since: Option<OffsetDateTime>,
+}
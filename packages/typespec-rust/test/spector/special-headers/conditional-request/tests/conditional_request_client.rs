@@ -0,0 +1,176 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::{
+    http::{
+        headers::Headers, AsyncRawResponse, ClientOptions, HttpClient, Request, StatusCode,
+        Transport,
+    },
+    Bytes, Result,
+};
+use spector_condreq::{
+    models::{
+        ConditionalRequestClientHeadIfModifiedSinceOptions,
+        ConditionalRequestClientPostIfMatchOptions, ConditionalRequestClientPostIfNoneMatchOptions,
+        ConditionalRequestClientPostIfUnmodifiedSinceOptions, ConditionalRequestError,
+        MatchConditions, RequestConditions,
+    },
+    ConditionalRequestClient, ConditionalRequestClientOptions,
+};
+use std::sync::Arc;
+use time::{Date, Month, OffsetDateTime, Time};
+
+/// Responds to every request with the provided status code.
+#[derive(Debug)]
+struct StatusResponder(StatusCode);
+
+#[async_trait::async_trait]
+impl HttpClient for StatusResponder {
+    async fn execute_request(&self, _request: &Request) -> Result<AsyncRawResponse> {
+        Ok(AsyncRawResponse::from_bytes(self.0, Headers::new(), Bytes::new()))
+    }
+}
+
+fn create_client(status: StatusCode) -> ConditionalRequestClient {
+    let options = ConditionalRequestClientOptions {
+        client_options: ClientOptions {
+            transport: Some(Transport::new(Arc::new(StatusResponder(status)))),
+            ..Default::default()
+        },
+    };
+    ConditionalRequestClient::with_no_credential("http://localhost:3000", Some(options)).unwrap()
+}
+
+fn date() -> OffsetDateTime {
+    OffsetDateTime::new_utc(
+        Date::from_calendar_date(2022, Month::August, 26).unwrap(),
+        Time::from_hms(14, 38, 0).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn head_if_modified_since() {
+    let client =
+        ConditionalRequestClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let options = ConditionalRequestClientHeadIfModifiedSinceOptions {
+        request_conditions: RequestConditions {
+            if_modified_since: Some(date()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client.head_if_modified_since(Some(options)).await.unwrap();
+}
+
+#[tokio::test]
+async fn post_if_match() {
+    let client =
+        ConditionalRequestClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let options = ConditionalRequestClientPostIfMatchOptions {
+        match_conditions: MatchConditions {
+            if_match: Some("\"valid\"".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client.post_if_match(Some(options)).await.unwrap();
+}
+
+#[tokio::test]
+async fn post_if_match_unsupported_condition() {
+    let client =
+        ConditionalRequestClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let options = ConditionalRequestClientPostIfMatchOptions {
+        match_conditions: MatchConditions {
+            if_none_match: Some("\"invalid\"".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client.post_if_match(Some(options)).await.unwrap_err();
+}
+
+#[tokio::test]
+async fn post_if_none_match() {
+    let client =
+        ConditionalRequestClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let options = ConditionalRequestClientPostIfNoneMatchOptions {
+        match_conditions: MatchConditions {
+            if_none_match: Some("\"invalid\"".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client.post_if_none_match(Some(options)).await.unwrap();
+}
+
+#[tokio::test]
+async fn post_if_unmodified_since() {
+    let client =
+        ConditionalRequestClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let options = ConditionalRequestClientPostIfUnmodifiedSinceOptions {
+        request_conditions: RequestConditions {
+            if_unmodified_since: Some(date()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    client.post_if_unmodified_since(Some(options)).await.unwrap();
+}
+
+#[tokio::test]
+async fn head_if_modified_since_not_modified() {
+    let client = create_client(StatusCode::NotModified);
+    let options = ConditionalRequestClientHeadIfModifiedSinceOptions {
+        request_conditions: RequestConditions {
+            if_modified_since: Some(date()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let error = client
+        .head_if_modified_since(Some(options))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        ConditionalRequestError::from(error),
+        ConditionalRequestError::NotModified
+    ));
+}
+
+#[tokio::test]
+async fn post_if_match_precondition_failed() {
+    let client = create_client(StatusCode::PreconditionFailed);
+    let options = ConditionalRequestClientPostIfMatchOptions {
+        match_conditions: MatchConditions {
+            if_match: Some("\"stale\"".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let error = client.post_if_match(Some(options)).await.unwrap_err();
+    assert!(matches!(
+        ConditionalRequestError::from(error),
+        ConditionalRequestError::PreconditionFailed
+    ));
+}
+
+#[tokio::test]
+async fn post_if_match_other_error() {
+    let client = create_client(StatusCode::BadRequest);
+    let options = ConditionalRequestClientPostIfMatchOptions {
+        match_conditions: MatchConditions {
+            if_match: Some("\"valid\"".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let error = client.post_if_match(Some(options)).await.unwrap_err();
+    match ConditionalRequestError::from(error) {
+        ConditionalRequestError::Other(error) => {
+            assert_eq!(error.http_status(), Some(StatusCode::BadRequest));
+        }
+        other => panic!("unexpected error {other:?}"),
+    }
+}
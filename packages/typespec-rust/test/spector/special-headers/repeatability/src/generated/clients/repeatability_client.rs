@@ -0,0 +1,134 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    RepeatabilityClientImmediateSuccessOptions, RepeatabilityClientImmediateSuccessResult,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url,
+        UrlExt,
+    },
+    time::{to_rfc7231, OffsetDateTime},
+    tracing, Result, Uuid,
+};
+
+/// Illustrates OASIS repeatability headers
+#[tracing::client]
+pub struct RepeatabilityClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`RepeatabilityClient`](RepeatabilityClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct RepeatabilityClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl RepeatabilityClient {
+    /// Creates a new RepeatabilityClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("SpecialHeaders.Repeatability")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<RepeatabilityClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Check we recognize Repeatability-Request-ID and Repeatability-First-Sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// ## Response Headers
+    ///
+    /// The returned [`Response`](azure_core::http::Response) implements the [`RepeatabilityClientImmediateSuccessResultHeaders`] trait, which provides
+    /// access to response headers. For example:
+    ///
+    /// ```no_run
+    /// use azure_core::{Result, http::{Response, NoFormat}};
+    /// use spector_repeatability::models::{RepeatabilityClientImmediateSuccessResult, RepeatabilityClientImmediateSuccessResultHeaders};
+    /// async fn example() -> Result<()> {
+    ///     let response: Response<RepeatabilityClientImmediateSuccessResult, NoFormat> = unimplemented!();
+    ///     // Access response headers
+    ///     if let Some(repeatability_result) = response.repeatability_result()? {
+    ///         println!("repeatability-result: {:?}", repeatability_result);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ### Available headers
+    /// * [`repeatability_result`()](crate::generated::models::RepeatabilityClientImmediateSuccessResultHeaders::repeatability_result) - repeatability-result
+    ///
+    /// [`RepeatabilityClientImmediateSuccessResultHeaders`]: crate::generated::models::RepeatabilityClientImmediateSuccessResultHeaders
+    #[tracing::function("SpecialHeaders.Repeatability.immediateSuccess")]
+    pub async fn immediate_success(
+        &self,
+        options: Option<RepeatabilityClientImmediateSuccessOptions<'_>>,
+    ) -> Result<Response<RepeatabilityClientImmediateSuccessResult, NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/special-headers/repeatability/immediateSuccess");
+        let mut request = Request::new(url, Method::Post);
+        let repeatability_first_sent = options
+            .repeatability_first_sent
+            .unwrap_or_else(OffsetDateTime::now_utc);
+        request.insert_header("repeatability-first-sent", to_rfc7231(&repeatability_first_sent));
+        let repeatability_request_id = options
+            .repeatability_request_id
+            .clone()
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        request.insert_header("repeatability-request-id", repeatability_request_id);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
@@ -0,0 +1,11 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImmediateSuccessResponseRepeatabilityResult {
+    Accepted,
+
+    Rejected,
+}
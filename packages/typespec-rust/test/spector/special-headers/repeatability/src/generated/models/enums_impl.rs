@@ -0,0 +1,45 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::ImmediateSuccessResponseRepeatabilityResult;
+use azure_core::error::{Error, ErrorKind};
+use std::{
+    convert::AsRef,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+impl FromStr for ImmediateSuccessResponseRepeatabilityResult {
+    type Err = Error;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "accepted" => ImmediateSuccessResponseRepeatabilityResult::Accepted,
+            "rejected" => ImmediateSuccessResponseRepeatabilityResult::Rejected,
+            _ => {
+                return Err(Error::with_message_fn(ErrorKind::DataConversion, || {
+                    format!("unknown variant of ImmediateSuccessResponseRepeatabilityResult found: \"{s}\"")
+                }))
+            }
+        })
+    }
+}
+
+impl AsRef<str> for ImmediateSuccessResponseRepeatabilityResult {
+    fn as_ref(&self) -> &str {
+        match self {
+            ImmediateSuccessResponseRepeatabilityResult::Accepted => "accepted",
+            ImmediateSuccessResponseRepeatabilityResult::Rejected => "rejected",
+        }
+    }
+}
+
+impl Display for ImmediateSuccessResponseRepeatabilityResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            ImmediateSuccessResponseRepeatabilityResult::Accepted => Display::fmt("accepted", f),
+            ImmediateSuccessResponseRepeatabilityResult::Rejected => Display::fmt("rejected", f),
+        }
+    }
+}
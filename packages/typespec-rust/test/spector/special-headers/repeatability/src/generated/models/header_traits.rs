@@ -0,0 +1,53 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{ImmediateSuccessResponseRepeatabilityResult, RepeatabilityClientImmediateSuccessResult};
+use azure_core::{
+    http::{
+        headers::{HeaderName, Headers},
+        NoFormat, Response,
+    },
+    Result,
+};
+
+const REPEATABILITY_RESULT: HeaderName = HeaderName::from_static("repeatability-result");
+
+/// Provides access to typed response headers for [`RepeatabilityClient::immediate_success()`](crate::generated::clients::RepeatabilityClient::immediate_success())
+///
+/// # Examples
+///
+/// ```no_run
+/// use azure_core::{Result, http::{Response, NoFormat}};
+/// use spector_repeatability::models::{RepeatabilityClientImmediateSuccessResult, RepeatabilityClientImmediateSuccessResultHeaders};
+/// async fn example() -> Result<()> {
+///     let response: Response<RepeatabilityClientImmediateSuccessResult, NoFormat> = unimplemented!();
+///     // Access response headers
+///     if let Some(repeatability_result) = response.repeatability_result()? {
+///         println!("repeatability-result: {:?}", repeatability_result);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait RepeatabilityClientImmediateSuccessResultHeaders: private::Sealed {
+    fn repeatability_result(&self) -> Result<Option<ImmediateSuccessResponseRepeatabilityResult>>;
+}
+
+impl RepeatabilityClientImmediateSuccessResultHeaders
+    for Response<RepeatabilityClientImmediateSuccessResult, NoFormat>
+{
+    /// Indicates whether the repeatable request was accepted or rejected.
+    fn repeatability_result(&self) -> Result<Option<ImmediateSuccessResponseRepeatabilityResult>> {
+        Headers::get_optional_as(self.headers(), &REPEATABILITY_RESULT)
+    }
+}
+
+mod private {
+    use super::RepeatabilityClientImmediateSuccessResult;
+    use azure_core::http::{NoFormat, Response};
+
+    pub trait Sealed {}
+
+    impl Sealed for Response<RepeatabilityClientImmediateSuccessResult, NoFormat> {}
+}
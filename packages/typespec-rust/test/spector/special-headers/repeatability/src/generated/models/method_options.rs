@@ -0,0 +1,19 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions, time::OffsetDateTime};
+
+/// Options to be passed to [`RepeatabilityClient::immediate_success()`](crate::generated::clients::RepeatabilityClient::immediate_success())
+#[derive(Clone, Default, SafeDebug)]
+pub struct RepeatabilityClientImmediateSuccessOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+
+    /// When not set, the current time is used. The same value is sent when the request is retried.
+    pub repeatability_first_sent: Option<OffsetDateTime>,
+
+    /// When not set, a new UUID is generated. The same value is sent when the request is retried.
+    pub repeatability_request_id: Option<String>,
+}
@@ -0,0 +1,33 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::time::OffsetDateTime;
+use spector_repeatability::{
+    models::{
+        ImmediateSuccessResponseRepeatabilityResult, RepeatabilityClientImmediateSuccessOptions,
+        RepeatabilityClientImmediateSuccessResultHeaders,
+    },
+    RepeatabilityClient,
+};
+
+#[tokio::test]
+async fn immediate_success() {
+    let client = RepeatabilityClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client.immediate_success(None).await.unwrap();
+    assert_eq!(
+        resp.repeatability_result().unwrap(),
+        Some(ImmediateSuccessResponseRepeatabilityResult::Accepted)
+    );
+}
+
+#[tokio::test]
+async fn immediate_success_with_values() {
+    let client = RepeatabilityClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let options = RepeatabilityClientImmediateSuccessOptions {
+        repeatability_first_sent: Some(OffsetDateTime::now_utc()),
+        repeatability_request_id: Some("2378d9bc-1726-11ee-be56-0242ac120002".to_string()),
+        ..Default::default()
+    };
+    client.immediate_success(Some(options)).await.unwrap();
+}
@@ -0,0 +1,263 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    Fish, NestedDiscriminatorClientGetMissingDiscriminatorOptions,
+    NestedDiscriminatorClientGetModelOptions, NestedDiscriminatorClientGetRecursiveModelOptions,
+    NestedDiscriminatorClientGetWrongDiscriminatorOptions,
+    NestedDiscriminatorClientPutModelOptions, NestedDiscriminatorClientPutRecursiveModelOptions,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions, Request, RequestContent,
+        Response, Url, UrlExt,
+    },
+    tracing, Result,
+};
+
+/// Illustrates multiple level inheritance with multiple discriminators.
+#[tracing::client]
+pub struct NestedDiscriminatorClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`NestedDiscriminatorClient`](NestedDiscriminatorClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct NestedDiscriminatorClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl NestedDiscriminatorClient {
+    /// Creates a new NestedDiscriminatorClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Type.Model.Inheritance.NestedDiscriminator")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<NestedDiscriminatorClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Type.Model.Inheritance.NestedDiscriminator.getMissingDiscriminator")]
+    pub async fn get_missing_discriminator(
+        &self,
+        options: Option<NestedDiscriminatorClientGetMissingDiscriminatorOptions<'_>>,
+    ) -> Result<Response<Fish>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/type/model/inheritance/nested-discriminator/missingdiscriminator");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Type.Model.Inheritance.NestedDiscriminator.getModel")]
+    pub async fn get_model(
+        &self,
+        options: Option<NestedDiscriminatorClientGetModelOptions<'_>>,
+    ) -> Result<Response<Fish>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/type/model/inheritance/nested-discriminator/model");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Type.Model.Inheritance.NestedDiscriminator.getRecursiveModel")]
+    pub async fn get_recursive_model(
+        &self,
+        options: Option<NestedDiscriminatorClientGetRecursiveModelOptions<'_>>,
+    ) -> Result<Response<Fish>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/type/model/inheritance/nested-discriminator/recursivemodel");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Type.Model.Inheritance.NestedDiscriminator.getWrongDiscriminator")]
+    pub async fn get_wrong_discriminator(
+        &self,
+        options: Option<NestedDiscriminatorClientGetWrongDiscriminatorOptions<'_>>,
+    ) -> Result<Response<Fish>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/type/model/inheritance/nested-discriminator/wrongdiscriminator");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Type.Model.Inheritance.NestedDiscriminator.putModel")]
+    pub async fn put_model(
+        &self,
+        input: RequestContent<Fish>,
+        options: Option<NestedDiscriminatorClientPutModelOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/type/model/inheritance/nested-discriminator/model");
+        let mut request = Request::new(url, Method::Put);
+        request.insert_header("content-type", "application/json");
+        request.set_body(input);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Type.Model.Inheritance.NestedDiscriminator.putRecursiveModel")]
+    pub async fn put_recursive_model(
+        &self,
+        input: RequestContent<Fish>,
+        options: Option<NestedDiscriminatorClientPutRecursiveModelOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/type/model/inheritance/nested-discriminator/recursivemodel");
+        let mut request = Request::new(url, Method::Put);
+        request.insert_header("content-type", "application/json");
+        request.set_body(input);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
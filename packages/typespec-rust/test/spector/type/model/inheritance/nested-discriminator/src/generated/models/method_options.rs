@@ -0,0 +1,48 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`NestedDiscriminatorClient::get_missing_discriminator()`](crate::generated::clients::NestedDiscriminatorClient::get_missing_discriminator())
+#[derive(Clone, Default, SafeDebug)]
+pub struct NestedDiscriminatorClientGetMissingDiscriminatorOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`NestedDiscriminatorClient::get_model()`](crate::generated::clients::NestedDiscriminatorClient::get_model())
+#[derive(Clone, Default, SafeDebug)]
+pub struct NestedDiscriminatorClientGetModelOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`NestedDiscriminatorClient::get_recursive_model()`](crate::generated::clients::NestedDiscriminatorClient::get_recursive_model())
+#[derive(Clone, Default, SafeDebug)]
+pub struct NestedDiscriminatorClientGetRecursiveModelOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`NestedDiscriminatorClient::get_wrong_discriminator()`](crate::generated::clients::NestedDiscriminatorClient::get_wrong_discriminator())
+#[derive(Clone, Default, SafeDebug)]
+pub struct NestedDiscriminatorClientGetWrongDiscriminatorOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`NestedDiscriminatorClient::put_model()`](crate::generated::clients::NestedDiscriminatorClient::put_model())
+#[derive(Clone, Default, SafeDebug)]
+pub struct NestedDiscriminatorClientPutModelOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`NestedDiscriminatorClient::put_recursive_model()`](crate::generated::clients::NestedDiscriminatorClient::put_recursive_model())
+#[derive(Clone, Default, SafeDebug)]
+pub struct NestedDiscriminatorClientPutRecursiveModelOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
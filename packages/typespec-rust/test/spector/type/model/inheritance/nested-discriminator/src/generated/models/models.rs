@@ -0,0 +1,70 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{models_serde, Fish};
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The third level model GoblinShark in polymorphic multiple levels inheritance.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[serde(rename = "goblin", tag = "sharktype")]
+pub struct GoblinShark {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<i32>,
+
+    /// Field has constant value shark. Any specified value will be ignored.
+    #[serde(serialize_with = "models_serde::serialize_string_literal_shark")]
+    pub kind: Option<String>,
+}
+
+/// The second level model in polymorphic multiple levels inheritance which contains references to other polymorphic instances.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[serde(rename = "salmon", tag = "kind")]
+pub struct Salmon {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friends: Option<Vec<Fish>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hate: Option<HashMap<String, Fish>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partner: Option<Box<Fish>>,
+}
+
+/// The third level model SawShark in polymorphic multiple levels inheritance.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[serde(rename = "saw", tag = "sharktype")]
+pub struct SawShark {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<i32>,
+
+    /// Field has constant value shark. Any specified value will be ignored.
+    #[serde(serialize_with = "models_serde::serialize_string_literal_shark")]
+    pub kind: Option<String>,
+}
+
+/// This is base model for polymorphic multiple levels inheritance with a discriminator.
+#[derive(Serialize)]
+pub(crate) struct UnknownFishKind<'a> {
+    pub age: &'a Option<i32>,
+
+    pub kind: &'a Option<String>,
+}
+
+/// The second level model in polymorphic multiple levels inheritance and it defines a new discriminator.
+#[derive(Serialize)]
+pub(crate) struct UnknownSharkSharktype<'a> {
+    pub age: &'a Option<i32>,
+
+    /// Field has constant value shark. Any specified value will be ignored.
+    #[serde(serialize_with = "models_serde::serialize_string_literal_shark")]
+    pub kind: &'a Option<String>,
+
+    pub sharktype: &'a Option<String>,
+}
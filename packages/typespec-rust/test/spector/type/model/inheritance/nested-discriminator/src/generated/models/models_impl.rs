@@ -0,0 +1,42 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{Fish, GoblinShark, Salmon, SawShark, Shark};
+
+impl From<GoblinShark> for Fish {
+    fn from(value: GoblinShark) -> Self {
+        Self::Shark(value.into())
+    }
+}
+
+impl From<GoblinShark> for Shark {
+    fn from(value: GoblinShark) -> Self {
+        Self::GoblinShark(value)
+    }
+}
+
+impl From<Salmon> for Fish {
+    fn from(value: Salmon) -> Self {
+        Self::Salmon(value)
+    }
+}
+
+impl From<SawShark> for Fish {
+    fn from(value: SawShark) -> Self {
+        Self::Shark(value.into())
+    }
+}
+
+impl From<SawShark> for Shark {
+    fn from(value: SawShark) -> Self {
+        Self::SawShark(value)
+    }
+}
+
+impl From<Shark> for Fish {
+    fn from(value: Shark) -> Self {
+        Self::Shark(value)
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{GoblinShark, Salmon, SawShark};
+use azure_core::fmt::SafeDebug;
+use serde::Deserialize;
+
+#[doc = r#"This is base model for polymorphic multiple levels inheritance with a discriminator."#]
+#[derive(Clone, Deserialize, SafeDebug)]
+#[serde(tag = "kind")]
+pub enum Fish {
+    #[serde(rename = "salmon")]
+    Salmon(Salmon),
+
+    #[serde(rename = "shark")]
+    Shark(Shark),
+
+    #[serde(untagged)]
+    UnknownKind {
+        age: Option<i32>,
+
+        kind: Option<String>,
+    },
+}
+
+#[doc = r#"The second level model in polymorphic multiple levels inheritance and it defines a new discriminator."#]
+#[derive(Clone, Deserialize, SafeDebug)]
+#[serde(tag = "sharktype")]
+pub enum Shark {
+    #[serde(rename = "goblin")]
+    GoblinShark(GoblinShark),
+
+    #[serde(rename = "saw")]
+    SawShark(SawShark),
+
+    #[serde(untagged)]
+    UnknownSharktype {
+        age: Option<i32>,
+
+        /// Field has constant value shark. Any specified value will be ignored.
+        kind: Option<String>,
+
+        sharktype: Option<String>,
+    },
+}
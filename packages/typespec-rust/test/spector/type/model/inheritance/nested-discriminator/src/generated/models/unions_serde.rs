@@ -0,0 +1,46 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{Fish, GoblinShark, Salmon, SawShark, Shark, UnknownFishKind, UnknownSharkSharktype};
+use serde::{Serialize, Serializer};
+
+impl Serialize for Fish {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Fish::Salmon(salmon) => Salmon::serialize(salmon, serializer),
+            Fish::Shark(shark) => Shark::serialize(shark, serializer),
+            Fish::UnknownKind { age, kind } => {
+                UnknownFishKind::serialize(&UnknownFishKind { age, kind }, serializer)
+            }
+        }
+    }
+}
+
+impl Serialize for Shark {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Shark::GoblinShark(goblin_shark) => GoblinShark::serialize(goblin_shark, serializer),
+            Shark::SawShark(saw_shark) => SawShark::serialize(saw_shark, serializer),
+            Shark::UnknownSharktype {
+                age,
+                kind,
+                sharktype,
+            } => UnknownSharkSharktype::serialize(
+                &UnknownSharkSharktype {
+                    age,
+                    kind,
+                    sharktype,
+                },
+                serializer,
+            ),
+        }
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_nesteddisc::{
+    models::{Fish, GoblinShark, Salmon, SawShark, Shark},
+    NestedDiscriminatorClient,
+};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn get_missing_discriminator() {
+    let client =
+        NestedDiscriminatorClient::with_no_credential("http://localhost:3000", None).unwrap();
+
+    let resp = client.get_missing_discriminator(None).await.unwrap();
+    assert_eq!(resp.status(), 200);
+    match resp.into_model().unwrap() {
+        Fish::UnknownKind { age, kind } => {
+            assert_eq!(age, Some(1));
+            assert!(kind.is_none());
+        }
+        other => panic!("expected base Fish, found {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn get_model() {
+    let client =
+        NestedDiscriminatorClient::with_no_credential("http://localhost:3000", None).unwrap();
+
+    let resp = client.get_model(None).await.unwrap();
+    assert_eq!(resp.status(), 200);
+
+    match resp.into_model().unwrap() {
+        Fish::Shark(Shark::GoblinShark(goblin_shark)) => {
+            assert_eq!(goblin_shark.age, Some(1));
+        }
+        other => panic!("expected GoblinShark, found {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn get_recursive_model() {
+    let client =
+        NestedDiscriminatorClient::with_no_credential("http://localhost:3000", None).unwrap();
+
+    let resp = client.get_recursive_model(None).await.unwrap();
+    assert_eq!(resp.status(), 200);
+
+    match resp.into_model().unwrap() {
+        Fish::Salmon(salmon) => {
+            assert_eq!(salmon.age, Some(1));
+
+            let partner = salmon.partner.expect("expected partner");
+            match *partner {
+                Fish::Shark(Shark::SawShark(saw_shark)) => {
+                    assert_eq!(saw_shark.age, Some(2));
+                }
+                other => panic!("expected SawShark partner, found {other:?}"),
+            }
+
+            let friends = salmon.friends.expect("expected friends");
+            assert_eq!(friends.len(), 2);
+            match &friends[1] {
+                Fish::Shark(Shark::GoblinShark(goblin_shark)) => {
+                    assert_eq!(goblin_shark.age, Some(2))
+                }
+                other => panic!("expected GoblinShark friend, found {other:?}"),
+            }
+
+            let hate = salmon.hate.expect("expected hate map");
+            match hate.get("key3").expect("expected key3 entry") {
+                Fish::Shark(Shark::SawShark(saw_shark)) => assert_eq!(saw_shark.age, Some(3)),
+                other => panic!("expected SawShark foe, found {other:?}"),
+            }
+        }
+        other => panic!("expected Salmon, found {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn get_wrong_discriminator() {
+    let client =
+        NestedDiscriminatorClient::with_no_credential("http://localhost:3000", None).unwrap();
+
+    let resp = client.get_wrong_discriminator(None).await.unwrap();
+    assert_eq!(resp.status(), 200);
+    match resp.into_model().unwrap() {
+        Fish::UnknownKind { age, kind } => {
+            assert_eq!(age, Some(1));
+            assert_eq!(kind, Some("wrongKind".to_string()));
+        }
+        other => panic!("expected base Fish, found {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn put_model() {
+    let client =
+        NestedDiscriminatorClient::with_no_credential("http://localhost:3000", None).unwrap();
+
+    let body = GoblinShark {
+        age: Some(1),
+        ..Default::default()
+    };
+
+    let resp = client
+        .put_model(Fish::from(body).try_into().unwrap(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 204);
+}
+
+#[tokio::test]
+async fn put_recursive_model() {
+    let client =
+        NestedDiscriminatorClient::with_no_credential("http://localhost:3000", None).unwrap();
+
+    let friend = Salmon {
+        age: Some(2),
+        partner: Some(Box::new(
+            Salmon {
+                age: Some(3),
+                ..Default::default()
+            }
+            .into(),
+        )),
+        hate: Some(HashMap::from([
+            (
+                "key1".to_string(),
+                Salmon {
+                    age: Some(4),
+                    ..Default::default()
+                }
+                .into(),
+            ),
+            (
+                "key2".to_string(),
+                GoblinShark {
+                    age: Some(2),
+                    ..Default::default()
+                }
+                .into(),
+            ),
+        ])),
+        ..Default::default()
+    };
+
+    let foe = Salmon {
+        age: Some(2),
+        friends: Some(vec![GoblinShark {
+            age: Some(3),
+            ..Default::default()
+        }
+        .into()]),
+        ..Default::default()
+    };
+
+    let body = Salmon {
+        age: Some(1),
+        partner: Some(Box::new(
+            SawShark {
+                age: Some(2),
+                ..Default::default()
+            }
+            .into(),
+        )),
+        friends: Some(vec![
+            friend.into(),
+            GoblinShark {
+                age: Some(2),
+                ..Default::default()
+            }
+            .into(),
+        ]),
+        hate: Some(HashMap::from([
+            (
+                "key3".to_string(),
+                SawShark {
+                    age: Some(3),
+                    ..Default::default()
+                }
+                .into(),
+            ),
+            ("key4".to_string(), foe.into()),
+        ])),
+    };
+
+    let resp = client
+        .put_recursive_model(Fish::from(body).try_into().unwrap(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 204);
+}
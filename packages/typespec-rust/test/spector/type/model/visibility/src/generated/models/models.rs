@@ -3,6 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+use super::Nullable;
 use azure_core::fmt::SafeDebug;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -13,8 +14,8 @@ pub struct ReadOnlyModel {
     /// Optional readonly nullable int list.
     ///
     /// Operational visibility: Read
-    #[serde(rename = "optionalNullableIntList", skip_serializing)]
-    pub optional_nullable_int_list: Option<Vec<i32>>,
+    #[serde(default, rename = "optionalNullableIntList", skip_serializing)]
+    pub optional_nullable_int_list: Nullable<Vec<i32>>,
 
     /// Optional readonly string dictionary.
     ///
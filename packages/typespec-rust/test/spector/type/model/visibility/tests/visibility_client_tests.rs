@@ -2,7 +2,7 @@
 //
 // Licensed under the MIT License. See License.txt in the project root for license information.
 
-use spector_visibility::models::{ReadOnlyModel, VisibilityModel};
+use spector_visibility::models::{Nullable, ReadOnlyModel, VisibilityModel};
 use spector_visibility::VisibilityClient;
 use std::collections::HashMap;
 
@@ -110,7 +110,7 @@ async fn put_read_only_model() {
         .await
         .unwrap();
     let value: ReadOnlyModel = resp.into_model().unwrap();
-    assert_eq!(value.optional_nullable_int_list, Some(vec![1, 2, 3]));
+    assert_eq!(value.optional_nullable_int_list, Nullable::Value(vec![1, 2, 3]));
     assert_eq!(
         value.optional_string_record,
         Some(HashMap::from([
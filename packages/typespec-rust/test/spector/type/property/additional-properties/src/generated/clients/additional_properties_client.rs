@@ -0,0 +1,420 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::clients::{
+    AdditionalPropertiesExtendsDifferentSpreadFloatClient,
+    AdditionalPropertiesExtendsDifferentSpreadModelArrayClient,
+    AdditionalPropertiesExtendsDifferentSpreadModelClient,
+    AdditionalPropertiesExtendsDifferentSpreadStringClient, AdditionalPropertiesExtendsFloatClient,
+    AdditionalPropertiesExtendsModelArrayClient, AdditionalPropertiesExtendsModelClient,
+    AdditionalPropertiesExtendsStringClient, AdditionalPropertiesExtendsUnknownClient,
+    AdditionalPropertiesExtendsUnknownDerivedClient,
+    AdditionalPropertiesExtendsUnknownDiscriminatedClient, AdditionalPropertiesIsFloatClient,
+    AdditionalPropertiesIsModelArrayClient, AdditionalPropertiesIsModelClient,
+    AdditionalPropertiesIsStringClient, AdditionalPropertiesIsUnknownClient,
+    AdditionalPropertiesIsUnknownDerivedClient, AdditionalPropertiesIsUnknownDiscriminatedClient,
+    AdditionalPropertiesMultipleSpreadClient, AdditionalPropertiesSpreadDifferentFloatClient,
+    AdditionalPropertiesSpreadDifferentModelArrayClient,
+    AdditionalPropertiesSpreadDifferentModelClient, AdditionalPropertiesSpreadDifferentStringClient,
+    AdditionalPropertiesSpreadFloatClient, AdditionalPropertiesSpreadModelArrayClient,
+    AdditionalPropertiesSpreadModelClient,
+    AdditionalPropertiesSpreadRecordNonDiscriminatedUnion2Client,
+    AdditionalPropertiesSpreadRecordNonDiscriminatedUnion3Client,
+    AdditionalPropertiesSpreadRecordNonDiscriminatedUnionClient,
+    AdditionalPropertiesSpreadRecordUnionClient, AdditionalPropertiesSpreadStringClient,
+};
+use azure_core::{
+    fmt::SafeDebug,
+    http::{ClientOptions, Pipeline, Url},
+    tracing, Result,
+};
+
+/// Tests for additional properties of models
+#[tracing::client]
+pub struct AdditionalPropertiesClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`AdditionalPropertiesClient`](AdditionalPropertiesClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl AdditionalPropertiesClient {
+    /// Creates a new AdditionalPropertiesClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Type.Property.AdditionalProperties")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<AdditionalPropertiesClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsDifferentSpreadFloatClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_different_spread_float_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsDifferentSpreadFloatClient {
+        AdditionalPropertiesExtendsDifferentSpreadFloatClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsDifferentSpreadModelArrayClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_different_spread_model_array_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsDifferentSpreadModelArrayClient {
+        AdditionalPropertiesExtendsDifferentSpreadModelArrayClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsDifferentSpreadModelClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_different_spread_model_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsDifferentSpreadModelClient {
+        AdditionalPropertiesExtendsDifferentSpreadModelClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsDifferentSpreadStringClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_different_spread_string_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsDifferentSpreadStringClient {
+        AdditionalPropertiesExtendsDifferentSpreadStringClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsFloatClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_float_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsFloatClient {
+        AdditionalPropertiesExtendsFloatClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsModelArrayClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_model_array_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsModelArrayClient {
+        AdditionalPropertiesExtendsModelArrayClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsModelClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_model_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsModelClient {
+        AdditionalPropertiesExtendsModelClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsStringClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_string_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsStringClient {
+        AdditionalPropertiesExtendsStringClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsUnknownClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_unknown_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsUnknownClient {
+        AdditionalPropertiesExtendsUnknownClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsUnknownDerivedClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_unknown_derived_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsUnknownDerivedClient {
+        AdditionalPropertiesExtendsUnknownDerivedClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesExtendsUnknownDiscriminatedClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_extends_unknown_discriminated_client(
+        &self,
+    ) -> AdditionalPropertiesExtendsUnknownDiscriminatedClient {
+        AdditionalPropertiesExtendsUnknownDiscriminatedClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesIsFloatClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_is_float_client(&self) -> AdditionalPropertiesIsFloatClient {
+        AdditionalPropertiesIsFloatClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesIsModelArrayClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_is_model_array_client(
+        &self,
+    ) -> AdditionalPropertiesIsModelArrayClient {
+        AdditionalPropertiesIsModelArrayClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesIsModelClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_is_model_client(&self) -> AdditionalPropertiesIsModelClient {
+        AdditionalPropertiesIsModelClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesIsStringClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_is_string_client(&self) -> AdditionalPropertiesIsStringClient {
+        AdditionalPropertiesIsStringClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesIsUnknownClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_is_unknown_client(
+        &self,
+    ) -> AdditionalPropertiesIsUnknownClient {
+        AdditionalPropertiesIsUnknownClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesIsUnknownDerivedClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_is_unknown_derived_client(
+        &self,
+    ) -> AdditionalPropertiesIsUnknownDerivedClient {
+        AdditionalPropertiesIsUnknownDerivedClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesIsUnknownDiscriminatedClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_is_unknown_discriminated_client(
+        &self,
+    ) -> AdditionalPropertiesIsUnknownDiscriminatedClient {
+        AdditionalPropertiesIsUnknownDiscriminatedClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesMultipleSpreadClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_multiple_spread_client(
+        &self,
+    ) -> AdditionalPropertiesMultipleSpreadClient {
+        AdditionalPropertiesMultipleSpreadClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadDifferentFloatClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_different_float_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadDifferentFloatClient {
+        AdditionalPropertiesSpreadDifferentFloatClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadDifferentModelArrayClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_different_model_array_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadDifferentModelArrayClient {
+        AdditionalPropertiesSpreadDifferentModelArrayClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadDifferentModelClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_different_model_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadDifferentModelClient {
+        AdditionalPropertiesSpreadDifferentModelClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadDifferentStringClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_different_string_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadDifferentStringClient {
+        AdditionalPropertiesSpreadDifferentStringClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadFloatClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_float_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadFloatClient {
+        AdditionalPropertiesSpreadFloatClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadModelArrayClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_model_array_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadModelArrayClient {
+        AdditionalPropertiesSpreadModelArrayClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadModelClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_model_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadModelClient {
+        AdditionalPropertiesSpreadModelClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadRecordNonDiscriminatedUnion2Client.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_record_non_discriminated_union2_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadRecordNonDiscriminatedUnion2Client {
+        AdditionalPropertiesSpreadRecordNonDiscriminatedUnion2Client {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadRecordNonDiscriminatedUnion3Client.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_record_non_discriminated_union3_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadRecordNonDiscriminatedUnion3Client {
+        AdditionalPropertiesSpreadRecordNonDiscriminatedUnion3Client {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadRecordNonDiscriminatedUnionClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_record_non_discriminated_union_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadRecordNonDiscriminatedUnionClient {
+        AdditionalPropertiesSpreadRecordNonDiscriminatedUnionClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadRecordUnionClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_record_union_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadRecordUnionClient {
+        AdditionalPropertiesSpreadRecordUnionClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    /// Returns a new instance of AdditionalPropertiesSpreadStringClient.
+    #[tracing::subclient]
+    pub fn get_additional_properties_spread_string_client(
+        &self,
+    ) -> AdditionalPropertiesSpreadStringClient {
+        AdditionalPropertiesSpreadStringClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    AdditionalPropertiesExtendsDifferentSpreadFloatClientGetOptions,
+    AdditionalPropertiesExtendsDifferentSpreadFloatClientPutOptions, DifferentSpreadFloatDerived,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    http::{
+        Method, NoFormat, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url,
+        UrlExt,
+    },
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct AdditionalPropertiesExtendsDifferentSpreadFloatClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+impl AdditionalPropertiesExtendsDifferentSpreadFloatClient {
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Get call
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Type.Property.AdditionalProperties.ExtendsDifferentSpreadFloat.get")]
+    pub async fn get(
+        &self,
+        options: Option<AdditionalPropertiesExtendsDifferentSpreadFloatClientGetOptions<'_>>,
+    ) -> Result<Response<DifferentSpreadFloatDerived>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/type/property/additional-properties/extendsDifferentSpreadFloat");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Put operation
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - body
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Type.Property.AdditionalProperties.ExtendsDifferentSpreadFloat.put")]
+    pub async fn put(
+        &self,
+        body: RequestContent<DifferentSpreadFloatDerived>,
+        options: Option<AdditionalPropertiesExtendsDifferentSpreadFloatClientPutOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/type/property/additional-properties/extendsDifferentSpreadFloat");
+        let mut request = Request::new(url, Method::Put);
+        request.insert_header("content-type", "application/json");
+        request.set_body(body);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    AdditionalPropertiesExtendsDifferentSpreadModelArrayClientGetOptions,
+    AdditionalPropertiesExtendsDifferentSpreadModelArrayClientPutOptions,
+    DifferentSpreadModelArrayDerived,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    http::{
+        Method, NoFormat, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url,
+        UrlExt,
+    },
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct AdditionalPropertiesExtendsDifferentSpreadModelArrayClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+impl AdditionalPropertiesExtendsDifferentSpreadModelArrayClient {
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Get call
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Type.Property.AdditionalProperties.ExtendsDifferentSpreadModelArray.get")]
+    pub async fn get(
+        &self,
+        options: Option<AdditionalPropertiesExtendsDifferentSpreadModelArrayClientGetOptions<'_>>,
+    ) -> Result<Response<DifferentSpreadModelArrayDerived>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/type/property/additional-properties/extendsDifferentSpreadModelArray");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Put operation
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - body
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Type.Property.AdditionalProperties.ExtendsDifferentSpreadModelArray.put")]
+    pub async fn put(
+        &self,
+        body: RequestContent<DifferentSpreadModelArrayDerived>,
+        options: Option<AdditionalPropertiesExtendsDifferentSpreadModelArrayClientPutOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/type/property/additional-properties/extendsDifferentSpreadModelArray");
+        let mut request = Request::new(url, Method::Put);
+        request.insert_header("content-type", "application/json");
+        request.set_body(body);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod additional_properties_client;
+mod additional_properties_extends_different_spread_float_client;
+mod additional_properties_extends_different_spread_model_array_client;
+mod additional_properties_extends_different_spread_model_client;
+mod additional_properties_extends_different_spread_string_client;
+mod additional_properties_extends_float_client;
+mod additional_properties_extends_model_array_client;
+mod additional_properties_extends_model_client;
+mod additional_properties_extends_string_client;
+mod additional_properties_extends_unknown_client;
+mod additional_properties_extends_unknown_derived_client;
+mod additional_properties_extends_unknown_discriminated_client;
+mod additional_properties_is_float_client;
+mod additional_properties_is_model_array_client;
+mod additional_properties_is_model_client;
+mod additional_properties_is_string_client;
+mod additional_properties_is_unknown_client;
+mod additional_properties_is_unknown_derived_client;
+mod additional_properties_is_unknown_discriminated_client;
+mod additional_properties_multiple_spread_client;
+mod additional_properties_spread_different_float_client;
+mod additional_properties_spread_different_model_array_client;
+mod additional_properties_spread_different_model_client;
+mod additional_properties_spread_different_string_client;
+mod additional_properties_spread_float_client;
+mod additional_properties_spread_model_array_client;
+mod additional_properties_spread_model_client;
+mod additional_properties_spread_record_non_discriminated_union2_client;
+mod additional_properties_spread_record_non_discriminated_union3_client;
+mod additional_properties_spread_record_non_discriminated_union_client;
+mod additional_properties_spread_record_union_client;
+mod additional_properties_spread_string_client;
+pub use additional_properties_client::*;
+pub use additional_properties_extends_different_spread_float_client::*;
+pub use additional_properties_extends_different_spread_model_array_client::*;
+pub use additional_properties_extends_different_spread_model_client::*;
+pub use additional_properties_extends_different_spread_string_client::*;
+pub use additional_properties_extends_float_client::*;
+pub use additional_properties_extends_model_array_client::*;
+pub use additional_properties_extends_model_client::*;
+pub use additional_properties_extends_string_client::*;
+pub use additional_properties_extends_unknown_client::*;
+pub use additional_properties_extends_unknown_derived_client::*;
+pub use additional_properties_extends_unknown_discriminated_client::*;
+pub use additional_properties_is_float_client::*;
+pub use additional_properties_is_model_array_client::*;
+pub use additional_properties_is_model_client::*;
+pub use additional_properties_is_string_client::*;
+pub use additional_properties_is_unknown_client::*;
+pub use additional_properties_is_unknown_derived_client::*;
+pub use additional_properties_is_unknown_discriminated_client::*;
+pub use additional_properties_multiple_spread_client::*;
+pub use additional_properties_spread_different_float_client::*;
+pub use additional_properties_spread_different_model_array_client::*;
+pub use additional_properties_spread_different_model_client::*;
+pub use additional_properties_spread_different_string_client::*;
+pub use additional_properties_spread_float_client::*;
+pub use additional_properties_spread_model_array_client::*;
+pub use additional_properties_spread_model_client::*;
+pub use additional_properties_spread_record_non_discriminated_union2_client::*;
+pub use additional_properties_spread_record_non_discriminated_union3_client::*;
+pub use additional_properties_spread_record_non_discriminated_union_client::*;
+pub use additional_properties_spread_record_union_client::*;
+pub use additional_properties_spread_string_client::*;
@@ -0,0 +1,440 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`AdditionalPropertiesExtendsDifferentSpreadFloatClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsDifferentSpreadFloatClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsDifferentSpreadFloatClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsDifferentSpreadFloatClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsDifferentSpreadFloatClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsDifferentSpreadFloatClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsDifferentSpreadModelArrayClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsDifferentSpreadModelArrayClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsDifferentSpreadModelArrayClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsDifferentSpreadModelArrayClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsDifferentSpreadModelArrayClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsDifferentSpreadModelArrayClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsDifferentSpreadModelClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsDifferentSpreadModelClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsDifferentSpreadModelClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsDifferentSpreadModelClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsDifferentSpreadModelClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsDifferentSpreadModelClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsDifferentSpreadStringClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsDifferentSpreadStringClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsDifferentSpreadStringClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsDifferentSpreadStringClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsDifferentSpreadStringClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsDifferentSpreadStringClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsFloatClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsFloatClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsFloatClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsFloatClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsFloatClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsFloatClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsModelArrayClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsModelArrayClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsModelArrayClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsModelArrayClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsModelArrayClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsModelArrayClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsModelClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsModelClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsModelClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsModelClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsModelClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsModelClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsStringClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsStringClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsStringClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsStringClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsStringClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsStringClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsUnknownClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsUnknownClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsUnknownClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsUnknownClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsUnknownClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsUnknownClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsUnknownDerivedClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsUnknownDerivedClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsUnknownDerivedClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsUnknownDerivedClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsUnknownDerivedClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsUnknownDerivedClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsUnknownDiscriminatedClient::get()`](crate::generated::clients::AdditionalPropertiesExtendsUnknownDiscriminatedClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsUnknownDiscriminatedClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesExtendsUnknownDiscriminatedClient::put()`](crate::generated::clients::AdditionalPropertiesExtendsUnknownDiscriminatedClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesExtendsUnknownDiscriminatedClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsFloatClient::get()`](crate::generated::clients::AdditionalPropertiesIsFloatClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsFloatClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsFloatClient::put()`](crate::generated::clients::AdditionalPropertiesIsFloatClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsFloatClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsModelArrayClient::get()`](crate::generated::clients::AdditionalPropertiesIsModelArrayClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsModelArrayClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsModelArrayClient::put()`](crate::generated::clients::AdditionalPropertiesIsModelArrayClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsModelArrayClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsModelClient::get()`](crate::generated::clients::AdditionalPropertiesIsModelClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsModelClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsModelClient::put()`](crate::generated::clients::AdditionalPropertiesIsModelClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsModelClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsStringClient::get()`](crate::generated::clients::AdditionalPropertiesIsStringClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsStringClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsStringClient::put()`](crate::generated::clients::AdditionalPropertiesIsStringClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsStringClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsUnknownClient::get()`](crate::generated::clients::AdditionalPropertiesIsUnknownClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsUnknownClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsUnknownClient::put()`](crate::generated::clients::AdditionalPropertiesIsUnknownClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsUnknownClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsUnknownDerivedClient::get()`](crate::generated::clients::AdditionalPropertiesIsUnknownDerivedClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsUnknownDerivedClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsUnknownDerivedClient::put()`](crate::generated::clients::AdditionalPropertiesIsUnknownDerivedClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsUnknownDerivedClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsUnknownDiscriminatedClient::get()`](crate::generated::clients::AdditionalPropertiesIsUnknownDiscriminatedClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsUnknownDiscriminatedClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesIsUnknownDiscriminatedClient::put()`](crate::generated::clients::AdditionalPropertiesIsUnknownDiscriminatedClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesIsUnknownDiscriminatedClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesMultipleSpreadClient::get()`](crate::generated::clients::AdditionalPropertiesMultipleSpreadClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesMultipleSpreadClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesMultipleSpreadClient::put()`](crate::generated::clients::AdditionalPropertiesMultipleSpreadClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesMultipleSpreadClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadDifferentFloatClient::get()`](crate::generated::clients::AdditionalPropertiesSpreadDifferentFloatClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadDifferentFloatClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadDifferentFloatClient::put()`](crate::generated::clients::AdditionalPropertiesSpreadDifferentFloatClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadDifferentFloatClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadDifferentModelArrayClient::get()`](crate::generated::clients::AdditionalPropertiesSpreadDifferentModelArrayClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadDifferentModelArrayClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadDifferentModelArrayClient::put()`](crate::generated::clients::AdditionalPropertiesSpreadDifferentModelArrayClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadDifferentModelArrayClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadDifferentModelClient::get()`](crate::generated::clients::AdditionalPropertiesSpreadDifferentModelClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadDifferentModelClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadDifferentModelClient::put()`](crate::generated::clients::AdditionalPropertiesSpreadDifferentModelClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadDifferentModelClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadDifferentStringClient::get()`](crate::generated::clients::AdditionalPropertiesSpreadDifferentStringClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadDifferentStringClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadDifferentStringClient::put()`](crate::generated::clients::AdditionalPropertiesSpreadDifferentStringClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadDifferentStringClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadFloatClient::get()`](crate::generated::clients::AdditionalPropertiesSpreadFloatClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadFloatClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadFloatClient::put()`](crate::generated::clients::AdditionalPropertiesSpreadFloatClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadFloatClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadModelArrayClient::get()`](crate::generated::clients::AdditionalPropertiesSpreadModelArrayClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadModelArrayClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadModelArrayClient::put()`](crate::generated::clients::AdditionalPropertiesSpreadModelArrayClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadModelArrayClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadModelClient::get()`](crate::generated::clients::AdditionalPropertiesSpreadModelClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadModelClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadModelClient::put()`](crate::generated::clients::AdditionalPropertiesSpreadModelClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadModelClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadRecordNonDiscriminatedUnion2Client::get()`](crate::generated::clients::AdditionalPropertiesSpreadRecordNonDiscriminatedUnion2Client::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadRecordNonDiscriminatedUnion2ClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadRecordNonDiscriminatedUnion2Client::put()`](crate::generated::clients::AdditionalPropertiesSpreadRecordNonDiscriminatedUnion2Client::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadRecordNonDiscriminatedUnion2ClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadRecordNonDiscriminatedUnion3Client::get()`](crate::generated::clients::AdditionalPropertiesSpreadRecordNonDiscriminatedUnion3Client::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadRecordNonDiscriminatedUnion3ClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadRecordNonDiscriminatedUnion3Client::put()`](crate::generated::clients::AdditionalPropertiesSpreadRecordNonDiscriminatedUnion3Client::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadRecordNonDiscriminatedUnion3ClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadRecordNonDiscriminatedUnionClient::get()`](crate::generated::clients::AdditionalPropertiesSpreadRecordNonDiscriminatedUnionClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadRecordNonDiscriminatedUnionClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadRecordNonDiscriminatedUnionClient::put()`](crate::generated::clients::AdditionalPropertiesSpreadRecordNonDiscriminatedUnionClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadRecordNonDiscriminatedUnionClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadRecordUnionClient::get()`](crate::generated::clients::AdditionalPropertiesSpreadRecordUnionClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadRecordUnionClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadRecordUnionClient::put()`](crate::generated::clients::AdditionalPropertiesSpreadRecordUnionClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadRecordUnionClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadStringClient::get()`](crate::generated::clients::AdditionalPropertiesSpreadStringClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadStringClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AdditionalPropertiesSpreadStringClient::put()`](crate::generated::clients::AdditionalPropertiesSpreadStringClient::put())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AdditionalPropertiesSpreadStringClientPutOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
@@ -0,0 +1,16 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod method_options;
+#[allow(clippy::module_inception)]
+mod models;
+mod models_impl;
+mod models_serde;
+mod unions;
+mod unions_impl;
+mod unions_serde;
+pub use method_options::*;
+pub use models::*;
+pub use unions::*;
@@ -0,0 +1,506 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{
+    models_serde, MultipleSpreadRecordAdditionalProperty,
+    SpreadRecordForNonDiscriminatedUnion2AdditionalProperty,
+    SpreadRecordForNonDiscriminatedUnion3AdditionalProperty,
+    SpreadRecordForNonDiscriminatedUnionAdditionalProperty, SpreadRecordForUnionAdditionalProperty,
+};
+use azure_core::{fmt::SafeDebug, time::OffsetDateTime, Value};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The model extends from a model that spread Record<float32> with the different known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct DifferentSpreadFloatDerived {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, f32>>,
+
+    /// The index property
+    #[serde(rename = "derivedProp", skip_serializing_if = "Option::is_none")]
+    pub derived_prop: Option<f32>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model spread Record<float32> with the different known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct DifferentSpreadFloatRecord {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, f32>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model extends from a model that spread Record<ModelForRecord[]> with the different known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct DifferentSpreadModelArrayDerived {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Vec<ModelForRecord>>>,
+
+    /// The index property
+    #[serde(rename = "derivedProp", skip_serializing_if = "Option::is_none")]
+    pub derived_prop: Option<Vec<ModelForRecord>>,
+
+    #[serde(rename = "knownProp", skip_serializing_if = "Option::is_none")]
+    pub known_prop: Option<String>,
+}
+
+/// The model spread Record<ModelForRecord[]> with the different known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct DifferentSpreadModelArrayRecord {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Vec<ModelForRecord>>>,
+
+    #[serde(rename = "knownProp", skip_serializing_if = "Option::is_none")]
+    pub known_prop: Option<String>,
+}
+
+/// The model extends from a model that spread Record<ModelForRecord> with the different known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct DifferentSpreadModelDerived {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, ModelForRecord>>,
+
+    /// The index property
+    #[serde(rename = "derivedProp", skip_serializing_if = "Option::is_none")]
+    pub derived_prop: Option<ModelForRecord>,
+
+    #[serde(rename = "knownProp", skip_serializing_if = "Option::is_none")]
+    pub known_prop: Option<String>,
+}
+
+/// The model spread Record<ModelForRecord> with the different known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct DifferentSpreadModelRecord {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, ModelForRecord>>,
+
+    #[serde(rename = "knownProp", skip_serializing_if = "Option::is_none")]
+    pub known_prop: Option<String>,
+}
+
+/// The model extends from a model that spread Record<string> with the different known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct DifferentSpreadStringDerived {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, String>>,
+
+    /// The index property
+    #[serde(rename = "derivedProp", skip_serializing_if = "Option::is_none")]
+    pub derived_prop: Option<String>,
+
+    /// The id property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<f32>,
+}
+
+/// The model spread Record<string> with the different known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct DifferentSpreadStringRecord {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, String>>,
+
+    /// The id property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<f32>,
+}
+
+/// The model extends from Record<float32> type.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct ExtendsFloatAdditionalProperties {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, f32>>,
+
+    /// The id property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<f32>,
+}
+
+/// The model extends from Record<ModelForRecord> type.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct ExtendsModelAdditionalProperties {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, ModelForRecord>>,
+
+    #[serde(rename = "knownProp", skip_serializing_if = "Option::is_none")]
+    pub known_prop: Option<ModelForRecord>,
+}
+
+/// The model extends from Record<ModelForRecord[]> type.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct ExtendsModelArrayAdditionalProperties {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Vec<ModelForRecord>>>,
+
+    #[serde(rename = "knownProp", skip_serializing_if = "Option::is_none")]
+    pub known_prop: Option<Vec<ModelForRecord>>,
+}
+
+/// The model extends from Record<string> type.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct ExtendsStringAdditionalProperties {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, String>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model extends from Record<unknown> type.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct ExtendsUnknownAdditionalProperties {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Value>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model extends from a type that extends from Record<unknown>.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct ExtendsUnknownAdditionalPropertiesDerived {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Value>>,
+
+    /// The age property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<f32>,
+
+    /// The index property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<i32>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The derived discriminated type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[serde(rename = "derived", tag = "kind")]
+pub struct ExtendsUnknownAdditionalPropertiesDiscriminatedDerived {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Value>>,
+
+    /// The age property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<f32>,
+
+    /// The index property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<i32>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model is from Record<float32> type.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct IsFloatAdditionalProperties {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, f32>>,
+
+    /// The id property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<f32>,
+}
+
+/// The model is from Record<ModelForRecord> type.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct IsModelAdditionalProperties {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, ModelForRecord>>,
+
+    #[serde(rename = "knownProp", skip_serializing_if = "Option::is_none")]
+    pub known_prop: Option<ModelForRecord>,
+}
+
+/// The model is from Record<ModelForRecord[]> type.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct IsModelArrayAdditionalProperties {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Vec<ModelForRecord>>>,
+
+    #[serde(rename = "knownProp", skip_serializing_if = "Option::is_none")]
+    pub known_prop: Option<Vec<ModelForRecord>>,
+}
+
+/// The model is from Record<string> type.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct IsStringAdditionalProperties {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, String>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model is from Record<unknown> type.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct IsUnknownAdditionalProperties {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Value>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model extends from a type that is Record<unknown> type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct IsUnknownAdditionalPropertiesDerived {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Value>>,
+
+    /// The age property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<f32>,
+
+    /// The index property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<i32>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The derived discriminated type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[serde(rename = "derived", tag = "kind")]
+pub struct IsUnknownAdditionalPropertiesDiscriminatedDerived {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Value>>,
+
+    /// The age property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<f32>,
+
+    /// The index property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<i32>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// model for record
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct ModelForRecord {
+    /// The state property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+/// The model spread Record<string> and Record<float32>
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct MultipleSpreadRecord {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, MultipleSpreadRecordAdditionalProperty>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flag: Option<bool>,
+}
+
+/// The model spread Record<float32> with the same known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct SpreadFloatRecord {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, f32>>,
+
+    /// The id property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<f32>,
+}
+
+/// The model spread Record<ModelForRecord[]> with the same known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct SpreadModelArrayRecord {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, Vec<ModelForRecord>>>,
+
+    #[serde(rename = "knownProp", skip_serializing_if = "Option::is_none")]
+    pub known_prop: Option<Vec<ModelForRecord>>,
+}
+
+/// The model spread Record<ModelForRecord> with the same known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct SpreadModelRecord {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, ModelForRecord>>,
+
+    #[serde(rename = "knownProp", skip_serializing_if = "Option::is_none")]
+    pub known_prop: Option<ModelForRecord>,
+}
+
+/// The model spread Record<WidgetData0 | WidgetData1>
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct SpreadRecordForNonDiscriminatedUnion {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties:
+        Option<HashMap<String, SpreadRecordForNonDiscriminatedUnionAdditionalProperty>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model spread Record<WidgetData2 | WidgetData1>
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct SpreadRecordForNonDiscriminatedUnion2 {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties:
+        Option<HashMap<String, SpreadRecordForNonDiscriminatedUnion2AdditionalProperty>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model spread Record<WidgetData2[] | WidgetData1>
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct SpreadRecordForNonDiscriminatedUnion3 {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties:
+        Option<HashMap<String, SpreadRecordForNonDiscriminatedUnion3AdditionalProperty>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model spread Record<string | float32>
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct SpreadRecordForUnion {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, SpreadRecordForUnionAdditionalProperty>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flag: Option<bool>,
+}
+
+/// The model spread Record<string> with the same known property type
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct SpreadStringRecord {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: Option<HashMap<String, String>>,
+
+    /// The name property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The model extends from Record<unknown> with a discriminator.
+#[derive(Serialize)]
+pub(crate) struct UnknownExtendsUnknownAdditionalPropertiesDiscriminatedKind<'a> {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: &'a Option<HashMap<String, Value>>,
+
+    /// The discriminator
+    pub kind: &'a Option<String>,
+
+    /// The name property
+    pub name: &'a Option<String>,
+}
+
+/// The model is Record<unknown> with a discriminator.
+#[derive(Serialize)]
+pub(crate) struct UnknownIsUnknownAdditionalPropertiesDiscriminatedKind<'a> {
+    /// Contains unnamed additional properties.
+    #[serde(flatten)]
+    pub additional_properties: &'a Option<HashMap<String, Value>>,
+
+    /// The discriminator
+    pub kind: &'a Option<String>,
+
+    /// The name property
+    pub name: &'a Option<String>,
+}
+
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct WidgetData0 {
+    #[serde(rename = "fooProp", skip_serializing_if = "Option::is_none")]
+    pub foo_prop: Option<String>,
+
+    /// Field has constant value kind0. Any specified value will be ignored.
+    #[serde(serialize_with = "models_serde::serialize_string_literal_kind0")]
+    pub kind: Option<String>,
+}
+
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct WidgetData1 {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "azure_core::time::rfc3339::option"
+    )]
+    pub end: Option<OffsetDateTime>,
+
+    /// Field has constant value kind1. Any specified value will be ignored.
+    #[serde(serialize_with = "models_serde::serialize_string_literal_kind1")]
+    pub kind: Option<String>,
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "azure_core::time::rfc3339::option"
+    )]
+    pub start: Option<OffsetDateTime>,
+}
+
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct WidgetData2 {
+    /// Field has constant value kind1. Any specified value will be ignored.
+    #[serde(serialize_with = "models_serde::serialize_string_literal_kind1")]
+    pub kind: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+}
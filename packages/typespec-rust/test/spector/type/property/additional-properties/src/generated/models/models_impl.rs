@@ -0,0 +1,264 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{
+    DifferentSpreadFloatDerived, DifferentSpreadFloatRecord, DifferentSpreadModelArrayDerived,
+    DifferentSpreadModelArrayRecord, DifferentSpreadModelDerived, DifferentSpreadModelRecord,
+    DifferentSpreadStringDerived, DifferentSpreadStringRecord, ExtendsFloatAdditionalProperties,
+    ExtendsModelAdditionalProperties, ExtendsModelArrayAdditionalProperties,
+    ExtendsStringAdditionalProperties, ExtendsUnknownAdditionalProperties,
+    ExtendsUnknownAdditionalPropertiesDerived, ExtendsUnknownAdditionalPropertiesDiscriminated,
+    ExtendsUnknownAdditionalPropertiesDiscriminatedDerived, IsFloatAdditionalProperties,
+    IsModelAdditionalProperties, IsModelArrayAdditionalProperties, IsStringAdditionalProperties,
+    IsUnknownAdditionalProperties, IsUnknownAdditionalPropertiesDerived,
+    IsUnknownAdditionalPropertiesDiscriminated, IsUnknownAdditionalPropertiesDiscriminatedDerived,
+    MultipleSpreadRecord, SpreadFloatRecord, SpreadModelArrayRecord, SpreadModelRecord,
+    SpreadRecordForNonDiscriminatedUnion, SpreadRecordForNonDiscriminatedUnion2,
+    SpreadRecordForNonDiscriminatedUnion3, SpreadRecordForUnion, SpreadStringRecord,
+};
+use azure_core::{http::RequestContent, json::to_json, Result};
+
+impl From<ExtendsUnknownAdditionalPropertiesDiscriminatedDerived>
+    for ExtendsUnknownAdditionalPropertiesDiscriminated
+{
+    fn from(value: ExtendsUnknownAdditionalPropertiesDiscriminatedDerived) -> Self {
+        Self::ExtendsUnknownAdditionalPropertiesDiscriminatedDerived(value)
+    }
+}
+
+impl From<IsUnknownAdditionalPropertiesDiscriminatedDerived>
+    for IsUnknownAdditionalPropertiesDiscriminated
+{
+    fn from(value: IsUnknownAdditionalPropertiesDiscriminatedDerived) -> Self {
+        Self::IsUnknownAdditionalPropertiesDiscriminatedDerived(value)
+    }
+}
+
+impl TryFrom<DifferentSpreadFloatDerived> for RequestContent<DifferentSpreadFloatDerived> {
+    type Error = azure_core::Error;
+    fn try_from(value: DifferentSpreadFloatDerived) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<DifferentSpreadFloatRecord> for RequestContent<DifferentSpreadFloatRecord> {
+    type Error = azure_core::Error;
+    fn try_from(value: DifferentSpreadFloatRecord) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<DifferentSpreadModelArrayDerived>
+    for RequestContent<DifferentSpreadModelArrayDerived>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: DifferentSpreadModelArrayDerived) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<DifferentSpreadModelArrayRecord> for RequestContent<DifferentSpreadModelArrayRecord> {
+    type Error = azure_core::Error;
+    fn try_from(value: DifferentSpreadModelArrayRecord) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<DifferentSpreadModelDerived> for RequestContent<DifferentSpreadModelDerived> {
+    type Error = azure_core::Error;
+    fn try_from(value: DifferentSpreadModelDerived) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<DifferentSpreadModelRecord> for RequestContent<DifferentSpreadModelRecord> {
+    type Error = azure_core::Error;
+    fn try_from(value: DifferentSpreadModelRecord) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<DifferentSpreadStringDerived> for RequestContent<DifferentSpreadStringDerived> {
+    type Error = azure_core::Error;
+    fn try_from(value: DifferentSpreadStringDerived) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<DifferentSpreadStringRecord> for RequestContent<DifferentSpreadStringRecord> {
+    type Error = azure_core::Error;
+    fn try_from(value: DifferentSpreadStringRecord) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<ExtendsFloatAdditionalProperties>
+    for RequestContent<ExtendsFloatAdditionalProperties>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: ExtendsFloatAdditionalProperties) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<ExtendsModelAdditionalProperties>
+    for RequestContent<ExtendsModelAdditionalProperties>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: ExtendsModelAdditionalProperties) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<ExtendsModelArrayAdditionalProperties>
+    for RequestContent<ExtendsModelArrayAdditionalProperties>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: ExtendsModelArrayAdditionalProperties) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<ExtendsStringAdditionalProperties>
+    for RequestContent<ExtendsStringAdditionalProperties>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: ExtendsStringAdditionalProperties) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<ExtendsUnknownAdditionalProperties>
+    for RequestContent<ExtendsUnknownAdditionalProperties>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: ExtendsUnknownAdditionalProperties) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<ExtendsUnknownAdditionalPropertiesDerived>
+    for RequestContent<ExtendsUnknownAdditionalPropertiesDerived>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: ExtendsUnknownAdditionalPropertiesDerived) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<IsFloatAdditionalProperties> for RequestContent<IsFloatAdditionalProperties> {
+    type Error = azure_core::Error;
+    fn try_from(value: IsFloatAdditionalProperties) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<IsModelAdditionalProperties> for RequestContent<IsModelAdditionalProperties> {
+    type Error = azure_core::Error;
+    fn try_from(value: IsModelAdditionalProperties) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<IsModelArrayAdditionalProperties>
+    for RequestContent<IsModelArrayAdditionalProperties>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: IsModelArrayAdditionalProperties) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<IsStringAdditionalProperties> for RequestContent<IsStringAdditionalProperties> {
+    type Error = azure_core::Error;
+    fn try_from(value: IsStringAdditionalProperties) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<IsUnknownAdditionalProperties> for RequestContent<IsUnknownAdditionalProperties> {
+    type Error = azure_core::Error;
+    fn try_from(value: IsUnknownAdditionalProperties) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<IsUnknownAdditionalPropertiesDerived>
+    for RequestContent<IsUnknownAdditionalPropertiesDerived>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: IsUnknownAdditionalPropertiesDerived) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<MultipleSpreadRecord> for RequestContent<MultipleSpreadRecord> {
+    type Error = azure_core::Error;
+    fn try_from(value: MultipleSpreadRecord) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<SpreadFloatRecord> for RequestContent<SpreadFloatRecord> {
+    type Error = azure_core::Error;
+    fn try_from(value: SpreadFloatRecord) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<SpreadModelArrayRecord> for RequestContent<SpreadModelArrayRecord> {
+    type Error = azure_core::Error;
+    fn try_from(value: SpreadModelArrayRecord) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<SpreadModelRecord> for RequestContent<SpreadModelRecord> {
+    type Error = azure_core::Error;
+    fn try_from(value: SpreadModelRecord) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<SpreadRecordForNonDiscriminatedUnion2>
+    for RequestContent<SpreadRecordForNonDiscriminatedUnion2>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: SpreadRecordForNonDiscriminatedUnion2) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<SpreadRecordForNonDiscriminatedUnion3>
+    for RequestContent<SpreadRecordForNonDiscriminatedUnion3>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: SpreadRecordForNonDiscriminatedUnion3) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<SpreadRecordForNonDiscriminatedUnion>
+    for RequestContent<SpreadRecordForNonDiscriminatedUnion>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: SpreadRecordForNonDiscriminatedUnion) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<SpreadRecordForUnion> for RequestContent<SpreadRecordForUnion> {
+    type Error = azure_core::Error;
+    fn try_from(value: SpreadRecordForUnion) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<SpreadStringRecord> for RequestContent<SpreadStringRecord> {
+    type Error = azure_core::Error;
+    fn try_from(value: SpreadStringRecord) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
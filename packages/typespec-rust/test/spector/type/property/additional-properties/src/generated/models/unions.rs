@@ -0,0 +1,93 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{
+    ExtendsUnknownAdditionalPropertiesDiscriminatedDerived,
+    IsUnknownAdditionalPropertiesDiscriminatedDerived, WidgetData0, WidgetData1, WidgetData2,
+};
+use azure_core::{fmt::SafeDebug, Value};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[doc = r#"The model extends from Record<unknown> with a discriminator."#]
+#[derive(Clone, Deserialize, SafeDebug)]
+#[serde(tag = "kind")]
+pub enum ExtendsUnknownAdditionalPropertiesDiscriminated {
+    #[serde(rename = "derived")]
+    ExtendsUnknownAdditionalPropertiesDiscriminatedDerived(
+        ExtendsUnknownAdditionalPropertiesDiscriminatedDerived,
+    ),
+
+    #[serde(untagged)]
+    UnknownKind {
+        /// Contains unnamed additional properties.
+        #[serde(flatten)]
+        additional_properties: Option<HashMap<String, Value>>,
+
+        /// The discriminator
+        kind: Option<String>,
+
+        /// The name property
+        name: Option<String>,
+    },
+}
+
+#[doc = r#"The model is Record<unknown> with a discriminator."#]
+#[derive(Clone, Deserialize, SafeDebug)]
+#[serde(tag = "kind")]
+pub enum IsUnknownAdditionalPropertiesDiscriminated {
+    #[serde(rename = "derived")]
+    IsUnknownAdditionalPropertiesDiscriminatedDerived(
+        IsUnknownAdditionalPropertiesDiscriminatedDerived,
+    ),
+
+    #[serde(untagged)]
+    UnknownKind {
+        /// Contains unnamed additional properties.
+        #[serde(flatten)]
+        additional_properties: Option<HashMap<String, Value>>,
+
+        /// The discriminator
+        kind: Option<String>,
+
+        /// The name property
+        name: Option<String>,
+    },
+}
+
+#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[serde(untagged)]
+pub enum MultipleSpreadRecordAdditionalProperty {
+    Float32(f32),
+    String(String),
+}
+
+#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[serde(untagged)]
+pub enum SpreadRecordForNonDiscriminatedUnion2AdditionalProperty {
+    WidgetData2(WidgetData2),
+    WidgetData1(WidgetData1),
+}
+
+#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[serde(untagged)]
+pub enum SpreadRecordForNonDiscriminatedUnion3AdditionalProperty {
+    WidgetData2Array(Vec<WidgetData2>),
+    WidgetData1(WidgetData1),
+}
+
+#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[serde(untagged)]
+pub enum SpreadRecordForNonDiscriminatedUnionAdditionalProperty {
+    WidgetData0(WidgetData0),
+    WidgetData1(WidgetData1),
+}
+
+#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[serde(untagged)]
+pub enum SpreadRecordForUnionAdditionalProperty {
+    Float32(f32),
+    String(String),
+}
@@ -0,0 +1,27 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{
+    ExtendsUnknownAdditionalPropertiesDiscriminated, IsUnknownAdditionalPropertiesDiscriminated,
+};
+use azure_core::{http::RequestContent, json::to_json, Result};
+
+impl TryFrom<ExtendsUnknownAdditionalPropertiesDiscriminated>
+    for RequestContent<ExtendsUnknownAdditionalPropertiesDiscriminated>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: ExtendsUnknownAdditionalPropertiesDiscriminated) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<IsUnknownAdditionalPropertiesDiscriminated>
+    for RequestContent<IsUnknownAdditionalPropertiesDiscriminated>
+{
+    type Error = azure_core::Error;
+    fn try_from(value: IsUnknownAdditionalPropertiesDiscriminated) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{
+    ExtendsUnknownAdditionalPropertiesDiscriminated,
+    ExtendsUnknownAdditionalPropertiesDiscriminatedDerived,
+    IsUnknownAdditionalPropertiesDiscriminated, IsUnknownAdditionalPropertiesDiscriminatedDerived,
+    UnknownExtendsUnknownAdditionalPropertiesDiscriminatedKind,
+    UnknownIsUnknownAdditionalPropertiesDiscriminatedKind,
+};
+use serde::{Serialize, Serializer};
+
+impl Serialize for ExtendsUnknownAdditionalPropertiesDiscriminated {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ExtendsUnknownAdditionalPropertiesDiscriminated::ExtendsUnknownAdditionalPropertiesDiscriminatedDerived(
+                extends_unknown_additional_properties_discriminated_derived,
+            ) => ExtendsUnknownAdditionalPropertiesDiscriminatedDerived::serialize(
+                extends_unknown_additional_properties_discriminated_derived,
+                serializer,
+            ),
+            ExtendsUnknownAdditionalPropertiesDiscriminated::UnknownKind {
+                additional_properties,
+                kind,
+                name,
+            } => UnknownExtendsUnknownAdditionalPropertiesDiscriminatedKind::serialize(
+                &UnknownExtendsUnknownAdditionalPropertiesDiscriminatedKind {
+                    additional_properties,
+                    kind,
+                    name,
+                },
+                serializer,
+            ),
+        }
+    }
+}
+
+impl Serialize for IsUnknownAdditionalPropertiesDiscriminated {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            IsUnknownAdditionalPropertiesDiscriminated::IsUnknownAdditionalPropertiesDiscriminatedDerived(
+                is_unknown_additional_properties_discriminated_derived,
+            ) => IsUnknownAdditionalPropertiesDiscriminatedDerived::serialize(
+                is_unknown_additional_properties_discriminated_derived,
+                serializer,
+            ),
+            IsUnknownAdditionalPropertiesDiscriminated::UnknownKind {
+                additional_properties,
+                kind,
+                name,
+            } => UnknownIsUnknownAdditionalPropertiesDiscriminatedKind::serialize(
+                &UnknownIsUnknownAdditionalPropertiesDiscriminatedKind {
+                    additional_properties,
+                    kind,
+                    name,
+                },
+                serializer,
+            ),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_addlprops::{models::DifferentSpreadFloatDerived, AdditionalPropertiesClient};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn get() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_additional_properties_extends_different_spread_float_client()
+        .get(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.name, Some("abc".to_string()));
+    assert_eq!(resp.derived_prop, Some(43.125));
+    let additional_properties = resp.additional_properties.unwrap();
+    assert_eq!(additional_properties.len(), 1);
+    assert_eq!(additional_properties["prop"], 43.125);
+}
+
+#[tokio::test]
+async fn put() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = DifferentSpreadFloatDerived {
+        additional_properties: Some(HashMap::from([("prop".to_string(), 43.125)])),
+        derived_prop: Some(43.125),
+        name: Some("abc".to_string()),
+    };
+    client
+        .get_additional_properties_extends_different_spread_float_client()
+        .put(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
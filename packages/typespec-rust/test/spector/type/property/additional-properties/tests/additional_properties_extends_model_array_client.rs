@@ -0,0 +1,61 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_addlprops::{
+    models::{ExtendsModelArrayAdditionalProperties, ModelForRecord},
+    AdditionalPropertiesClient,
+};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn get() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_additional_properties_extends_model_array_client()
+        .get(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    let known_prop = resp.known_prop.unwrap();
+    assert_eq!(known_prop.len(), 2);
+    assert_eq!(known_prop[0].state, Some("ok".to_string()));
+    let additional_properties = resp.additional_properties.unwrap();
+    assert_eq!(additional_properties.len(), 1);
+    assert_eq!(additional_properties["prop"].len(), 2);
+    assert_eq!(additional_properties["prop"][0].state, Some("ok".to_string()));
+}
+
+#[tokio::test]
+async fn put() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = ExtendsModelArrayAdditionalProperties {
+        additional_properties: Some(HashMap::from([(
+            "prop".to_string(),
+            vec![
+                ModelForRecord {
+                    state: Some("ok".to_string()),
+                },
+                ModelForRecord {
+                    state: Some("ok".to_string()),
+                },
+            ],
+        )])),
+        known_prop: Some(vec![
+            ModelForRecord {
+                state: Some("ok".to_string()),
+            },
+            ModelForRecord {
+                state: Some("ok".to_string()),
+            },
+        ]),
+    };
+    client
+        .get_additional_properties_extends_model_array_client()
+        .put(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
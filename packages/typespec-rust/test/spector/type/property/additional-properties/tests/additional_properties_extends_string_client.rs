@@ -0,0 +1,38 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_addlprops::{models::ExtendsStringAdditionalProperties, AdditionalPropertiesClient};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn get() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_additional_properties_extends_string_client()
+        .get(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.name, Some("ExtendsStringAdditionalProperties".to_string()));
+    let additional_properties = resp.additional_properties.unwrap();
+    assert_eq!(additional_properties.len(), 1);
+    assert_eq!(additional_properties["prop"], "abc");
+}
+
+#[tokio::test]
+async fn put() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = ExtendsStringAdditionalProperties {
+        additional_properties: Some(HashMap::from([("prop".to_string(), "abc".to_string())])),
+        name: Some("ExtendsStringAdditionalProperties".to_string()),
+    };
+    client
+        .get_additional_properties_extends_string_client()
+        .put(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,52 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use serde_json::json;
+use spector_addlprops::{
+    models::ExtendsUnknownAdditionalPropertiesDerived,
+    AdditionalPropertiesClient,
+};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn get() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_additional_properties_extends_unknown_derived_client()
+        .get(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.name, Some("ExtendsUnknownAdditionalProperties".to_string()));
+    assert_eq!(resp.index, Some(314));
+    assert_eq!(resp.age, Some(2.71875));
+    let additional_properties = resp.additional_properties.unwrap();
+    assert_eq!(additional_properties.len(), 3);
+    assert_eq!(additional_properties["prop1"], json!(32));
+    assert_eq!(additional_properties["prop2"], json!(true));
+    assert_eq!(additional_properties["prop3"], json!("abc"));
+}
+
+#[tokio::test]
+async fn put() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = ExtendsUnknownAdditionalPropertiesDerived {
+        additional_properties: Some(HashMap::from([
+            ("prop1".to_string(), json!(32)),
+            ("prop2".to_string(), json!(true)),
+            ("prop3".to_string(), json!("abc")),
+        ])),
+        age: Some(2.71875),
+        index: Some(314),
+        name: Some("ExtendsUnknownAdditionalProperties".to_string()),
+    };
+    client
+        .get_additional_properties_extends_unknown_derived_client()
+        .put(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,54 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_addlprops::{
+    models::{MultipleSpreadRecord, MultipleSpreadRecordAdditionalProperty},
+    AdditionalPropertiesClient,
+};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn get() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_additional_properties_multiple_spread_client()
+        .get(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.flag, Some(true));
+    let additional_properties = resp.additional_properties.unwrap();
+    assert_eq!(additional_properties.len(), 2);
+    match &additional_properties["prop1"] {
+        MultipleSpreadRecordAdditionalProperty::String(prop1) => assert_eq!(prop1, "abc"),
+        other => panic!("expected String, found {other:?}"),
+    }
+    match &additional_properties["prop2"] {
+        MultipleSpreadRecordAdditionalProperty::Float32(prop2) => assert_eq!(*prop2, 43.125),
+        other => panic!("expected Float32, found {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn put() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = MultipleSpreadRecord {
+        additional_properties: Some(HashMap::from([
+            (
+                "prop1".to_string(),
+                MultipleSpreadRecordAdditionalProperty::String("abc".to_string()),
+            ),
+            ("prop2".to_string(), MultipleSpreadRecordAdditionalProperty::Float32(43.125)),
+        ])),
+        flag: Some(true),
+    };
+    client
+        .get_additional_properties_multiple_spread_client()
+        .put(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,78 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::time::OffsetDateTime;
+use spector_addlprops::{
+    models::{
+        SpreadRecordForNonDiscriminatedUnion3,
+        SpreadRecordForNonDiscriminatedUnion3AdditionalProperty,
+        WidgetData1,
+        WidgetData2,
+    },
+    AdditionalPropertiesClient,
+};
+use std::collections::HashMap;
+
+fn parse_date_time(value: &str) -> OffsetDateTime {
+    OffsetDateTime::parse(value, &time::format_description::well_known::Rfc3339).unwrap()
+}
+
+#[tokio::test]
+async fn get() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_additional_properties_spread_record_non_discriminated_union3_client()
+        .get(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.name, Some("abc".to_string()));
+    let additional_properties = resp.additional_properties.unwrap();
+    assert_eq!(additional_properties.len(), 2);
+    match &additional_properties["prop1"] {
+        SpreadRecordForNonDiscriminatedUnion3AdditionalProperty::WidgetData2Array(widgets) => {
+            assert_eq!(widgets.len(), 2)
+        }
+        other => panic!("expected WidgetData2Array, found {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn put() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = SpreadRecordForNonDiscriminatedUnion3 {
+        additional_properties: Some(HashMap::from([
+            (
+                "prop1".to_string(),
+                SpreadRecordForNonDiscriminatedUnion3AdditionalProperty::WidgetData2Array(vec![
+                    WidgetData2 {
+                        start: Some("2021-01-01T00:00:00Z".to_string()),
+                        ..Default::default()
+                    },
+                    WidgetData2 {
+                        start: Some("2021-01-01T00:00:00Z".to_string()),
+                        ..Default::default()
+                    },
+                ]),
+            ),
+            (
+                "prop2".to_string(),
+                SpreadRecordForNonDiscriminatedUnion3AdditionalProperty::WidgetData1(WidgetData1 {
+                    start: Some(parse_date_time("2021-01-01T00:00:00Z")),
+                    end: Some(parse_date_time("2021-01-02T00:00:00Z")),
+                    ..Default::default()
+                }),
+            ),
+        ])),
+        name: Some("abc".to_string()),
+    };
+    client
+        .get_additional_properties_spread_record_non_discriminated_union3_client()
+        .put(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
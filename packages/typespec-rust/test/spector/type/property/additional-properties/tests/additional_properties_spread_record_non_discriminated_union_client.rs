@@ -0,0 +1,72 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::time::OffsetDateTime;
+use spector_addlprops::{
+    models::{
+        SpreadRecordForNonDiscriminatedUnion,
+        SpreadRecordForNonDiscriminatedUnionAdditionalProperty,
+        WidgetData0,
+        WidgetData1,
+    },
+    AdditionalPropertiesClient,
+};
+use std::collections::HashMap;
+
+fn parse_date_time(value: &str) -> OffsetDateTime {
+    OffsetDateTime::parse(value, &time::format_description::well_known::Rfc3339).unwrap()
+}
+
+#[tokio::test]
+async fn get() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_additional_properties_spread_record_non_discriminated_union_client()
+        .get(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.name, Some("abc".to_string()));
+    let additional_properties = resp.additional_properties.unwrap();
+    assert_eq!(additional_properties.len(), 2);
+    match &additional_properties["prop1"] {
+        SpreadRecordForNonDiscriminatedUnionAdditionalProperty::WidgetData0(widget) => {
+            assert_eq!(widget.foo_prop, Some("abc".to_string()))
+        }
+        other => panic!("expected WidgetData0, found {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn put() {
+    let client =
+        AdditionalPropertiesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = SpreadRecordForNonDiscriminatedUnion {
+        additional_properties: Some(HashMap::from([
+            (
+                "prop1".to_string(),
+                SpreadRecordForNonDiscriminatedUnionAdditionalProperty::WidgetData0(WidgetData0 {
+                    foo_prop: Some("abc".to_string()),
+                    ..Default::default()
+                }),
+            ),
+            (
+                "prop2".to_string(),
+                SpreadRecordForNonDiscriminatedUnionAdditionalProperty::WidgetData1(WidgetData1 {
+                    start: Some(parse_date_time("2021-01-01T00:00:00Z")),
+                    end: Some(parse_date_time("2021-01-02T00:00:00Z")),
+                    ..Default::default()
+                }),
+            ),
+        ])),
+        name: Some("abc".to_string()),
+    };
+    client
+        .get_additional_properties_spread_record_non_discriminated_union_client()
+        .put(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
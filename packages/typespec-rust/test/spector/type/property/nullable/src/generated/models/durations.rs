@@ -0,0 +1,108 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    error::{Error, ErrorKind},
+    time::Duration,
+    Result,
+};
+
+/// Parses an ISO 8601 duration (e.g. P1DT2H30M) into a Duration.
+pub(crate) fn parse_iso8601_duration(value: &str) -> Result<Duration> {
+    let invalid = || {
+        Error::with_message(ErrorKind::DataConversion, format!("invalid duration {value}"))
+    };
+    let designators = value.strip_prefix('-').unwrap_or(value);
+    let designators = designators.strip_prefix('P').ok_or_else(invalid)?;
+    let mut nanoseconds: i128 = 0;
+    let mut number = String::new();
+    let mut in_time = false;
+    let mut has_components = false;
+    for c in designators.chars() {
+        match c {
+            '0'..='9' | '.' => number.push(c),
+            ',' => number.push('.'),
+            'T' if !in_time && number.is_empty() => in_time = true,
+            _ => {
+                let unit_seconds: i128 = match (c, in_time) {
+                    ('W', false) => 604_800,
+                    ('D', false) => 86_400,
+                    ('H', true) => 3_600,
+                    ('M', true) => 60,
+                    ('S', true) => 1,
+                    _ => return Err(invalid()),
+                };
+                let unit = unit_seconds * 1_000_000_000;
+                let (whole, fraction) = number.split_once('.').unwrap_or((number.as_str(), ""));
+                if (whole.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+                    return Err(invalid());
+                }
+                let whole: i128 = if whole.is_empty() {
+                    0
+                } else {
+                    whole.parse().map_err(|_| invalid())?
+                };
+                // fractional digits after the 18th are less than a nanosecond for all units
+                let fraction = &fraction[..fraction.len().min(18)];
+                let scale = 10_i128.pow(fraction.len() as u32);
+                let fraction: i128 = if fraction.is_empty() {
+                    0
+                } else {
+                    fraction.parse().map_err(|_| invalid())?
+                };
+                nanoseconds = whole
+                    .checked_mul(unit)
+                    .and_then(|whole| whole.checked_add(fraction * unit / scale))
+                    .and_then(|component| nanoseconds.checked_add(component))
+                    .ok_or_else(invalid)?;
+                number.clear();
+                has_components = true;
+            }
+        }
+    }
+    if !has_components || !number.is_empty() || designators.ends_with('T') {
+        return Err(invalid());
+    }
+    let seconds = i64::try_from(nanoseconds / 1_000_000_000).map_err(|_| invalid())?;
+    let duration = Duration::new(seconds, (nanoseconds % 1_000_000_000) as i32);
+    Ok(if value.starts_with('-') {
+        -duration
+    } else {
+        duration
+    })
+}
+
+/// Formats a Duration as an ISO 8601 duration (e.g. P1DT2H30M).
+pub(crate) fn to_iso8601_duration(value: &Duration) -> String {
+    let mut result = String::from(if value.is_negative() { "-P" } else { "P" });
+    let value = value.abs();
+    let (days, hours, minutes, seconds, nanoseconds) = (
+        value.whole_days(),
+        value.whole_hours() % 24,
+        value.whole_minutes() % 60,
+        value.whole_seconds() % 60,
+        value.subsec_nanoseconds(),
+    );
+    if days > 0 {
+        result.push_str(&format!("{days}D"));
+    }
+    if days > 0 && hours == 0 && minutes == 0 && seconds == 0 && nanoseconds == 0 {
+        return result;
+    }
+    result.push('T');
+    if hours > 0 {
+        result.push_str(&format!("{hours}H"));
+    }
+    if minutes > 0 {
+        result.push_str(&format!("{minutes}M"));
+    }
+    if nanoseconds > 0 {
+        let fraction = format!("{nanoseconds:09}");
+        result.push_str(&format!("{seconds}.{}S", fraction.trim_end_matches('0')));
+    } else if seconds > 0 || (hours == 0 && minutes == 0) {
+        result.push_str(&format!("{seconds}S"));
+    }
+    result
+}
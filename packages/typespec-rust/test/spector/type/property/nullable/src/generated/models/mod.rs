@@ -3,10 +3,14 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+mod durations;
 mod method_options;
 #[allow(clippy::module_inception)]
 mod models;
 mod models_impl;
 mod models_serde;
+mod nullable;
+pub use durations::*;
 pub use method_options::*;
 pub use models::*;
+pub use nullable::*;
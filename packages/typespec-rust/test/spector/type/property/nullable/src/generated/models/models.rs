@@ -3,8 +3,11 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use super::models_serde;
-use azure_core::{base64, fmt::SafeDebug, time::OffsetDateTime};
+use super::{models_serde, Nullable};
+use azure_core::{
+    fmt::SafeDebug,
+    time::{Duration, OffsetDateTime},
+};
 use serde::{Deserialize, Serialize};
 
 /// Template type for testing models with nullable property. Pass in the type of the property you are looking for
@@ -13,16 +16,19 @@ pub struct BytesProperty {
     /// Property
     #[serde(
         default,
-        deserialize_with = "base64::option::deserialize",
         rename = "nullableProperty",
-        serialize_with = "base64::option::serialize",
-        skip_serializing_if = "Option::is_none"
+        skip_serializing_if = "Nullable::is_absent",
+        with = "models_serde::nullable_encoded_bytes_std"
     )]
-    pub nullable_property: Option<Vec<u8>>,
+    pub nullable_property: Nullable<Vec<u8>>,
 
     /// Required property
-    #[serde(rename = "requiredProperty", skip_serializing_if = "Option::is_none")]
-    pub required_property: Option<String>,
+    #[serde(
+        default,
+        rename = "requiredProperty",
+        skip_serializing_if = "Nullable::is_absent"
+    )]
+    pub required_property: Nullable<String>,
 }
 
 /// Model with collection bytes properties
@@ -32,38 +38,58 @@ pub struct CollectionsByteProperty {
     #[serde(
         default,
         rename = "nullableProperty",
-        skip_serializing_if = "Option::is_none",
-        with = "models_serde::option_vec_encoded_bytes_std"
+        skip_serializing_if = "Nullable::is_absent",
+        with = "models_serde::nullable_vec_encoded_bytes_std"
     )]
-    pub nullable_property: Option<Vec<Vec<u8>>>,
+    pub nullable_property: Nullable<Vec<Vec<u8>>>,
 
     /// Required property
-    #[serde(rename = "requiredProperty", skip_serializing_if = "Option::is_none")]
-    pub required_property: Option<String>,
+    #[serde(
+        default,
+        rename = "requiredProperty",
+        skip_serializing_if = "Nullable::is_absent"
+    )]
+    pub required_property: Nullable<String>,
 }
 
 /// Model with collection models properties
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct CollectionsModelProperty {
     /// Property
-    #[serde(rename = "nullableProperty", skip_serializing_if = "Option::is_none")]
-    pub nullable_property: Option<Vec<InnerModel>>,
+    #[serde(
+        default,
+        rename = "nullableProperty",
+        skip_serializing_if = "Nullable::is_absent"
+    )]
+    pub nullable_property: Nullable<Vec<InnerModel>>,
 
     /// Required property
-    #[serde(rename = "requiredProperty", skip_serializing_if = "Option::is_none")]
-    pub required_property: Option<String>,
+    #[serde(
+        default,
+        rename = "requiredProperty",
+        skip_serializing_if = "Nullable::is_absent"
+    )]
+    pub required_property: Nullable<String>,
 }
 
 /// Model with collection string properties
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct CollectionsStringProperty {
     /// Property
-    #[serde(rename = "nullableProperty", skip_serializing_if = "Option::is_none")]
-    pub nullable_property: Option<Vec<String>>,
+    #[serde(
+        default,
+        rename = "nullableProperty",
+        skip_serializing_if = "Nullable::is_absent"
+    )]
+    pub nullable_property: Nullable<Vec<String>>,
 
     /// Required property
-    #[serde(rename = "requiredProperty", skip_serializing_if = "Option::is_none")]
-    pub required_property: Option<String>,
+    #[serde(
+        default,
+        rename = "requiredProperty",
+        skip_serializing_if = "Nullable::is_absent"
+    )]
+    pub required_property: Nullable<String>,
 }
 
 /// Model with a datetime property
@@ -73,44 +99,65 @@ pub struct DatetimeProperty {
     #[serde(
         default,
         rename = "nullableProperty",
-        skip_serializing_if = "Option::is_none",
-        with = "azure_core::time::rfc3339::option"
+        skip_serializing_if = "Nullable::is_absent",
+        with = "models_serde::nullable_offset_date_time_rfc3339"
     )]
-    pub nullable_property: Option<OffsetDateTime>,
+    pub nullable_property: Nullable<OffsetDateTime>,
 
     /// Required property
-    #[serde(rename = "requiredProperty", skip_serializing_if = "Option::is_none")]
-    pub required_property: Option<String>,
+    #[serde(
+        default,
+        rename = "requiredProperty",
+        skip_serializing_if = "Nullable::is_absent"
+    )]
+    pub required_property: Nullable<String>,
 }
 
 /// Model with a duration property
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct DurationProperty {
     /// Property
-    #[serde(rename = "nullableProperty", skip_serializing_if = "Option::is_none")]
-    pub nullable_property: Option<String>,
+    #[serde(
+        default,
+        rename = "nullableProperty",
+        skip_serializing_if = "Nullable::is_absent",
+        with = "models_serde::nullable_duration_iso8601"
+    )]
+    pub nullable_property: Nullable<Duration>,
 
     /// Required property
-    #[serde(rename = "requiredProperty", skip_serializing_if = "Option::is_none")]
-    pub required_property: Option<String>,
+    #[serde(
+        default,
+        rename = "requiredProperty",
+        skip_serializing_if = "Nullable::is_absent"
+    )]
+    pub required_property: Nullable<String>,
 }
 
 /// Inner model used in collections model property
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct InnerModel {
     /// Inner model property
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub property: Option<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub property: Nullable<String>,
 }
 
 /// Template type for testing models with nullable property. Pass in the type of the property you are looking for
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct StringProperty {
     /// Property
-    #[serde(rename = "nullableProperty", skip_serializing_if = "Option::is_none")]
-    pub nullable_property: Option<String>,
+    #[serde(
+        default,
+        rename = "nullableProperty",
+        skip_serializing_if = "Nullable::is_absent"
+    )]
+    pub nullable_property: Nullable<String>,
 
     /// Required property
-    #[serde(rename = "requiredProperty", skip_serializing_if = "Option::is_none")]
-    pub required_property: Option<String>,
+    #[serde(
+        default,
+        rename = "requiredProperty",
+        skip_serializing_if = "Nullable::is_absent"
+    )]
+    pub required_property: Nullable<String>,
 }
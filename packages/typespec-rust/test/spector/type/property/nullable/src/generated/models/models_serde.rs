@@ -3,13 +3,120 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-pub mod option_vec_encoded_bytes_std {
+pub mod nullable_duration_iso8601 {
     #![allow(clippy::type_complexity)]
+    use crate::generated::models::{parse_iso8601_duration, to_iso8601_duration, Nullable};
+    use azure_core::time::Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Nullable<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<String>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let decoded0 =
+                    parse_iso8601_duration(&to_deserialize).map_err(serde::de::Error::custom)?;
+                Ok(Nullable::Value(decoded0))
+            }
+            None => Ok(Nullable::Null),
+        }
+    }
+
+    pub fn serialize<S>(to_serialize: &Nullable<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Nullable::Value(to_serialize) = to_serialize {
+            let encoded0 = to_iso8601_duration(to_serialize);
+            <Option<String>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+pub mod nullable_encoded_bytes_std {
+    #![allow(clippy::type_complexity)]
+    use crate::generated::models::Nullable;
+    use azure_core::base64;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Nullable<Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<String>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let decoded0 = base64::decode(to_deserialize).map_err(serde::de::Error::custom)?;
+                Ok(Nullable::Value(decoded0))
+            }
+            None => Ok(Nullable::Null),
+        }
+    }
+
+    pub fn serialize<S>(to_serialize: &Nullable<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Nullable::Value(to_serialize) = to_serialize {
+            let encoded0 = base64::encode(to_serialize);
+            <Option<String>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+pub mod nullable_offset_date_time_rfc3339 {
+    #![allow(clippy::type_complexity)]
+    use crate::generated::models::Nullable;
+    use azure_core::time::{parse_rfc3339, to_rfc3339, OffsetDateTime};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Nullable<OffsetDateTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<String>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let decoded0 = parse_rfc3339(&to_deserialize).map_err(serde::de::Error::custom)?;
+                Ok(Nullable::Value(decoded0))
+            }
+            None => Ok(Nullable::Null),
+        }
+    }
+
+    pub fn serialize<S>(
+        to_serialize: &Nullable<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Nullable::Value(to_serialize) = to_serialize {
+            let encoded0 = to_rfc3339(to_serialize);
+            <Option<String>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+pub mod nullable_vec_encoded_bytes_std {
+    #![allow(clippy::type_complexity)]
+    use crate::generated::models::Nullable;
     use azure_core::base64;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
     use std::result::Result;
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<Vec<u8>>>, D::Error>
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Nullable<Vec<Vec<u8>>>, D::Error>
     where
         D: Deserializer<'de>,
     {
@@ -20,20 +127,20 @@ pub mod option_vec_encoded_bytes_std {
                 for v in to_deserialize {
                     decoded0.push(base64::decode(v).map_err(serde::de::Error::custom)?);
                 }
-                Ok(Some(decoded0))
+                Ok(Nullable::Value(decoded0))
             }
-            None => Ok(None),
+            None => Ok(Nullable::Null),
         }
     }
 
     pub fn serialize<S>(
-        to_serialize: &Option<Vec<Vec<u8>>>,
+        to_serialize: &Nullable<Vec<Vec<u8>>>,
         serializer: S,
     ) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        if let Some(to_serialize) = to_serialize {
+        if let Nullable::Value(to_serialize) = to_serialize {
             let encoded0 = to_serialize.iter().map(base64::encode).collect();
             <Option<Vec<String>>>::serialize(&Some(encoded0), serializer)
         } else {
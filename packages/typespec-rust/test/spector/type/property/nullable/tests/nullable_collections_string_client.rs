@@ -0,0 +1,68 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_nullable::{
+    models::{CollectionsStringProperty, Nullable},
+    NullableClient,
+};
+
+#[tokio::test]
+async fn get_non_null() {
+    let client = NullableClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_nullable_collections_string_client()
+        .get_non_null(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.required_property, Nullable::Value("foo".to_string()));
+    assert_eq!(
+        resp.nullable_property,
+        Nullable::Value(vec!["hello".to_string(), "world".to_string()])
+    );
+}
+
+#[tokio::test]
+async fn get_null() {
+    let client = NullableClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_nullable_collections_string_client()
+        .get_null(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.required_property, Nullable::Value("foo".to_string()));
+    assert!(resp.nullable_property.is_null());
+}
+
+#[tokio::test]
+async fn patch_non_null() {
+    let client = NullableClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = CollectionsStringProperty {
+        nullable_property: vec!["hello".to_string(), "world".to_string()].into(),
+        required_property: "foo".to_string().into(),
+    };
+    client
+        .get_nullable_collections_string_client()
+        .patch_non_null(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn patch_null() {
+    let client = NullableClient::with_no_credential("http://localhost:3000", None).unwrap();
+    // the mock API expects an explicit null for the nullable property
+    let body = CollectionsStringProperty {
+        nullable_property: Nullable::Null,
+        required_property: "foo".to_string().into(),
+    };
+    client
+        .get_nullable_collections_string_client()
+        .patch_null(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
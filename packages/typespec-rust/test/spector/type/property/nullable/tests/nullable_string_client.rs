@@ -0,0 +1,95 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::json::{from_json, to_json};
+use spector_nullable::{
+    models::{Nullable, StringProperty},
+    NullableClient,
+};
+
+#[tokio::test]
+async fn get_non_null() {
+    let client = NullableClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_nullable_string_client()
+        .get_non_null(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.required_property, Nullable::Value("foo".to_string()));
+    assert_eq!(resp.nullable_property, Nullable::Value("hello".to_string()));
+}
+
+#[tokio::test]
+async fn get_null() {
+    let client = NullableClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_nullable_string_client()
+        .get_null(None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.required_property, Nullable::Value("foo".to_string()));
+    assert!(resp.nullable_property.is_null());
+}
+
+#[tokio::test]
+async fn patch_non_null() {
+    let client = NullableClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = StringProperty {
+        nullable_property: "hello".to_string().into(),
+        required_property: "foo".to_string().into(),
+    };
+    client
+        .get_nullable_string_client()
+        .patch_non_null(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn patch_null() {
+    let client = NullableClient::with_no_credential("http://localhost:3000", None).unwrap();
+    // the mock API expects an explicit null for the nullable property
+    let body = StringProperty {
+        nullable_property: Nullable::Null,
+        required_property: "foo".to_string().into(),
+    };
+    client
+        .get_nullable_string_client()
+        .patch_null(body.try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
+
+#[test]
+fn absent_null_and_value_de() {
+    let absent: StringProperty = from_json(r#"{"requiredProperty":"foo"}"#).unwrap();
+    assert!(absent.nullable_property.is_absent());
+
+    let null: StringProperty =
+        from_json(r#"{"requiredProperty":"foo","nullableProperty":null}"#).unwrap();
+    assert!(null.nullable_property.is_null());
+
+    let value: StringProperty =
+        from_json(r#"{"requiredProperty":"foo","nullableProperty":"hello"}"#).unwrap();
+    assert_eq!(value.nullable_property.as_value(), Some(&"hello".to_string()));
+}
+
+#[test]
+fn absent_null_and_value_se() {
+    let mut body = StringProperty {
+        required_property: "foo".to_string().into(),
+        ..Default::default()
+    };
+    assert_eq!(to_json(&body).unwrap(), r#"{"requiredProperty":"foo"}"#);
+
+    body.nullable_property = Nullable::Null;
+    assert_eq!(to_json(&body).unwrap(), r#"{"nullableProperty":null,"requiredProperty":"foo"}"#);
+
+    body.nullable_property = "hello".to_string().into();
+    assert_eq!(to_json(&body).unwrap(), r#"{"nullableProperty":"hello","requiredProperty":"foo"}"#);
+}
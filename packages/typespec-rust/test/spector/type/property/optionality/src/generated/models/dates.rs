@@ -0,0 +1,35 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    error::{Error, ErrorKind},
+    Result,
+};
+use time::{format_description::well_known::Iso8601, Date, Time};
+
+/// Parses an ISO 8601 calendar date (e.g. 2022-08-26) into a Date.
+pub(crate) fn parse_plain_date(value: &str) -> Result<Date> {
+    Date::parse(value, &Iso8601::DATE).map_err(|err| Error::new(ErrorKind::DataConversion, err))
+}
+
+/// Parses an ISO 8601 time of day (e.g. 13:06:12) into a Time.
+pub(crate) fn parse_plain_time(value: &str) -> Result<Time> {
+    Time::parse(value, &Iso8601::TIME).map_err(|err| Error::new(ErrorKind::DataConversion, err))
+}
+
+/// Formats a Date as an ISO 8601 calendar date (e.g. 2022-08-26).
+pub(crate) fn to_plain_date(value: &Date) -> String {
+    format!("{:04}-{:02}-{:02}", value.year(), u8::from(value.month()), value.day())
+}
+
+/// Formats a Time as an ISO 8601 time of day (e.g. 13:06:12).
+pub(crate) fn to_plain_time(value: &Time) -> String {
+    let mut result = format!("{:02}:{:02}:{:02}", value.hour(), value.minute(), value.second());
+    if value.nanosecond() > 0 {
+        let fraction = format!("{:09}", value.nanosecond());
+        result.push_str(&format!(".{}", fraction.trim_end_matches('0')));
+    }
+    result
+}
@@ -3,6 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+mod dates;
 mod enums;
 mod enums_impl;
 mod enums_serde;
@@ -11,6 +12,7 @@ mod method_options;
 mod models;
 mod models_impl;
 mod models_serde;
+pub use dates::*;
 pub use enums::*;
 pub use method_options::*;
 pub use models::*;
@@ -9,6 +9,7 @@ use super::{
 };
 use azure_core::{base64, fmt::SafeDebug, time::OffsetDateTime};
 use serde::{Deserialize, Serialize};
+use time::{Date, Time};
 
 /// Model with boolean literal property
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
@@ -106,16 +107,24 @@ pub struct IntLiteralProperty {
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct PlainDateProperty {
     /// Property
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub property: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_plain_date"
+    )]
+    pub property: Option<Date>,
 }
 
 /// Model with a plainTime property
 #[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
 pub struct PlainTimeProperty {
     /// Property
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub property: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "models_serde::option_plain_time"
+    )]
+    pub property: Option<Time>,
 }
 
 /// Model with required and optional properties
@@ -5,6 +5,74 @@
 
 use serde::Serializer;
 
+pub mod option_plain_date {
+    #![allow(clippy::type_complexity)]
+    use crate::generated::models::{parse_plain_date, to_plain_date};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+    use time::Date;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Date>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<String>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let decoded0 = parse_plain_date(&to_deserialize).map_err(serde::de::Error::custom)?;
+                Ok(Some(decoded0))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn serialize<S>(to_serialize: &Option<Date>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(to_serialize) = to_serialize {
+            let encoded0 = to_plain_date(to_serialize);
+            <Option<String>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
+pub mod option_plain_time {
+    #![allow(clippy::type_complexity)]
+    use crate::generated::models::{parse_plain_time, to_plain_time};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+    use time::Time;
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Time>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let to_deserialize = <Option<String>>::deserialize(deserializer)?;
+        match to_deserialize {
+            Some(to_deserialize) => {
+                let decoded0 = parse_plain_time(&to_deserialize).map_err(serde::de::Error::custom)?;
+                Ok(Some(decoded0))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn serialize<S>(to_serialize: &Option<Time>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(to_serialize) = to_serialize {
+            let encoded0 = to_plain_time(to_serialize);
+            <Option<String>>::serialize(&Some(encoded0), serializer)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+}
+
 pub mod option_vec_encoded_bytes_std {
     #![allow(clippy::type_complexity)]
     use azure_core::base64;
@@ -3,6 +3,7 @@
 // Licensed under the MIT License. See License.txt in the project root for license information.
 
 use spector_optionality::{models::PlainDateProperty, OptionalClient};
+use time::{Date, Month};
 
 #[tokio::test]
 async fn get_all() {
@@ -15,7 +16,7 @@ async fn get_all() {
         .into_model()
         .unwrap();
     // According to mockapi.ts, the all endpoint returns { property: "2022-12-12" }
-    assert_eq!(resp.property, Some("2022-12-12".to_string()));
+    assert_eq!(resp.property, Some(Date::from_calendar_date(2022, Month::December, 12).unwrap()));
 }
 
 #[tokio::test]
@@ -35,7 +36,7 @@ async fn get_default() {
 async fn put_all() {
     let client = OptionalClient::with_no_credential("http://localhost:3000", None).unwrap();
     let model = PlainDateProperty {
-        property: Some("2022-12-12".to_string()),
+        property: Some(Date::from_calendar_date(2022, Month::December, 12).unwrap()),
     };
 
     client
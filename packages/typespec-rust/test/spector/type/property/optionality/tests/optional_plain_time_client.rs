@@ -3,6 +3,7 @@
 // Licensed under the MIT License. See License.txt in the project root for license information.
 
 use spector_optionality::{models::PlainTimeProperty, OptionalClient};
+use time::Time;
 
 #[tokio::test]
 async fn get_all() {
@@ -15,7 +16,7 @@ async fn get_all() {
         .into_model()
         .unwrap();
     // According to mockapi.ts, the all endpoint returns { property: "13:06:12" }
-    assert_eq!(resp.property, Some("13:06:12".to_string()));
+    assert_eq!(resp.property, Some(Time::from_hms(13, 6, 12).unwrap()));
 }
 
 #[tokio::test]
@@ -36,7 +37,7 @@ async fn put_all() {
     let client = OptionalClient::with_no_credential("http://localhost:3000", None).unwrap();
     // Create a model with property set to time
     let model = PlainTimeProperty {
-        property: Some("13:06:12".to_string()),
+        property: Some(Time::from_hms(13, 6, 12).unwrap()),
     };
 
     client
@@ -0,0 +1,164 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::{
+    clients::AddedInterfaceV2Client,
+    models::{AddedClientV1Options, AddedClientV2Options, ApiVersion, ModelV1, ModelV2},
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientOptions, Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response,
+        Url, UrlExt,
+    },
+    tracing, Result,
+};
+
+/// Test for the `@added` decorator.
+#[tracing::client]
+pub struct AddedClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`AddedClient`](AddedClient)
+#[derive(Clone, SafeDebug)]
+pub struct AddedClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+    /// Need to be set as 'v1' or 'v2' in client.
+    pub version: ApiVersion,
+}
+
+impl AddedClient {
+    /// Creates a new AddedClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Versioning.Added")]
+    pub fn with_no_credential(endpoint: &str, options: Option<AddedClientOptions>) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let mut endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        let mut host = String::from("versioning/added/api-version:{version}");
+        host = host.replace("{version}", options.version.as_ref());
+        endpoint = endpoint.join(&host)?;
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Returns a new instance of AddedInterfaceV2Client.
+    #[tracing::subclient]
+    pub fn get_added_interface_v2_client(&self) -> AddedInterfaceV2Client {
+        AddedInterfaceV2Client {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+        }
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Versioning.Added.v1")]
+    pub async fn v1(
+        &self,
+        body: RequestContent<ModelV1>,
+        header_v2: String,
+        options: Option<AddedClientV1Options<'_>>,
+    ) -> Result<Response<ModelV1>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/v1");
+        let mut request = Request::new(url, Method::Post);
+        request.insert_header("accept", "application/json");
+        request.insert_header("content-type", "application/json");
+        request.insert_header("header-v2", header_v2);
+        request.set_body(body);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Versioning.Added.v2")]
+    pub async fn v2(
+        &self,
+        body: RequestContent<ModelV2>,
+        options: Option<AddedClientV2Options<'_>>,
+    ) -> Result<Response<ModelV2>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/v2");
+        let mut request = Request::new(url, Method::Post);
+        request.insert_header("accept", "application/json");
+        request.insert_header("content-type", "application/json");
+        request.set_body(body);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
+
+/// Default value for [`AddedClientOptions::version`].
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2;
+
+impl Default for AddedClientOptions {
+    fn default() -> Self {
+        Self {
+            client_options: ClientOptions::default(),
+            version: DEFAULT_VERSION,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod added_client;
+mod added_interface_v2_client;
+pub use added_client::*;
+pub use added_interface_v2_client::*;
@@ -0,0 +1,27 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`AddedClient::v1()`](crate::generated::clients::AddedClient::v1())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AddedClientV1Options<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AddedClient::v2()`](crate::generated::clients::AddedClient::v2())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AddedClientV2Options<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`AddedInterfaceV2Client::v2_in_interface()`](crate::generated::clients::AddedInterfaceV2Client::v2_in_interface())
+#[derive(Clone, Default, SafeDebug)]
+pub struct AddedInterfaceV2ClientV2InInterfaceOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
@@ -0,0 +1,32 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{EnumV1, EnumV2, UnionV1, UnionV2};
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct ModelV1 {
+    #[serde(rename = "enumProp", skip_serializing_if = "Option::is_none")]
+    pub enum_prop: Option<EnumV1>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prop: Option<String>,
+
+    #[serde(rename = "unionProp", skip_serializing_if = "Option::is_none")]
+    pub union_prop: Option<UnionV1>,
+}
+
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct ModelV2 {
+    #[serde(rename = "enumProp", skip_serializing_if = "Option::is_none")]
+    pub enum_prop: Option<EnumV2>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prop: Option<String>,
+
+    #[serde(rename = "unionProp", skip_serializing_if = "Option::is_none")]
+    pub union_prop: Option<UnionV2>,
+}
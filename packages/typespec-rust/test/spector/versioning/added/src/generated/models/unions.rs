@@ -0,0 +1,21 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[serde(untagged)]
+pub enum UnionV1 {
+    Int32(i32),
+    String(String),
+}
+
+#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[serde(untagged)]
+pub enum UnionV2 {
+    Int32(i32),
+    String(String),
+}
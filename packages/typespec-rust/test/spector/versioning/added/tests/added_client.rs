@@ -0,0 +1,72 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_veradded::{
+    models::{EnumV1, EnumV2, ModelV1, ModelV2, UnionV1, UnionV2},
+    AddedClient,
+};
+
+fn model_v2() -> ModelV2 {
+    ModelV2 {
+        enum_prop: Some(EnumV2::EnumMember),
+        prop: Some("foo".to_string()),
+        union_prop: Some(UnionV2::String("bar".to_string())),
+    }
+}
+
+fn assert_model_v2(model: ModelV2) {
+    assert_eq!(model.enum_prop, Some(EnumV2::EnumMember));
+    assert_eq!(model.prop, Some("foo".to_string()));
+    match model.union_prop {
+        Some(UnionV2::String(value)) => assert_eq!(value, "bar"),
+        other => panic!("unexpected union value {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn v1() {
+    let client = AddedClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = ModelV1 {
+        enum_prop: Some(EnumV1::EnumMemberV2),
+        prop: Some("foo".to_string()),
+        union_prop: Some(UnionV1::Int32(10)),
+    };
+    let resp = client
+        .v1(body.try_into().unwrap(), "bar".to_string(), None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.enum_prop, Some(EnumV1::EnumMemberV2));
+    assert_eq!(resp.prop, Some("foo".to_string()));
+    match resp.union_prop {
+        Some(UnionV1::Int32(value)) => assert_eq!(value, 10),
+        other => panic!("unexpected union value {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn v2() {
+    let client = AddedClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .v2(model_v2().try_into().unwrap(), None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_model_v2(resp);
+}
+
+#[tokio::test]
+async fn v2_in_interface() {
+    let client = AddedClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_added_interface_v2_client()
+        .v2_in_interface(model_v2().try_into().unwrap(), None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_model_v2(resp);
+}
@@ -3,7 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::generated::models::{MadeOptionalClientTestOptions, TestModel};
+use crate::generated::models::{ApiVersion, MadeOptionalClientTestOptions, TestModel};
 use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
@@ -27,7 +27,7 @@ pub struct MadeOptionalClientOptions {
     /// Allows customization of the client.
     pub client_options: ClientOptions,
     /// Need to be set as 'v1' or 'v2' in client.
-    pub version: String,
+    pub version: ApiVersion,
 }
 
 impl MadeOptionalClient {
@@ -51,7 +51,7 @@ impl MadeOptionalClient {
             ));
         }
         let mut host = String::from("versioning/made-optional/api-version:{version}");
-        host = host.replace("{version}", &options.version);
+        host = host.replace("{version}", options.version.as_ref());
         endpoint = endpoint.join(&host)?;
         Ok(Self {
             endpoint,
@@ -112,13 +112,13 @@ impl MadeOptionalClient {
 }
 
 /// Default value for [`MadeOptionalClientOptions::version`].
-pub(crate) const DEFAULT_VERSION: &str = "v2";
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2;
 
 impl Default for MadeOptionalClientOptions {
     fn default() -> Self {
         Self {
             client_options: ClientOptions::default(),
-            version: String::from(DEFAULT_VERSION),
+            version: DEFAULT_VERSION,
         }
     }
 }
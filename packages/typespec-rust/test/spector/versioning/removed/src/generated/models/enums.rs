@@ -0,0 +1,29 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    V1,
+
+    V2Preview,
+
+    V2,
+
+    /// Any other value not defined in `ApiVersion`.
+    UnknownValue(String),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EnumV2 {
+    EnumMemberV2,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EnumV3 {
+    EnumMemberV1,
+
+    EnumMemberV2Preview,
+}
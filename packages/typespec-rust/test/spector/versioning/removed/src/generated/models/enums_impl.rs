@@ -0,0 +1,120 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{ApiVersion, EnumV2, EnumV3};
+use azure_core::error::{Error, ErrorKind};
+use std::{
+    convert::{AsRef, From, Infallible},
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+impl<'a> From<&'a ApiVersion> for &'a str {
+    fn from(e: &'a ApiVersion) -> Self {
+        match e {
+            ApiVersion::V1 => "v1",
+            ApiVersion::V2Preview => "v2preview",
+            ApiVersion::V2 => "v2",
+            ApiVersion::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for ApiVersion {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "v1" => ApiVersion::V1,
+            "v2preview" => ApiVersion::V2Preview,
+            "v2" => ApiVersion::V2,
+            _ => ApiVersion::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for ApiVersion {
+    fn as_ref(&self) -> &str {
+        match self {
+            ApiVersion::V1 => "v1",
+            ApiVersion::V2Preview => "v2preview",
+            ApiVersion::V2 => "v2",
+            ApiVersion::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl Display for ApiVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            ApiVersion::V1 => f.write_str("v1"),
+            ApiVersion::V2Preview => f.write_str("v2preview"),
+            ApiVersion::V2 => f.write_str("v2"),
+            ApiVersion::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
+
+impl FromStr for EnumV2 {
+    type Err = Error;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "enumMemberV2" => EnumV2::EnumMemberV2,
+            _ => {
+                return Err(Error::with_message_fn(ErrorKind::DataConversion, || {
+                    format!("unknown variant of EnumV2 found: \"{s}\"")
+                }))
+            }
+        })
+    }
+}
+
+impl AsRef<str> for EnumV2 {
+    fn as_ref(&self) -> &str {
+        match self {
+            EnumV2::EnumMemberV2 => "enumMemberV2",
+        }
+    }
+}
+
+impl Display for EnumV2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            EnumV2::EnumMemberV2 => Display::fmt("enumMemberV2", f),
+        }
+    }
+}
+
+impl FromStr for EnumV3 {
+    type Err = Error;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "enumMemberV1" => EnumV3::EnumMemberV1,
+            "enumMemberV2Preview" => EnumV3::EnumMemberV2Preview,
+            _ => {
+                return Err(Error::with_message_fn(ErrorKind::DataConversion, || {
+                    format!("unknown variant of EnumV3 found: \"{s}\"")
+                }))
+            }
+        })
+    }
+}
+
+impl AsRef<str> for EnumV3 {
+    fn as_ref(&self) -> &str {
+        match self {
+            EnumV3::EnumMemberV1 => "enumMemberV1",
+            EnumV3::EnumMemberV2Preview => "enumMemberV2Preview",
+        }
+    }
+}
+
+impl Display for EnumV3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            EnumV3::EnumMemberV1 => Display::fmt("enumMemberV1", f),
+            EnumV3::EnumMemberV2Preview => Display::fmt("enumMemberV2Preview", f),
+        }
+    }
+}
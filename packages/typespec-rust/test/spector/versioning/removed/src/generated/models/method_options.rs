@@ -0,0 +1,20 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`RemovedClient::model_v3()`](crate::generated::clients::RemovedClient::model_v3())
+#[derive(Clone, Default, SafeDebug)]
+pub struct RemovedClientModelV3Options<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`RemovedClient::v2()`](crate::generated::clients::RemovedClient::v2())
+#[derive(Clone, Default, SafeDebug)]
+pub struct RemovedClientV2Options<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
@@ -0,0 +1,17 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod enums;
+mod enums_impl;
+mod enums_serde;
+mod method_options;
+#[allow(clippy::module_inception)]
+mod models;
+mod models_impl;
+mod unions;
+pub use enums::*;
+pub use method_options::*;
+pub use models::*;
+pub use unions::*;
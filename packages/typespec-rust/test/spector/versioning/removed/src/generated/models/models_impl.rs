@@ -0,0 +1,21 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{ModelV2, ModelV3};
+use azure_core::{http::RequestContent, json::to_json, Result};
+
+impl TryFrom<ModelV2> for RequestContent<ModelV2> {
+    type Error = azure_core::Error;
+    fn try_from(value: ModelV2) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
+
+impl TryFrom<ModelV3> for RequestContent<ModelV3> {
+    type Error = azure_core::Error;
+    fn try_from(value: ModelV3) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
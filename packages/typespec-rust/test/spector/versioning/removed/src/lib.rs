@@ -0,0 +1,9 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod generated;
+pub use generated::*;
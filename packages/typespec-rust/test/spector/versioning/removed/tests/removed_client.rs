@@ -0,0 +1,64 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_verremoved::{
+    models::{ApiVersion, EnumV2, EnumV3, ModelV2, ModelV3, UnionV2},
+    RemovedClient, RemovedClientOptions,
+};
+
+fn create_client(version: ApiVersion) -> RemovedClient {
+    RemovedClient::with_no_credential(
+        "http://localhost:3000",
+        Some(RemovedClientOptions {
+            version,
+            ..Default::default()
+        }),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn model_v3() {
+    // the enum property was removed in v2preview and added back in v2
+    for (version, enum_prop) in [
+        (ApiVersion::V1, Some(EnumV3::EnumMemberV1)),
+        (ApiVersion::V2Preview, None),
+        (ApiVersion::V2, Some(EnumV3::EnumMemberV1)),
+    ] {
+        let body = ModelV3 {
+            enum_prop,
+            id: Some("123".to_string()),
+        };
+        let resp = create_client(version)
+            .model_v3(body.try_into().unwrap(), None)
+            .await
+            .unwrap()
+            .into_model()
+            .unwrap();
+        assert_eq!(resp.enum_prop, enum_prop);
+        assert_eq!(resp.id, Some("123".to_string()));
+    }
+}
+
+#[tokio::test]
+async fn v2() {
+    let client = RemovedClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = ModelV2 {
+        enum_prop: Some(EnumV2::EnumMemberV2),
+        prop: Some("foo".to_string()),
+        union_prop: Some(UnionV2::String("bar".to_string())),
+    };
+    let resp = client
+        .v2(body.try_into().unwrap(), None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.enum_prop, Some(EnumV2::EnumMemberV2));
+    assert_eq!(resp.prop, Some("foo".to_string()));
+    match resp.union_prop {
+        Some(UnionV2::String(value)) => assert_eq!(value, "bar"),
+        other => panic!("unexpected union value {other:?}"),
+    }
+}
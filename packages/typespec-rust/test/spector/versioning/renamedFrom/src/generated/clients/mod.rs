@@ -0,0 +1,9 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod renamed_from_client;
+mod renamed_from_new_interface_client;
+pub use renamed_from_client::*;
+pub use renamed_from_new_interface_client::*;
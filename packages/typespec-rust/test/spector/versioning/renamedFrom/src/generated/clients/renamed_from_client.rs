@@ -0,0 +1,181 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::{
+    clients::RenamedFromNewInterfaceClient,
+    models::{
+        from_previous_wire_format, to_previous_wire_format, ApiVersion, NewModel,
+        RenamedFromClientNewOpOptions, WireChange,
+    },
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientOptions, Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response,
+        Url, UrlExt,
+    },
+    tracing, Result,
+};
+
+/// Test for the `@renamedFrom` decorator.
+#[tracing::client]
+pub struct RenamedFromClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+    pub(crate) version: ApiVersion,
+}
+
+/// Options used when creating a [`RenamedFromClient`](RenamedFromClient)
+#[derive(Clone, SafeDebug)]
+pub struct RenamedFromClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+    /// Need to be set as 'v1' or 'v2' in client.
+    pub version: ApiVersion,
+}
+
+impl RenamedFromClient {
+    /// Creates a new RenamedFromClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Versioning.RenamedFrom")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<RenamedFromClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let mut endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        let mut host = String::from("versioning/renamed-from/api-version:{version}");
+        host = host.replace("{version}", options.version.as_ref());
+        endpoint = endpoint.join(&host)?;
+        Ok(Self {
+            endpoint,
+            version: options.version,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Returns a new instance of RenamedFromNewInterfaceClient.
+    #[tracing::subclient]
+    pub fn get_renamed_from_new_interface_client(&self) -> RenamedFromNewInterfaceClient {
+        RenamedFromNewInterfaceClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+            version: self.version.clone(),
+        }
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Versioning.RenamedFrom.newOp")]
+    pub async fn new_op(
+        &self,
+        body: RequestContent<NewModel>,
+        new_query: &str,
+        options: Option<RenamedFromClientNewOpOptions<'_>>,
+    ) -> Result<Response<NewModel>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/test");
+        let mut query_builder = url.query_builder();
+        query_builder.set_pair(
+            if [ApiVersion::V1].contains(&self.version) {
+                "oldQuery"
+            } else {
+                "newQuery"
+            },
+            new_query,
+        );
+        query_builder.build();
+        let mut request = Request::new(url, Method::Post);
+        request.insert_header("accept", "application/json");
+        request.insert_header("content-type", "application/json");
+        request.set_body(body);
+        let request_wire_changes: &[WireChange] = if [ApiVersion::V1].contains(&self.version) {
+            &[
+                WireChange::Name {
+                    path: &[],
+                    name: "newProp",
+                    previous: "oldProp",
+                },
+                WireChange::Value {
+                    path: &["enumProp"],
+                    value: "newEnumMember",
+                    previous: "oldEnumMember",
+                },
+            ]
+        } else {
+            &[]
+        };
+        to_previous_wire_format(&mut request, request_wire_changes)?;
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        let response_wire_changes: &[WireChange] = if [ApiVersion::V1].contains(&self.version) {
+            &[
+                WireChange::Name {
+                    path: &[],
+                    name: "newProp",
+                    previous: "oldProp",
+                },
+                WireChange::Value {
+                    path: &["enumProp"],
+                    value: "newEnumMember",
+                    previous: "oldEnumMember",
+                },
+            ]
+        } else {
+            &[]
+        };
+        Ok(from_previous_wire_format(rsp, response_wire_changes)?.into())
+    }
+}
+
+/// Default value for [`RenamedFromClientOptions::version`].
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2;
+
+impl Default for RenamedFromClientOptions {
+    fn default() -> Self {
+        Self {
+            client_options: ClientOptions::default(),
+            version: DEFAULT_VERSION,
+        }
+    }
+}
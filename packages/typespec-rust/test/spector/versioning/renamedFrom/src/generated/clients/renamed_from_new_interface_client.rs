@@ -0,0 +1,95 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    from_previous_wire_format, to_previous_wire_format, ApiVersion, NewModel,
+    RenamedFromNewInterfaceClientNewOpInNewInterfaceOptions, WireChange,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    http::{Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url, UrlExt},
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct RenamedFromNewInterfaceClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+    pub(crate) version: ApiVersion,
+}
+
+impl RenamedFromNewInterfaceClient {
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Versioning.RenamedFrom.NewInterface.newOpInNewInterface")]
+    pub async fn new_op_in_new_interface(
+        &self,
+        body: RequestContent<NewModel>,
+        options: Option<RenamedFromNewInterfaceClientNewOpInNewInterfaceOptions<'_>>,
+    ) -> Result<Response<NewModel>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/interface/test");
+        let mut request = Request::new(url, Method::Post);
+        request.insert_header("accept", "application/json");
+        request.insert_header("content-type", "application/json");
+        request.set_body(body);
+        let request_wire_changes: &[WireChange] = if [ApiVersion::V1].contains(&self.version) {
+            &[
+                WireChange::Name {
+                    path: &[],
+                    name: "newProp",
+                    previous: "oldProp",
+                },
+                WireChange::Value {
+                    path: &["enumProp"],
+                    value: "newEnumMember",
+                    previous: "oldEnumMember",
+                },
+            ]
+        } else {
+            &[]
+        };
+        to_previous_wire_format(&mut request, request_wire_changes)?;
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        let response_wire_changes: &[WireChange] = if [ApiVersion::V1].contains(&self.version) {
+            &[
+                WireChange::Name {
+                    path: &[],
+                    name: "newProp",
+                    previous: "oldProp",
+                },
+                WireChange::Value {
+                    path: &["enumProp"],
+                    value: "newEnumMember",
+                    previous: "oldEnumMember",
+                },
+            ]
+        } else {
+            &[]
+        };
+        Ok(from_previous_wire_format(rsp, response_wire_changes)?.into())
+    }
+}
@@ -0,0 +1,20 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`RenamedFromClient::new_op()`](crate::generated::clients::RenamedFromClient::new_op())
+#[derive(Clone, Default, SafeDebug)]
+pub struct RenamedFromClientNewOpOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`RenamedFromNewInterfaceClient::new_op_in_new_interface()`](crate::generated::clients::RenamedFromNewInterfaceClient::new_op_in_new_interface())
+#[derive(Clone, Default, SafeDebug)]
+pub struct RenamedFromNewInterfaceClientNewOpInNewInterfaceOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
@@ -0,0 +1,20 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{NewEnum, NewUnion};
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct NewModel {
+    #[serde(rename = "enumProp", skip_serializing_if = "Option::is_none")]
+    pub enum_prop: Option<NewEnum>,
+
+    #[serde(rename = "newProp", skip_serializing_if = "Option::is_none")]
+    pub new_prop: Option<String>,
+
+    #[serde(rename = "unionProp", skip_serializing_if = "Option::is_none")]
+    pub union_prop: Option<NewUnion>,
+}
@@ -0,0 +1,14 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::NewModel;
+use azure_core::{http::RequestContent, json::to_json, Result};
+
+impl TryFrom<NewModel> for RequestContent<NewModel> {
+    type Error = azure_core::Error;
+    fn try_from(value: NewModel) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    http::{Body, RawResponse, Request},
+    json::{from_json, to_json},
+    Result, Value,
+};
+
+/// A change to the JSON wire format of a body made in a later API version.
+///
+/// The path contains the wire names of the enclosing properties in the latest API version, with `*` for the elements of arrays and dictionaries.
+pub(crate) enum WireChange {
+    /// A property was renamed.
+    Name {
+        path: &'static [&'static str],
+        name: &'static str,
+        previous: &'static str,
+    },
+
+    /// An enum value was renamed.
+    Value {
+        path: &'static [&'static str],
+        value: &'static str,
+        previous: &'static str,
+    },
+}
+
+impl WireChange {
+    fn path(&self) -> &'static [&'static str] {
+        match self {
+            Self::Name { path, .. } | Self::Value { path, .. } => path,
+        }
+    }
+
+    fn apply(&self, value: &mut Value, to_previous: bool) {
+        match *self {
+            Self::Name {
+                path,
+                name,
+                previous,
+            } => {
+                let (from, to) = if to_previous {
+                    (name, previous)
+                } else {
+                    (previous, name)
+                };
+                visit(value, path, &mut |object| {
+                    let Some(object) = object.as_object_mut() else {
+                        return;
+                    };
+                    if let Some(item) = object.remove(from) {
+                        object.insert(to.to_string(), item);
+                    }
+                });
+            }
+            Self::Value {
+                path,
+                value: latest,
+                previous,
+            } => {
+                let (from, to) = if to_previous {
+                    (latest, previous)
+                } else {
+                    (previous, latest)
+                };
+                visit(value, path, &mut |item| {
+                    if item.as_str() == Some(from) {
+                        *item = Value::from(to);
+                    }
+                });
+            }
+        }
+    }
+}
+
+/// Converts a JSON request body from the latest wire format to the wire format of an earlier API version.
+pub(crate) fn to_previous_wire_format(
+    request: &mut Request,
+    changes: &[WireChange],
+) -> Result<()> {
+    let Body::Bytes(bytes) = request.body() else {
+        return Ok(());
+    };
+    if changes.is_empty() || bytes.is_empty() {
+        return Ok(());
+    }
+    let mut value: Value = from_json(bytes)?;
+    // nested values are changed first as their paths contain the latest wire names
+    let mut changes: Vec<&WireChange> = changes.iter().collect();
+    changes.sort_by_key(|change| std::cmp::Reverse(change.path().len()));
+    for change in changes {
+        change.apply(&mut value, true);
+    }
+    request.set_body(to_json(&value)?);
+    Ok(())
+}
+
+/// Converts a JSON response body from the wire format of an earlier API version to the latest wire format.
+pub(crate) fn from_previous_wire_format(
+    rsp: RawResponse,
+    changes: &[WireChange],
+) -> Result<RawResponse> {
+    let (status, headers, body) = rsp.deconstruct();
+    if changes.is_empty() || body.is_empty() {
+        return Ok(RawResponse::from_bytes(status, headers, body));
+    }
+    let mut value: Value = from_json(&body)?;
+    // enclosing values are changed first so nested paths contain the latest wire names
+    let mut changes: Vec<&WireChange> = changes.iter().collect();
+    changes.sort_by_key(|change| change.path().len());
+    for change in changes {
+        change.apply(&mut value, false);
+    }
+    Ok(RawResponse::from_bytes(status, headers, to_json(&value)?))
+}
+
+/// Calls f for each value at the path within value.
+fn visit(value: &mut Value, path: &[&str], f: &mut dyn FnMut(&mut Value)) {
+    let Some((first, rest)) = path.split_first() else {
+        f(value);
+        return;
+    };
+    match value {
+        Value::Array(items) if *first == "*" => {
+            for item in items {
+                visit(item, rest, f);
+            }
+        }
+        Value::Object(object) if *first == "*" => {
+            for item in object.values_mut() {
+                visit(item, rest, f);
+            }
+        }
+        Value::Object(object) => {
+            if let Some(item) = object.get_mut(*first) {
+                visit(item, rest, f);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::{
+    http::{
+        headers::Headers, AsyncRawResponse, Body, ClientOptions, HttpClient, Request, StatusCode,
+        Transport,
+    },
+    Bytes, Result, Value,
+};
+use spector_renamedfrom::{
+    models::{ApiVersion, NewEnum, NewModel, NewUnion},
+    RenamedFromClient, RenamedFromClientOptions,
+};
+use std::sync::{Arc, Mutex};
+
+/// Records the URL and body of each request and responds with a NewModel in the v1 wire format.
+#[derive(Debug, Default)]
+struct V1Recorder {
+    urls: Mutex<Vec<String>>,
+    bodies: Mutex<Vec<Value>>,
+}
+
+#[async_trait::async_trait]
+impl HttpClient for V1Recorder {
+    async fn execute_request(&self, request: &Request) -> Result<AsyncRawResponse> {
+        self.urls.lock().unwrap().push(request.url().to_string());
+        if let Body::Bytes(bytes) = request.body() {
+            self.bodies
+                .lock()
+                .unwrap()
+                .push(serde_json::from_slice(bytes).unwrap());
+        }
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::Ok,
+            Headers::new(),
+            Bytes::from_static(
+                br#"{"enumProp":"oldEnumMember","oldProp":"foo","unionProp":10}"#,
+            ),
+        ))
+    }
+}
+
+fn v1_client(recorder: Arc<V1Recorder>) -> RenamedFromClient {
+    let options = RenamedFromClientOptions {
+        client_options: ClientOptions {
+            transport: Some(Transport::new(recorder)),
+            ..Default::default()
+        },
+        version: ApiVersion::V1,
+    };
+    RenamedFromClient::with_no_credential("http://localhost:3000", Some(options)).unwrap()
+}
+
+fn assert_v1_body(recorder: &V1Recorder) {
+    assert_eq!(
+        *recorder.bodies.lock().unwrap(),
+        vec![serde_json::json!({"enumProp": "oldEnumMember", "oldProp": "foo", "unionProp": 10})]
+    );
+}
+
+fn new_model() -> NewModel {
+    NewModel {
+        enum_prop: Some(NewEnum::NewEnumMember),
+        new_prop: Some("foo".to_string()),
+        union_prop: Some(NewUnion::Int32(10)),
+    }
+}
+
+fn assert_new_model(model: NewModel) {
+    assert_eq!(model.enum_prop, Some(NewEnum::NewEnumMember));
+    assert_eq!(model.new_prop, Some("foo".to_string()));
+    match model.union_prop {
+        Some(NewUnion::Int32(value)) => assert_eq!(value, 10),
+        other => panic!("unexpected union value {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn new_op() {
+    let client = RenamedFromClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .new_op(new_model().try_into().unwrap(), "test", None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_new_model(resp);
+}
+
+#[tokio::test]
+async fn new_op_in_new_interface() {
+    let client = RenamedFromClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_renamed_from_new_interface_client()
+        .new_op_in_new_interface(new_model().try_into().unwrap(), None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_new_model(resp);
+}
+
+#[tokio::test]
+async fn new_op_with_old_version() {
+    let recorder = Arc::new(V1Recorder::default());
+    let client = v1_client(recorder.clone());
+    let resp = client
+        .new_op(new_model().try_into().unwrap(), "test", None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_new_model(resp);
+    assert_eq!(
+        *recorder.urls.lock().unwrap(),
+        vec!["http://localhost:3000/versioning/renamed-from/api-version:v1/test?oldQuery=test"]
+    );
+    assert_v1_body(&recorder);
+}
+
+#[tokio::test]
+async fn new_op_in_new_interface_with_old_version() {
+    let recorder = Arc::new(V1Recorder::default());
+    let client = v1_client(recorder.clone());
+    let resp = client
+        .get_renamed_from_new_interface_client()
+        .new_op_in_new_interface(new_model().try_into().unwrap(), None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_new_model(resp);
+    assert_v1_body(&recorder);
+}
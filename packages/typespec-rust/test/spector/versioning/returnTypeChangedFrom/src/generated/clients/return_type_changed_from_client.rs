@@ -0,0 +1,133 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    from_previous_wire_format, ApiVersion, JsonKind, ReturnTypeChangedFromClientTestOptions,
+    WireChange,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientOptions, Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response,
+        Url, UrlExt,
+    },
+    tracing, Result,
+};
+
+/// Test for the `@returnTypeChangedFrom` decorator.
+#[tracing::client]
+pub struct ReturnTypeChangedFromClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+    pub(crate) version: ApiVersion,
+}
+
+/// Options used when creating a [`ReturnTypeChangedFromClient`](ReturnTypeChangedFromClient)
+#[derive(Clone, SafeDebug)]
+pub struct ReturnTypeChangedFromClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+    /// Need to be set as 'v1' or 'v2' in client.
+    pub version: ApiVersion,
+}
+
+impl ReturnTypeChangedFromClient {
+    /// Creates a new ReturnTypeChangedFromClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Versioning.ReturnTypeChangedFrom")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<ReturnTypeChangedFromClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let mut endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        let mut host = String::from("versioning/return-type-changed-from/api-version:{version}");
+        host = host.replace("{version}", options.version.as_ref());
+        endpoint = endpoint.join(&host)?;
+        Ok(Self {
+            endpoint,
+            version: options.version,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Versioning.ReturnTypeChangedFrom.test")]
+    pub async fn test(
+        &self,
+        body: RequestContent<String>,
+        options: Option<ReturnTypeChangedFromClientTestOptions<'_>>,
+    ) -> Result<Response<String>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/test");
+        let mut request = Request::new(url, Method::Post);
+        request.insert_header("accept", "application/json");
+        request.insert_header("content-type", "application/json");
+        request.set_body(body);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        let response_wire_changes: &[WireChange] = if [ApiVersion::V1].contains(&self.version) {
+            &[WireChange::Type {
+                path: &[],
+                kind: JsonKind::String,
+                previous: JsonKind::Number,
+            }]
+        } else {
+            &[]
+        };
+        Ok(from_previous_wire_format(rsp, response_wire_changes)?.into())
+    }
+}
+
+/// Default value for [`ReturnTypeChangedFromClientOptions::version`].
+pub(crate) const DEFAULT_VERSION: ApiVersion = ApiVersion::V2;
+
+impl Default for ReturnTypeChangedFromClientOptions {
+    fn default() -> Self {
+        Self {
+            client_options: ClientOptions::default(),
+            version: DEFAULT_VERSION,
+        }
+    }
+}
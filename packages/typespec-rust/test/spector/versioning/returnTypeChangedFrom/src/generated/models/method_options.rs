@@ -0,0 +1,13 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`ReturnTypeChangedFromClient::test()`](crate::generated::clients::ReturnTypeChangedFromClient::test())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ReturnTypeChangedFromClientTestOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
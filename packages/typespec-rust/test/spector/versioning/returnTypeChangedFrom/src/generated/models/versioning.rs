@@ -0,0 +1,111 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    http::RawResponse,
+    json::{from_json, to_json},
+    Result, Value,
+};
+
+/// A change to the JSON wire format of a body made in a later API version.
+///
+/// The path contains the wire names of the enclosing properties in the latest API version, with `*` for the elements of arrays and dictionaries.
+pub(crate) enum WireChange {
+    /// The type of a scalar value was changed.
+    Type {
+        path: &'static [&'static str],
+        kind: JsonKind,
+        previous: JsonKind,
+    },
+}
+
+/// The kinds of JSON values a scalar type is sent as.
+#[derive(Clone, Copy)]
+pub(crate) enum JsonKind {
+    Number,
+    String,
+}
+
+impl WireChange {
+    fn path(&self) -> &'static [&'static str] {
+        match self {
+            Self::Type { path, .. } => path,
+        }
+    }
+
+    fn apply(&self, value: &mut Value, to_previous: bool) {
+        match *self {
+            Self::Type {
+                path,
+                kind,
+                previous,
+            } => {
+                let kind = if to_previous { previous } else { kind };
+                visit(value, path, &mut |item| convert(item, kind));
+            }
+        }
+    }
+}
+
+/// Converts a JSON response body from the wire format of an earlier API version to the latest wire format.
+pub(crate) fn from_previous_wire_format(
+    rsp: RawResponse,
+    changes: &[WireChange],
+) -> Result<RawResponse> {
+    let (status, headers, body) = rsp.deconstruct();
+    if changes.is_empty() || body.is_empty() {
+        return Ok(RawResponse::from_bytes(status, headers, body));
+    }
+    let mut value: Value = from_json(&body)?;
+    // enclosing values are changed first so nested paths contain the latest wire names
+    let mut changes: Vec<&WireChange> = changes.iter().collect();
+    changes.sort_by_key(|change| change.path().len());
+    for change in changes {
+        change.apply(&mut value, false);
+    }
+    Ok(RawResponse::from_bytes(status, headers, to_json(&value)?))
+}
+
+/// Calls f for each value at the path within value.
+fn visit(value: &mut Value, path: &[&str], f: &mut dyn FnMut(&mut Value)) {
+    let Some((first, rest)) = path.split_first() else {
+        f(value);
+        return;
+    };
+    match value {
+        Value::Array(items) if *first == "*" => {
+            for item in items {
+                visit(item, rest, f);
+            }
+        }
+        Value::Object(object) if *first == "*" => {
+            for item in object.values_mut() {
+                visit(item, rest, f);
+            }
+        }
+        Value::Object(object) => {
+            if let Some(item) = object.get_mut(*first) {
+                visit(item, rest, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Converts a JSON scalar to the specified kind. Values that can't be converted are left unchanged.
+fn convert(value: &mut Value, kind: JsonKind) {
+    let converted = match (kind, &*value) {
+        (JsonKind::Number, Value::String(s)) => {
+            from_json::<_, Value>(s).ok().filter(Value::is_number)
+        }
+        (JsonKind::String, Value::Bool(_) | Value::Number(_)) => {
+            Some(Value::String(value.to_string()))
+        }
+        _ => None,
+    };
+    if let Some(converted) = converted {
+        *value = converted;
+    }
+}
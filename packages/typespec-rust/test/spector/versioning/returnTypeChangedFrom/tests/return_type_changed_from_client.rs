@@ -0,0 +1,56 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::{
+    http::{
+        headers::Headers, AsyncRawResponse, ClientOptions, HttpClient, Request, RequestContent,
+        StatusCode, Transport,
+    },
+    Bytes, Result,
+};
+use spector_returntypechanged::{
+    models::ApiVersion, ReturnTypeChangedFromClient, ReturnTypeChangedFromClientOptions,
+};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test() {
+    let client =
+        ReturnTypeChangedFromClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = RequestContent::try_from("test".to_string()).unwrap();
+    let resp: String = client.test(body, None).await.unwrap().into_model().unwrap();
+    assert_eq!(resp, "test");
+}
+
+/// Responds with the v1 return type, an int32.
+#[derive(Debug)]
+struct V1Responder;
+
+#[async_trait::async_trait]
+impl HttpClient for V1Responder {
+    async fn execute_request(&self, _request: &Request) -> Result<AsyncRawResponse> {
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::Ok,
+            Headers::new(),
+            Bytes::from_static(b"10"),
+        ))
+    }
+}
+
+#[tokio::test]
+async fn test_with_old_version() {
+    let options = ReturnTypeChangedFromClientOptions {
+        client_options: ClientOptions {
+            transport: Some(Transport::new(Arc::new(V1Responder))),
+            ..Default::default()
+        },
+        version: ApiVersion::V1,
+    };
+    let client =
+        ReturnTypeChangedFromClient::with_no_credential("http://localhost:3000", Some(options))
+            .unwrap();
+    let body = RequestContent::try_from("test".to_string()).unwrap();
+    let resp: String = client.test(body, None).await.unwrap().into_model().unwrap();
+    assert_eq!(resp, "10");
+}
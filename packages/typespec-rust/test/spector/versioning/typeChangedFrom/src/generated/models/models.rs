@@ -0,0 +1,16 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct TestModel {
+    #[serde(rename = "changedProp", skip_serializing_if = "Option::is_none")]
+    pub changed_prop: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prop: Option<String>,
+}
@@ -0,0 +1,86 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::{
+    http::{
+        headers::Headers, AsyncRawResponse, Body, ClientOptions, HttpClient, Request, StatusCode,
+        Transport,
+    },
+    Bytes, Result, Value,
+};
+use spector_typechanged::{
+    models::{ApiVersion, TestModel},
+    TypeChangedFromClient, TypeChangedFromClientOptions,
+};
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn test() {
+    let client = TypeChangedFromClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let body = TestModel {
+        changed_prop: Some("bar".to_string()),
+        prop: Some("foo".to_string()),
+    };
+    let resp = client
+        .test(body.try_into().unwrap(), "baz", None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.changed_prop, Some("bar".to_string()));
+    assert_eq!(resp.prop, Some("foo".to_string()));
+}
+
+/// Records the body of each request and responds with a TestModel in the v1 wire format.
+#[derive(Debug, Default)]
+struct V1Recorder {
+    bodies: Mutex<Vec<Value>>,
+}
+
+#[async_trait::async_trait]
+impl HttpClient for V1Recorder {
+    async fn execute_request(&self, request: &Request) -> Result<AsyncRawResponse> {
+        if let Body::Bytes(bytes) = request.body() {
+            self.bodies
+                .lock()
+                .unwrap()
+                .push(serde_json::from_slice(bytes).unwrap());
+        }
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::Ok,
+            Headers::new(),
+            Bytes::from_static(br#"{"changedProp":20,"prop":"foo"}"#),
+        ))
+    }
+}
+
+#[tokio::test]
+async fn test_with_old_version() {
+    let recorder = Arc::new(V1Recorder::default());
+    let options = TypeChangedFromClientOptions {
+        client_options: ClientOptions {
+            transport: Some(Transport::new(recorder.clone())),
+            ..Default::default()
+        },
+        version: ApiVersion::V1,
+    };
+    let client =
+        TypeChangedFromClient::with_no_credential("http://localhost:3000", Some(options)).unwrap();
+    let body = TestModel {
+        changed_prop: Some("10".to_string()),
+        prop: Some("foo".to_string()),
+    };
+    let resp = client
+        .test(body.try_into().unwrap(), "baz", None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(resp.changed_prop, Some("20".to_string()));
+    assert_eq!(resp.prop, Some("foo".to_string()));
+    assert_eq!(
+        *recorder.bodies.lock().unwrap(),
+        vec![serde_json::json!({"changedProp": 10, "prop": "foo"})]
+    );
+}